@@ -1,6 +1,7 @@
 const BRIDGES: &[&str] = &[
     "src/lib.rs",
     "src/nft.rs",
+    "src/gov.rs",
     "src/contract.rs",
     "src/ethereum.rs",
     "src/uint.rs",
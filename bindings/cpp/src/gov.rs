@@ -0,0 +1,121 @@
+use anyhow::{anyhow, Result};
+use defi_wallet_core_common::{GovClient, GovProposalStatus};
+
+#[cxx::bridge(namespace = "org::defi_wallet_core")]
+mod ffi {
+    unsafe extern "C++" {
+        include!("defi-wallet-core-cpp/include/nft.h");
+        type Pagination = crate::nft::ffi::Pagination;
+    }
+
+    extern "Rust" {
+        type GovGrpcClient;
+        /// Create a new grpc client of the governance module
+        fn new_gov_grpc_client(grpc_url: String) -> Result<Box<GovGrpcClient>>;
+        /// Proposal queries the proposal of the given ID (json)
+        fn proposal(self: &GovGrpcClient, proposal_id: u64) -> Result<String>;
+        /// Proposals queries the proposals (json) filtered by the status ("deposit_period",
+        /// "voting_period", "passed", "rejected", "failed" or empty for any),
+        /// the voter and the depositor (empty for any)
+        fn proposals(
+            self: &GovGrpcClient,
+            status: String,
+            voter: String,
+            depositor: String,
+            pagination: &Pagination,
+        ) -> Result<String>;
+        /// TallyResult queries the current tally of the given proposal (json)
+        fn tally_result(self: &GovGrpcClient, proposal_id: u64) -> Result<String>;
+        /// Deposits queries the deposits of the given proposal (json)
+        fn deposits(
+            self: &GovGrpcClient,
+            proposal_id: u64,
+            pagination: &Pagination,
+        ) -> Result<String>;
+        /// Vote queries the vote of the voter on the given proposal (json)
+        fn vote(self: &GovGrpcClient, proposal_id: u64, voter: String) -> Result<String>;
+        /// Params queries the voting, deposit and tallying parameters (json)
+        fn params(self: &GovGrpcClient) -> Result<String>;
+    }
+}
+
+fn proposal_status(status: &str) -> Result<Option<GovProposalStatus>> {
+    match status {
+        "" => Ok(None),
+        "deposit_period" => Ok(Some(GovProposalStatus::DepositPeriod)),
+        "voting_period" => Ok(Some(GovProposalStatus::VotingPeriod)),
+        "passed" => Ok(Some(GovProposalStatus::Passed)),
+        "rejected" => Ok(Some(GovProposalStatus::Rejected)),
+        "failed" => Ok(Some(GovProposalStatus::Failed)),
+        _ => Err(anyhow!("Invalid proposal status: {}", status)),
+    }
+}
+
+/// Wrapper of `GovClient`
+/// It is a rust opaque type, internals can not be seen in C++
+pub struct GovGrpcClient(GovClient);
+
+/// Create a new grpc client of the governance module
+// It can only be defined outside the `impl GovGrpcClient`, otherwise the mod ffi can not find it
+pub fn new_gov_grpc_client(grpc_url: String) -> Result<Box<GovGrpcClient>> {
+    let client = GovClient::new_blocking(grpc_url)?;
+    Ok(Box::new(GovGrpcClient(client)))
+}
+
+impl GovGrpcClient {
+    /// Proposal queries the proposal of the given ID
+    pub fn proposal(&self, proposal_id: u64) -> Result<String> {
+        let proposal = self
+            .0
+            .proposal_blocking(proposal_id)?
+            .ok_or_else(|| anyhow!("No proposal"))?;
+        Ok(serde_json::to_string(&proposal)?)
+    }
+
+    /// Proposals queries the proposals filtered by the status, the voter and the depositor
+    pub fn proposals(
+        &self,
+        status: String,
+        voter: String,
+        depositor: String,
+        pagination: &ffi::Pagination,
+    ) -> Result<String> {
+        let proposals = self.0.proposals_blocking(
+            proposal_status(&status)?,
+            voter,
+            depositor,
+            pagination.into(),
+        )?;
+        Ok(serde_json::to_string(&proposals)?)
+    }
+
+    /// TallyResult queries the current tally of the given proposal
+    pub fn tally_result(&self, proposal_id: u64) -> Result<String> {
+        let tally = self
+            .0
+            .tally_result_blocking(proposal_id)?
+            .ok_or_else(|| anyhow!("No tally result"))?;
+        Ok(serde_json::to_string(&tally)?)
+    }
+
+    /// Deposits queries the deposits of the given proposal
+    pub fn deposits(&self, proposal_id: u64, pagination: &ffi::Pagination) -> Result<String> {
+        let deposits = self.0.deposits_blocking(proposal_id, pagination.into())?;
+        Ok(serde_json::to_string(&deposits)?)
+    }
+
+    /// Vote queries the vote of the voter on the given proposal
+    pub fn vote(&self, proposal_id: u64, voter: String) -> Result<String> {
+        let vote = self
+            .0
+            .vote_blocking(proposal_id, voter)?
+            .ok_or_else(|| anyhow!("No vote"))?;
+        Ok(serde_json::to_string(&vote)?)
+    }
+
+    /// Params queries the voting, deposit and tallying parameters
+    pub fn params(&self) -> Result<String> {
+        let params = self.0.params_blocking()?;
+        Ok(serde_json::to_string(&params)?)
+    }
+}
@@ -2,11 +2,22 @@ use anyhow::{anyhow, Result};
 use cxx::{type_id, ExternType};
 use defi_wallet_core_common::node::ethereum::provider::set_ethers_httpagent;
 use defi_wallet_core_common::{
-    broadcast_tx_sync_blocking, build_signed_msg_tx, build_signed_single_msg_tx,
-    get_account_balance_blocking, get_account_details_blocking, get_single_msg_sign_payload,
-    CosmosSDKMsg, CosmosSDKTxInfo, EthError, EthNetwork, EthTxInfo, HDWallet, Height, LoginInfo,
-    Network, PublicKeyBytesWrapper, RawRpcAccountResponse, SecretKey, SingleCoin,
-    TransactionReceipt, TxBroadcastResult, WalletCoin, COMPRESSED_SECP256K1_PUBKEY_SIZE,
+    broadcast_tx_and_wait_blocking, broadcast_tx_sync_blocking, build_multisig_partial_signature,
+    build_packet_forward_memo, build_signed_msg_tx, build_signed_multisig_tx,
+    build_signed_single_msg_tx, get_account_balance_blocking, get_account_details_blocking,
+    get_delegation_rewards_blocking, get_delegations_blocking, get_fee_allowances_blocking,
+    get_grantee_grants_blocking, get_granter_grants_blocking, get_grants_blocking,
+    get_ibc_timeout_blocking, get_redelegations_blocking, get_single_msg_sign_payload,
+    get_staking_params_blocking, get_unbonding_delegations_blocking, get_validators_blocking,
+    get_vesting_balances, parse_psbt, sign_psbt, Authorization, BaseParser, BitcoinAddressType,
+    BitcoinNetwork, ChainInfo, CosmosError, CosmosEventListener, CosmosSDKClient, CosmosSDKMsg,
+    CosmosSDKTxInfo, CosmosSubscription, CosmosSubscriptionEvent, CosmosTxBuilder, DecimalCoin,
+    DiscoveredAddress, EthError, EthNetwork, EthTxInfo, FeeAllowance, GasPriceSource, HDWallet,
+    Height, IbcClient, LoginInfo, MultisigPartialSignature, MultisigPublicKey, Network,
+    PacketForwardHop, ParamChange, ProposalContent, PublicKeyBytesWrapper, RawRpcAccountResponse,
+    SecretKey, SingleCoin, Slip39Group, StakeAuthorizationType, TransactionReceipt,
+    TxBroadcastResult, TxHistoryClient, TxHistoryFilter, ValidatorBondStatus, VoteOption,
+    WalletCoin, WeightedVoteOption, COMPRESSED_SECP256K1_PUBKEY_SIZE,
 };
 
 use ethers::types::Signature;
@@ -15,6 +26,8 @@ use std::str::FromStr;
 use std::sync::Arc;
 mod nft;
 
+mod gov;
+
 mod contract;
 
 mod uint;
@@ -155,6 +168,8 @@ pub enum CosmosSDKMsgRaw {
         /// Timeout timestamp (in nanoseconds) relative to the current block timestamp.
         /// The timeout is disabled when set to 0.
         timeout_timestamp: u64,
+        /// the memo, e.g. a packet forward middleware memo (empty if none)
+        memo: String,
     },
 }
 
@@ -272,6 +287,7 @@ impl From<&CosmosSDKMsgRaw> for CosmosSDKMsg {
                 revision_height,
                 revision_number,
                 timeout_timestamp,
+                memo,
             } => CosmosSDKMsg::IbcTransfer {
                 receiver: receiver.to_owned(),
                 source_port: source_port.to_owned(),
@@ -285,6 +301,7 @@ impl From<&CosmosSDKMsgRaw> for CosmosSDKMsg {
                     revision_number: *revision_number,
                 },
                 timeout_timestamp: *timeout_timestamp,
+                memo: ibc_transfer_memo(memo),
             },
         }
     }
@@ -310,6 +327,20 @@ pub mod ffi {
         CosmosHub,
         /// Ethereum
         Ethereum,
+        /// Bitcoin mainnet native segwit (BIP84)
+        Bitcoin,
+        /// Bitcoin mainnet legacy (BIP44)
+        BitcoinLegacy,
+        /// Bitcoin mainnet nested segwit (BIP49)
+        BitcoinNestedSegwit,
+        /// Bitcoin mainnet taproot (BIP86)
+        BitcoinTaproot,
+        /// Bitcoin testnet native segwit (BIP84)
+        BitcoinTestnet,
+        /// Solana
+        Solana,
+        /// Aptos
+        Aptos,
     }
 
     pub enum MnemonicWordCount {
@@ -321,6 +352,35 @@ pub mod ffi {
         TwentyFour,
     }
 
+    pub enum MnemonicLanguage {
+        English,
+        ChineseSimplified,
+        ChineseTraditional,
+        Czech,
+        French,
+        Italian,
+        Japanese,
+        Korean,
+        Spanish,
+    }
+
+    pub enum KeystoreKdf {
+        /// scrypt (n = 2^13, r = 8, p = 1)
+        Scrypt,
+        /// PBKDF2 with HMAC-SHA256 (c = 262144)
+        Pbkdf2,
+    }
+
+    /// where the gas price of the estimated fee comes from
+    pub enum GasPriceSourceKind {
+        /// a fixed gas price
+        Fixed,
+        /// the current base fee of the `x/feemarket` module (Ethermint-based chains)
+        FeeMarket,
+        /// the minimum gas price configured on the node
+        MinGasPrice,
+    }
+
     pub enum EthAmount {
         /// 10^-18 ETH
         WeiDecimal,
@@ -361,11 +421,115 @@ pub mod ffi {
         pub bech32hrp: String,
         /// the coin type to use
         pub coin_type: u32,
+        /// the fee coins of other denominations (in addition to `fee_amount` of `fee_denom`)
+        pub extra_fees: Vec<CosmosCoinRaw>,
+        /// the fee coins of decimal or large amounts (in addition to the above ones)
+        pub decimal_fees: Vec<CosmosDecimalCoinRaw>,
+        /// the account paying the fee (empty if it is the signer)
+        pub payer: String,
+        /// the account granting the fee allowance to the signer (empty if none)
+        pub granter: String,
+    }
+    /// a coin amount
+    pub struct CosmosCoinRaw {
+        pub amount: u64,
+        pub denom: String,
+    }
+    /// a decimal coin amount in the unit `denom` (e.g. "1.5" cro),
+    /// which is `10^exponent` of `base_denom` (e.g. 8 for "basecro");
+    /// the integer amount in the base denomination has `denom` = `base_denom` and `exponent` 0
+    pub struct CosmosDecimalCoinRaw {
+        pub amount: String,
+        pub denom: String,
+        pub base_denom: String,
+        pub exponent: u32,
+    }
+    /// a multisig member's signature
+    pub struct MultisigPartialSignatureRaw {
+        /// the member's compressed secp256k1 public key
+        pub public_key: Vec<u8>,
+        /// the member's signature
+        pub signature: Vec<u8>,
+    }
+    /// a weighted governance vote option
+    pub struct GovWeightedVoteOptionRaw {
+        /// "yes", "abstain", "no" or "no_with_veto"
+        pub option: String,
+        /// the weight as a decimal string, e.g. "0.5"
+        pub weight: String,
+    }
+    /// a parameter change of the parameter change proposal
+    pub struct GovParamChangeRaw {
+        /// the module subspace, e.g. "staking"
+        pub subspace: String,
+        /// the parameter key, e.g. "MaxValidators"
+        pub key: String,
+        /// the JSON encoded value, e.g. "105"
+        pub value: String,
     }
     pub struct CosmosAccountInfoRaw {
         pub account_number: u64,
         pub sequence_number: u64,
     }
+    /// a hop of a multi-hop IBC transfer via the packet forward middleware
+    pub struct PacketForwardHopRaw {
+        /// the recipient address on the chain the tokens are forwarded to
+        pub receiver: String,
+        /// the port the tokens are forwarded from (usually "transfer")
+        pub port: String,
+        /// the channel the tokens are forwarded through
+        pub channel: String,
+        /// the timeout of the forwarded transfer in seconds (0 for the default one)
+        pub timeout_seconds: u64,
+        /// the number of retries on timeout (negative for the default one)
+        pub retries: i32,
+    }
+    /// the timeout of an IBC transfer
+    pub struct IbcTimeoutRaw {
+        /// the revision number of the counterparty chain (0 if disabled)
+        pub revision_number: u64,
+        /// the block height of the counterparty chain (0 if disabled)
+        pub revision_height: u64,
+        /// the counterparty chain's block time in nanoseconds (0 if disabled)
+        pub timeout_timestamp: u64,
+    }
+    /// the result of validating a BIP39 phrase
+    pub struct MnemonicValidationRaw {
+        /// the number of words in the phrase
+        pub word_count: u32,
+        /// the (zero-based) positions of the words that are not in the wordlist
+        pub invalid_word_positions: Vec<u32>,
+        /// whether the number of words is 12, 15, 18, 21 or 24
+        pub valid_word_count: bool,
+        /// whether the checksum is correct
+        pub checksum_valid: bool,
+        /// whether the language of the wordlist was detected
+        pub language_detected: bool,
+        /// the detected language (English if not detected)
+        pub language: MnemonicLanguage,
+        /// whether the phrase can be used to recover a wallet
+        pub is_valid: bool,
+    }
+    /// the member threshold and the member count of a SLIP-39 group
+    pub struct Slip39GroupRaw {
+        pub member_threshold: u8,
+        pub member_count: u8,
+    }
+    /// the SLIP-39 share mnemonics of a group
+    pub struct Slip39GroupSharesRaw {
+        pub shares: Vec<String>,
+    }
+    /// an address of the wallet with on-chain activity
+    pub struct DiscoveredAddressRaw {
+        /// the address index
+        pub index: u32,
+        /// the address
+        pub address: String,
+        /// the balance in base units
+        pub balance: String,
+        /// the account sequence (Cosmos SDK) or nonce (Ethereum)
+        pub sequence: u64,
+    }
     #[derive(Debug, Default)]
     pub struct CosmosTransactionReceiptRaw {
         /// tendermint transaction hash in hexadecimal
@@ -415,17 +579,157 @@ pub mod ffi {
             api_url: String,
             address: String,
         ) -> Result<CosmosAccountInfoRaw>;
+        /// query the vested, vesting and locked coins of the vesting account at the time
+        /// (in seconds since the Unix epoch) (json)
+        pub fn query_vesting_balances(
+            api_url: String,
+            address: String,
+            time: i64,
+        ) -> Result<String>;
         /// broadcast the cosmos transaction
         pub fn broadcast_tx(
             tendermint_rpc_url: String,
             raw_signed_tx: Vec<u8>,
         ) -> Result<CosmosTransactionReceiptRaw>;
+        /// broadcast the cosmos transaction and wait until it's committed
+        /// (the receipt json has `height` 0 if the transaction was rejected by CheckTx)
+        pub fn broadcast_tx_and_wait(
+            tendermint_rpc_url: String,
+            raw_signed_tx: Vec<u8>,
+            timeout_ms: u64,
+            interval_ms: u64,
+        ) -> Result<String>;
         /// query account balance from cosmos address and denom name
         pub fn query_account_balance(
             grpc_url: String,
             address: String,
             denom: String,
         ) -> Result<String>;
+        /// query the delegations of the delegator (json)
+        pub fn query_delegations(grpc_url: String, delegator_address: String) -> Result<String>;
+        /// query the unbonding delegations of the delegator (json)
+        pub fn query_unbonding_delegations(
+            grpc_url: String,
+            delegator_address: String,
+        ) -> Result<String>;
+        /// query the redelegations of the delegator (json)
+        pub fn query_redelegations(grpc_url: String, delegator_address: String) -> Result<String>;
+        /// query the pending rewards of the delegator (json)
+        pub fn query_delegation_rewards(
+            grpc_url: String,
+            delegator_address: String,
+        ) -> Result<String>;
+        /// query the validators (json) with the given status
+        /// ("bonded", "unbonding", "unbonded" or "" for all of them)
+        pub fn query_validators(grpc_url: String, status: String) -> Result<String>;
+        /// query the staking parameters (json)
+        pub fn query_staking_params(grpc_url: String) -> Result<String>;
+        /// query the authorizations granted by the granter to the grantee (json)
+        /// (all of them if `msg_type_url` is empty)
+        pub fn query_grants(
+            grpc_url: String,
+            granter: String,
+            grantee: String,
+            msg_type_url: String,
+        ) -> Result<String>;
+        /// query the authorizations granted by the granter (json)
+        pub fn query_granter_grants(grpc_url: String, granter: String) -> Result<String>;
+        /// query the authorizations granted to the grantee (json)
+        pub fn query_grantee_grants(grpc_url: String, grantee: String) -> Result<String>;
+        /// query the fee allowances granted to the grantee (json)
+        pub fn query_fee_allowances(grpc_url: String, grantee: String) -> Result<String>;
+        /// query a page of the address transactions (json, latest first) via the Tendermint
+        /// `tx_search` (`filter` is "sent", "received" or "staking", `page` starts from 1
+        /// and `per_page` is at most 100)
+        pub fn query_tx_history(
+            tendermint_rpc_url: String,
+            address: String,
+            filter: String,
+            page: u32,
+            per_page: u8,
+        ) -> Result<String>;
+        /// query a page of the address transactions (json, latest first) via the gRPC
+        /// `GetTxsEvent` service (`filter` is "sent", "received" or "staking"
+        /// and `offset` is the number of the skipped transactions)
+        pub fn query_txs_event(
+            grpc_url: String,
+            address: String,
+            filter: String,
+            offset: u64,
+            limit: u64,
+        ) -> Result<String>;
+        /// query the trace of the IBC denomination ("ibc/{hash}") (json)
+        pub fn query_ibc_denom_trace(api_url: String, hash: String) -> Result<String>;
+        /// query the IBC channel of the port (json)
+        pub fn query_ibc_channel(
+            api_url: String,
+            port_id: String,
+            channel_id: String,
+        ) -> Result<String>;
+        /// query the light client state of the counterparty chain of the IBC channel (json)
+        pub fn query_ibc_channel_client_state(
+            api_url: String,
+            port_id: String,
+            channel_id: String,
+        ) -> Result<String>;
+        /// query the IBC connection (json)
+        pub fn query_ibc_connection(api_url: String, connection_id: String) -> Result<String>;
+        /// returns the IBC transfer timeout after the number of blocks and seconds
+        /// from the latest block of the counterparty chain
+        /// (a zero offset disables the corresponding timeout)
+        pub fn get_ibc_timeout(
+            counterparty_tendermint_rpc_url: String,
+            height_offset: u64,
+            timeout_seconds: u64,
+        ) -> Result<IbcTimeoutRaw>;
+        /// returns the IBC denomination ("ibc/{hash}") of the base denomination
+        /// transferred over the path (e.g. "transfer/channel-0")
+        pub fn get_ibc_denom(path: String, base_denom: String) -> String;
+        /// parses the chain-registry `chain.json` and `assetlist.json` (empty if none)
+        /// into the chain id, bech32 prefix, slip44, network, fee tokens, endpoints
+        /// and assets (json)
+        pub fn parse_chain_registry(chain_json: String, assetlist_json: String) -> Result<String>;
+        /// converts the decimal amount in the unit (e.g. "1.5" cro) to the integer amount
+        /// in the base denomination (e.g. "150000000"); `denom_units_json` is the
+        /// `denom_units` of the denomination metadata
+        pub fn get_base_amount(
+            amount: String,
+            denom: String,
+            base_denom: String,
+            denom_units_json: String,
+        ) -> Result<String>;
+        /// converts the integer amount in the base denomination to the decimal amount
+        /// in the unit; `denom_units_json` is the `denom_units` of the denomination metadata
+        pub fn get_decimal_amount(
+            base_amount: String,
+            denom: String,
+            base_denom: String,
+            denom_units_json: String,
+        ) -> Result<String>;
+        /// returns the packet forward middleware memo of `MsgTransfer`
+        /// forwarding the tokens through the hops in order
+        pub fn get_packet_forward_memo(hops: Vec<PacketForwardHopRaw>) -> Result<String>;
+        type CosmosEventSubscription;
+        /// subscribes to the committed transactions of the address over the Tendermint
+        /// websocket (the filter is "sent", "received" or "staking")
+        /// `on_event` receives the transfer or staking event json and `on_error` the
+        /// connection error from a background thread; it reconnects with a backoff
+        pub fn subscribe_cosmos_events(
+            tendermint_websocket_url: String,
+            address: String,
+            filter: String,
+            on_event: fn(String),
+            on_error: fn(String),
+        ) -> Result<Box<CosmosEventSubscription>>;
+        /// subscribes to the new blocks over the Tendermint websocket
+        /// (`on_event` receives the block height and time json)
+        pub fn subscribe_cosmos_new_blocks(
+            tendermint_websocket_url: String,
+            on_event: fn(String),
+            on_error: fn(String),
+        ) -> Box<CosmosEventSubscription>;
+        /// stops the subscription (no more callbacks)
+        fn unsubscribe(self: &CosmosEventSubscription);
         type PrivateKey;
         type CosmosSDKMsgRaw;
         /// creates the signed transaction for cosmos
@@ -434,6 +738,54 @@ pub mod ffi {
             private_key: &PrivateKey,
             msg: &CosmosSDKMsgRaw,
         ) -> Result<Vec<u8>>;
+        /// estimates the gas limit and the fee of the transaction by simulating it (json);
+        /// `gas_price` is the gas price of `Fixed` (e.g. "0.025basecro") or the fee denomination
+        pub fn estimate_msg_fee(
+            grpc_url: String,
+            api_url: String,
+            tx_info: CosmosSDKTxInfoRaw,
+            sender_pubkey: Vec<u8>,
+            msg: &CosmosSDKMsgRaw,
+            gas_price_source: GasPriceSourceKind,
+            gas_price: String,
+            gas_adjustment: f64,
+        ) -> Result<String>;
+        /// creates the signed transaction for cosmos with the estimated gas limit and fee;
+        /// `gas_price` is the gas price of `Fixed` (e.g. "0.025basecro") or the fee denomination
+        pub fn get_msg_signed_tx_with_estimated_fee(
+            grpc_url: String,
+            api_url: String,
+            tx_info: CosmosSDKTxInfoRaw,
+            private_key: &PrivateKey,
+            msg: &CosmosSDKMsgRaw,
+            gas_price_source: GasPriceSourceKind,
+            gas_price: String,
+            gas_adjustment: f64,
+        ) -> Result<Vec<u8>>;
+        /// returns the bech32 address of the k-of-n multisig account
+        /// (`public_keys` are the hex-encoded compressed secp256k1 public keys of the members)
+        pub fn get_multisig_address(
+            threshold: u32,
+            public_keys: Vec<String>,
+            bech32hrp: String,
+        ) -> Result<String>;
+        /// signs the transaction from the multisig account as one of its members
+        pub fn get_multisig_partial_signature(
+            tx_info: CosmosSDKTxInfoRaw,
+            private_key: &PrivateKey,
+            msg: &CosmosSDKMsgRaw,
+            threshold: u32,
+            public_keys: Vec<String>,
+        ) -> Result<MultisigPartialSignatureRaw>;
+        /// combines the partial signatures of the multisig account members
+        /// into the signed transaction
+        pub fn get_multisig_signed_tx(
+            tx_info: CosmosSDKTxInfoRaw,
+            msg: &CosmosSDKMsgRaw,
+            threshold: u32,
+            public_keys: Vec<String>,
+            signatures: Vec<MultisigPartialSignatureRaw>,
+        ) -> Result<Vec<u8>>;
         /// creates the transaction signing payload (`SignDoc`)
         /// for `MsgSend` from the Cosmos SDK bank module
         pub fn get_single_bank_send_signdoc(
@@ -453,15 +805,48 @@ pub mod ffi {
             amount: u64,
             denom: String,
         ) -> Result<Vec<u8>>;
+        /// creates the signed transaction
+        /// for `MsgSend` of a decimal coin from the Cosmos SDK bank module
+        #[cxx_name = "get_single_bank_send_signed_tx"]
+        fn get_single_bank_send_decimal_signed_tx(
+            tx_info: CosmosSDKTxInfoRaw,
+            private_key: &PrivateKey,
+            recipient_address: String,
+            coin: CosmosDecimalCoinRaw,
+        ) -> Result<Vec<u8>>;
         type Wallet;
-        /// generates the HD wallet with a BIP39 backup phrase (English words) and password
-        fn new_wallet(password: String, word_count: MnemonicWordCount) -> Result<Box<Wallet>>;
+        /// generates the HD wallet with a BIP39 backup phrase (in the given language) and password
+        fn new_wallet(
+            password: String,
+            word_count: MnemonicWordCount,
+            language: MnemonicLanguage,
+        ) -> Result<Box<Wallet>>;
 
         /// get backup mnemonic phrase
         fn get_backup_mnemonic_phrase(self: &Wallet) -> Result<String>;
 
-        /// generate mnemonics
-        fn generate_mnemonics(password: String, word_count: MnemonicWordCount) -> Result<String>;
+        /// validates a BIP39 phrase (the language is detected automatically)
+        fn validate_mnemonic(mnemonic: String) -> MnemonicValidationRaw;
+        /// validates a BIP39 phrase in the given language
+        #[cxx_name = "validate_mnemonic"]
+        fn validate_mnemonic_in_language(
+            mnemonic: String,
+            language: MnemonicLanguage,
+        ) -> MnemonicValidationRaw;
+
+        /// returns up to `limit` BIP39 words in the given language that start with the prefix
+        fn suggest_mnemonic_words(
+            prefix: String,
+            language: MnemonicLanguage,
+            limit: u32,
+        ) -> Vec<String>;
+
+        /// generate mnemonics (in the given language)
+        fn generate_mnemonics(
+            password: String,
+            word_count: MnemonicWordCount,
+            language: MnemonicLanguage,
+        ) -> Result<String>;
 
         /// recovers/imports HD wallet from a BIP39 backup phrase (English words) and password
         /// and save to secure storage
@@ -479,22 +864,114 @@ pub mod ffi {
             username: String,
         ) -> Result<Box<Wallet>>;
 
-        /// recovers/imports HD wallet from a BIP39 backup phrase (English words) and password
+        /// recovers/imports HD wallet from a BIP39 backup phrase and password
+        /// (the language of the phrase is detected automatically)
         fn restore_wallet(mnemonic: String, password: String) -> Result<Box<Wallet>>;
+        /// recovers/imports HD wallet from a BIP39 backup phrase in the given language and password
+        fn restore_wallet_in_language(
+            mnemonic: String,
+            password: String,
+            language: MnemonicLanguage,
+        ) -> Result<Box<Wallet>>;
+        /// decrypts HD wallet from a keystore JSON created by `Wallet::to_keystore`
+        fn restore_wallet_from_keystore(
+            keystore_json: String,
+            password: String,
+        ) -> Result<Box<Wallet>>;
+        /// encrypts the seed and the backup mnemonic phrase
+        /// into a Web3 Secret Storage (V3) keystore JSON
+        fn to_keystore(self: &Wallet, password: String, kdf: KeystoreKdf) -> Result<String>;
+        /// recovers/imports HD wallet from a quorum of SLIP-39 share mnemonics and passphrase
+        fn restore_wallet_from_slip39_shares(
+            shares: Vec<String>,
+            passphrase: String,
+        ) -> Result<Box<Wallet>>;
+        /// splits the backup mnemonic entropy (or the seed of non-English or password-protected wallets) into SLIP-39 share mnemonics (one list per group)
+        fn to_slip39_shares(
+            self: &Wallet,
+            group_threshold: u8,
+            groups: Vec<Slip39GroupRaw>,
+            passphrase: String,
+            iteration_exponent: u8,
+        ) -> Result<Vec<Slip39GroupSharesRaw>>;
         /// returns the default address of the wallet
         fn get_default_address(self: &Wallet, coin: CoinType) -> Result<String>;
         /// returns the address from index in wallet
         fn get_address(self: &Wallet, coin: CoinType, index: u32) -> Result<String>;
+        /// returns the address from index in wallet of the chain from the chain-registry
+        /// `chain.json`
+        fn get_chain_address(self: &Wallet, chain_json: String, index: u32) -> Result<String>;
         /// returns the ethereum address from index in wallet
         fn get_eth_address(self: &Wallet, index: u32) -> Result<String>;
         /// return the secret key for a given derivation path
         fn get_key(self: &Wallet, derivation_path: String) -> Result<Box<PrivateKey>>;
+        /// returns the extended private key (xprv/yprv/zprv, or tprv/uprv/vprv on testnet) for a given derivation path
+        fn get_extended_private_key(self: &Wallet, derivation_path: String) -> Result<String>;
+        /// returns the extended public key (xpub/ypub/zpub, or tpub/upub/vpub on testnet) for a given derivation path
+        fn get_extended_public_key(self: &Wallet, derivation_path: String) -> Result<String>;
+        /// returns the account-level extended private key (xprv/yprv/zprv, or tprv/uprv/vprv on testnet)
+        fn get_account_extended_private_key(
+            self: &Wallet,
+            coin: CoinType,
+            account: u32,
+        ) -> Result<String>;
+        /// returns the account-level extended public key (xpub/ypub/zpub, or tpub/upub/vpub on testnet)
+        fn get_account_extended_public_key(
+            self: &Wallet,
+            coin: CoinType,
+            account: u32,
+        ) -> Result<String>;
+        /// returns the used addresses of a Cosmos SDK coin type (blocking)
+        /// it stops after `gap_limit` consecutive unused addresses
+        fn discover_cosmos_accounts(
+            self: &Wallet,
+            coin: CoinType,
+            tendermint_rpc_url: String,
+            grpc_url: String,
+            denom: String,
+            gap_limit: u32,
+        ) -> Result<Vec<DiscoveredAddressRaw>>;
+        /// returns the used Ethereum addresses (blocking)
+        /// it stops after `gap_limit` consecutive unused addresses
+        fn discover_eth_accounts(
+            self: &Wallet,
+            web3api_url: String,
+            gap_limit: u32,
+        ) -> Result<Vec<DiscoveredAddressRaw>>;
+        type WatchOnlyWallet;
+        /// constructs a watch-only wallet from an account-level extended public key
+        fn new_watch_only_wallet(
+            extended_public_key: String,
+            coin: CoinType,
+        ) -> Result<Box<WatchOnlyWallet>>;
+        /// returns the extended public key of the watch-only wallet
+        fn get_extended_public_key(self: &WatchOnlyWallet) -> String;
+        /// returns the compressed public key bytes from index in watch-only wallet
+        fn get_public_key_bytes(self: &WatchOnlyWallet, index: u32) -> Result<Vec<u8>>;
+        /// returns the default address of the watch-only wallet
+        fn get_default_address(self: &WatchOnlyWallet) -> Result<String>;
+        /// returns the address from index in watch-only wallet
+        fn get_address(self: &WatchOnlyWallet, index: u32) -> Result<String>;
         /// generates a random private key
         fn new_privatekey() -> Box<PrivateKey>;
         /// constructs private key from bytes
         fn new_privatekey_from_bytes(bytes: Vec<u8>) -> Result<Box<PrivateKey>>;
         /// constructs private key from hex string
         fn new_privatekey_from_hex(hex: String) -> Result<Box<PrivateKey>>;
+        /// decrypts private key from a Web3 Secret Storage (V3) keystore JSON
+        fn new_privatekey_from_keystore(
+            keystore_json: String,
+            password: String,
+        ) -> Result<Box<PrivateKey>>;
+        /// encrypts private key into a Web3 Secret Storage (V3) keystore JSON
+        fn to_keystore(self: &PrivateKey, password: String, kdf: KeystoreKdf) -> Result<String>;
+        /// decrypts a keystore and encrypts it again with a new password
+        fn change_keystore_password(
+            keystore_json: String,
+            old_password: String,
+            new_password: String,
+            kdf: KeystoreKdf,
+        ) -> Result<String>;
         /// creates the signed transaction
         /// for `MsgDelegate` from the Cosmos SDK staking module
         fn get_staking_delegate_signed_tx(
@@ -506,6 +983,16 @@ pub mod ffi {
             with_reward_withdrawal: bool,
         ) -> Result<Vec<u8>>;
         /// creates the signed transaction
+        /// for `MsgDelegate` of a decimal coin from the Cosmos SDK staking module
+        #[cxx_name = "get_staking_delegate_signed_tx"]
+        fn get_staking_delegate_decimal_signed_tx(
+            tx_info: CosmosSDKTxInfoRaw,
+            private_key: &PrivateKey,
+            validator_address: String,
+            coin: CosmosDecimalCoinRaw,
+            with_reward_withdrawal: bool,
+        ) -> Result<Vec<u8>>;
+        /// creates the signed transaction
         /// for `MsgBeginRedelegate` from the Cosmos SDK staking module
         fn get_staking_redelegate_signed_tx(
             tx_info: CosmosSDKTxInfoRaw,
@@ -517,6 +1004,17 @@ pub mod ffi {
             with_reward_withdrawal: bool,
         ) -> Result<Vec<u8>>;
         /// creates the signed transaction
+        /// for `MsgBeginRedelegate` of a decimal coin from the Cosmos SDK staking module
+        #[cxx_name = "get_staking_redelegate_signed_tx"]
+        fn get_staking_redelegate_decimal_signed_tx(
+            tx_info: CosmosSDKTxInfoRaw,
+            private_key: &PrivateKey,
+            validator_src_address: String,
+            validator_dst_address: String,
+            coin: CosmosDecimalCoinRaw,
+            with_reward_withdrawal: bool,
+        ) -> Result<Vec<u8>>;
+        /// creates the signed transaction
         /// for `MsgUndelegate` from the Cosmos SDK staking module
         fn get_staking_unbond_signed_tx(
             tx_info: CosmosSDKTxInfoRaw,
@@ -527,6 +1025,16 @@ pub mod ffi {
             with_reward_withdrawal: bool,
         ) -> Result<Vec<u8>>;
         /// creates the signed transaction
+        /// for `MsgUndelegate` of a decimal coin from the Cosmos SDK staking module
+        #[cxx_name = "get_staking_unbond_signed_tx"]
+        fn get_staking_unbond_decimal_signed_tx(
+            tx_info: CosmosSDKTxInfoRaw,
+            private_key: &PrivateKey,
+            validator_address: String,
+            coin: CosmosDecimalCoinRaw,
+            with_reward_withdrawal: bool,
+        ) -> Result<Vec<u8>>;
+        /// creates the signed transaction
         /// for `MsgSetWithdrawAddress` from the Cosmos SDK distributon module
         fn get_distribution_set_withdraw_address_signed_tx(
             tx_info: CosmosSDKTxInfoRaw,
@@ -553,7 +1061,187 @@ pub mod ffi {
             revision_height: u64,
             revision_number: u64,
             timeout_timestamp: u64,
+            memo: String,
+        ) -> Result<Vec<u8>>;
+        /// creates the signed transaction
+        /// for `MsgTransfer` of a decimal coin from the Cosmos SDK ibc module
+        #[cxx_name = "get_ibc_transfer_signed_tx"]
+        fn get_ibc_transfer_decimal_signed_tx(
+            tx_info: CosmosSDKTxInfoRaw,
+            private_key: &PrivateKey,
+            receiver: String,
+            source_port: String,
+            source_channel: String,
+            token: CosmosDecimalCoinRaw,
+            revision_height: u64,
+            revision_number: u64,
+            timeout_timestamp: u64,
+            memo: String,
+        ) -> Result<Vec<u8>>;
+        /// creates the signed transaction
+        /// for `MsgVote` from the Cosmos SDK gov module
+        /// (`option` is "yes", "abstain", "no" or "no_with_veto")
+        fn get_gov_vote_signed_tx(
+            tx_info: CosmosSDKTxInfoRaw,
+            private_key: &PrivateKey,
+            proposal_id: u64,
+            option: String,
+        ) -> Result<Vec<u8>>;
+        /// creates the signed transaction
+        /// for `MsgVoteWeighted` from the Cosmos SDK gov module
+        fn get_gov_vote_weighted_signed_tx(
+            tx_info: CosmosSDKTxInfoRaw,
+            private_key: &PrivateKey,
+            proposal_id: u64,
+            options: Vec<GovWeightedVoteOptionRaw>,
+        ) -> Result<Vec<u8>>;
+        /// creates the signed transaction
+        /// for `MsgDeposit` from the Cosmos SDK gov module
+        fn get_gov_deposit_signed_tx(
+            tx_info: CosmosSDKTxInfoRaw,
+            private_key: &PrivateKey,
+            proposal_id: u64,
+            amount: u64,
+            denom: String,
+        ) -> Result<Vec<u8>>;
+        /// creates the signed transaction
+        /// for `MsgDeposit` of a decimal coin from the Cosmos SDK gov module
+        #[cxx_name = "get_gov_deposit_signed_tx"]
+        fn get_gov_deposit_decimal_signed_tx(
+            tx_info: CosmosSDKTxInfoRaw,
+            private_key: &PrivateKey,
+            proposal_id: u64,
+            coin: CosmosDecimalCoinRaw,
+        ) -> Result<Vec<u8>>;
+        /// creates the signed transaction
+        /// for `MsgSubmitProposal` with a `TextProposal` from the Cosmos SDK gov module
+        /// (a zero `initial_deposit` is omitted)
+        fn get_gov_submit_text_proposal_signed_tx(
+            tx_info: CosmosSDKTxInfoRaw,
+            private_key: &PrivateKey,
+            title: String,
+            description: String,
+            initial_deposit: u64,
+            denom: String,
+        ) -> Result<Vec<u8>>;
+        /// creates the signed transaction
+        /// for `MsgSubmitProposal` with a `ParameterChangeProposal` from the Cosmos SDK gov module
+        /// (a zero `initial_deposit` is omitted)
+        fn get_gov_submit_param_change_proposal_signed_tx(
+            tx_info: CosmosSDKTxInfoRaw,
+            private_key: &PrivateKey,
+            title: String,
+            description: String,
+            changes: Vec<GovParamChangeRaw>,
+            initial_deposit: u64,
+            denom: String,
+        ) -> Result<Vec<u8>>;
+        /// creates the signed transaction
+        /// for `MsgGrant` with a `GenericAuthorization` from the Cosmos SDK authz module
+        /// (`expiration` is in seconds since the Unix epoch, 0 for no expiration)
+        fn get_authz_grant_generic_signed_tx(
+            tx_info: CosmosSDKTxInfoRaw,
+            private_key: &PrivateKey,
+            grantee: String,
+            msg_type_url: String,
+            expiration: i64,
+        ) -> Result<Vec<u8>>;
+        /// creates the signed transaction
+        /// for `MsgGrant` with a `SendAuthorization` from the Cosmos SDK authz module
+        /// (`expiration` is in seconds since the Unix epoch, 0 for no expiration)
+        fn get_authz_grant_send_signed_tx(
+            tx_info: CosmosSDKTxInfoRaw,
+            private_key: &PrivateKey,
+            grantee: String,
+            spend_limit: u64,
+            denom: String,
+            expiration: i64,
+        ) -> Result<Vec<u8>>;
+        /// creates the signed transaction
+        /// for `MsgGrant` with a `StakeAuthorization` from the Cosmos SDK authz module
+        /// (`authorization_type` is "delegate", "undelegate" or "redelegate",
+        /// `max_tokens` is 0 for no limit, at most one of the validator lists can be set
+        /// and `expiration` is in seconds since the Unix epoch, 0 for no expiration)
+        fn get_authz_grant_stake_signed_tx(
+            tx_info: CosmosSDKTxInfoRaw,
+            private_key: &PrivateKey,
+            grantee: String,
+            authorization_type: String,
+            max_tokens: u64,
+            denom: String,
+            allow_list: Vec<String>,
+            deny_list: Vec<String>,
+            expiration: i64,
         ) -> Result<Vec<u8>>;
+        /// creates the signed transaction
+        /// for `MsgExec` from the Cosmos SDK authz module
+        /// (the message is executed on behalf of the granter)
+        fn get_authz_exec_signed_tx(
+            tx_info: CosmosSDKTxInfoRaw,
+            private_key: &PrivateKey,
+            granter: String,
+            msg: &CosmosSDKMsgRaw,
+        ) -> Result<Vec<u8>>;
+        /// creates the signed transaction
+        /// for `MsgRevoke` from the Cosmos SDK authz module
+        fn get_authz_revoke_signed_tx(
+            tx_info: CosmosSDKTxInfoRaw,
+            private_key: &PrivateKey,
+            grantee: String,
+            msg_type_url: String,
+        ) -> Result<Vec<u8>>;
+        /// creates the signed transaction
+        /// for `MsgGrantAllowance` with a `BasicAllowance` from the Cosmos SDK feegrant module
+        /// (`spend_limit` is 0 for no limit and `expiration` is in seconds since the Unix epoch,
+        /// 0 for no expiration)
+        fn get_feegrant_grant_basic_allowance_signed_tx(
+            tx_info: CosmosSDKTxInfoRaw,
+            private_key: &PrivateKey,
+            grantee: String,
+            spend_limit: u64,
+            denom: String,
+            expiration: i64,
+        ) -> Result<Vec<u8>>;
+        /// creates the signed transaction
+        /// for `MsgGrantAllowance` with a `PeriodicAllowance` from the Cosmos SDK feegrant module
+        /// (`spend_limit` is 0 for no limit, `period` is in seconds and `expiration` is in seconds
+        /// since the Unix epoch, 0 for no expiration)
+        fn get_feegrant_grant_periodic_allowance_signed_tx(
+            tx_info: CosmosSDKTxInfoRaw,
+            private_key: &PrivateKey,
+            grantee: String,
+            spend_limit: u64,
+            period_spend_limit: u64,
+            denom: String,
+            period: u64,
+            expiration: i64,
+        ) -> Result<Vec<u8>>;
+        /// creates the signed transaction
+        /// for `MsgRevokeAllowance` from the Cosmos SDK feegrant module
+        fn get_feegrant_revoke_allowance_signed_tx(
+            tx_info: CosmosSDKTxInfoRaw,
+            private_key: &PrivateKey,
+            grantee: String,
+        ) -> Result<Vec<u8>>;
+        /// creates the signed transaction
+        /// for `MsgCreateVestingAccount` from the Cosmos SDK vesting module
+        /// (`end_time` is in seconds since the Unix epoch)
+        fn get_vesting_create_account_signed_tx(
+            tx_info: CosmosSDKTxInfoRaw,
+            private_key: &PrivateKey,
+            to_address: String,
+            amount: u64,
+            denom: String,
+            end_time: i64,
+            delayed: bool,
+        ) -> Result<Vec<u8>>;
+
+        /// parses a base64-encoded PSBT (BIP174)
+        /// returns the json of its inputs, outputs and fee
+        fn parse_bitcoin_psbt(psbt: String, testnet: bool) -> Result<String>;
+        /// signs the inputs of a base64-encoded PSBT (BIP174) that spend the outputs of the key
+        /// returns the base64-encoded PSBT with the partial signatures
+        fn sign_bitcoin_psbt(psbt: String, private_key: &PrivateKey) -> Result<String>;
 
         type CppLoginInfo;
         /// Create Login Info by `msg`
@@ -685,6 +1373,28 @@ impl From<CoinType> for WalletCoin {
             CoinType::CosmosHub => WalletCoin::CosmosSDK {
                 network: Network::CosmosHub,
             },
+            CoinType::Bitcoin => WalletCoin::Bitcoin {
+                network: BitcoinNetwork::Mainnet,
+                address_type: BitcoinAddressType::P2wpkh,
+            },
+            CoinType::BitcoinLegacy => WalletCoin::Bitcoin {
+                network: BitcoinNetwork::Mainnet,
+                address_type: BitcoinAddressType::P2pkh,
+            },
+            CoinType::BitcoinNestedSegwit => WalletCoin::Bitcoin {
+                network: BitcoinNetwork::Mainnet,
+                address_type: BitcoinAddressType::P2shP2wpkh,
+            },
+            CoinType::BitcoinTaproot => WalletCoin::Bitcoin {
+                network: BitcoinNetwork::Mainnet,
+                address_type: BitcoinAddressType::P2tr,
+            },
+            CoinType::BitcoinTestnet => WalletCoin::Bitcoin {
+                network: BitcoinNetwork::Testnet,
+                address_type: BitcoinAddressType::P2wpkh,
+            },
+            CoinType::Solana => WalletCoin::Solana,
+            CoinType::Aptos => WalletCoin::Aptos,
             _ => WalletCoin::Ethereum {
                 network: EthNetwork::Mainnet,
             },
@@ -703,6 +1413,69 @@ impl From<MnemonicWordCount> for defi_wallet_core_common::MnemonicWordCount {
     }
 }
 
+use ffi::MnemonicLanguage;
+impl From<MnemonicLanguage> for defi_wallet_core_common::MnemonicLanguage {
+    fn from(language: MnemonicLanguage) -> Self {
+        match language {
+            MnemonicLanguage::ChineseSimplified => {
+                defi_wallet_core_common::MnemonicLanguage::ChineseSimplified
+            }
+            MnemonicLanguage::ChineseTraditional => {
+                defi_wallet_core_common::MnemonicLanguage::ChineseTraditional
+            }
+            MnemonicLanguage::Czech => defi_wallet_core_common::MnemonicLanguage::Czech,
+            MnemonicLanguage::French => defi_wallet_core_common::MnemonicLanguage::French,
+            MnemonicLanguage::Italian => defi_wallet_core_common::MnemonicLanguage::Italian,
+            MnemonicLanguage::Japanese => defi_wallet_core_common::MnemonicLanguage::Japanese,
+            MnemonicLanguage::Korean => defi_wallet_core_common::MnemonicLanguage::Korean,
+            MnemonicLanguage::Spanish => defi_wallet_core_common::MnemonicLanguage::Spanish,
+            _ => defi_wallet_core_common::MnemonicLanguage::English,
+        }
+    }
+}
+
+impl From<defi_wallet_core_common::MnemonicLanguage> for MnemonicLanguage {
+    fn from(language: defi_wallet_core_common::MnemonicLanguage) -> Self {
+        match language {
+            defi_wallet_core_common::MnemonicLanguage::English => MnemonicLanguage::English,
+            defi_wallet_core_common::MnemonicLanguage::ChineseSimplified => {
+                MnemonicLanguage::ChineseSimplified
+            }
+            defi_wallet_core_common::MnemonicLanguage::ChineseTraditional => {
+                MnemonicLanguage::ChineseTraditional
+            }
+            defi_wallet_core_common::MnemonicLanguage::Czech => MnemonicLanguage::Czech,
+            defi_wallet_core_common::MnemonicLanguage::French => MnemonicLanguage::French,
+            defi_wallet_core_common::MnemonicLanguage::Italian => MnemonicLanguage::Italian,
+            defi_wallet_core_common::MnemonicLanguage::Japanese => MnemonicLanguage::Japanese,
+            defi_wallet_core_common::MnemonicLanguage::Korean => MnemonicLanguage::Korean,
+            defi_wallet_core_common::MnemonicLanguage::Spanish => MnemonicLanguage::Spanish,
+        }
+    }
+}
+
+use ffi::KeystoreKdf;
+impl From<KeystoreKdf> for defi_wallet_core_common::KeystoreKdf {
+    fn from(kdf: KeystoreKdf) -> Self {
+        match kdf {
+            KeystoreKdf::Pbkdf2 => defi_wallet_core_common::KeystoreKdf::Pbkdf2,
+            _ => defi_wallet_core_common::KeystoreKdf::Scrypt,
+        }
+    }
+}
+
+use ffi::GasPriceSourceKind;
+/// `gas_price` is the gas price of `Fixed` or the fee denomination of the others
+fn gas_price_source(kind: GasPriceSourceKind, gas_price: String) -> Result<GasPriceSource> {
+    Ok(match kind {
+        GasPriceSourceKind::FeeMarket => GasPriceSource::FeeMarket { denom: gas_price },
+        GasPriceSourceKind::MinGasPrice => GasPriceSource::MinGasPrice { denom: gas_price },
+        _ => GasPriceSource::Fixed {
+            gas_price: gas_price.parse().map_err(CosmosError::from)?,
+        },
+    })
+}
+
 pub struct PrivateKey {
     key: Arc<SecretKey>,
 }
@@ -732,7 +1505,37 @@ fn new_privatekey_from_hex(hex: String) -> Result<Box<PrivateKey>> {
     }))
 }
 
+/// decrypts private key from a Web3 Secret Storage (V3) keystore JSON
+fn new_privatekey_from_keystore(
+    keystore_json: String,
+    password: String,
+) -> Result<Box<PrivateKey>> {
+    Ok(Box::new(PrivateKey {
+        key: Arc::new(SecretKey::from_keystore(keystore_json, password)?),
+    }))
+}
+
+/// decrypts a keystore and encrypts it again with a new password
+fn change_keystore_password(
+    keystore_json: String,
+    old_password: String,
+    new_password: String,
+    kdf: KeystoreKdf,
+) -> Result<String> {
+    Ok(defi_wallet_core_common::change_keystore_password(
+        keystore_json,
+        old_password,
+        new_password,
+        Some(kdf.into()),
+    )?)
+}
+
 impl PrivateKey {
+    /// encrypts private key into a Web3 Secret Storage (V3) keystore JSON
+    pub fn to_keystore(&self, password: String, kdf: KeystoreKdf) -> Result<String> {
+        Ok(self.key.to_keystore(password, Some(kdf.into()))?)
+    }
+
     /// gets public key to byte array
     pub fn get_public_key_bytes(&self) -> Vec<u8> {
         self.key.get_public_key_bytes()
@@ -758,23 +1561,97 @@ pub struct Wallet {
     wallet: HDWallet,
 }
 
-/// generates the HD wallet with a BIP39 backup phrase (English words) and password
-fn new_wallet(password: String, word_count: MnemonicWordCount) -> Result<Box<Wallet>> {
-    let wallet = HDWallet::generate_wallet(Some(password), Some(word_count.into()))?;
+/// generates the HD wallet with a BIP39 backup phrase (in the given language) and password
+fn new_wallet(
+    password: String,
+    word_count: MnemonicWordCount,
+    language: MnemonicLanguage,
+) -> Result<Box<Wallet>> {
+    let wallet = HDWallet::generate_wallet(
+        Some(password),
+        Some(word_count.into()),
+        Some(language.into()),
+    )?;
     Ok(Box::new(Wallet { wallet }))
 }
 
-/// generate mnemonics
-fn generate_mnemonics(password: String, word_count: MnemonicWordCount) -> Result<String> {
-    let wallet = HDWallet::generate_wallet(Some(password), Some(word_count.into()))?;
+/// generate mnemonics (in the given language)
+fn generate_mnemonics(
+    password: String,
+    word_count: MnemonicWordCount,
+    language: MnemonicLanguage,
+) -> Result<String> {
+    let wallet = HDWallet::generate_wallet(
+        Some(password),
+        Some(word_count.into()),
+        Some(language.into()),
+    )?;
     wallet
         .get_backup_mnemonic_phrase()
         .ok_or_else(|| anyhow!("Cannot generate new mnemonics"))
 }
 
-/// recovers/imports HD wallet from a BIP39 backup phrase (English words) and password
+/// validates a BIP39 phrase (the language is detected automatically)
+fn validate_mnemonic(mnemonic: String) -> ffi::MnemonicValidationRaw {
+    defi_wallet_core_common::validate_mnemonic(mnemonic, None).into()
+}
+
+/// validates a BIP39 phrase in the given language
+fn validate_mnemonic_in_language(
+    mnemonic: String,
+    language: MnemonicLanguage,
+) -> ffi::MnemonicValidationRaw {
+    defi_wallet_core_common::validate_mnemonic(mnemonic, Some(language.into())).into()
+}
+
+impl From<defi_wallet_core_common::MnemonicValidation> for ffi::MnemonicValidationRaw {
+    fn from(validation: defi_wallet_core_common::MnemonicValidation) -> Self {
+        ffi::MnemonicValidationRaw {
+            word_count: validation.word_count,
+            invalid_word_positions: validation.invalid_word_positions,
+            valid_word_count: validation.valid_word_count,
+            checksum_valid: validation.checksum_valid,
+            language_detected: validation.language.is_some(),
+            language: validation.language.unwrap_or_default().into(),
+            is_valid: validation.is_valid,
+        }
+    }
+}
+
+/// returns up to `limit` BIP39 words in the given language that start with the prefix
+fn suggest_mnemonic_words(prefix: String, language: MnemonicLanguage, limit: u32) -> Vec<String> {
+    defi_wallet_core_common::suggest_mnemonic_words(prefix, Some(language.into()), limit)
+}
+
+/// recovers/imports HD wallet from a BIP39 backup phrase and password
+/// (the language of the phrase is detected automatically)
 fn restore_wallet(mnemonic: String, password: String) -> Result<Box<Wallet>> {
-    let wallet = HDWallet::recover_wallet(mnemonic, Some(password))?;
+    let wallet = HDWallet::recover_wallet(mnemonic, Some(password), None)?;
+    Ok(Box::new(Wallet { wallet }))
+}
+
+/// recovers/imports HD wallet from a BIP39 backup phrase in the given language and password
+fn restore_wallet_in_language(
+    mnemonic: String,
+    password: String,
+    language: MnemonicLanguage,
+) -> Result<Box<Wallet>> {
+    let wallet = HDWallet::recover_wallet(mnemonic, Some(password), Some(language.into()))?;
+    Ok(Box::new(Wallet { wallet }))
+}
+
+/// decrypts HD wallet from a keystore JSON created by `Wallet::to_keystore`
+fn restore_wallet_from_keystore(keystore_json: String, password: String) -> Result<Box<Wallet>> {
+    let wallet = HDWallet::from_keystore(keystore_json, password)?;
+    Ok(Box::new(Wallet { wallet }))
+}
+
+/// recovers/imports HD wallet from a quorum of SLIP-39 share mnemonics and passphrase
+fn restore_wallet_from_slip39_shares(
+    shares: Vec<String>,
+    passphrase: String,
+) -> Result<Box<Wallet>> {
+    let wallet = HDWallet::recover_from_slip39_shares(shares, Some(passphrase))?;
     Ok(Box::new(Wallet { wallet }))
 }
 
@@ -793,7 +1670,7 @@ fn restore_wallet_save_to_securestorage(
     let infojson = serde_json::to_string(&securestorageinfo)?;
     let entry = keyring::Entry::new(&servicename, &username)?;
     entry.set_password(&infojson)?;
-    let wallet = HDWallet::recover_wallet(mnemonic, Some(password))?;
+    let wallet = HDWallet::recover_wallet(mnemonic, Some(password), None)?;
     Ok(Box::new(Wallet { wallet }))
 }
 
@@ -806,8 +1683,11 @@ fn restore_wallet_load_from_securestorage(
 
     let infojson = entry.get_password()?;
     let securestorageinfo: SecureStorageWaleltInfo = serde_json::from_str(&infojson)?;
-    let wallet =
-        HDWallet::recover_wallet(securestorageinfo.mnemonic, Some(securestorageinfo.password))?;
+    let wallet = HDWallet::recover_wallet(
+        securestorageinfo.mnemonic,
+        Some(securestorageinfo.password),
+        None,
+    )?;
     Ok(Box::new(Wallet { wallet }))
 }
 
@@ -832,7 +1712,7 @@ fn restore_wallet_save_to_securestorage(
     if result == 0 {
         return Err(anyhow!("Cannot save to secure storage"));
     }
-    let wallet = HDWallet::recover_wallet(mnemonic, Some(password))?;
+    let wallet = HDWallet::recover_wallet(mnemonic, Some(password), None)?;
     Ok(Box::new(Wallet { wallet }))
 }
 
@@ -857,8 +1737,11 @@ fn restore_wallet_load_from_securestorage(
 
     let infojson = androidinfo.result;
     let securestorageinfo: SecureStorageWaleltInfo = serde_json::from_str(&infojson)?;
-    let wallet =
-        HDWallet::recover_wallet(securestorageinfo.mnemonic, Some(securestorageinfo.password))?;
+    let wallet = HDWallet::recover_wallet(
+        securestorageinfo.mnemonic,
+        Some(securestorageinfo.password),
+        None,
+    )?;
     Ok(Box::new(Wallet { wallet }))
 }
 
@@ -870,6 +1753,39 @@ impl Wallet {
             .ok_or_else(|| anyhow!("No backup mnemonic phrase"))
     }
 
+    /// encrypts the seed and the backup mnemonic phrase
+    /// into a Web3 Secret Storage (V3) keystore JSON
+    pub fn to_keystore(&self, password: String, kdf: KeystoreKdf) -> Result<String> {
+        Ok(self.wallet.to_keystore(password, Some(kdf.into()))?)
+    }
+
+    /// splits the backup mnemonic entropy (or the seed of non-English or password-protected wallets) into SLIP-39 share mnemonics (one list per group)
+    pub fn to_slip39_shares(
+        &self,
+        group_threshold: u8,
+        groups: Vec<ffi::Slip39GroupRaw>,
+        passphrase: String,
+        iteration_exponent: u8,
+    ) -> Result<Vec<ffi::Slip39GroupSharesRaw>> {
+        let groups = groups
+            .into_iter()
+            .map(|group| Slip39Group {
+                member_threshold: group.member_threshold,
+                member_count: group.member_count,
+            })
+            .collect();
+        let shares = self.wallet.to_slip39_shares(
+            group_threshold,
+            groups,
+            Some(passphrase),
+            Some(iteration_exponent),
+        )?;
+        Ok(shares
+            .into_iter()
+            .map(|shares| ffi::Slip39GroupSharesRaw { shares })
+            .collect())
+    }
+
     /// returns the default address of the wallet
     pub fn get_default_address(&self, coin: CoinType) -> Result<String> {
         self.get_address(coin, 0)
@@ -880,6 +1796,16 @@ impl Wallet {
         Ok(self.wallet.get_address(coin.into(), index)?)
     }
 
+    /// returns the address from index in wallet of the chain from the chain-registry
+    /// `chain.json`
+    pub fn get_chain_address(&self, chain_json: String, index: u32) -> Result<String> {
+        let chain_info = ChainInfo::from_registry(&chain_json, None)?;
+        let coin = WalletCoin::CosmosSDK {
+            network: chain_info.network,
+        };
+        Ok(self.wallet.get_address(coin, index)?)
+    }
+
     /// returns the ethereum address from index in wallet
     pub fn get_eth_address(&self, index: u32) -> Result<String> {
         self.get_address(CoinType::Ethereum, index)
@@ -890,6 +1816,133 @@ impl Wallet {
         let key = self.wallet.get_key(derivation_path)?;
         Ok(Box::new(PrivateKey { key }))
     }
+
+    /// returns the extended private key (xprv/yprv/zprv, or tprv/uprv/vprv on testnet) for a given derivation path
+    pub fn get_extended_private_key(&self, derivation_path: String) -> Result<String> {
+        Ok(self.wallet.get_extended_private_key(derivation_path)?)
+    }
+
+    /// returns the extended public key (xpub/ypub/zpub, or tpub/upub/vpub on testnet) for a given derivation path
+    pub fn get_extended_public_key(&self, derivation_path: String) -> Result<String> {
+        Ok(self.wallet.get_extended_public_key(derivation_path)?)
+    }
+
+    /// returns the account-level extended private key (xprv/yprv/zprv, or tprv/uprv/vprv on testnet)
+    pub fn get_account_extended_private_key(&self, coin: CoinType, account: u32) -> Result<String> {
+        Ok(self
+            .wallet
+            .get_account_extended_private_key(coin.into(), account)?)
+    }
+
+    /// returns the account-level extended public key (xpub/ypub/zpub, or tpub/upub/vpub on testnet)
+    pub fn get_account_extended_public_key(&self, coin: CoinType, account: u32) -> Result<String> {
+        Ok(self
+            .wallet
+            .get_account_extended_public_key(coin.into(), account)?)
+    }
+
+    /// returns the used addresses of a Cosmos SDK coin type (blocking)
+    /// it stops after `gap_limit` consecutive unused addresses
+    pub fn discover_cosmos_accounts(
+        &self,
+        coin: CoinType,
+        tendermint_rpc_url: String,
+        grpc_url: String,
+        denom: String,
+        gap_limit: u32,
+    ) -> Result<Vec<ffi::DiscoveredAddressRaw>> {
+        let network = match WalletCoin::from(coin) {
+            WalletCoin::CosmosSDK { network } => network,
+            _ => return Err(anyhow!("Not a Cosmos SDK coin type")),
+        };
+        let client = CosmosSDKClient::new(tendermint_rpc_url, grpc_url);
+        let addresses =
+            self.wallet
+                .discover_cosmos_accounts(network, &client, &denom, Some(gap_limit))?;
+        Ok(addresses.into_iter().map(|x| x.into()).collect())
+    }
+
+    /// returns the used Ethereum addresses (blocking)
+    /// it stops after `gap_limit` consecutive unused addresses
+    pub fn discover_eth_accounts(
+        &self,
+        web3api_url: String,
+        gap_limit: u32,
+    ) -> Result<Vec<ffi::DiscoveredAddressRaw>> {
+        let addresses = self.wallet.discover_eth_accounts(
+            EthNetwork::Mainnet,
+            &web3api_url,
+            Some(gap_limit),
+        )?;
+        Ok(addresses.into_iter().map(|x| x.into()).collect())
+    }
+}
+
+impl From<DiscoveredAddress> for ffi::DiscoveredAddressRaw {
+    fn from(address: DiscoveredAddress) -> Self {
+        ffi::DiscoveredAddressRaw {
+            index: address.index,
+            address: address.address,
+            balance: address.balance,
+            sequence: address.sequence,
+        }
+    }
+}
+
+pub struct WatchOnlyWallet {
+    wallet: defi_wallet_core_common::WatchOnlyWallet,
+}
+
+/// constructs a watch-only wallet from an account-level extended public key
+fn new_watch_only_wallet(
+    extended_public_key: String,
+    coin: CoinType,
+) -> Result<Box<WatchOnlyWallet>> {
+    let wallet = defi_wallet_core_common::WatchOnlyWallet::new(extended_public_key, coin.into())?;
+    Ok(Box::new(WatchOnlyWallet { wallet }))
+}
+
+impl WatchOnlyWallet {
+    /// returns the extended public key of the watch-only wallet
+    pub fn get_extended_public_key(&self) -> String {
+        self.wallet.get_extended_public_key()
+    }
+
+    /// returns the compressed public key bytes from index in watch-only wallet
+    pub fn get_public_key_bytes(&self, index: u32) -> Result<Vec<u8>> {
+        Ok(self.wallet.get_public_key_bytes(index)?)
+    }
+
+    /// returns the default address of the watch-only wallet
+    pub fn get_default_address(&self) -> Result<String> {
+        Ok(self.wallet.get_default_address()?)
+    }
+
+    /// returns the address from index in watch-only wallet
+    pub fn get_address(&self, index: u32) -> Result<String> {
+        Ok(self.wallet.get_address(index)?)
+    }
+}
+
+impl From<ffi::CosmosDecimalCoinRaw> for SingleCoin {
+    fn from(coin: ffi::CosmosDecimalCoinRaw) -> Self {
+        SingleCoin::Decimal {
+            amount: coin.amount,
+            denom: coin.denom,
+            base_denom: coin.base_denom,
+            exponent: coin.exponent,
+        }
+    }
+}
+
+/// the coin of the integer amount in the base denomination
+fn integer_coin(amount: u64, denom: String) -> ffi::CosmosDecimalCoinRaw {
+    ffi::CosmosDecimalCoinRaw {
+        amount: amount.to_string(),
+        base_denom: denom.clone(),
+        denom,
+        exponent: 0,
+    }
 }
 
 impl From<ffi::CosmosSDKTxInfoRaw> for CosmosSDKTxInfo {
@@ -898,10 +1951,18 @@ impl From<ffi::CosmosSDKTxInfoRaw> for CosmosSDKTxInfo {
             account_number: info.account_number,
             sequence_number: info.sequence_number,
             gas_limit: info.gas_limit,
-            fee_amount: SingleCoin::Other {
-                amount: info.fee_amount.to_string(),
-                denom: info.fee_denom,
-            },
+            fee_amount: std::iter::once((info.fee_amount, info.fee_denom))
+                .chain(
+                    info.extra_fees
+                        .into_iter()
+                        .map(|fee| (fee.amount, fee.denom)),
+                )
+                .map(|(amount, denom)| SingleCoin::Other {
+                    amount: amount.to_string(),
+                    denom,
+                })
+                .chain(info.decimal_fees.into_iter().map(Into::into))
+                .collect(),
             timeout_height: info.timeout_height,
             memo_note: Some(info.memo_note),
             network: Network::Other {
@@ -909,6 +1970,8 @@ impl From<ffi::CosmosSDKTxInfoRaw> for CosmosSDKTxInfo {
                 coin_type: info.coin_type,
                 bech32hrp: info.bech32hrp,
             },
+            payer: Some(info.payer).filter(|payer| !payer.is_empty()),
+            granter: Some(info.granter).filter(|granter| !granter.is_empty()),
         }
     }
 }
@@ -951,15 +2014,28 @@ pub fn get_single_bank_send_signed_tx(
     recipient_address: String,
     amount: u64,
     denom: String,
+) -> Result<Vec<u8>> {
+    get_single_bank_send_decimal_signed_tx(
+        tx_info,
+        private_key,
+        recipient_address,
+        integer_coin(amount, denom),
+    )
+}
+
+/// creates the signed transaction
+/// for `MsgSend` of a decimal coin from the Cosmos SDK bank module
+pub fn get_single_bank_send_decimal_signed_tx(
+    tx_info: ffi::CosmosSDKTxInfoRaw,
+    private_key: &PrivateKey,
+    recipient_address: String,
+    coin: ffi::CosmosDecimalCoinRaw,
 ) -> Result<Vec<u8>> {
     let ret = build_signed_single_msg_tx(
         tx_info.into(),
         CosmosSDKMsg::BankSend {
             recipient_address,
-            amount: SingleCoin::Other {
-                amount: format!("{}", amount),
-                denom,
-            },
+            amount: coin.into(),
         },
         private_key.key.clone(),
     )?;
@@ -976,13 +2052,28 @@ pub fn get_staking_delegate_signed_tx(
     amount: u64,
     denom: String,
     with_reward_withdrawal: bool,
+) -> Result<Vec<u8>> {
+    get_staking_delegate_decimal_signed_tx(
+        tx_info,
+        private_key,
+        validator_address,
+        integer_coin(amount, denom),
+        with_reward_withdrawal,
+    )
+}
+
+/// creates the signed transaction
+/// for `MsgDelegate` of a decimal coin from the Cosmos SDK staking module
+pub fn get_staking_delegate_decimal_signed_tx(
+    tx_info: ffi::CosmosSDKTxInfoRaw,
+    private_key: &PrivateKey,
+    validator_address: String,
+    coin: ffi::CosmosDecimalCoinRaw,
+    with_reward_withdrawal: bool,
 ) -> Result<Vec<u8>> {
     let mut messages = vec![CosmosSDKMsg::StakingDelegate {
         validator_address: validator_address.clone(),
-        amount: SingleCoin::Other {
-            amount: format!("{}", amount),
-            denom,
-        },
+        amount: coin.into(),
     }];
 
     if with_reward_withdrawal {
@@ -1002,14 +2093,31 @@ pub fn get_staking_redelegate_signed_tx(
     amount: u64,
     denom: String,
     with_reward_withdrawal: bool,
+) -> Result<Vec<u8>> {
+    get_staking_redelegate_decimal_signed_tx(
+        tx_info,
+        private_key,
+        validator_src_address,
+        validator_dst_address,
+        integer_coin(amount, denom),
+        with_reward_withdrawal,
+    )
+}
+
+/// creates the signed transaction
+/// for `MsgBeginRedelegate` of a decimal coin from the Cosmos SDK staking module
+pub fn get_staking_redelegate_decimal_signed_tx(
+    tx_info: ffi::CosmosSDKTxInfoRaw,
+    private_key: &PrivateKey,
+    validator_src_address: String,
+    validator_dst_address: String,
+    coin: ffi::CosmosDecimalCoinRaw,
+    with_reward_withdrawal: bool,
 ) -> Result<Vec<u8>> {
     let mut messages = vec![CosmosSDKMsg::StakingBeginRedelegate {
         validator_src_address: validator_src_address.clone(),
         validator_dst_address: validator_dst_address.clone(),
-        amount: SingleCoin::Other {
-            amount: format!("{}", amount),
-            denom,
-        },
+        amount: coin.into(),
     }];
 
     if with_reward_withdrawal {
@@ -1033,13 +2141,28 @@ pub fn get_staking_unbond_signed_tx(
     amount: u64,
     denom: String,
     with_reward_withdrawal: bool,
+) -> Result<Vec<u8>> {
+    get_staking_unbond_decimal_signed_tx(
+        tx_info,
+        private_key,
+        validator_address,
+        integer_coin(amount, denom),
+        with_reward_withdrawal,
+    )
+}
+
+/// creates the signed transaction
+/// for `MsgUndelegate` of a decimal coin from the Cosmos SDK staking module
+pub fn get_staking_unbond_decimal_signed_tx(
+    tx_info: ffi::CosmosSDKTxInfoRaw,
+    private_key: &PrivateKey,
+    validator_address: String,
+    coin: ffi::CosmosDecimalCoinRaw,
+    with_reward_withdrawal: bool,
 ) -> Result<Vec<u8>> {
     let mut messages = vec![CosmosSDKMsg::StakingUndelegate {
         validator_address: validator_address.clone(),
-        amount: SingleCoin::Other {
-            amount: format!("{}", amount),
-            denom,
-        },
+        amount: coin.into(),
     }];
 
     if with_reward_withdrawal {
@@ -1081,6 +2204,15 @@ pub fn get_distribution_withdraw_reward_signed_tx(
     Ok(ret)
 }
 
+/// the optional memo of `MsgTransfer` (none if empty)
+fn ibc_transfer_memo(memo: &str) -> Option<String> {
+    if memo.is_empty() {
+        None
+    } else {
+        Some(memo.to_owned())
+    }
+}
+
 /// creates the signed transaction
 /// for `MsgTransfer` from the Cosmos SDK ibc module
 #[allow(clippy::too_many_arguments)]
@@ -1095,6 +2227,36 @@ pub fn get_ibc_transfer_signed_tx(
     revision_height: u64,
     revision_number: u64,
     timeout_timestamp: u64,
+    memo: String,
+) -> Result<Vec<u8>> {
+    get_ibc_transfer_decimal_signed_tx(
+        tx_info,
+        private_key,
+        receiver,
+        source_port,
+        source_channel,
+        integer_coin(token, denom),
+        revision_height,
+        revision_number,
+        timeout_timestamp,
+        memo,
+    )
+}
+
+/// creates the signed transaction
+/// for `MsgTransfer` of a decimal coin from the Cosmos SDK ibc module
+#[allow(clippy::too_many_arguments)]
+pub fn get_ibc_transfer_decimal_signed_tx(
+    tx_info: ffi::CosmosSDKTxInfoRaw,
+    private_key: &PrivateKey,
+    receiver: String,
+    source_port: String,
+    source_channel: String,
+    token: ffi::CosmosDecimalCoinRaw,
+    revision_height: u64,
+    revision_number: u64,
+    timeout_timestamp: u64,
+    memo: String,
 ) -> Result<Vec<u8>> {
     // TODO: Need to support converting receiver from hex address to bech32 here.
 
@@ -1104,15 +2266,403 @@ pub fn get_ibc_transfer_signed_tx(
             receiver,
             source_port,
             source_channel,
-            token: SingleCoin::Other {
-                amount: format!("{}", token),
-                denom,
-            },
+            token: token.into(),
             timeout_height: Height {
                 revision_height,
                 revision_number,
             },
             timeout_timestamp,
+            memo: ibc_transfer_memo(&memo),
+        },
+        private_key.key.clone(),
+    )?;
+
+    Ok(ret)
+}
+
+/// creates the signed transaction
+/// for `MsgVote` from the Cosmos SDK gov module
+pub fn get_gov_vote_signed_tx(
+    tx_info: ffi::CosmosSDKTxInfoRaw,
+    private_key: &PrivateKey,
+    proposal_id: u64,
+    option: String,
+) -> Result<Vec<u8>> {
+    let ret = build_signed_single_msg_tx(
+        tx_info.into(),
+        CosmosSDKMsg::GovVote {
+            proposal_id,
+            option: option.parse::<VoteOption>()?,
+        },
+        private_key.key.clone(),
+    )?;
+
+    Ok(ret)
+}
+
+/// creates the signed transaction
+/// for `MsgVoteWeighted` from the Cosmos SDK gov module
+pub fn get_gov_vote_weighted_signed_tx(
+    tx_info: ffi::CosmosSDKTxInfoRaw,
+    private_key: &PrivateKey,
+    proposal_id: u64,
+    options: Vec<ffi::GovWeightedVoteOptionRaw>,
+) -> Result<Vec<u8>> {
+    let options = options
+        .into_iter()
+        .map(|option| {
+            Ok(WeightedVoteOption {
+                option: option.option.parse::<VoteOption>()?,
+                weight: option.weight,
+            })
+        })
+        .collect::<Result<_>>()?;
+    let ret = build_signed_single_msg_tx(
+        tx_info.into(),
+        CosmosSDKMsg::GovVoteWeighted {
+            proposal_id,
+            options,
+        },
+        private_key.key.clone(),
+    )?;
+
+    Ok(ret)
+}
+
+/// creates the signed transaction
+/// for `MsgDeposit` from the Cosmos SDK gov module
+pub fn get_gov_deposit_signed_tx(
+    tx_info: ffi::CosmosSDKTxInfoRaw,
+    private_key: &PrivateKey,
+    proposal_id: u64,
+    amount: u64,
+    denom: String,
+) -> Result<Vec<u8>> {
+    get_gov_deposit_decimal_signed_tx(
+        tx_info,
+        private_key,
+        proposal_id,
+        integer_coin(amount, denom),
+    )
+}
+
+/// creates the signed transaction
+/// for `MsgDeposit` of a decimal coin from the Cosmos SDK gov module
+pub fn get_gov_deposit_decimal_signed_tx(
+    tx_info: ffi::CosmosSDKTxInfoRaw,
+    private_key: &PrivateKey,
+    proposal_id: u64,
+    coin: ffi::CosmosDecimalCoinRaw,
+) -> Result<Vec<u8>> {
+    let ret = build_signed_single_msg_tx(
+        tx_info.into(),
+        CosmosSDKMsg::GovDeposit {
+            proposal_id,
+            amount: coin.into(),
+        },
+        private_key.key.clone(),
+    )?;
+
+    Ok(ret)
+}
+
+/// creates the signed transaction
+/// for `MsgSubmitProposal` with a `TextProposal` from the Cosmos SDK gov module
+/// (a zero `initial_deposit` is omitted)
+pub fn get_gov_submit_text_proposal_signed_tx(
+    tx_info: ffi::CosmosSDKTxInfoRaw,
+    private_key: &PrivateKey,
+    title: String,
+    description: String,
+    initial_deposit: u64,
+    denom: String,
+) -> Result<Vec<u8>> {
+    let ret = build_signed_single_msg_tx(
+        tx_info.into(),
+        CosmosSDKMsg::GovSubmitProposal {
+            content: ProposalContent::Text { title, description },
+            initial_deposit: vec![SingleCoin::Other {
+                amount: format!("{}", initial_deposit),
+                denom,
+            }],
+        },
+        private_key.key.clone(),
+    )?;
+
+    Ok(ret)
+}
+
+/// creates the signed transaction
+/// for `MsgSubmitProposal` with a `ParameterChangeProposal` from the Cosmos SDK gov module
+/// (a zero `initial_deposit` is omitted)
+pub fn get_gov_submit_param_change_proposal_signed_tx(
+    tx_info: ffi::CosmosSDKTxInfoRaw,
+    private_key: &PrivateKey,
+    title: String,
+    description: String,
+    changes: Vec<ffi::GovParamChangeRaw>,
+    initial_deposit: u64,
+    denom: String,
+) -> Result<Vec<u8>> {
+    let changes = changes
+        .into_iter()
+        .map(|change| ParamChange {
+            subspace: change.subspace,
+            key: change.key,
+            value: change.value,
+        })
+        .collect();
+    let ret = build_signed_single_msg_tx(
+        tx_info.into(),
+        CosmosSDKMsg::GovSubmitProposal {
+            content: ProposalContent::ParameterChange {
+                title,
+                description,
+                changes,
+            },
+            initial_deposit: vec![SingleCoin::Other {
+                amount: format!("{}", initial_deposit),
+                denom,
+            }],
+        },
+        private_key.key.clone(),
+    )?;
+
+    Ok(ret)
+}
+
+/// the expiration time (0 for no expiration)
+fn expiration(seconds: i64) -> Option<i64> {
+    if seconds == 0 {
+        None
+    } else {
+        Some(seconds)
+    }
+}
+
+/// the spend limit coins (an empty limit for 0)
+fn coins(amount: u64, denom: String) -> Vec<SingleCoin> {
+    if amount == 0 {
+        vec![]
+    } else {
+        vec![SingleCoin::Other {
+            amount: format!("{}", amount),
+            denom,
+        }]
+    }
+}
+
+/// creates the signed transaction
+/// for `MsgGrant` with a `GenericAuthorization` from the Cosmos SDK authz module
+pub fn get_authz_grant_generic_signed_tx(
+    tx_info: ffi::CosmosSDKTxInfoRaw,
+    private_key: &PrivateKey,
+    grantee: String,
+    msg_type_url: String,
+    expiration_time: i64,
+) -> Result<Vec<u8>> {
+    let ret = build_signed_single_msg_tx(
+        tx_info.into(),
+        CosmosSDKMsg::AuthzGrant {
+            grantee,
+            authorization: Authorization::Generic { msg: msg_type_url },
+            expiration: expiration(expiration_time),
+        },
+        private_key.key.clone(),
+    )?;
+
+    Ok(ret)
+}
+
+/// creates the signed transaction
+/// for `MsgGrant` with a `SendAuthorization` from the Cosmos SDK authz module
+pub fn get_authz_grant_send_signed_tx(
+    tx_info: ffi::CosmosSDKTxInfoRaw,
+    private_key: &PrivateKey,
+    grantee: String,
+    spend_limit: u64,
+    denom: String,
+    expiration_time: i64,
+) -> Result<Vec<u8>> {
+    let ret = build_signed_single_msg_tx(
+        tx_info.into(),
+        CosmosSDKMsg::AuthzGrant {
+            grantee,
+            authorization: Authorization::Send {
+                spend_limit: coins(spend_limit, denom),
+            },
+            expiration: expiration(expiration_time),
+        },
+        private_key.key.clone(),
+    )?;
+
+    Ok(ret)
+}
+
+/// creates the signed transaction
+/// for `MsgGrant` with a `StakeAuthorization` from the Cosmos SDK authz module
+#[allow(clippy::too_many_arguments)]
+pub fn get_authz_grant_stake_signed_tx(
+    tx_info: ffi::CosmosSDKTxInfoRaw,
+    private_key: &PrivateKey,
+    grantee: String,
+    authorization_type: String,
+    max_tokens: u64,
+    denom: String,
+    allow_list: Vec<String>,
+    deny_list: Vec<String>,
+    expiration_time: i64,
+) -> Result<Vec<u8>> {
+    let ret = build_signed_single_msg_tx(
+        tx_info.into(),
+        CosmosSDKMsg::AuthzGrant {
+            grantee,
+            authorization: Authorization::Stake {
+                max_tokens: coins(max_tokens, denom).pop(),
+                allow_list,
+                deny_list,
+                authorization_type: authorization_type.parse::<StakeAuthorizationType>()?,
+            },
+            expiration: expiration(expiration_time),
+        },
+        private_key.key.clone(),
+    )?;
+
+    Ok(ret)
+}
+
+/// creates the signed transaction
+/// for `MsgExec` from the Cosmos SDK authz module
+pub fn get_authz_exec_signed_tx(
+    tx_info: ffi::CosmosSDKTxInfoRaw,
+    private_key: &PrivateKey,
+    granter: String,
+    msg: &CosmosSDKMsgRaw,
+) -> Result<Vec<u8>> {
+    let ret = build_signed_single_msg_tx(
+        tx_info.into(),
+        CosmosSDKMsg::AuthzExec {
+            granter,
+            msgs: vec![msg.into()],
+        },
+        private_key.key.clone(),
+    )?;
+
+    Ok(ret)
+}
+
+/// creates the signed transaction
+/// for `MsgRevoke` from the Cosmos SDK authz module
+pub fn get_authz_revoke_signed_tx(
+    tx_info: ffi::CosmosSDKTxInfoRaw,
+    private_key: &PrivateKey,
+    grantee: String,
+    msg_type_url: String,
+) -> Result<Vec<u8>> {
+    let ret = build_signed_single_msg_tx(
+        tx_info.into(),
+        CosmosSDKMsg::AuthzRevoke {
+            grantee,
+            msg_type_url,
+        },
+        private_key.key.clone(),
+    )?;
+
+    Ok(ret)
+}
+
+/// creates the signed transaction
+/// for `MsgGrantAllowance` with a `BasicAllowance` from the Cosmos SDK feegrant module
+pub fn get_feegrant_grant_basic_allowance_signed_tx(
+    tx_info: ffi::CosmosSDKTxInfoRaw,
+    private_key: &PrivateKey,
+    grantee: String,
+    spend_limit: u64,
+    denom: String,
+    expiration_time: i64,
+) -> Result<Vec<u8>> {
+    let ret = build_signed_single_msg_tx(
+        tx_info.into(),
+        CosmosSDKMsg::FeegrantGrantAllowance {
+            grantee,
+            allowance: FeeAllowance::Basic {
+                spend_limit: coins(spend_limit, denom),
+                expiration: expiration(expiration_time),
+            },
+        },
+        private_key.key.clone(),
+    )?;
+
+    Ok(ret)
+}
+
+/// creates the signed transaction
+/// for `MsgGrantAllowance` with a `PeriodicAllowance` from the Cosmos SDK feegrant module
+#[allow(clippy::too_many_arguments)]
+pub fn get_feegrant_grant_periodic_allowance_signed_tx(
+    tx_info: ffi::CosmosSDKTxInfoRaw,
+    private_key: &PrivateKey,
+    grantee: String,
+    spend_limit: u64,
+    period_spend_limit: u64,
+    denom: String,
+    period: u64,
+    expiration_time: i64,
+) -> Result<Vec<u8>> {
+    let ret = build_signed_single_msg_tx(
+        tx_info.into(),
+        CosmosSDKMsg::FeegrantGrantAllowance {
+            grantee,
+            allowance: FeeAllowance::Periodic {
+                spend_limit: coins(spend_limit, denom.clone()),
+                expiration: expiration(expiration_time),
+                period,
+                period_spend_limit: coins(period_spend_limit, denom),
+            },
+        },
+        private_key.key.clone(),
+    )?;
+
+    Ok(ret)
+}
+
+/// creates the signed transaction
+/// for `MsgRevokeAllowance` from the Cosmos SDK feegrant module
+pub fn get_feegrant_revoke_allowance_signed_tx(
+    tx_info: ffi::CosmosSDKTxInfoRaw,
+    private_key: &PrivateKey,
+    grantee: String,
+) -> Result<Vec<u8>> {
+    let ret = build_signed_single_msg_tx(
+        tx_info.into(),
+        CosmosSDKMsg::FeegrantRevokeAllowance { grantee },
+        private_key.key.clone(),
+    )?;
+
+    Ok(ret)
+}
+
+/// creates the signed transaction
+/// for `MsgCreateVestingAccount` from the Cosmos SDK vesting module
+pub fn get_vesting_create_account_signed_tx(
+    tx_info: ffi::CosmosSDKTxInfoRaw,
+    private_key: &PrivateKey,
+    to_address: String,
+    amount: u64,
+    denom: String,
+    end_time: i64,
+    delayed: bool,
+) -> Result<Vec<u8>> {
+    let ret = build_signed_single_msg_tx(
+        tx_info.into(),
+        CosmosSDKMsg::VestingCreateAccount {
+            to_address,
+            amount: vec![SingleCoin::Other {
+                amount: format!("{}", amount),
+                denom,
+            }],
+            end_time,
+            delayed,
         },
         private_key.key.clone(),
     )?;
@@ -1130,6 +2680,136 @@ pub fn get_msg_signed_tx(
     Ok(ret)
 }
 
+/// estimates the gas limit and the fee of the transaction by simulating it (json)
+#[allow(clippy::too_many_arguments)]
+pub fn estimate_msg_fee(
+    grpc_url: String,
+    api_url: String,
+    tx_info: ffi::CosmosSDKTxInfoRaw,
+    sender_pubkey: Vec<u8>,
+    msg: &CosmosSDKMsgRaw,
+    gas_price_source_kind: GasPriceSourceKind,
+    gas_price: String,
+    gas_adjustment: f64,
+) -> Result<String> {
+    let builder = CosmosTxBuilder::new(
+        grpc_url,
+        api_url,
+        gas_price_source(gas_price_source_kind, gas_price)?,
+        Some(gas_adjustment),
+    );
+    let estimate = builder.estimate_fee_blocking(
+        tx_info.into(),
+        vec![msg.into()],
+        PublicKeyBytesWrapper(sender_pubkey),
+    )?;
+    Ok(serde_json::to_string(&estimate)?)
+}
+
+/// creates the signed transaction for cosmos with the estimated gas limit and fee
+#[allow(clippy::too_many_arguments)]
+pub fn get_msg_signed_tx_with_estimated_fee(
+    grpc_url: String,
+    api_url: String,
+    tx_info: ffi::CosmosSDKTxInfoRaw,
+    private_key: &PrivateKey,
+    msg: &CosmosSDKMsgRaw,
+    gas_price_source_kind: GasPriceSourceKind,
+    gas_price: String,
+    gas_adjustment: f64,
+) -> Result<Vec<u8>> {
+    let builder = CosmosTxBuilder::new(
+        grpc_url,
+        api_url,
+        gas_price_source(gas_price_source_kind, gas_price)?,
+        Some(gas_adjustment),
+    );
+    let ret = builder.build_signed_msg_tx_blocking(
+        tx_info.into(),
+        vec![msg.into()],
+        private_key.key.clone(),
+    )?;
+    Ok(ret)
+}
+
+fn multisig_public_key(threshold: u32, public_keys: Vec<String>) -> Result<Arc<MultisigPublicKey>> {
+    let public_keys = public_keys
+        .iter()
+        .map(hex::decode)
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(Arc::new(MultisigPublicKey::new(threshold, public_keys)?))
+}
+
+/// returns the bech32 address of the k-of-n multisig account
+pub fn get_multisig_address(
+    threshold: u32,
+    public_keys: Vec<String>,
+    bech32hrp: String,
+) -> Result<String> {
+    let multisig_key = multisig_public_key(threshold, public_keys)?;
+    Ok(multisig_key.get_address(&bech32hrp)?)
+}
+
+/// signs the transaction from the multisig account as one of its members
+pub fn get_multisig_partial_signature(
+    tx_info: ffi::CosmosSDKTxInfoRaw,
+    private_key: &PrivateKey,
+    msg: &CosmosSDKMsgRaw,
+    threshold: u32,
+    public_keys: Vec<String>,
+) -> Result<ffi::MultisigPartialSignatureRaw> {
+    let signature = build_multisig_partial_signature(
+        tx_info.into(),
+        vec![msg.into()],
+        multisig_public_key(threshold, public_keys)?,
+        private_key.key.clone(),
+    )?;
+    Ok(ffi::MultisigPartialSignatureRaw {
+        public_key: signature.public_key,
+        signature: signature.signature,
+    })
+}
+
+/// combines the partial signatures of the multisig account members into the signed transaction
+pub fn get_multisig_signed_tx(
+    tx_info: ffi::CosmosSDKTxInfoRaw,
+    msg: &CosmosSDKMsgRaw,
+    threshold: u32,
+    public_keys: Vec<String>,
+    signatures: Vec<ffi::MultisigPartialSignatureRaw>,
+) -> Result<Vec<u8>> {
+    let signatures = signatures
+        .into_iter()
+        .map(|s| MultisigPartialSignature {
+            public_key: s.public_key,
+            signature: s.signature,
+        })
+        .collect();
+    let ret = build_signed_multisig_tx(
+        tx_info.into(),
+        vec![msg.into()],
+        multisig_public_key(threshold, public_keys)?,
+        signatures,
+    )?;
+    Ok(ret)
+}
+
+/// parses a base64-encoded PSBT (BIP174) and returns the json of its inputs, outputs and fee
+fn parse_bitcoin_psbt(psbt: String, testnet: bool) -> Result<String> {
+    let network = if testnet {
+        BitcoinNetwork::Testnet
+    } else {
+        BitcoinNetwork::Mainnet
+    };
+    let info = parse_psbt(&psbt, network)?;
+    Ok(serde_json::to_string(&info)?)
+}
+
+/// signs the inputs of a base64-encoded PSBT (BIP174) that spend the outputs of the key
+fn sign_bitcoin_psbt(psbt: String, private_key: &PrivateKey) -> Result<String> {
+    Ok(sign_psbt(&psbt, private_key.key.clone())?)
+}
+
 /// query account details from cosmos address
 pub fn query_account_details(api_url: String, address: String) -> Result<String> {
     let account_details: RawRpcAccountResponse = get_account_details_blocking(&api_url, &address)?;
@@ -1161,6 +2841,29 @@ pub fn query_account_details_info(
     }
 }
 
+/// query the vested, vesting and locked coins of the vesting account at the time (json)
+pub fn query_vesting_balances(api_url: String, address: String, time: i64) -> Result<String> {
+    match get_account_details_blocking(&api_url, &address)? {
+        RawRpcAccountResponse::OkResponse { account } => {
+            let vesting = account
+                .vesting
+                .ok_or_else(|| anyhow!("{} is not a vesting account", address))?;
+            let balances = get_vesting_balances(&vesting, time)?;
+            Ok(serde_json::to_string(&balances)?)
+        }
+        RawRpcAccountResponse::ErrorResponse {
+            code,
+            message,
+            details,
+        } => Err(anyhow!(
+            "RawRpcAccountResponse error {} {} {:?}",
+            code,
+            message,
+            details
+        )),
+    }
+}
+
 /// query account balance from cosmos address and denom name
 pub fn query_account_balance(grpc_url: String, address: String, denom: String) -> Result<String> {
     let balance = get_account_balance_blocking(&grpc_url, &address, &denom)?;
@@ -1168,6 +2871,299 @@ pub fn query_account_balance(grpc_url: String, address: String, denom: String) -
     Ok(serde_json::to_string(&balance)?)
 }
 
+/// query the delegations of the delegator
+pub fn query_delegations(grpc_url: String, delegator_address: String) -> Result<String> {
+    let delegations = get_delegations_blocking(&grpc_url, &delegator_address, None)?;
+    Ok(serde_json::to_string(&delegations)?)
+}
+
+/// query the unbonding delegations of the delegator
+pub fn query_unbonding_delegations(grpc_url: String, delegator_address: String) -> Result<String> {
+    let unbondings = get_unbonding_delegations_blocking(&grpc_url, &delegator_address, None)?;
+    Ok(serde_json::to_string(&unbondings)?)
+}
+
+/// query the redelegations of the delegator
+pub fn query_redelegations(grpc_url: String, delegator_address: String) -> Result<String> {
+    let redelegations = get_redelegations_blocking(&grpc_url, &delegator_address, None)?;
+    Ok(serde_json::to_string(&redelegations)?)
+}
+
+/// query the pending rewards of the delegator
+pub fn query_delegation_rewards(grpc_url: String, delegator_address: String) -> Result<String> {
+    let rewards = get_delegation_rewards_blocking(&grpc_url, &delegator_address)?;
+    Ok(serde_json::to_string(&rewards)?)
+}
+
+/// query the validators with the given status
+pub fn query_validators(grpc_url: String, status: String) -> Result<String> {
+    let status = match status.as_str() {
+        "" => None,
+        "bonded" => Some(ValidatorBondStatus::Bonded),
+        "unbonding" => Some(ValidatorBondStatus::Unbonding),
+        "unbonded" => Some(ValidatorBondStatus::Unbonded),
+        _ => return Err(anyhow!("invalid validator status: {}", status)),
+    };
+    let validators = get_validators_blocking(&grpc_url, status, None)?;
+    Ok(serde_json::to_string(&validators)?)
+}
+
+/// query the staking parameters
+pub fn query_staking_params(grpc_url: String) -> Result<String> {
+    let params = get_staking_params_blocking(&grpc_url)?;
+    Ok(serde_json::to_string(&params)?)
+}
+
+/// query the authorizations granted by the granter to the grantee
+pub fn query_grants(
+    grpc_url: String,
+    granter: String,
+    grantee: String,
+    msg_type_url: String,
+) -> Result<String> {
+    let grants = get_grants_blocking(&grpc_url, &granter, &grantee, &msg_type_url, None)?;
+    Ok(serde_json::to_string(&grants)?)
+}
+
+/// query the authorizations granted by the granter
+pub fn query_granter_grants(grpc_url: String, granter: String) -> Result<String> {
+    let grants = get_granter_grants_blocking(&grpc_url, &granter, None)?;
+    Ok(serde_json::to_string(&grants)?)
+}
+
+/// query the authorizations granted to the grantee
+pub fn query_grantee_grants(grpc_url: String, grantee: String) -> Result<String> {
+    let grants = get_grantee_grants_blocking(&grpc_url, &grantee, None)?;
+    Ok(serde_json::to_string(&grants)?)
+}
+
+/// query the fee allowances granted to the grantee
+pub fn query_fee_allowances(grpc_url: String, grantee: String) -> Result<String> {
+    let allowances = get_fee_allowances_blocking(&grpc_url, &grantee, None)?;
+    Ok(serde_json::to_string(&allowances)?)
+}
+
+fn tx_history_filter(filter: &str) -> Result<TxHistoryFilter> {
+    match filter {
+        "sent" => Ok(TxHistoryFilter::Sent),
+        "received" => Ok(TxHistoryFilter::Received),
+        "staking" => Ok(TxHistoryFilter::Staking),
+        _ => Err(anyhow!("invalid transaction history filter: {}", filter)),
+    }
+}
+
+struct CppEventListener {
+    on_event: fn(String),
+    on_error: fn(String),
+}
+
+impl CosmosEventListener for CppEventListener {
+    fn on_event(&self, event: CosmosSubscriptionEvent) {
+        match serde_json::to_string(&event) {
+            Ok(json) => (self.on_event)(json),
+            Err(e) => (self.on_error)(e.to_string()),
+        }
+    }
+
+    fn on_error(&self, error: String) {
+        (self.on_error)(error)
+    }
+}
+
+pub struct CosmosEventSubscription {
+    subscription: defi_wallet_core_common::CosmosEventSubscription,
+}
+
+impl CosmosEventSubscription {
+    fn new(
+        tendermint_websocket_url: String,
+        subscription: CosmosSubscription,
+        on_event: fn(String),
+        on_error: fn(String),
+    ) -> Box<Self> {
+        Box::new(Self {
+            subscription: defi_wallet_core_common::CosmosEventSubscription::new(
+                tendermint_websocket_url,
+                subscription,
+                Box::new(CppEventListener { on_event, on_error }),
+            ),
+        })
+    }
+
+    /// stops the subscription (no more callbacks)
+    pub fn unsubscribe(&self) {
+        self.subscription.unsubscribe()
+    }
+}
+
+/// subscribes to the committed transactions of the address over the Tendermint websocket
+pub fn subscribe_cosmos_events(
+    tendermint_websocket_url: String,
+    address: String,
+    filter: String,
+    on_event: fn(String),
+    on_error: fn(String),
+) -> Result<Box<CosmosEventSubscription>> {
+    let subscription = CosmosSubscription::Address {
+        address,
+        filter: tx_history_filter(&filter)?,
+    };
+    Ok(CosmosEventSubscription::new(
+        tendermint_websocket_url,
+        subscription,
+        on_event,
+        on_error,
+    ))
+}
+
+/// subscribes to the new blocks over the Tendermint websocket
+pub fn subscribe_cosmos_new_blocks(
+    tendermint_websocket_url: String,
+    on_event: fn(String),
+    on_error: fn(String),
+) -> Box<CosmosEventSubscription> {
+    CosmosEventSubscription::new(
+        tendermint_websocket_url,
+        CosmosSubscription::NewBlock,
+        on_event,
+        on_error,
+    )
+}
+
+/// query a page of the address transactions via the Tendermint `tx_search`
+pub fn query_tx_history(
+    tendermint_rpc_url: String,
+    address: String,
+    filter: String,
+    page: u32,
+    per_page: u8,
+) -> Result<String> {
+    let client = TxHistoryClient::new(tendermint_rpc_url, String::new(), Arc::new(BaseParser));
+    let history =
+        client.search_txs_blocking(&address, tx_history_filter(&filter)?, page, per_page)?;
+    Ok(serde_json::to_string(&history)?)
+}
+
+/// query a page of the address transactions via the gRPC `GetTxsEvent` service
+pub fn query_txs_event(
+    grpc_url: String,
+    address: String,
+    filter: String,
+    offset: u64,
+    limit: u64,
+) -> Result<String> {
+    let client = TxHistoryClient::new(String::new(), grpc_url, Arc::new(BaseParser));
+    let history =
+        client.get_txs_event_blocking(&address, tx_history_filter(&filter)?, offset, limit)?;
+    Ok(serde_json::to_string(&history)?)
+}
+
+/// query the trace of the IBC denomination
+pub fn query_ibc_denom_trace(api_url: String, hash: String) -> Result<String> {
+    let trace = IbcClient::new(api_url).denom_trace_blocking(&hash)?;
+    Ok(serde_json::to_string(&trace)?)
+}
+
+/// query the IBC channel of the port
+pub fn query_ibc_channel(api_url: String, port_id: String, channel_id: String) -> Result<String> {
+    let channel = IbcClient::new(api_url).channel_blocking(&port_id, &channel_id)?;
+    Ok(serde_json::to_string(&channel)?)
+}
+
+/// query the light client state of the counterparty chain of the IBC channel
+pub fn query_ibc_channel_client_state(
+    api_url: String,
+    port_id: String,
+    channel_id: String,
+) -> Result<String> {
+    let client_state =
+        IbcClient::new(api_url).channel_client_state_blocking(&port_id, &channel_id)?;
+    Ok(serde_json::to_string(&client_state)?)
+}
+
+/// query the IBC connection
+pub fn query_ibc_connection(api_url: String, connection_id: String) -> Result<String> {
+    let connection = IbcClient::new(api_url).connection_blocking(&connection_id)?;
+    Ok(serde_json::to_string(&connection)?)
+}
+
+/// returns the IBC transfer timeout from the latest block of the counterparty chain
+pub fn get_ibc_timeout(
+    counterparty_tendermint_rpc_url: String,
+    height_offset: u64,
+    timeout_seconds: u64,
+) -> Result<ffi::IbcTimeoutRaw> {
+    let timeout = get_ibc_timeout_blocking(
+        &counterparty_tendermint_rpc_url,
+        height_offset,
+        timeout_seconds,
+    )?;
+    Ok(ffi::IbcTimeoutRaw {
+        revision_number: timeout.timeout_height.revision_number,
+        revision_height: timeout.timeout_height.revision_height,
+        timeout_timestamp: timeout.timeout_timestamp,
+    })
+}
+
+/// returns the IBC denomination of the base denomination transferred over the path
+pub fn get_ibc_denom(path: String, base_denom: String) -> String {
+    defi_wallet_core_common::get_ibc_denom(&path, &base_denom)
+}
+
+/// parses the chain-registry `chain.json` and `assetlist.json` (empty if none) (json)
+pub fn parse_chain_registry(chain_json: String, assetlist_json: String) -> Result<String> {
+    let assetlist_json = if assetlist_json.is_empty() {
+        None
+    } else {
+        Some(assetlist_json)
+    };
+    let chain_info = defi_wallet_core_common::parse_chain_registry(&chain_json, assetlist_json)?;
+    Ok(serde_json::to_string(&chain_info)?)
+}
+
+/// converts the decimal amount in the unit to the integer amount in the base denomination
+pub fn get_base_amount(
+    amount: String,
+    denom: String,
+    base_denom: String,
+    denom_units_json: String,
+) -> Result<String> {
+    let coin = DecimalCoin::from_denom_units(&amount, &denom, &base_denom, &denom_units_json)?;
+    Ok(coin.base_amount()?)
+}
+
+/// converts the integer amount in the base denomination to the decimal amount in the unit
+pub fn get_decimal_amount(
+    base_amount: String,
+    denom: String,
+    base_denom: String,
+    denom_units_json: String,
+) -> Result<String> {
+    let coin = DecimalCoin::from_base_amount(&base_amount, &denom, &base_denom, &denom_units_json)?;
+    Ok(coin.amount)
+}
+
+/// returns the packet forward middleware memo forwarding the tokens through the hops
+pub fn get_packet_forward_memo(hops: Vec<ffi::PacketForwardHopRaw>) -> Result<String> {
+    let hops = hops
+        .into_iter()
+        .map(|hop| {
+            Ok(PacketForwardHop {
+                receiver: hop.receiver,
+                port: hop.port,
+                channel: hop.channel,
+                timeout_seconds: hop.timeout_seconds,
+                retries: if hop.retries < 0 {
+                    None
+                } else {
+                    Some(u8::try_from(hop.retries)?)
+                },
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(build_packet_forward_memo(&hops)?)
+}
+
 /// broadcast the cosmos transaction
 pub fn broadcast_tx(
     tendermint_rpc_url: String,
@@ -1181,6 +3177,23 @@ pub fn broadcast_tx(
     }
 }
 
+/// broadcast the cosmos transaction and wait until it's committed
+/// (the receipt json has `height` 0 if the transaction was rejected by CheckTx)
+pub fn broadcast_tx_and_wait(
+    tendermint_rpc_url: String,
+    raw_signed_tx: Vec<u8>,
+    timeout_ms: u64,
+    interval_ms: u64,
+) -> Result<String> {
+    let receipt = broadcast_tx_and_wait_blocking(
+        &tendermint_rpc_url,
+        raw_signed_tx,
+        timeout_ms,
+        interval_ms,
+    )?;
+    Ok(serde_json::to_string(&receipt)?)
+}
+
 // create Login Info by `msg`
 /// all information from the EIP-4361 plaintext message:
 /// https://eips.ethereum.org/EIPS/eip-4361
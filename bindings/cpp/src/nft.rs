@@ -6,7 +6,7 @@ use std::fmt;
 
 #[cxx::bridge(namespace = "org::defi_wallet_core")]
 #[allow(clippy::too_many_arguments)]
-mod ffi {
+pub mod ffi {
 
     #[derive(Serialize, Deserialize, Debug)]
     pub struct Denom {
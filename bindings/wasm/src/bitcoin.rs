@@ -0,0 +1,37 @@
+use crate::{format_to_js_error, PrivateKey};
+use defi_wallet_core_common::BitcoinNetwork as CoreBitcoinNetwork;
+use wasm_bindgen::prelude::*;
+
+/// Bitcoin networks
+#[wasm_bindgen]
+pub enum BitcoinNetwork {
+    Mainnet,
+    Testnet,
+    Signet,
+    Regtest,
+}
+
+impl From<BitcoinNetwork> for CoreBitcoinNetwork {
+    fn from(network: BitcoinNetwork) -> Self {
+        match network {
+            BitcoinNetwork::Mainnet => CoreBitcoinNetwork::Mainnet,
+            BitcoinNetwork::Testnet => CoreBitcoinNetwork::Testnet,
+            BitcoinNetwork::Signet => CoreBitcoinNetwork::Signet,
+            BitcoinNetwork::Regtest => CoreBitcoinNetwork::Regtest,
+        }
+    }
+}
+
+/// Parse a base64-encoded PSBT (BIP174) and return its inputs, outputs and fee
+#[wasm_bindgen]
+pub fn parse_bitcoin_psbt(psbt: &str, network: BitcoinNetwork) -> Result<JsValue, JsValue> {
+    let info = defi_wallet_core_common::parse_psbt(psbt, network.into())?;
+    serde_wasm_bindgen::to_value(&info).map_err(format_to_js_error)
+}
+
+/// Sign the inputs of a base64-encoded PSBT (BIP174) that spend the outputs of the key
+/// return the base64-encoded PSBT with the partial signatures
+#[wasm_bindgen]
+pub fn sign_bitcoin_psbt(psbt: &str, private_key: PrivateKey) -> Result<String, JsValue> {
+    Ok(defi_wallet_core_common::sign_psbt(psbt, private_key.key)?)
+}
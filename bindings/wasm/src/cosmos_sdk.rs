@@ -1,17 +1,28 @@
 use crate::{format_to_js_error, PrivateKey};
 use cosmos_sdk_proto::cosmos::base::query::v1beta1::PageRequest;
 use defi_wallet_core_common::{
-    broadcast_tx_sync, build_signed_msg_tx, get_account_balance, get_account_details, node,
-    CosmosSDKMsg, CosmosSDKTxInfo, Height, Network, SingleCoin,
+    broadcast_tx_and_wait, broadcast_tx_sync, build_multisig_partial_signature,
+    build_signed_amino_msg_tx, build_signed_msg_tx, build_signed_multisig_tx, get_account_balance,
+    get_account_details, get_delegation_rewards, get_delegations, get_fee_allowance,
+    get_fee_allowances, get_grantee_grants, get_granter_grants, get_grants, get_ibc_timeout,
+    get_redelegations, get_staking_params, get_unbonding_delegations, get_validators,
+    get_vesting_balances, node, Authorization, BaseParser, CosmosSDKMsg, CosmosSDKTxInfo,
+    CosmosTxBuilder, DecimalCoin, FeeAllowance, GasPriceSource, GovClient, Height, IbcClient,
+    MultisigPartialSignature, MultisigPublicKey, Network, PacketForwardHop, ParamChange,
+    ProposalContent, PublicKeyBytesWrapper, RawRpcAccountResponse, SingleCoin, TxHistoryClient,
+    VoteOption, WeightedVoteOption,
 };
 use js_sys::Promise;
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::future_to_promise;
 
 mod signer;
+mod subscription;
 
 pub use signer::*;
+pub use subscription::*;
 
 /// Cosmos client
 #[wasm_bindgen]
@@ -45,6 +56,343 @@ impl CosmosClient {
         })
     }
 
+    /// Retrieve the vested, vesting and locked coins of a vesting account
+    /// at the time (in seconds since the Unix epoch).
+    pub fn query_vesting_balances(&self, address: String, time: i64) -> Promise {
+        let api_url = self.config.api_url.to_owned();
+        future_to_promise(async move {
+            let account = match get_account_details(&api_url, &address).await? {
+                RawRpcAccountResponse::OkResponse { account } => account,
+                RawRpcAccountResponse::ErrorResponse { message, .. } => {
+                    return Err(JsValue::from_str(&message))
+                }
+            };
+            let vesting = account.vesting.ok_or_else(|| {
+                JsValue::from_str(&format!("{} is not a vesting account", address))
+            })?;
+            let balances = get_vesting_balances(&vesting, time)?;
+            serde_wasm_bindgen::to_value(&balances).map_err(format_to_js_error)
+        })
+    }
+
+    /// Query the delegations of the delegator.
+    pub fn query_delegations(&self, delegator_address: String, offset: u64, limit: u64) -> Promise {
+        let grpc_web_url = self.config.grpc_web_url.to_owned();
+        future_to_promise(async move {
+            let delegations = get_delegations(
+                &grpc_web_url,
+                &delegator_address,
+                Some(page_request(offset, limit)),
+            )
+            .await?;
+            serde_wasm_bindgen::to_value(&delegations).map_err(format_to_js_error)
+        })
+    }
+
+    /// Query the unbonding delegations of the delegator.
+    pub fn query_unbonding_delegations(
+        &self,
+        delegator_address: String,
+        offset: u64,
+        limit: u64,
+    ) -> Promise {
+        let grpc_web_url = self.config.grpc_web_url.to_owned();
+        future_to_promise(async move {
+            let unbondings = get_unbonding_delegations(
+                &grpc_web_url,
+                &delegator_address,
+                Some(page_request(offset, limit)),
+            )
+            .await?;
+            serde_wasm_bindgen::to_value(&unbondings).map_err(format_to_js_error)
+        })
+    }
+
+    /// Query the redelegations of the delegator.
+    pub fn query_redelegations(
+        &self,
+        delegator_address: String,
+        offset: u64,
+        limit: u64,
+    ) -> Promise {
+        let grpc_web_url = self.config.grpc_web_url.to_owned();
+        future_to_promise(async move {
+            let redelegations = get_redelegations(
+                &grpc_web_url,
+                &delegator_address,
+                Some(page_request(offset, limit)),
+            )
+            .await?;
+            serde_wasm_bindgen::to_value(&redelegations).map_err(format_to_js_error)
+        })
+    }
+
+    /// Query the pending rewards of the delegator (per validator and in total).
+    pub fn query_delegation_rewards(&self, delegator_address: String) -> Promise {
+        let grpc_web_url = self.config.grpc_web_url.to_owned();
+        future_to_promise(async move {
+            let rewards = get_delegation_rewards(&grpc_web_url, &delegator_address).await?;
+            serde_wasm_bindgen::to_value(&rewards).map_err(format_to_js_error)
+        })
+    }
+
+    /// Query the validators, optionally filtered by their status.
+    pub fn query_validators(
+        &self,
+        status: Option<ValidatorBondStatus>,
+        offset: u64,
+        limit: u64,
+    ) -> Promise {
+        let grpc_web_url = self.config.grpc_web_url.to_owned();
+        future_to_promise(async move {
+            let validators = get_validators(
+                &grpc_web_url,
+                status.map(Into::into),
+                Some(page_request(offset, limit)),
+            )
+            .await?;
+            serde_wasm_bindgen::to_value(&validators).map_err(format_to_js_error)
+        })
+    }
+
+    /// Query the parameters of the staking module.
+    pub fn query_staking_params(&self) -> Promise {
+        let grpc_web_url = self.config.grpc_web_url.to_owned();
+        future_to_promise(async move {
+            let params = get_staking_params(&grpc_web_url).await?;
+            serde_wasm_bindgen::to_value(&params).map_err(format_to_js_error)
+        })
+    }
+
+    /// Query the authorizations granted by the granter to the grantee
+    /// (all of them if `msg_type_url` is empty).
+    pub fn query_grants(
+        &self,
+        granter: String,
+        grantee: String,
+        msg_type_url: String,
+        offset: u64,
+        limit: u64,
+    ) -> Promise {
+        let grpc_web_url = self.config.grpc_web_url.to_owned();
+        future_to_promise(async move {
+            let grants = get_grants(
+                &grpc_web_url,
+                &granter,
+                &grantee,
+                &msg_type_url,
+                Some(page_request(offset, limit)),
+            )
+            .await?;
+            serde_wasm_bindgen::to_value(&grants).map_err(format_to_js_error)
+        })
+    }
+
+    /// Query the authorizations granted by the granter.
+    pub fn query_granter_grants(&self, granter: String, offset: u64, limit: u64) -> Promise {
+        let grpc_web_url = self.config.grpc_web_url.to_owned();
+        future_to_promise(async move {
+            let grants =
+                get_granter_grants(&grpc_web_url, &granter, Some(page_request(offset, limit)))
+                    .await?;
+            serde_wasm_bindgen::to_value(&grants).map_err(format_to_js_error)
+        })
+    }
+
+    /// Query the authorizations granted to the grantee.
+    pub fn query_grantee_grants(&self, grantee: String, offset: u64, limit: u64) -> Promise {
+        let grpc_web_url = self.config.grpc_web_url.to_owned();
+        future_to_promise(async move {
+            let grants =
+                get_grantee_grants(&grpc_web_url, &grantee, Some(page_request(offset, limit)))
+                    .await?;
+            serde_wasm_bindgen::to_value(&grants).map_err(format_to_js_error)
+        })
+    }
+
+    /// Query the fee allowance granted by the granter to the grantee.
+    pub fn query_fee_allowance(&self, granter: String, grantee: String) -> Promise {
+        let grpc_web_url = self.config.grpc_web_url.to_owned();
+        future_to_promise(async move {
+            let allowance = get_fee_allowance(&grpc_web_url, &granter, &grantee).await?;
+            serde_wasm_bindgen::to_value(&allowance).map_err(format_to_js_error)
+        })
+    }
+
+    /// Query the fee allowances granted to the grantee.
+    pub fn query_fee_allowances(&self, grantee: String, offset: u64, limit: u64) -> Promise {
+        let grpc_web_url = self.config.grpc_web_url.to_owned();
+        future_to_promise(async move {
+            let allowances =
+                get_fee_allowances(&grpc_web_url, &grantee, Some(page_request(offset, limit)))
+                    .await?;
+            serde_wasm_bindgen::to_value(&allowances).map_err(format_to_js_error)
+        })
+    }
+
+    /// Query the governance proposal of the given ID.
+    pub fn query_gov_proposal(&self, proposal_id: u64) -> Promise {
+        let mut client = GovClient::new(self.config.grpc_web_url.to_owned());
+        future_to_promise(async move {
+            let proposal = client.proposal(proposal_id).await?;
+            serde_wasm_bindgen::to_value(&proposal).map_err(format_to_js_error)
+        })
+    }
+
+    /// Query the governance proposals, optionally filtered by the status, the voter and the
+    /// depositor (an empty voter or depositor disables the filter).
+    pub fn query_gov_proposals(
+        &self,
+        status: Option<GovProposalStatus>,
+        voter: String,
+        depositor: String,
+        offset: u64,
+        limit: u64,
+    ) -> Promise {
+        let mut client = GovClient::new(self.config.grpc_web_url.to_owned());
+        future_to_promise(async move {
+            let proposals = client
+                .proposals(
+                    status.map(Into::into),
+                    voter,
+                    depositor,
+                    Some(page_request(offset, limit)),
+                )
+                .await?;
+            serde_wasm_bindgen::to_value(&proposals).map_err(format_to_js_error)
+        })
+    }
+
+    /// Query the current tally of the given governance proposal.
+    pub fn query_gov_tally_result(&self, proposal_id: u64) -> Promise {
+        let mut client = GovClient::new(self.config.grpc_web_url.to_owned());
+        future_to_promise(async move {
+            let tally = client.tally_result(proposal_id).await?;
+            serde_wasm_bindgen::to_value(&tally).map_err(format_to_js_error)
+        })
+    }
+
+    /// Query the deposits of the given governance proposal.
+    pub fn query_gov_deposits(&self, proposal_id: u64, offset: u64, limit: u64) -> Promise {
+        let mut client = GovClient::new(self.config.grpc_web_url.to_owned());
+        future_to_promise(async move {
+            let deposits = client
+                .deposits(proposal_id, Some(page_request(offset, limit)))
+                .await?;
+            serde_wasm_bindgen::to_value(&deposits).map_err(format_to_js_error)
+        })
+    }
+
+    /// Query the vote of the voter on the given governance proposal.
+    pub fn query_gov_vote(&self, proposal_id: u64, voter: String) -> Promise {
+        let mut client = GovClient::new(self.config.grpc_web_url.to_owned());
+        future_to_promise(async move {
+            let vote = client.vote(proposal_id, voter).await?;
+            serde_wasm_bindgen::to_value(&vote).map_err(format_to_js_error)
+        })
+    }
+
+    /// Query the parameters of the governance module.
+    pub fn query_gov_params(&self) -> Promise {
+        let mut client = GovClient::new(self.config.grpc_web_url.to_owned());
+        future_to_promise(async move {
+            let params = client.params().await?;
+            serde_wasm_bindgen::to_value(&params).map_err(format_to_js_error)
+        })
+    }
+
+    /// Query a page of the transactions of the address (latest first) via the Tendermint
+    /// `tx_search` (`page` starts from 1 and `per_page` is at most 100).
+    pub fn query_tx_history(
+        &self,
+        address: String,
+        filter: TxHistoryFilter,
+        page: u32,
+        per_page: u8,
+    ) -> Promise {
+        let client = self.tx_history_client();
+        future_to_promise(async move {
+            let history = client
+                .search_txs(&address, filter.into(), page, per_page)
+                .await?;
+            serde_wasm_bindgen::to_value(&history).map_err(format_to_js_error)
+        })
+    }
+
+    /// Query a page of the transactions of the address (latest first) via the gRPC-web
+    /// `GetTxsEvent` service (`offset` is the number of the skipped transactions).
+    pub fn query_txs_event(
+        &self,
+        address: String,
+        filter: TxHistoryFilter,
+        offset: u64,
+        limit: u64,
+    ) -> Promise {
+        let client = self.tx_history_client();
+        future_to_promise(async move {
+            let history = client
+                .get_txs_event(&address, filter.into(), offset, limit)
+                .await?;
+            serde_wasm_bindgen::to_value(&history).map_err(format_to_js_error)
+        })
+    }
+
+    /// Query the trace (path and base denomination) of the IBC denomination ("ibc/{hash}").
+    pub fn query_ibc_denom_trace(&self, hash: String) -> Promise {
+        let client = IbcClient::new(self.config.api_url.to_owned());
+        future_to_promise(async move {
+            let trace = client.denom_trace(&hash).await?;
+            serde_wasm_bindgen::to_value(&trace).map_err(format_to_js_error)
+        })
+    }
+
+    /// Query the IBC channel of the port.
+    pub fn query_ibc_channel(&self, port_id: String, channel_id: String) -> Promise {
+        let client = IbcClient::new(self.config.api_url.to_owned());
+        future_to_promise(async move {
+            let channel = client.channel(&port_id, &channel_id).await?;
+            serde_wasm_bindgen::to_value(&channel).map_err(format_to_js_error)
+        })
+    }
+
+    /// Query the light client state of the counterparty chain of the IBC channel.
+    pub fn query_ibc_channel_client_state(&self, port_id: String, channel_id: String) -> Promise {
+        let client = IbcClient::new(self.config.api_url.to_owned());
+        future_to_promise(async move {
+            let client_state = client.channel_client_state(&port_id, &channel_id).await?;
+            serde_wasm_bindgen::to_value(&client_state).map_err(format_to_js_error)
+        })
+    }
+
+    /// Query the IBC connection.
+    pub fn query_ibc_connection(&self, connection_id: String) -> Promise {
+        let client = IbcClient::new(self.config.api_url.to_owned());
+        future_to_promise(async move {
+            let connection = client.connection(&connection_id).await?;
+            serde_wasm_bindgen::to_value(&connection).map_err(format_to_js_error)
+        })
+    }
+
+    /// Compute the IBC transfer timeout (`timeout_height` and `timeout_timestamp`) after
+    /// the number of blocks and seconds from the latest block of the counterparty chain
+    /// (a zero offset disables the corresponding timeout).
+    pub fn query_ibc_timeout(
+        &self,
+        counterparty_tendermint_rpc_url: String,
+        height_offset: u64,
+        timeout_seconds: u64,
+    ) -> Promise {
+        future_to_promise(async move {
+            let timeout = get_ibc_timeout(
+                &counterparty_tendermint_rpc_url,
+                height_offset,
+                timeout_seconds,
+            )
+            .await?;
+            serde_wasm_bindgen::to_value(&timeout).map_err(format_to_js_error)
+        })
+    }
+
     /// Broadcast a signed transaction.
     #[wasm_bindgen]
     pub fn broadcast_tx(&self, raw_signed_tx: Vec<u8>) -> Promise {
@@ -62,6 +410,226 @@ impl CosmosClient {
             serde_wasm_bindgen::to_value(&resp).map_err(format_to_js_error)
         })
     }
+
+    /// Broadcast a signed transaction and query it every `interval_ms` (default 1000)
+    /// until it's committed or `timeout_ms` (default 60000) elapses.
+    /// The receipt has `height` 0 if the transaction was rejected by CheckTx.
+    #[wasm_bindgen]
+    pub fn broadcast_tx_and_wait(
+        &self,
+        raw_signed_tx: Vec<u8>,
+        timeout_ms: Option<u64>,
+        interval_ms: Option<u64>,
+    ) -> Promise {
+        let tendermint_rpc_url = self.config.tendermint_rpc_url.to_owned();
+        future_to_promise(async move {
+            let receipt = broadcast_tx_and_wait(
+                &tendermint_rpc_url,
+                raw_signed_tx,
+                timeout_ms.unwrap_or(node::DEFAULT_TX_POLL_TIMEOUT_MS),
+                interval_ms.unwrap_or(node::DEFAULT_TX_POLL_INTERVAL_MS),
+            )
+            .await?;
+            serde_wasm_bindgen::to_value(&receipt).map_err(format_to_js_error)
+        })
+    }
+
+    /// Estimate the gas limit and the fee of the pending messages by simulating the transaction.
+    /// `gas_price_source` is a `GasPriceSource`, e.g.
+    /// `{"Fixed": {"gas_price": {"amount": "0.025", "denom": "uatom"}}}`
+    /// or `{"FeeMarket": {"denom": "basetcro"}}` or `{"MinGasPrice": {"denom": "uatom"}}`.
+    pub fn estimate_tx_fee(
+        &self,
+        tx: &CosmosTx,
+        tx_info: CosmosSDKTxInfoRaw,
+        sender_pubkey: Vec<u8>,
+        gas_price_source: JsValue,
+        gas_adjustment: Option<f64>,
+    ) -> Promise {
+        let builder = self.tx_builder(gas_price_source, gas_adjustment);
+        let msgs: Vec<CosmosSDKMsg> = tx.msgs.iter().map(|m| m.msg.clone()).collect();
+        future_to_promise(async move {
+            let estimate = builder?
+                .estimate_fee(&tx_info.into(), &msgs, PublicKeyBytesWrapper(sender_pubkey))
+                .await?;
+            serde_wasm_bindgen::to_value(&estimate).map_err(format_to_js_error)
+        })
+    }
+
+    /// Sign the transaction with the estimated gas limit and fee
+    /// and move out all pending messages.
+    /// `gas_price_source` is the same as in `estimate_tx_fee`.
+    pub fn sign_tx_with_estimated_fee(
+        &self,
+        tx: &mut CosmosTx,
+        private_key: PrivateKey,
+        tx_info: CosmosSDKTxInfoRaw,
+        gas_price_source: JsValue,
+        gas_adjustment: Option<f64>,
+    ) -> Promise {
+        let builder = self.tx_builder(gas_price_source, gas_adjustment);
+        let msgs: Vec<CosmosSDKMsg> = tx.msgs.drain(..).map(|m| m.msg).collect();
+        future_to_promise(async move {
+            let signed_tx = builder?
+                .build_signed_msg_tx(tx_info.into(), msgs, private_key.key.as_ref())
+                .await?;
+            Ok(js_sys::Uint8Array::from(signed_tx.as_slice()).into())
+        })
+    }
+}
+
+impl CosmosClient {
+    fn tx_history_client(&self) -> TxHistoryClient {
+        TxHistoryClient::new(
+            self.config.tendermint_rpc_url.to_owned(),
+            self.config.grpc_web_url.to_owned(),
+            Arc::new(BaseParser),
+        )
+    }
+
+    fn tx_builder(
+        &self,
+        gas_price_source: JsValue,
+        gas_adjustment: Option<f64>,
+    ) -> Result<CosmosTxBuilder, JsValue> {
+        let gas_price_source: GasPriceSource =
+            serde_wasm_bindgen::from_value(gas_price_source).map_err(format_to_js_error)?;
+        Ok(CosmosTxBuilder::new(
+            self.config.grpc_web_url.to_owned(),
+            self.config.api_url.to_owned(),
+            gas_price_source,
+            gas_adjustment,
+        ))
+    }
+}
+
+/// the status of a governance proposal
+#[wasm_bindgen]
+pub enum GovProposalStatus {
+    DepositPeriod,
+    VotingPeriod,
+    Passed,
+    Rejected,
+    Failed,
+}
+
+impl From<GovProposalStatus> for node::GovProposalStatus {
+    fn from(status: GovProposalStatus) -> Self {
+        match status {
+            GovProposalStatus::DepositPeriod => node::GovProposalStatus::DepositPeriod,
+            GovProposalStatus::VotingPeriod => node::GovProposalStatus::VotingPeriod,
+            GovProposalStatus::Passed => node::GovProposalStatus::Passed,
+            GovProposalStatus::Rejected => node::GovProposalStatus::Rejected,
+            GovProposalStatus::Failed => node::GovProposalStatus::Failed,
+        }
+    }
+}
+
+/// the transactions of an address to search for
+#[wasm_bindgen]
+pub enum TxHistoryFilter {
+    /// the transactions signed by the address
+    Sent,
+    /// the transactions transferring tokens to the address
+    Received,
+    /// the staking transactions signed by the address
+    Staking,
+}
+
+impl From<TxHistoryFilter> for node::TxHistoryFilter {
+    fn from(filter: TxHistoryFilter) -> Self {
+        match filter {
+            TxHistoryFilter::Sent => node::TxHistoryFilter::Sent,
+            TxHistoryFilter::Received => node::TxHistoryFilter::Received,
+            TxHistoryFilter::Staking => node::TxHistoryFilter::Staking,
+        }
+    }
+}
+
+/// the bond status of a validator
+#[wasm_bindgen]
+pub enum ValidatorBondStatus {
+    Unbonded,
+    Unbonding,
+    Bonded,
+}
+
+impl From<ValidatorBondStatus> for node::ValidatorBondStatus {
+    fn from(status: ValidatorBondStatus) -> Self {
+        match status {
+            ValidatorBondStatus::Unbonded => node::ValidatorBondStatus::Unbonded,
+            ValidatorBondStatus::Unbonding => node::ValidatorBondStatus::Unbonding,
+            ValidatorBondStatus::Bonded => node::ValidatorBondStatus::Bonded,
+        }
+    }
+}
+
+/// Get the IBC denomination ("ibc/{hash}") of the base denomination transferred over the path
+/// (e.g. "transfer/channel-0")
+#[wasm_bindgen]
+pub fn get_ibc_denom(path: &str, base_denom: &str) -> String {
+    defi_wallet_core_common::get_ibc_denom(path, base_denom)
+}
+
+/// Convert the decimal amount in the unit (e.g. "1.5" cro) to the integer amount in the
+/// base denomination (e.g. "150000000") without precision loss.
+/// `denom_units_json` is the `denom_units` of the denomination metadata, e.g.
+/// `[{"denom": "basecro", "exponent": 0}, {"denom": "cro", "exponent": 8}]`
+#[wasm_bindgen]
+pub fn get_base_amount(
+    amount: &str,
+    denom: &str,
+    base_denom: &str,
+    denom_units_json: &str,
+) -> Result<String, JsValue> {
+    Ok(
+        DecimalCoin::from_denom_units(amount, denom, base_denom, denom_units_json)?
+            .base_amount()?,
+    )
+}
+
+/// Convert the integer amount in the base denomination to the decimal amount in the unit.
+/// `denom_units_json` is the `denom_units` of the denomination metadata.
+#[wasm_bindgen]
+pub fn get_decimal_amount(
+    base_amount: &str,
+    denom: &str,
+    base_denom: &str,
+    denom_units_json: &str,
+) -> Result<String, JsValue> {
+    Ok(DecimalCoin::from_base_amount(base_amount, denom, base_denom, denom_units_json)?.amount)
+}
+
+/// Parse the cosmos chain-registry `chain.json` and optionally `assetlist.json` into a
+/// `ChainInfo` (chain id, bech32 prefix, slip44, network, fee tokens, endpoints and assets).
+#[wasm_bindgen]
+pub fn parse_chain_registry(
+    chain_json: &str,
+    assetlist_json: Option<String>,
+) -> Result<JsValue, JsValue> {
+    let chain_info = defi_wallet_core_common::parse_chain_registry(chain_json, assetlist_json)?;
+    serde_wasm_bindgen::to_value(&chain_info).map_err(format_to_js_error)
+}
+
+/// Build the packet forward middleware memo of the IBC transfer forwarding the tokens
+/// through the hops in order. `hops` is an array of `PacketForwardHop`, e.g.
+/// `[{"receiver": "osmo1...", "port": "transfer", "channel": "channel-141",
+/// "timeout_seconds": 600, "retries": 2}]`
+#[wasm_bindgen]
+pub fn build_packet_forward_memo(hops: JsValue) -> Result<String, JsValue> {
+    let hops: Vec<PacketForwardHop> =
+        serde_wasm_bindgen::from_value(hops).map_err(format_to_js_error)?;
+    Ok(defi_wallet_core_common::build_packet_forward_memo(&hops)?)
+}
+
+fn page_request(offset: u64, limit: u64) -> PageRequest {
+    PageRequest {
+        key: vec![],
+        offset,
+        limit,
+        count_total: false,
+        reverse: false,
+    }
 }
 
 /// Cosmos client configuration
@@ -87,6 +655,61 @@ impl CosmosClientConfig {
     }
 }
 
+/// governance vote options
+#[wasm_bindgen]
+pub enum GovVoteOption {
+    Yes,
+    Abstain,
+    No,
+    NoWithVeto,
+}
+
+impl From<GovVoteOption> for VoteOption {
+    fn from(option: GovVoteOption) -> Self {
+        match option {
+            GovVoteOption::Yes => VoteOption::Yes,
+            GovVoteOption::Abstain => VoteOption::Abstain,
+            GovVoteOption::No => VoteOption::No,
+            GovVoteOption::NoWithVeto => VoteOption::NoWithVeto,
+        }
+    }
+}
+
+/// Cosmos coin of an arbitrary (integer or decimal) amount
+#[wasm_bindgen]
+pub struct CosmosCoin {
+    coin: SingleCoin,
+}
+
+#[wasm_bindgen]
+impl CosmosCoin {
+    /// construct the coin of the integer amount in the base denomination
+    /// (e.g. "1000000000000000000000" aevmos)
+    pub fn from_base_amount(amount: String, denom: String) -> Self {
+        Self {
+            coin: SingleCoin::Other { amount, denom },
+        }
+    }
+
+    /// construct the coin of the decimal amount in the unit (e.g. "1.5" cro),
+    /// which is `10^exponent` of the base denomination (e.g. 8 for "basecro")
+    pub fn from_decimal_amount(
+        amount: String,
+        denom: String,
+        base_denom: String,
+        exponent: u32,
+    ) -> Result<CosmosCoin, JsValue> {
+        let coin = DecimalCoin {
+            amount,
+            denom,
+            base_denom,
+            exponent,
+        };
+        coin.base_amount()?;
+        Ok(Self { coin: coin.into() })
+    }
+}
+
 /// Cosmos message wrapper
 #[wasm_bindgen]
 pub struct CosmosMsg {
@@ -109,6 +732,16 @@ impl CosmosMsg {
         }
     }
 
+    /// construct BankSend message of an integer or decimal coin
+    pub fn build_bank_send_coin_msg(recipient_address: String, coin: &CosmosCoin) -> Self {
+        Self {
+            msg: CosmosSDKMsg::BankSend {
+                recipient_address,
+                amount: coin.coin.clone(),
+            },
+        }
+    }
+
     /// construct NftIssueDenom message
     #[wasm_bindgen]
     pub fn build_nft_issue_denom_msg(id: String, name: String, schema: String) -> Self {
@@ -230,6 +863,41 @@ impl CosmosMsg {
         }
     }
 
+    /// construct StakingBeginRedelegate message of an integer or decimal coin
+    pub fn build_staking_begin_redelegate_coin_msg(
+        validator_src_address: String,
+        validator_dst_address: String,
+        coin: &CosmosCoin,
+    ) -> Self {
+        Self {
+            msg: CosmosSDKMsg::StakingBeginRedelegate {
+                validator_src_address,
+                validator_dst_address,
+                amount: coin.coin.clone(),
+            },
+        }
+    }
+
+    /// construct StakingDelegate message of an integer or decimal coin
+    pub fn build_staking_delegate_coin_msg(validator_address: String, coin: &CosmosCoin) -> Self {
+        Self {
+            msg: CosmosSDKMsg::StakingDelegate {
+                validator_address,
+                amount: coin.coin.clone(),
+            },
+        }
+    }
+
+    /// construct StakingUndelegate message of an integer or decimal coin
+    pub fn build_staking_undelegate_coin_msg(validator_address: String, coin: &CosmosCoin) -> Self {
+        Self {
+            msg: CosmosSDKMsg::StakingUndelegate {
+                validator_address,
+                amount: coin.coin.clone(),
+            },
+        }
+    }
+
     /// construct DistributionSetWithdrawAddress message
     pub fn build_distribution_set_withdraw_address_msg(withdraw_address: String) -> Self {
         Self {
@@ -244,7 +912,7 @@ impl CosmosMsg {
         }
     }
 
-    /// construct IbcTransfer message
+    /// construct IbcTransfer message (with an optional memo, e.g. from `build_packet_forward_memo`)
     #[allow(clippy::too_many_arguments)]
     pub fn build_ibc_transfer_msg(
         receiver: String,
@@ -255,6 +923,7 @@ impl CosmosMsg {
         revision_height: u64,
         revision_number: u64,
         timeout_timestamp: u64,
+        memo: Option<String>,
     ) -> Self {
         Self {
             msg: CosmosSDKMsg::IbcTransfer {
@@ -270,11 +939,274 @@ impl CosmosMsg {
                     revision_number,
                 },
                 timeout_timestamp,
+                memo,
+            },
+        }
+    }
+
+    /// construct IbcTransfer message of an integer or decimal coin
+    #[allow(clippy::too_many_arguments)]
+    pub fn build_ibc_transfer_coin_msg(
+        receiver: String,
+        source_port: String,
+        source_channel: String,
+        coin: &CosmosCoin,
+        revision_height: u64,
+        revision_number: u64,
+        timeout_timestamp: u64,
+        memo: Option<String>,
+    ) -> Self {
+        Self {
+            msg: CosmosSDKMsg::IbcTransfer {
+                receiver,
+                source_port,
+                source_channel,
+                token: coin.coin.clone(),
+                timeout_height: Height {
+                    revision_height,
+                    revision_number,
+                },
+                timeout_timestamp,
+                memo,
+            },
+        }
+    }
+
+    /// construct GovVote message
+    pub fn build_gov_vote_msg(proposal_id: u64, option: GovVoteOption) -> Self {
+        Self {
+            msg: CosmosSDKMsg::GovVote {
+                proposal_id,
+                option: option.into(),
+            },
+        }
+    }
+
+    /// construct GovVoteWeighted message
+    /// `options` is an array of `{ option: "VOTE_OPTION_YES", weight: "0.5" }` objects
+    pub fn build_gov_vote_weighted_msg(
+        proposal_id: u64,
+        options: JsValue,
+    ) -> Result<CosmosMsg, JsValue> {
+        let options: Vec<WeightedVoteOption> =
+            serde_wasm_bindgen::from_value(options).map_err(format_to_js_error)?;
+        Ok(Self {
+            msg: CosmosSDKMsg::GovVoteWeighted {
+                proposal_id,
+                options,
+            },
+        })
+    }
+
+    /// construct GovDeposit message
+    pub fn build_gov_deposit_msg(proposal_id: u64, amount: u64, denom: String) -> Self {
+        Self {
+            msg: CosmosSDKMsg::GovDeposit {
+                proposal_id,
+                amount: SingleCoin::Other {
+                    amount: amount.to_string(),
+                    denom,
+                },
+            },
+        }
+    }
+
+    /// construct GovDeposit message of an integer or decimal coin
+    pub fn build_gov_deposit_coin_msg(proposal_id: u64, coin: &CosmosCoin) -> Self {
+        Self {
+            msg: CosmosSDKMsg::GovDeposit {
+                proposal_id,
+                amount: coin.coin.clone(),
+            },
+        }
+    }
+
+    /// construct GovSubmitProposal message with a text proposal
+    /// (a zero `initial_deposit` is omitted)
+    pub fn build_gov_submit_text_proposal_msg(
+        title: String,
+        description: String,
+        initial_deposit: u64,
+        denom: String,
+    ) -> Self {
+        Self {
+            msg: CosmosSDKMsg::GovSubmitProposal {
+                content: ProposalContent::Text { title, description },
+                initial_deposit: vec![SingleCoin::Other {
+                    amount: initial_deposit.to_string(),
+                    denom,
+                }],
+            },
+        }
+    }
+
+    /// construct GovSubmitProposal message with a parameter change proposal
+    /// (a zero `initial_deposit` is omitted)
+    /// `changes` is an array of `{ subspace: "staking", key: "MaxValidators", value: "105" }` objects
+    pub fn build_gov_submit_param_change_proposal_msg(
+        title: String,
+        description: String,
+        changes: JsValue,
+        initial_deposit: u64,
+        denom: String,
+    ) -> Result<CosmosMsg, JsValue> {
+        let changes: Vec<ParamChange> =
+            serde_wasm_bindgen::from_value(changes).map_err(format_to_js_error)?;
+        Ok(Self {
+            msg: CosmosSDKMsg::GovSubmitProposal {
+                content: ProposalContent::ParameterChange {
+                    title,
+                    description,
+                    changes,
+                },
+                initial_deposit: vec![SingleCoin::Other {
+                    amount: initial_deposit.to_string(),
+                    denom,
+                }],
+            },
+        })
+    }
+
+    /// construct AuthzGrant message with a generic authorization of the message type
+    /// (`expiration` is in seconds since the Unix epoch)
+    pub fn build_authz_grant_generic_msg(
+        grantee: String,
+        msg_type_url: String,
+        expiration: Option<i64>,
+    ) -> Self {
+        Self {
+            msg: CosmosSDKMsg::AuthzGrant {
+                grantee,
+                authorization: Authorization::Generic { msg: msg_type_url },
+                expiration,
+            },
+        }
+    }
+
+    /// construct AuthzGrant message
+    /// `authorization` is an object in the proto JSON format, e.g.
+    /// `{ "@type": "/cosmos.bank.v1beta1.SendAuthorization", spend_limit: [{ amount: "10", denom: "basecro" }] }`
+    pub fn build_authz_grant_msg(
+        grantee: String,
+        authorization: JsValue,
+        expiration: Option<i64>,
+    ) -> Result<CosmosMsg, JsValue> {
+        let authorization: Authorization =
+            serde_wasm_bindgen::from_value(authorization).map_err(format_to_js_error)?;
+        Ok(Self {
+            msg: CosmosSDKMsg::AuthzGrant {
+                grantee,
+                authorization,
+                expiration,
+            },
+        })
+    }
+
+    /// construct AuthzExec message which moves out all pending messages of `tx`
+    /// (they are executed on behalf of the granter)
+    pub fn build_authz_exec_msg(granter: String, tx: &mut CosmosTx) -> Self {
+        Self {
+            msg: CosmosSDKMsg::AuthzExec {
+                granter,
+                msgs: tx.msgs.drain(..).map(|m| m.msg).collect(),
+            },
+        }
+    }
+
+    /// construct AuthzRevoke message
+    pub fn build_authz_revoke_msg(grantee: String, msg_type_url: String) -> Self {
+        Self {
+            msg: CosmosSDKMsg::AuthzRevoke {
+                grantee,
+                msg_type_url,
+            },
+        }
+    }
+
+    /// construct FeegrantGrantAllowance message with a basic allowance
+    /// (no limit if `spend_limit` is 0 and no expiration if `expiration` is not set)
+    pub fn build_feegrant_grant_basic_allowance_msg(
+        grantee: String,
+        spend_limit: u64,
+        denom: String,
+        expiration: Option<i64>,
+    ) -> Self {
+        Self {
+            msg: CosmosSDKMsg::FeegrantGrantAllowance {
+                grantee,
+                allowance: FeeAllowance::Basic {
+                    spend_limit: coins(spend_limit, denom),
+                    expiration,
+                },
+            },
+        }
+    }
+
+    /// construct FeegrantGrantAllowance message with a periodic allowance
+    /// (`period` is in seconds)
+    pub fn build_feegrant_grant_periodic_allowance_msg(
+        grantee: String,
+        spend_limit: u64,
+        period_spend_limit: u64,
+        denom: String,
+        period: u64,
+        expiration: Option<i64>,
+    ) -> Self {
+        Self {
+            msg: CosmosSDKMsg::FeegrantGrantAllowance {
+                grantee,
+                allowance: FeeAllowance::Periodic {
+                    spend_limit: coins(spend_limit, denom.clone()),
+                    expiration,
+                    period,
+                    period_spend_limit: coins(period_spend_limit, denom),
+                },
+            },
+        }
+    }
+
+    /// construct FeegrantRevokeAllowance message
+    pub fn build_feegrant_revoke_allowance_msg(grantee: String) -> Self {
+        Self {
+            msg: CosmosSDKMsg::FeegrantRevokeAllowance { grantee },
+        }
+    }
+
+    /// construct VestingCreateAccount message
+    /// (`end_time` is in seconds since the Unix epoch)
+    pub fn build_vesting_create_account_msg(
+        to_address: String,
+        amount: u64,
+        denom: String,
+        end_time: i64,
+        delayed: bool,
+    ) -> Self {
+        Self {
+            msg: CosmosSDKMsg::VestingCreateAccount {
+                to_address,
+                amount: vec![SingleCoin::Other {
+                    amount: amount.to_string(),
+                    denom,
+                }],
+                end_time,
+                delayed,
             },
         }
     }
 }
 
+/// the spend limit coins (an empty limit for 0)
+fn coins(amount: u64, denom: String) -> Vec<SingleCoin> {
+    if amount == 0 {
+        vec![]
+    } else {
+        vec![SingleCoin::Other {
+            amount: amount.to_string(),
+            denom,
+        }]
+    }
+}
+
 /// Cosmos transaction
 #[wasm_bindgen]
 pub struct CosmosTx {
@@ -315,6 +1247,89 @@ impl CosmosTx {
             private_key.key,
         )?)
     }
+
+    /// Sign the transaction in the legacy Amino JSON mode and move out all pending messages
+    #[wasm_bindgen]
+    pub fn sign_amino_into(
+        &mut self,
+        private_key: PrivateKey,
+        tx_info: CosmosSDKTxInfoRaw,
+    ) -> Result<Vec<u8>, JsValue> {
+        Ok(build_signed_amino_msg_tx(
+            tx_info.into(),
+            self.msgs.drain(..).map(|m| m.msg).collect(),
+            private_key.key,
+        )?)
+    }
+
+    /// Sign the transaction as a member of the multisig account
+    /// and return the partial signature (the pending messages are kept)
+    #[wasm_bindgen]
+    pub fn sign_multisig_partial(
+        &self,
+        private_key: PrivateKey,
+        tx_info: CosmosSDKTxInfoRaw,
+        multisig_key: &CosmosMultisigKey,
+    ) -> Result<JsValue, JsValue> {
+        let signature = build_multisig_partial_signature(
+            tx_info.into(),
+            self.msgs.iter().map(|m| m.msg.clone()).collect(),
+            multisig_key.key.clone(),
+            private_key.key,
+        )?;
+        serde_wasm_bindgen::to_value(&signature).map_err(format_to_js_error)
+    }
+
+    /// Combine the partial signatures of the multisig account members
+    /// into the signed transaction and move out all pending messages
+    #[wasm_bindgen]
+    pub fn combine_multisig_into(
+        &mut self,
+        tx_info: CosmosSDKTxInfoRaw,
+        multisig_key: &CosmosMultisigKey,
+        partial_signatures: JsValue,
+    ) -> Result<Vec<u8>, JsValue> {
+        let partial_signatures: Vec<MultisigPartialSignature> =
+            serde_wasm_bindgen::from_value(partial_signatures).map_err(format_to_js_error)?;
+        Ok(build_signed_multisig_tx(
+            tx_info.into(),
+            self.msgs.drain(..).map(|m| m.msg).collect(),
+            multisig_key.key.clone(),
+            partial_signatures,
+        )?)
+    }
+}
+
+/// k-of-n multisig (legacy Amino) public key
+#[wasm_bindgen]
+pub struct CosmosMultisigKey {
+    key: Arc<MultisigPublicKey>,
+}
+
+#[wasm_bindgen]
+impl CosmosMultisigKey {
+    /// constructs the multisig key from the threshold
+    /// and the members' compressed secp256k1 public keys
+    #[wasm_bindgen(constructor)]
+    pub fn new(threshold: u32, public_keys: JsValue) -> Result<CosmosMultisigKey, JsValue> {
+        let public_keys: Vec<Vec<u8>> =
+            serde_wasm_bindgen::from_value(public_keys).map_err(format_to_js_error)?;
+        Ok(Self {
+            key: Arc::new(MultisigPublicKey::new(threshold, public_keys)?),
+        })
+    }
+
+    /// returns the threshold
+    #[wasm_bindgen]
+    pub fn get_threshold(&self) -> u32 {
+        self.key.get_threshold()
+    }
+
+    /// returns the bech32 address of the multisig account
+    #[wasm_bindgen]
+    pub fn get_address(&self, bech32hrp: String) -> Result<String, JsValue> {
+        Ok(self.key.get_address(&bech32hrp)?)
+    }
 }
 
 /// the common transaction data needed for Cosmos SDK transactions
@@ -341,6 +1356,12 @@ pub struct CosmosSDKTxInfoRaw {
     pub bech32hrp: String,
     /// the coin type to use
     pub coin_type: u32,
+    /// optional account paying the fee (it must sign the transaction as well)
+    pub payer: Option<String>,
+    /// optional account granting the fee allowance to the signer
+    pub granter: Option<String>,
+    /// the fee coins added by `add_fee` (in addition to `fee_amount` of `fee_denom`)
+    extra_fees: Vec<SingleCoin>,
 }
 
 #[wasm_bindgen]
@@ -371,8 +1392,25 @@ impl CosmosSDKTxInfoRaw {
             chain_id,
             bech32hrp,
             coin_type,
+            payer: None,
+            granter: None,
+            extra_fees: vec![],
         }
     }
+
+    /// add a fee coin of another denomination
+    /// (for the chains accepting several fee denominations)
+    pub fn add_fee(&mut self, amount: u64, denom: String) {
+        self.extra_fees.push(SingleCoin::Other {
+            amount: amount.to_string(),
+            denom,
+        });
+    }
+
+    /// add a fee coin of an integer or decimal amount
+    pub fn add_fee_coin(&mut self, coin: &CosmosCoin) {
+        self.extra_fees.push(coin.coin.clone());
+    }
 }
 
 impl From<CosmosSDKTxInfoRaw> for CosmosSDKTxInfo {
@@ -381,10 +1419,12 @@ impl From<CosmosSDKTxInfoRaw> for CosmosSDKTxInfo {
             account_number: info.account_number,
             sequence_number: info.sequence_number,
             gas_limit: info.gas_limit,
-            fee_amount: SingleCoin::Other {
+            fee_amount: std::iter::once(SingleCoin::Other {
                 amount: info.fee_amount.to_string(),
                 denom: info.fee_denom,
-            },
+            })
+            .chain(info.extra_fees)
+            .collect(),
             timeout_height: info.timeout_height,
             memo_note: info.memo_note,
             network: Network::Other {
@@ -392,6 +1432,8 @@ impl From<CosmosSDKTxInfoRaw> for CosmosSDKTxInfo {
                 coin_type: info.coin_type,
                 bech32hrp: info.bech32hrp,
             },
+            payer: info.payer,
+            granter: info.granter,
         }
     }
 }
@@ -25,3 +25,10 @@ pub fn cosmos_sign_direct(
         body_bytes,
     )?)
 }
+
+/// Sign a legacy Amino JSON sign doc (e.g. requested by a dApp or required by Ledger).
+/// Return the 64-byte signature as a HEX string.
+#[wasm_bindgen(js_name = cosmos_signAmino)]
+pub fn cosmos_sign_amino(private_key: PrivateKey, sign_doc_json: &str) -> Result<String, JsValue> {
+    Ok(CosmosSigner::new(private_key.key).sign_amino(sign_doc_json)?)
+}
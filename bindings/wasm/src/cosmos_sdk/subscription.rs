@@ -0,0 +1,218 @@
+use crate::format_to_js_error;
+use defi_wallet_core_common::{
+    parse_subscription_message, subscription_request, CosmosSubscription, ReconnectBackoff,
+};
+use gloo_timers::callback::Timeout;
+use js_sys::Function;
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::{MessageEvent, WebSocket};
+
+struct SubscriptionState {
+    tendermint_websocket_url: String,
+    subscription: CosmosSubscription,
+    backoff: ReconnectBackoff,
+    on_event: Function,
+    on_error: Function,
+    socket: Option<WebSocket>,
+    /// the websocket handlers (kept alive while the socket is open);
+    /// they only hold a weak reference to the state, so that dropping the subscription frees them
+    handlers: Vec<Closure<dyn FnMut(JsValue)>>,
+    retries: u32,
+    unsubscribed: bool,
+}
+
+impl Drop for SubscriptionState {
+    fn drop(&mut self) {
+        if let Some(socket) = self.socket.take() {
+            close_socket(&socket);
+        }
+    }
+}
+
+/// Subscription to the transfer and staking events or the new blocks over the Tendermint
+/// websocket (usually on ws://{host}:26657/websocket).
+/// It reconnects with an exponential backoff when the connection is closed.
+#[wasm_bindgen]
+pub struct CosmosEventSubscription {
+    state: Rc<RefCell<SubscriptionState>>,
+}
+
+#[wasm_bindgen]
+impl CosmosEventSubscription {
+    /// Subscribe to the events.
+    /// `subscription` is a `CosmosSubscription`, e.g.
+    /// `{"Address": {"address": "cro1...", "filter": "Received"}}` or `"NewBlock"`.
+    /// `backoff` is an optional `ReconnectBackoff`, e.g.
+    /// `{"initial_delay_ms": 1000, "max_delay_ms": 30000, "max_retries": null}`.
+    /// `on_event` is called with each decoded event and `on_error` with the error message.
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        tendermint_websocket_url: String,
+        subscription: JsValue,
+        backoff: JsValue,
+        on_event: Function,
+        on_error: Function,
+    ) -> Result<CosmosEventSubscription, JsValue> {
+        let subscription: CosmosSubscription = serde_wasm_bindgen::from_value(subscription)?;
+        let backoff: ReconnectBackoff = if backoff.is_undefined() || backoff.is_null() {
+            ReconnectBackoff::default()
+        } else {
+            serde_wasm_bindgen::from_value(backoff)?
+        };
+        let state = Rc::new(RefCell::new(SubscriptionState {
+            tendermint_websocket_url,
+            subscription,
+            backoff,
+            on_event,
+            on_error,
+            socket: None,
+            handlers: vec![],
+            retries: 0,
+            unsubscribed: false,
+        }));
+        connect(&state)?;
+        Ok(Self { state })
+    }
+
+    /// Stop the subscription (no more callbacks).
+    #[wasm_bindgen]
+    pub fn unsubscribe(&self) {
+        let socket = {
+            let mut state = self.state.borrow_mut();
+            state.unsubscribed = true;
+            state.socket.take()
+        };
+        if let Some(socket) = socket {
+            close_socket(&socket);
+        }
+    }
+}
+
+/// detach the handlers from the socket and close it
+fn close_socket(socket: &WebSocket) {
+    socket.set_onopen(None);
+    socket.set_onmessage(None);
+    socket.set_onclose(None);
+    let _ = socket.close();
+}
+
+fn report_error(state: &Rc<RefCell<SubscriptionState>>, error: JsValue) {
+    let on_error = state.borrow().on_error.clone();
+    let message = error.as_string().unwrap_or_else(|| format!("{:?}", error));
+    let _ = on_error.call1(&JsValue::NULL, &JsValue::from_str(&message));
+}
+
+fn handle_message(state: &Rc<RefCell<SubscriptionState>>, event: JsValue) -> Result<(), JsValue> {
+    let message = match event.dyn_into::<MessageEvent>()?.data().as_string() {
+        Some(message) => message,
+        None => return Ok(()),
+    };
+    // the state isn't borrowed during the callbacks as they may unsubscribe
+    let (events, on_event) = {
+        let state = state.borrow();
+        (
+            parse_subscription_message(&state.subscription, &message)?,
+            state.on_event.clone(),
+        )
+    };
+    for event in events {
+        let event = serde_wasm_bindgen::to_value(&event).map_err(format_to_js_error)?;
+        on_event.call1(&JsValue::NULL, &event)?;
+    }
+    Ok(())
+}
+
+fn connect(state: &Rc<RefCell<SubscriptionState>>) -> Result<(), JsValue> {
+    let (socket, request) = {
+        let state = state.borrow();
+        (
+            WebSocket::new(&state.tendermint_websocket_url)?,
+            subscription_request(&state.subscription)?,
+        )
+    };
+
+    let onopen = {
+        let (state, socket) = (Rc::downgrade(state), socket.clone());
+        Closure::wrap(Box::new(move |_event: JsValue| {
+            if let Some(state) = state.upgrade() {
+                match socket.send_with_str(&request) {
+                    Ok(()) => state.borrow_mut().retries = 0,
+                    Err(e) => report_error(&state, e),
+                }
+            }
+        }) as Box<dyn FnMut(JsValue)>)
+    };
+    let onmessage = {
+        let state = Rc::downgrade(state);
+        Closure::wrap(Box::new(move |event: JsValue| {
+            if let Some(state) = state.upgrade() {
+                if let Err(e) = handle_message(&state, event) {
+                    report_error(&state, e);
+                }
+            }
+        }) as Box<dyn FnMut(JsValue)>)
+    };
+    let onclose = {
+        let state = Rc::downgrade(state);
+        Closure::wrap(Box::new(move |_event: JsValue| {
+            if let Some(state) = state.upgrade() {
+                schedule_reconnect(&state);
+            }
+        }) as Box<dyn FnMut(JsValue)>)
+    };
+    socket.set_onopen(Some(onopen.as_ref().unchecked_ref()));
+    socket.set_onmessage(Some(onmessage.as_ref().unchecked_ref()));
+    socket.set_onclose(Some(onclose.as_ref().unchecked_ref()));
+
+    let mut state = state.borrow_mut();
+    state.socket = Some(socket);
+    state.handlers = vec![onopen, onmessage, onclose];
+    Ok(())
+}
+
+/// reconnect after the backoff delay (from a timer, as the handlers of the closed socket
+/// can't be dropped while they run)
+fn schedule_reconnect(state: &Rc<RefCell<SubscriptionState>>) {
+    let delay_ms = {
+        let mut state = state.borrow_mut();
+        if state.unsubscribed {
+            return;
+        }
+        state.socket = None;
+        if state.backoff.can_retry(state.retries) {
+            let delay_ms = state.backoff.delay_ms(state.retries);
+            state.retries += 1;
+            Some(delay_ms)
+        } else {
+            None
+        }
+    };
+    let delay_ms = match delay_ms {
+        Some(delay_ms) => delay_ms,
+        None => {
+            return report_error(
+                state,
+                JsValue::from_str("the connection was closed and the retries are exhausted"),
+            )
+        }
+    };
+    report_error(
+        state,
+        JsValue::from_str("the connection was closed, reconnecting"),
+    );
+    let state = Rc::downgrade(state);
+    Timeout::new(u32::try_from(delay_ms).unwrap_or(u32::MAX), move || {
+        let state = match state.upgrade() {
+            Some(state) if !state.borrow().unsubscribed => state,
+            _ => return,
+        };
+        if let Err(e) = connect(&state) {
+            report_error(&state, e);
+            schedule_reconnect(&state);
+        }
+    })
+    .forget();
+}
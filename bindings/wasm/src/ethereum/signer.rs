@@ -18,8 +18,8 @@ pub fn eth_sign_insecure(private_key: PrivateKey, hash: &str) -> Result<String,
 /// Sign an arbitrary message as per EIP-191.
 /// Return a signature of hex string with prefix `0x`.
 #[wasm_bindgen]
-pub fn personal_sign(private_key: PrivateKey, message: &str) -> String {
-    EthSigner::new(private_key.key).personal_sign(message)
+pub fn personal_sign(private_key: PrivateKey, message: &str) -> Result<String, JsValue> {
+    Ok(EthSigner::new(private_key.key).personal_sign(message)?)
 }
 
 /// Sign an EIP-712 typed data from a JSON string of specified schema as below. The field
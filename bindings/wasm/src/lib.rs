@@ -1,13 +1,20 @@
 use defi_wallet_core_common::{
-    bytes_to_hex, hex_to_bytes, EthNetwork, HDWallet, Network, SecretKey, WalletCoin,
+    bytes_to_hex, hex_to_bytes, BitcoinAddressType, BitcoinNetwork as CoreBitcoinNetwork,
+    ChainInfo, Ed25519SecretKey, EthNetwork, HDWallet, Network, SecretKey, WalletCoin,
+    WatchOnlyWallet as CoreWatchOnlyWallet,
+};
+use defi_wallet_core_common::{
+    change_keystore_password, KeystoreKdf as CoreKeystoreKdf, Slip39Group,
 };
 use std::sync::Arc;
 use wasm_bindgen::prelude::*;
 
+mod bitcoin;
 mod cosmos_sdk;
 mod ethereum;
 mod utils;
 
+pub use bitcoin::*;
 pub use cosmos_sdk::*;
 pub use ethereum::*;
 pub use utils::*;
@@ -18,6 +25,12 @@ pub struct Wallet {
     wallet: HDWallet,
 }
 
+/// Watch-only wallet wrapper for Wasm
+#[wasm_bindgen]
+pub struct WatchOnlyWallet {
+    wallet: CoreWatchOnlyWallet,
+}
+
 /// Signing key wrapper for Wasm
 #[derive(Clone)]
 #[wasm_bindgen]
@@ -51,6 +64,24 @@ impl PrivateKey {
         })
     }
 
+    /// decrypts private key from a Web3 Secret Storage (V3) keystore JSON
+    #[wasm_bindgen]
+    pub fn from_keystore(keystore_json: String, password: String) -> Result<PrivateKey, JsValue> {
+        Ok(Self {
+            key: Arc::new(SecretKey::from_keystore(keystore_json, password)?),
+        })
+    }
+
+    /// encrypts private key into a Web3 Secret Storage (V3) keystore JSON
+    #[wasm_bindgen]
+    pub fn to_keystore(
+        &self,
+        password: String,
+        kdf: Option<KeystoreKdf>,
+    ) -> Result<String, JsValue> {
+        Ok(self.key.to_keystore(password, kdf.map(|val| val.into()))?)
+    }
+
     // eth sign message data
     #[wasm_bindgen]
     pub fn eth_sign(&self, message: Vec<u8>, chain_id: u64) -> Result<Vec<u8>, JsValue> {
@@ -103,6 +134,82 @@ impl Default for PrivateKey {
     }
 }
 
+/// Ed25519 signing key wrapper for Wasm (Solana and Aptos)
+#[derive(Clone)]
+#[wasm_bindgen]
+pub struct Ed25519PrivateKey {
+    key: Arc<Ed25519SecretKey>,
+}
+
+#[wasm_bindgen]
+impl Ed25519PrivateKey {
+    /// generates a random private key
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self {
+            key: Arc::new(Ed25519SecretKey::new()),
+        }
+    }
+
+    /// constructs private key from bytes
+    #[wasm_bindgen]
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<Ed25519PrivateKey, JsValue> {
+        Ok(Self {
+            key: Arc::new(Ed25519SecretKey::from_bytes(bytes)?),
+        })
+    }
+
+    /// constructs private key from hex
+    #[wasm_bindgen]
+    pub fn from_hex(hex: String) -> Result<Ed25519PrivateKey, JsValue> {
+        Ok(Self {
+            key: Arc::new(Ed25519SecretKey::from_hex(hex)?),
+        })
+    }
+
+    /// gets public key to byte array
+    #[wasm_bindgen]
+    pub fn get_public_key_bytes(&self) -> Vec<u8> {
+        self.key.get_public_key_bytes()
+    }
+
+    /// gets public key to a hex string without the 0x prefix
+    #[wasm_bindgen]
+    pub fn get_public_key_hex(&self) -> String {
+        self.key.get_public_key_hex()
+    }
+
+    /// converts private key to byte array
+    #[wasm_bindgen]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.key.to_bytes()
+    }
+
+    /// converts private key to a hex string without the 0x prefix
+    #[wasm_bindgen]
+    pub fn to_hex(&self) -> String {
+        self.key.to_hex()
+    }
+
+    /// signs an arbitrary message and returns the 64-byte signature
+    #[wasm_bindgen]
+    pub fn sign(&self, message: Vec<u8>) -> Vec<u8> {
+        self.key.sign(message)
+    }
+
+    /// converts private to address with coin type (Solana or Aptos)
+    #[wasm_bindgen]
+    pub fn to_address(&self, coin: CoinType) -> Result<String, JsValue> {
+        Ok(self.key.to_address(coin.into())?)
+    }
+}
+
+impl Default for Ed25519PrivateKey {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// basic supported coins for wasm
 /// TODO: re-work with `Network`
 /// (wasm only supports C-style enums)
@@ -124,6 +231,20 @@ pub enum CoinType {
     Polygon,
     /// BinanceSmartChain
     BSC,
+    /// Bitcoin mainnet native segwit (BIP84)
+    Bitcoin,
+    /// Bitcoin mainnet legacy (BIP44)
+    BitcoinLegacy,
+    /// Bitcoin mainnet nested segwit (BIP49)
+    BitcoinNestedSegwit,
+    /// Bitcoin mainnet taproot (BIP86)
+    BitcoinTaproot,
+    /// Bitcoin testnet native segwit (BIP84)
+    BitcoinTestnet,
+    /// Solana
+    Solana,
+    /// Aptos
+    Aptos,
 }
 
 impl From<CoinType> for WalletCoin {
@@ -153,6 +274,28 @@ impl From<CoinType> for WalletCoin {
             CoinType::Polygon => WalletCoin::Ethereum {
                 network: EthNetwork::Polygon,
             },
+            CoinType::Bitcoin => WalletCoin::Bitcoin {
+                network: CoreBitcoinNetwork::Mainnet,
+                address_type: BitcoinAddressType::P2wpkh,
+            },
+            CoinType::BitcoinLegacy => WalletCoin::Bitcoin {
+                network: CoreBitcoinNetwork::Mainnet,
+                address_type: BitcoinAddressType::P2pkh,
+            },
+            CoinType::BitcoinNestedSegwit => WalletCoin::Bitcoin {
+                network: CoreBitcoinNetwork::Mainnet,
+                address_type: BitcoinAddressType::P2shP2wpkh,
+            },
+            CoinType::BitcoinTaproot => WalletCoin::Bitcoin {
+                network: CoreBitcoinNetwork::Mainnet,
+                address_type: BitcoinAddressType::P2tr,
+            },
+            CoinType::BitcoinTestnet => WalletCoin::Bitcoin {
+                network: CoreBitcoinNetwork::Testnet,
+                address_type: BitcoinAddressType::P2wpkh,
+            },
+            CoinType::Solana => WalletCoin::Solana,
+            CoinType::Aptos => WalletCoin::Aptos,
         }
     }
 }
@@ -177,6 +320,114 @@ impl From<MnemonicWordCount> for defi_wallet_core_common::MnemonicWordCount {
     }
 }
 
+/// language of the BIP39 mnemonic wordlist
+#[wasm_bindgen]
+pub enum MnemonicLanguage {
+    English,
+    ChineseSimplified,
+    ChineseTraditional,
+    Czech,
+    French,
+    Italian,
+    Japanese,
+    Korean,
+    Spanish,
+}
+
+impl From<MnemonicLanguage> for defi_wallet_core_common::MnemonicLanguage {
+    fn from(language: MnemonicLanguage) -> Self {
+        match language {
+            MnemonicLanguage::English => defi_wallet_core_common::MnemonicLanguage::English,
+            MnemonicLanguage::ChineseSimplified => {
+                defi_wallet_core_common::MnemonicLanguage::ChineseSimplified
+            }
+            MnemonicLanguage::ChineseTraditional => {
+                defi_wallet_core_common::MnemonicLanguage::ChineseTraditional
+            }
+            MnemonicLanguage::Czech => defi_wallet_core_common::MnemonicLanguage::Czech,
+            MnemonicLanguage::French => defi_wallet_core_common::MnemonicLanguage::French,
+            MnemonicLanguage::Italian => defi_wallet_core_common::MnemonicLanguage::Italian,
+            MnemonicLanguage::Japanese => defi_wallet_core_common::MnemonicLanguage::Japanese,
+            MnemonicLanguage::Korean => defi_wallet_core_common::MnemonicLanguage::Korean,
+            MnemonicLanguage::Spanish => defi_wallet_core_common::MnemonicLanguage::Spanish,
+        }
+    }
+}
+
+impl From<defi_wallet_core_common::MnemonicLanguage> for MnemonicLanguage {
+    fn from(language: defi_wallet_core_common::MnemonicLanguage) -> Self {
+        match language {
+            defi_wallet_core_common::MnemonicLanguage::English => MnemonicLanguage::English,
+            defi_wallet_core_common::MnemonicLanguage::ChineseSimplified => {
+                MnemonicLanguage::ChineseSimplified
+            }
+            defi_wallet_core_common::MnemonicLanguage::ChineseTraditional => {
+                MnemonicLanguage::ChineseTraditional
+            }
+            defi_wallet_core_common::MnemonicLanguage::Czech => MnemonicLanguage::Czech,
+            defi_wallet_core_common::MnemonicLanguage::French => MnemonicLanguage::French,
+            defi_wallet_core_common::MnemonicLanguage::Italian => MnemonicLanguage::Italian,
+            defi_wallet_core_common::MnemonicLanguage::Japanese => MnemonicLanguage::Japanese,
+            defi_wallet_core_common::MnemonicLanguage::Korean => MnemonicLanguage::Korean,
+            defi_wallet_core_common::MnemonicLanguage::Spanish => MnemonicLanguage::Spanish,
+        }
+    }
+}
+
+/// key derivation function used to encrypt a keystore
+#[wasm_bindgen]
+pub enum KeystoreKdf {
+    Scrypt,
+    Pbkdf2,
+}
+
+impl From<KeystoreKdf> for CoreKeystoreKdf {
+    fn from(kdf: KeystoreKdf) -> Self {
+        match kdf {
+            KeystoreKdf::Scrypt => CoreKeystoreKdf::Scrypt,
+            KeystoreKdf::Pbkdf2 => CoreKeystoreKdf::Pbkdf2,
+        }
+    }
+}
+
+/// Decrypt a keystore and encrypt it again with a new password
+#[wasm_bindgen]
+pub fn change_password_of_keystore(
+    keystore_json: String,
+    old_password: String,
+    new_password: String,
+    kdf: Option<KeystoreKdf>,
+) -> Result<String, JsValue> {
+    Ok(change_keystore_password(
+        keystore_json,
+        old_password,
+        new_password,
+        kdf.map(|val| val.into()),
+    )?)
+}
+
+/// Validate a BIP39 phrase: returns the invalid word positions, the checksum validity,
+/// the word count and the detected language
+#[wasm_bindgen]
+pub fn validate_mnemonic(
+    mnemonic_phrase: String,
+    language: Option<MnemonicLanguage>,
+) -> Result<JsValue, JsValue> {
+    let validation =
+        defi_wallet_core_common::validate_mnemonic(mnemonic_phrase, language.map(|val| val.into()));
+    serde_wasm_bindgen::to_value(&validation).map_err(format_to_js_error)
+}
+
+/// Return up to `limit` BIP39 words (English by default) that start with the prefix
+#[wasm_bindgen]
+pub fn suggest_mnemonic_words(
+    prefix: String,
+    language: Option<MnemonicLanguage>,
+    limit: u32,
+) -> Vec<String> {
+    defi_wallet_core_common::suggest_mnemonic_words(prefix, language.map(|val| val.into()), limit)
+}
+
 /// Convert byte array to a hex string without the 0x prefix
 #[wasm_bindgen]
 pub fn bytes2hex(data: Vec<u8>) -> String {
@@ -191,26 +442,85 @@ pub fn hex2bytes(data: String) -> Vec<u8> {
 
 #[wasm_bindgen]
 impl Wallet {
-    /// generate a random wallet (with an optional password)
+    /// generate a random wallet (with an optional password and mnemonic language)
     #[wasm_bindgen(constructor)]
     pub fn new(
         password: Option<String>,
         word_count: Option<MnemonicWordCount>,
+        language: Option<MnemonicLanguage>,
     ) -> Result<Wallet, JsValue> {
-        let wallet = HDWallet::new_wallet(password, word_count.map(|val| val.into()))?;
+        let wallet = HDWallet::new_wallet(
+            password,
+            word_count.map(|val| val.into()),
+            language.map(|val| val.into()),
+        )?;
         Ok(Self { wallet })
     }
 
-    /// recovers/imports HD wallet from a BIP39 backup phrase (English words) and an optional password
+    /// recovers/imports HD wallet from a BIP39 backup phrase and an optional password
+    /// the language of the phrase is detected automatically if it is not provided
     #[wasm_bindgen]
     pub fn recover_wallet(
         mnemonic_phase: String,
         password: Option<String>,
+        language: Option<MnemonicLanguage>,
+    ) -> Result<Wallet, JsValue> {
+        let wallet =
+            HDWallet::recover_wallet(mnemonic_phase, password, language.map(|val| val.into()))?;
+        Ok(Self { wallet })
+    }
+
+    /// decrypts HD wallet from a keystore JSON created by `to_keystore`
+    #[wasm_bindgen]
+    pub fn from_keystore(keystore_json: String, password: String) -> Result<Wallet, JsValue> {
+        let wallet = HDWallet::from_keystore(keystore_json, password)?;
+        Ok(Self { wallet })
+    }
+
+    /// encrypts the seed and the backup mnemonic phrase into a Web3 Secret Storage (V3) keystore JSON
+    #[wasm_bindgen]
+    pub fn to_keystore(
+        &self,
+        password: String,
+        kdf: Option<KeystoreKdf>,
+    ) -> Result<String, JsValue> {
+        Ok(self
+            .wallet
+            .to_keystore(password, kdf.map(|val| val.into()))?)
+    }
+
+    /// recovers/imports HD wallet from a quorum of SLIP-39 share mnemonics and an optional passphrase
+    #[wasm_bindgen]
+    pub fn recover_from_slip39_shares(
+        shares: Vec<String>,
+        passphrase: Option<String>,
     ) -> Result<Wallet, JsValue> {
-        let wallet = HDWallet::recover_wallet(mnemonic_phase, password)?;
+        let wallet = HDWallet::recover_from_slip39_shares(shares, passphrase)?;
         Ok(Self { wallet })
     }
 
+    /// splits the backup mnemonic entropy (or the seed of non-English or password-protected wallets) into SLIP-39 share mnemonics
+    /// `groups` is an array of `{ member_threshold, member_count }` objects,
+    /// and the result is an array of share mnemonics for each group
+    #[wasm_bindgen]
+    pub fn to_slip39_shares(
+        &self,
+        group_threshold: u8,
+        groups: JsValue,
+        passphrase: Option<String>,
+        iteration_exponent: Option<u8>,
+    ) -> Result<JsValue, JsValue> {
+        let groups: Vec<Slip39Group> =
+            serde_wasm_bindgen::from_value(groups).map_err(format_to_js_error)?;
+        let shares = self.wallet.to_slip39_shares(
+            group_threshold,
+            groups,
+            passphrase,
+            iteration_exponent,
+        )?;
+        serde_wasm_bindgen::to_value(&shares).map_err(format_to_js_error)
+    }
+
     /// return the default address for a given coin type
     #[wasm_bindgen]
     pub fn get_default_address(&self, coin: CoinType) -> Result<String, JsValue> {
@@ -223,6 +533,16 @@ impl Wallet {
         Ok(self.wallet.get_address(coin.into(), index)?)
     }
 
+    /// return the address of a chain parsed by `parse_chain_registry` for a given index
+    #[wasm_bindgen]
+    pub fn get_chain_address(&self, chain_info: JsValue, index: u32) -> Result<String, JsValue> {
+        let chain_info: ChainInfo = serde_wasm_bindgen::from_value(chain_info)?;
+        let coin = WalletCoin::CosmosSDK {
+            network: chain_info.network,
+        };
+        Ok(self.wallet.get_address(coin, index)?)
+    }
+
     /// obtain a signing key for a given derivation path
     /// derivation_path is bip44 key path
     #[wasm_bindgen]
@@ -238,9 +558,103 @@ impl Wallet {
         Ok(PrivateKey { key })
     }
 
+    /// obtain an ed25519 signing key for a given (fully hardened) derivation path
+    #[wasm_bindgen]
+    pub fn get_ed25519_key(&self, derivation_path: String) -> Result<Ed25519PrivateKey, JsValue> {
+        let key = self.wallet.get_ed25519_key(derivation_path)?;
+        Ok(Ed25519PrivateKey { key })
+    }
+
+    /// obtain an ed25519 signing key for a given CoinType (Solana or Aptos) and index
+    #[wasm_bindgen]
+    pub fn get_ed25519_key_from_index(
+        &self,
+        coin: CoinType,
+        index: u32,
+    ) -> Result<Ed25519PrivateKey, JsValue> {
+        let key = self.wallet.get_ed25519_key_from_index(coin.into(), index)?;
+        Ok(Ed25519PrivateKey { key })
+    }
+
+    /// return the extended private key (xprv/yprv/zprv, or tprv/uprv/vprv on testnet) for a given derivation path
+    #[wasm_bindgen]
+    pub fn get_extended_private_key(&self, derivation_path: String) -> Result<String, JsValue> {
+        Ok(self.wallet.get_extended_private_key(derivation_path)?)
+    }
+
+    /// return the extended public key (xpub/ypub/zpub, or tpub/upub/vpub on testnet) for a given derivation path
+    #[wasm_bindgen]
+    pub fn get_extended_public_key(&self, derivation_path: String) -> Result<String, JsValue> {
+        Ok(self.wallet.get_extended_public_key(derivation_path)?)
+    }
+
+    /// return the account-level extended private key (xprv/yprv/zprv, or tprv/uprv/vprv on testnet) for a given CoinType
+    #[wasm_bindgen]
+    pub fn get_account_extended_private_key(
+        &self,
+        coin: CoinType,
+        account: u32,
+    ) -> Result<String, JsValue> {
+        Ok(self
+            .wallet
+            .get_account_extended_private_key(coin.into(), account)?)
+    }
+
+    /// return the account-level extended public key (xpub/ypub/zpub, or tpub/upub/vpub on testnet) for a given CoinType
+    #[wasm_bindgen]
+    pub fn get_account_extended_public_key(
+        &self,
+        coin: CoinType,
+        account: u32,
+    ) -> Result<String, JsValue> {
+        Ok(self
+            .wallet
+            .get_account_extended_public_key(coin.into(), account)?)
+    }
+
     /// Get the mnemonic for the wallet
     #[wasm_bindgen]
     pub fn get_backup_mnemonic_phrase(&self) -> Option<String> {
         self.wallet.get_backup_mnemonic_phrase()
     }
+
+    /// Get the language of the mnemonic for the wallet
+    #[wasm_bindgen]
+    pub fn get_mnemonic_language(&self) -> Option<MnemonicLanguage> {
+        self.wallet.get_mnemonic_language().map(|val| val.into())
+    }
+}
+
+#[wasm_bindgen]
+impl WatchOnlyWallet {
+    /// constructs a watch-only wallet from an account-level extended public key
+    #[wasm_bindgen(constructor)]
+    pub fn new(extended_public_key: String, coin: CoinType) -> Result<WatchOnlyWallet, JsValue> {
+        let wallet = CoreWatchOnlyWallet::new(extended_public_key, coin.into())?;
+        Ok(Self { wallet })
+    }
+
+    /// return the extended public key of the wallet
+    #[wasm_bindgen]
+    pub fn get_extended_public_key(&self) -> String {
+        self.wallet.get_extended_public_key()
+    }
+
+    /// return the compressed public key bytes for a given index
+    #[wasm_bindgen]
+    pub fn get_public_key_bytes(&self, index: u32) -> Result<Vec<u8>, JsValue> {
+        Ok(self.wallet.get_public_key_bytes(index)?)
+    }
+
+    /// return the default address
+    #[wasm_bindgen]
+    pub fn get_default_address(&self) -> Result<String, JsValue> {
+        Ok(self.wallet.get_default_address()?)
+    }
+
+    /// return the address for a given index
+    #[wasm_bindgen]
+    pub fn get_address(&self, index: u32) -> Result<String, JsValue> {
+        Ok(self.wallet.get_address(index)?)
+    }
 }
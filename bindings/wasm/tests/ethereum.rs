@@ -17,10 +17,10 @@ use js_sys::BigInt;
 wasm_bindgen_test_configure!(run_in_browser);
 #[wasm_bindgen_test]
 async fn test_ethereum() {
-    let from_wallet = Wallet::recover_wallet(SIGNER1_MNEMONIC.to_owned(), None).unwrap();
+    let from_wallet = Wallet::recover_wallet(SIGNER1_MNEMONIC.to_owned(), None, None).unwrap();
     let from_address = from_wallet.get_default_address(CoinType::Cronos).unwrap();
     let private_key = from_wallet.get_key_from_index(CoinType::Cronos, 0).unwrap();
-    let to_wallet = Wallet::recover_wallet(SIGNER2_MNEMONIC.to_owned(), None).unwrap();
+    let to_wallet = Wallet::recover_wallet(SIGNER2_MNEMONIC.to_owned(), None, None).unwrap();
     let to_address = to_wallet.get_default_address(CoinType::Cronos).unwrap();
     let now = Instant::now();
     let initial_balance: BigInt =
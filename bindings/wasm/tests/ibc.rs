@@ -54,6 +54,7 @@ async fn send_transfer_msg() {
         0,
         0,
         timeout.as_nanos().try_into().unwrap(),
+        None,
     ));
     let signed_data = tx
         .sign_into(
@@ -89,7 +89,7 @@ pub(crate) fn cronos_client() -> CosmosClient {
 }
 
 pub(crate) fn get_private_key(mnemonic: &str) -> PrivateKey {
-    let wallet = Wallet::recover_wallet(mnemonic.to_owned(), None).unwrap();
+    let wallet = Wallet::recover_wallet(mnemonic.to_owned(), None, None).unwrap();
     wallet.get_key("m/44'/394'/0'/0/0".to_owned()).unwrap()
 }
 
@@ -5,6 +5,8 @@
 mod cosmos_sdk;
 /// wrappers around Web3 API + basic contract types
 pub mod ethereum;
+/// wrappers around Cosmos SDK governance grpc/grpc-web API
+pub mod gov;
 /// wrappers around chainmain NFT grpc/grpc-web API
 pub mod nft;
 /// wasm binding related functions
@@ -14,6 +16,7 @@ mod error;
 pub use cosmos_sdk::*;
 pub use error::*;
 pub use ethereum::*;
+pub use gov::*;
 pub use nft::*;
 #[cfg(target_arch = "wasm32")]
 pub use wasm_binding::*;
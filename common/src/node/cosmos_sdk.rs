@@ -1,11 +1,14 @@
 use super::error::RestError;
 #[cfg(not(target_arch = "wasm32"))]
-use cosmos_sdk_proto::cosmos::{
-    bank::v1beta1::{query_client::QueryClient, Metadata, QueryDenomMetadataRequest},
-    tx::v1beta1::{service_client::ServiceClient, SimulateRequest},
+use crate::PageRequest;
+#[cfg(not(target_arch = "wasm32"))]
+use cosmos_sdk_proto::cosmos::bank::v1beta1::{
+    query_client::QueryClient, Metadata, QueryDenomMetadataRequest,
 };
+use cosmos_sdk_proto::cosmos::tx::v1beta1::{service_client::ServiceClient, SimulateRequest};
 #[cfg(not(target_arch = "wasm32"))]
 use itertools::Itertools;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, DisplayFromStr};
 use tendermint_rpc::{
@@ -13,9 +16,53 @@ use tendermint_rpc::{
     request, response,
 };
 
-mod balance_query;
+/// connects the gRPC (or gRPC-web for JS/WASM) query client
+#[cfg(target_arch = "wasm32")]
+macro_rules! connect {
+    ($client:ident, $url:expr) => {
+        Ok::<_, RestError>($client::new(tonic_web_wasm_client::Client::new(
+            $url.to_owned(),
+        )))
+    };
+}
 
+#[cfg(not(target_arch = "wasm32"))]
+macro_rules! connect {
+    ($client:ident, $url:expr) => {
+        $client::connect($url.to_owned())
+            .await
+            .map_err(RestError::GRPCTransportError)
+    };
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn block_on<T, E: From<RestError>>(
+    future: impl std::future::Future<Output = Result<T, E>>,
+) -> Result<T, E> {
+    tokio::runtime::Runtime::new()
+        .map_err(|_err| RestError::AsyncRuntimeError)?
+        .block_on(future)
+}
+
+mod authz_query;
+mod balance_query;
+mod event_subscription;
+mod gas_estimation;
+mod ibc_query;
+mod staking_query;
+mod tx_history;
+mod tx_wait;
+mod vesting_account;
+
+pub use authz_query::*;
 pub use balance_query::*;
+pub use event_subscription::*;
+pub use gas_estimation::*;
+pub use ibc_query::*;
+pub use staking_query::*;
+pub use tx_history::*;
+pub use tx_wait::*;
+pub use vesting_account::*;
 
 /// The raw response from the account API
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
@@ -41,6 +88,7 @@ pub enum RawRpcAccountResponse {
 /// the raw account status data from the account API
 #[serde_as]
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[serde(try_from = "RawRpcAccount")]
 pub struct RawRpcAccountStatus {
     /// the protobuf type
     #[serde(rename = "@type")]
@@ -55,6 +103,85 @@ pub struct RawRpcAccountStatus {
     /// the sequence number / nonce
     #[serde_as(as = "DisplayFromStr")]
     pub sequence: u64,
+    /// the vesting details (only for the vesting accounts)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vesting: Option<VestingAccountInfo>,
+}
+
+/// the raw base account data from the account API
+#[serde_as]
+#[derive(Deserialize)]
+struct RawRpcBaseAccount {
+    address: String,
+    pub_key: Option<RawRpcPubKey>,
+    #[serde_as(as = "DisplayFromStr")]
+    account_number: u64,
+    #[serde_as(as = "DisplayFromStr")]
+    sequence: u64,
+}
+
+/// the raw account data from the account API
+/// (the base account is nested in the vesting and module accounts)
+#[serde_as]
+#[derive(Deserialize)]
+struct RawRpcAccount {
+    #[serde(rename = "@type")]
+    account_type: String,
+    #[serde(default)]
+    address: Option<String>,
+    #[serde(default)]
+    pub_key: Option<RawRpcPubKey>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    account_number: Option<u64>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    sequence: Option<u64>,
+    /// the module (or Ethermint) accounts
+    #[serde(default)]
+    base_account: Option<RawRpcBaseAccount>,
+    /// the vesting accounts
+    #[serde(flatten)]
+    vesting_account: RawRpcVestingAccount,
+    /// set when deserializing the serialized `RawRpcAccountStatus`
+    #[serde(default)]
+    vesting: Option<VestingAccountInfo>,
+}
+
+impl TryFrom<RawRpcAccount> for RawRpcAccountStatus {
+    type Error = String;
+
+    fn try_from(account: RawRpcAccount) -> Result<Self, Self::Error> {
+        let (base_account, vesting) = match account
+            .vesting_account
+            .into_parts(&account.account_type)
+        {
+            Some(parts) => parts,
+            None => {
+                let base_account = match (account.address, account.account_number, account.sequence)
+                {
+                    (Some(address), Some(account_number), Some(sequence)) => RawRpcBaseAccount {
+                        address,
+                        pub_key: account.pub_key,
+                        account_number,
+                        sequence,
+                    },
+                    _ => account.base_account.ok_or_else(|| {
+                        format!("missing base account in {}", account.account_type)
+                    })?,
+                };
+                (base_account, account.vesting)
+            }
+        };
+        Ok(Self {
+            account_type: account.account_type,
+            address: base_account.address,
+            pub_key: base_account.pub_key,
+            account_number: base_account.account_number,
+            sequence: base_account.sequence,
+            vesting,
+        })
+    }
 }
 
 /// the raw pubkey data returned from the account API
@@ -120,26 +247,45 @@ pub fn get_account_details_blocking(
     Ok(resp)
 }
 
+/// queries the Cosmos REST API
+async fn query_rest<T: DeserializeOwned>(url: String) -> Result<T, RestError> {
+    reqwest::Client::new()
+        .get(url)
+        .send()
+        .await
+        .map_err(RestError::RequestError)?
+        .json::<T>()
+        .await
+        .map_err(RestError::RequestError)
+}
+
+async fn query_simulate(grpc_url: &str, tx: Vec<u8>) -> Result<u64, RestError> {
+    let mut client = connect!(ServiceClient, grpc_url)?;
+    let request = SimulateRequest {
+        tx_bytes: tx,
+        ..Default::default()
+    };
+    let res = client
+        .simulate(request)
+        .await
+        .map_err(RestError::GRPCError)?;
+    let gas_info = res.into_inner().gas_info.ok_or(RestError::MissingResult)?;
+    Ok(gas_info.gas_used)
+}
+
+/// given the gRPC-web endpoint and the raw signed transaction bytes,
+/// it'll submit the transaction for simulating its execution and return the used gas.
+/// (async for JS/WASM)
+#[cfg(target_arch = "wasm32")]
+pub async fn simulate(grpc_web_url: &str, tx: Vec<u8>) -> Result<u64, RestError> {
+    query_simulate(grpc_web_url, tx).await
+}
+
 /// given the gRPC endpoint and the raw signed transaction bytes,
 /// it'll submit the transaction for simulating its execution and return the used gas.
 #[cfg(not(target_arch = "wasm32"))]
 pub fn simulate_blocking(grpc_url: &str, tx: Vec<u8>) -> Result<u64, RestError> {
-    let rt = tokio::runtime::Runtime::new().map_err(|_err| RestError::AsyncRuntimeError)?;
-    let result = rt.block_on(async move {
-        let mut client = ServiceClient::connect(grpc_url.to_owned())
-            .await
-            .map_err(RestError::GRPCTransportError)?;
-        let request = SimulateRequest {
-            tx_bytes: tx,
-            ..Default::default()
-        };
-        let res = client
-            .simulate(request)
-            .await
-            .map_err(RestError::GRPCError)?;
-        res.into_inner().gas_info.ok_or(RestError::MissingResult)
-    })?;
-    Ok(result.gas_used)
+    block_on(query_simulate(grpc_url, tx))
 }
 
 /// Metadata about a coin denomination
@@ -352,6 +498,22 @@ impl CosmosSDKClient {
         broadcast_tx_blocking(&self.tendermint_rpc_url, raw_signed_tx, txmode)
     }
 
+    /// broadcast the tx and wait until it's committed (blocking)
+    /// default timeout is 60 seconds and default interval is 1 second
+    pub fn broadcast_tx_and_wait(
+        &self,
+        raw_signed_tx: Vec<u8>,
+        timeout_ms: Option<u64>,
+        interval_ms: Option<u64>,
+    ) -> Result<CosmosTxReceipt, RestError> {
+        broadcast_tx_and_wait_blocking(
+            &self.tendermint_rpc_url,
+            raw_signed_tx,
+            timeout_ms.unwrap_or(DEFAULT_TX_POLL_TIMEOUT_MS),
+            interval_ms.unwrap_or(DEFAULT_TX_POLL_INTERVAL_MS),
+        )
+    }
+
     /// return the balance (blocking)
     pub fn get_account_balance(
         &self,
@@ -376,4 +538,101 @@ impl CosmosSDKClient {
     pub fn simulate(&self, raw_signed_tx: Vec<u8>) -> Result<u64, RestError> {
         simulate_blocking(&self.grpc_url, raw_signed_tx)
     }
+
+    /// return the delegations of the delegator (blocking)
+    pub fn get_delegations(
+        &self,
+        delegator_address: &str,
+        pagination: Option<PageRequest>,
+    ) -> Result<Vec<DelegationInfo>, RestError> {
+        get_delegations_blocking(&self.grpc_url, delegator_address, pagination)
+    }
+
+    /// return the unbonding delegations of the delegator (blocking)
+    pub fn get_unbonding_delegations(
+        &self,
+        delegator_address: &str,
+        pagination: Option<PageRequest>,
+    ) -> Result<Vec<UnbondingDelegationInfo>, RestError> {
+        get_unbonding_delegations_blocking(&self.grpc_url, delegator_address, pagination)
+    }
+
+    /// return the redelegations of the delegator (blocking)
+    pub fn get_redelegations(
+        &self,
+        delegator_address: &str,
+        pagination: Option<PageRequest>,
+    ) -> Result<Vec<RedelegationInfo>, RestError> {
+        get_redelegations_blocking(&self.grpc_url, delegator_address, pagination)
+    }
+
+    /// return the pending rewards of the delegator (blocking)
+    pub fn get_delegation_rewards(
+        &self,
+        delegator_address: &str,
+    ) -> Result<DelegationRewards, RestError> {
+        get_delegation_rewards_blocking(&self.grpc_url, delegator_address)
+    }
+
+    /// return the validators, optionally filtered by their status (blocking)
+    pub fn get_validators(
+        &self,
+        status: Option<ValidatorBondStatus>,
+        pagination: Option<PageRequest>,
+    ) -> Result<Vec<ValidatorInfo>, RestError> {
+        get_validators_blocking(&self.grpc_url, status, pagination)
+    }
+
+    /// return the staking parameters (blocking)
+    pub fn get_staking_params(&self) -> Result<StakingParams, RestError> {
+        get_staking_params_blocking(&self.grpc_url)
+    }
+
+    /// return the authorizations granted by the granter to the grantee,
+    /// optionally filtered by the message type URL (blocking)
+    pub fn get_grants(
+        &self,
+        granter: &str,
+        grantee: &str,
+        msg_type_url: &str,
+        pagination: Option<PageRequest>,
+    ) -> Result<Vec<AuthzGrantInfo>, RestError> {
+        get_grants_blocking(&self.grpc_url, granter, grantee, msg_type_url, pagination)
+    }
+
+    /// return the authorizations granted by the granter (blocking)
+    pub fn get_granter_grants(
+        &self,
+        granter: &str,
+        pagination: Option<PageRequest>,
+    ) -> Result<Vec<AuthzGrantInfo>, RestError> {
+        get_granter_grants_blocking(&self.grpc_url, granter, pagination)
+    }
+
+    /// return the authorizations granted to the grantee (blocking)
+    pub fn get_grantee_grants(
+        &self,
+        grantee: &str,
+        pagination: Option<PageRequest>,
+    ) -> Result<Vec<AuthzGrantInfo>, RestError> {
+        get_grantee_grants_blocking(&self.grpc_url, grantee, pagination)
+    }
+
+    /// return the fee allowance granted by the granter to the grantee (blocking)
+    pub fn get_fee_allowance(
+        &self,
+        granter: &str,
+        grantee: &str,
+    ) -> Result<FeeAllowanceInfo, RestError> {
+        get_fee_allowance_blocking(&self.grpc_url, granter, grantee)
+    }
+
+    /// return the fee allowances granted to the grantee (blocking)
+    pub fn get_fee_allowances(
+        &self,
+        grantee: &str,
+        pagination: Option<PageRequest>,
+    ) -> Result<Vec<FeeAllowanceInfo>, RestError> {
+        get_fee_allowances_blocking(&self.grpc_url, grantee, pagination)
+    }
 }
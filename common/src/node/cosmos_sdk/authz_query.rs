@@ -0,0 +1,325 @@
+#[cfg(not(target_arch = "wasm32"))]
+use super::block_on;
+use crate::{Authorization, FeeAllowance, PageRequest, RestError};
+use cosmos_sdk_proto::cosmos::authz::v1beta1::{
+    query_client::QueryClient as AuthzQueryClient, GrantAuthorization, QueryGranteeGrantsRequest,
+    QueryGranterGrantsRequest, QueryGrantsRequest,
+};
+use cosmos_sdk_proto::cosmos::feegrant::v1beta1::{
+    query_client::QueryClient as FeegrantQueryClient, Grant as FeegrantGrant,
+    QueryAllowanceRequest, QueryAllowancesRequest,
+};
+use serde::{Deserialize, Serialize};
+
+/// an authorization granted by the granter to the grantee
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct AuthzGrantInfo {
+    pub granter: String,
+    pub grantee: String,
+    /// the type URL of the authorization (also set for the unsupported ones)
+    pub authorization_type_url: String,
+    /// the decoded authorization (if it is supported)
+    pub authorization: Option<Authorization>,
+    /// the expiration time in seconds since the Unix epoch
+    pub expiration: Option<i64>,
+}
+
+impl From<GrantAuthorization> for AuthzGrantInfo {
+    fn from(grant: GrantAuthorization) -> Self {
+        let authorization = grant.authorization.unwrap_or_default();
+        Self {
+            granter: grant.granter,
+            grantee: grant.grantee,
+            authorization: Authorization::try_from(&authorization).ok(),
+            authorization_type_url: authorization.type_url,
+            expiration: grant.expiration.map(|time| time.seconds),
+        }
+    }
+}
+
+/// a fee allowance granted by the granter to the grantee
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct FeeAllowanceInfo {
+    pub granter: String,
+    pub grantee: String,
+    /// the type URL of the allowance (also set for the unsupported ones)
+    pub allowance_type_url: String,
+    /// the decoded allowance (if it is supported)
+    pub allowance: Option<FeeAllowance>,
+}
+
+impl From<FeegrantGrant> for FeeAllowanceInfo {
+    fn from(grant: FeegrantGrant) -> Self {
+        let allowance = grant.allowance.unwrap_or_default();
+        Self {
+            granter: grant.granter,
+            grantee: grant.grantee,
+            allowance: FeeAllowance::try_from(&allowance).ok(),
+            allowance_type_url: allowance.type_url,
+        }
+    }
+}
+
+async fn query_grants(
+    grpc_url: &str,
+    granter: &str,
+    grantee: &str,
+    msg_type_url: &str,
+    pagination: Option<PageRequest>,
+) -> Result<Vec<AuthzGrantInfo>, RestError> {
+    let mut client = connect!(AuthzQueryClient, grpc_url)?;
+    let request = QueryGrantsRequest {
+        granter: granter.to_owned(),
+        grantee: grantee.to_owned(),
+        msg_type_url: msg_type_url.to_owned(),
+        pagination,
+    };
+    let res = client
+        .grants(request)
+        .await
+        .map_err(RestError::GRPCError)?
+        .into_inner();
+    // the grants between two accounts have no granter and grantee
+    Ok(res
+        .grants
+        .into_iter()
+        .map(|grant| {
+            GrantAuthorization {
+                granter: granter.to_owned(),
+                grantee: grantee.to_owned(),
+                authorization: grant.authorization,
+                expiration: grant.expiration,
+            }
+            .into()
+        })
+        .collect())
+}
+
+async fn query_granter_grants(
+    grpc_url: &str,
+    granter: &str,
+    pagination: Option<PageRequest>,
+) -> Result<Vec<AuthzGrantInfo>, RestError> {
+    let mut client = connect!(AuthzQueryClient, grpc_url)?;
+    let request = QueryGranterGrantsRequest {
+        granter: granter.to_owned(),
+        pagination,
+    };
+    let res = client
+        .granter_grants(request)
+        .await
+        .map_err(RestError::GRPCError)?
+        .into_inner();
+    Ok(res.grants.into_iter().map(Into::into).collect())
+}
+
+async fn query_grantee_grants(
+    grpc_url: &str,
+    grantee: &str,
+    pagination: Option<PageRequest>,
+) -> Result<Vec<AuthzGrantInfo>, RestError> {
+    let mut client = connect!(AuthzQueryClient, grpc_url)?;
+    let request = QueryGranteeGrantsRequest {
+        grantee: grantee.to_owned(),
+        pagination,
+    };
+    let res = client
+        .grantee_grants(request)
+        .await
+        .map_err(RestError::GRPCError)?
+        .into_inner();
+    Ok(res.grants.into_iter().map(Into::into).collect())
+}
+
+async fn query_fee_allowance(
+    grpc_url: &str,
+    granter: &str,
+    grantee: &str,
+) -> Result<FeeAllowanceInfo, RestError> {
+    let mut client = connect!(FeegrantQueryClient, grpc_url)?;
+    let request = QueryAllowanceRequest {
+        granter: granter.to_owned(),
+        grantee: grantee.to_owned(),
+    };
+    let res = client
+        .allowance(request)
+        .await
+        .map_err(RestError::GRPCError)?
+        .into_inner();
+    Ok(res.allowance.ok_or(RestError::MissingResult)?.into())
+}
+
+async fn query_fee_allowances(
+    grpc_url: &str,
+    grantee: &str,
+    pagination: Option<PageRequest>,
+) -> Result<Vec<FeeAllowanceInfo>, RestError> {
+    let mut client = connect!(FeegrantQueryClient, grpc_url)?;
+    let request = QueryAllowancesRequest {
+        grantee: grantee.to_owned(),
+        pagination,
+    };
+    let res = client
+        .allowances(request)
+        .await
+        .map_err(RestError::GRPCError)?
+        .into_inner();
+    Ok(res.allowances.into_iter().map(Into::into).collect())
+}
+
+/// return the authorizations granted by the granter to the grantee,
+/// optionally filtered by the message type URL (async for JS/WASM)
+#[cfg(target_arch = "wasm32")]
+pub async fn get_grants(
+    grpc_web_url: &str,
+    granter: &str,
+    grantee: &str,
+    msg_type_url: &str,
+    pagination: Option<PageRequest>,
+) -> Result<Vec<AuthzGrantInfo>, RestError> {
+    query_grants(grpc_web_url, granter, grantee, msg_type_url, pagination).await
+}
+
+/// return the authorizations granted by the granter to the grantee,
+/// optionally filtered by the message type URL (blocking for other platforms)
+#[cfg(not(target_arch = "wasm32"))]
+pub fn get_grants_blocking(
+    grpc_url: &str,
+    granter: &str,
+    grantee: &str,
+    msg_type_url: &str,
+    pagination: Option<PageRequest>,
+) -> Result<Vec<AuthzGrantInfo>, RestError> {
+    block_on(query_grants(
+        grpc_url,
+        granter,
+        grantee,
+        msg_type_url,
+        pagination,
+    ))
+}
+
+/// return the authorizations granted by the granter (async for JS/WASM)
+#[cfg(target_arch = "wasm32")]
+pub async fn get_granter_grants(
+    grpc_web_url: &str,
+    granter: &str,
+    pagination: Option<PageRequest>,
+) -> Result<Vec<AuthzGrantInfo>, RestError> {
+    query_granter_grants(grpc_web_url, granter, pagination).await
+}
+
+/// return the authorizations granted by the granter (blocking for other platforms)
+#[cfg(not(target_arch = "wasm32"))]
+pub fn get_granter_grants_blocking(
+    grpc_url: &str,
+    granter: &str,
+    pagination: Option<PageRequest>,
+) -> Result<Vec<AuthzGrantInfo>, RestError> {
+    block_on(query_granter_grants(grpc_url, granter, pagination))
+}
+
+/// return the authorizations granted to the grantee (async for JS/WASM)
+#[cfg(target_arch = "wasm32")]
+pub async fn get_grantee_grants(
+    grpc_web_url: &str,
+    grantee: &str,
+    pagination: Option<PageRequest>,
+) -> Result<Vec<AuthzGrantInfo>, RestError> {
+    query_grantee_grants(grpc_web_url, grantee, pagination).await
+}
+
+/// return the authorizations granted to the grantee (blocking for other platforms)
+#[cfg(not(target_arch = "wasm32"))]
+pub fn get_grantee_grants_blocking(
+    grpc_url: &str,
+    grantee: &str,
+    pagination: Option<PageRequest>,
+) -> Result<Vec<AuthzGrantInfo>, RestError> {
+    block_on(query_grantee_grants(grpc_url, grantee, pagination))
+}
+
+/// return the fee allowance granted by the granter to the grantee (async for JS/WASM)
+#[cfg(target_arch = "wasm32")]
+pub async fn get_fee_allowance(
+    grpc_web_url: &str,
+    granter: &str,
+    grantee: &str,
+) -> Result<FeeAllowanceInfo, RestError> {
+    query_fee_allowance(grpc_web_url, granter, grantee).await
+}
+
+/// return the fee allowance granted by the granter to the grantee (blocking for other platforms)
+#[cfg(not(target_arch = "wasm32"))]
+pub fn get_fee_allowance_blocking(
+    grpc_url: &str,
+    granter: &str,
+    grantee: &str,
+) -> Result<FeeAllowanceInfo, RestError> {
+    block_on(query_fee_allowance(grpc_url, granter, grantee))
+}
+
+/// return the fee allowances granted to the grantee (async for JS/WASM)
+#[cfg(target_arch = "wasm32")]
+pub async fn get_fee_allowances(
+    grpc_web_url: &str,
+    grantee: &str,
+    pagination: Option<PageRequest>,
+) -> Result<Vec<FeeAllowanceInfo>, RestError> {
+    query_fee_allowances(grpc_web_url, grantee, pagination).await
+}
+
+/// return the fee allowances granted to the grantee (blocking for other platforms)
+#[cfg(not(target_arch = "wasm32"))]
+pub fn get_fee_allowances_blocking(
+    grpc_url: &str,
+    grantee: &str,
+    pagination: Option<PageRequest>,
+) -> Result<Vec<FeeAllowanceInfo>, RestError> {
+    block_on(query_fee_allowances(grpc_url, grantee, pagination))
+}
+
+#[cfg(test)]
+mod authz_query_tests {
+    use super::*;
+    use cosmos_sdk_proto::cosmos::authz::v1beta1::GenericAuthorization;
+    use cosmos_sdk_proto::traits::Message;
+
+    #[test]
+    fn test_grant_info_conversion() {
+        let grant = GrantAuthorization {
+            granter: "cosmos1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5lzv7xu".to_owned(),
+            grantee: "cosmos1pkptre7fdkl6gfrzlesjjvhxhlc3r4gmmk8rs6".to_owned(),
+            authorization: Some(cosmrs::Any {
+                type_url: "/cosmos.authz.v1beta1.GenericAuthorization".to_owned(),
+                value: GenericAuthorization {
+                    msg: "/cosmos.gov.v1beta1.MsgVote".to_owned(),
+                }
+                .encode_to_vec(),
+            }),
+            expiration: None,
+        };
+        let info = AuthzGrantInfo::from(grant);
+        assert_eq!(
+            info.authorization,
+            Some(Authorization::Generic {
+                msg: "/cosmos.gov.v1beta1.MsgVote".to_owned()
+            })
+        );
+
+        // the unsupported allowances are only reported by their type URL
+        let info = FeeAllowanceInfo::from(FeegrantGrant {
+            granter: "cosmos1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5lzv7xu".to_owned(),
+            grantee: "cosmos1pkptre7fdkl6gfrzlesjjvhxhlc3r4gmmk8rs6".to_owned(),
+            allowance: Some(cosmrs::Any {
+                type_url: "/cosmos.feegrant.v1beta1.AllowedMsgAllowance".to_owned(),
+                value: vec![],
+            }),
+        });
+        assert_eq!(
+            info.allowance_type_url,
+            "/cosmos.feegrant.v1beta1.AllowedMsgAllowance"
+        );
+        assert_eq!(info.allowance, None);
+    }
+}
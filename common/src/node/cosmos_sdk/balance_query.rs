@@ -4,7 +4,7 @@ use cosmos_sdk_proto::cosmos::bank::v1beta1::{QueryBalanceRequest, QueryBalanceR
 use serde::{Deserialize, Serialize};
 
 /// The raw balance data from the balance API
-#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
 pub struct RawRpcBalance {
     /// denomination
     pub denom: String,
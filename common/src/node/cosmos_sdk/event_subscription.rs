@@ -0,0 +1,562 @@
+use crate::{RestError, TxHistoryFilter};
+#[cfg(not(target_arch = "wasm32"))]
+use futures::{channel::mpsc, channel::oneshot, Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+#[cfg(not(target_arch = "wasm32"))]
+use std::sync::Mutex;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Duration;
+use tendermint::abci;
+use tendermint_rpc::endpoint::subscribe;
+use tendermint_rpc::event::{Event, EventData};
+use tendermint_rpc::query::{EventType, Query};
+use tendermint_rpc::{request, response};
+#[cfg(not(target_arch = "wasm32"))]
+use tendermint_rpc::{SubscriptionClient, Url, WebSocketClient};
+
+/// the events to subscribe to over the Tendermint websocket (usually on 26657/websocket)
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum CosmosSubscription {
+    /// the committed transactions of the address
+    Address {
+        address: String,
+        filter: TxHistoryFilter,
+    },
+    /// the new blocks
+    NewBlock,
+}
+
+impl CosmosSubscription {
+    /// the Tendermint event query, e.g. `tm.event = 'Tx' AND transfer.recipient = '...'`
+    pub fn query(&self) -> Query {
+        match self {
+            CosmosSubscription::Address { address, filter } => filter
+                .events(address)
+                .into_iter()
+                .fold(Query::from(EventType::Tx), |query, (key, value)| {
+                    query.and_eq(key, value)
+                }),
+            CosmosSubscription::NewBlock => Query::from(EventType::NewBlock),
+        }
+    }
+
+    /// if the decoded transaction event concerns the subscribed address
+    fn includes(&self, event: &CosmosSubscriptionEvent) -> bool {
+        match (self, event) {
+            (
+                CosmosSubscription::Address { address, filter },
+                CosmosSubscriptionEvent::Transfer {
+                    sender, recipient, ..
+                },
+            ) => match filter {
+                TxHistoryFilter::Received => recipient == address,
+                TxHistoryFilter::Sent => sender == address || recipient == address,
+                TxHistoryFilter::Staking => false,
+            },
+            (CosmosSubscription::Address { filter, .. }, _) => *filter != TxHistoryFilter::Received,
+            (CosmosSubscription::NewBlock, _) => true,
+        }
+    }
+}
+
+/// a transfer or staking event of a committed transaction or a new block
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum CosmosSubscriptionEvent {
+    Transfer {
+        /// tendermint transaction hash in hexadecimal
+        tx_hash: String,
+        height: u64,
+        sender: String,
+        recipient: String,
+        /// the transferred coins, e.g. "100basecro,5ibc/..."
+        amount: String,
+    },
+    Delegate {
+        tx_hash: String,
+        height: u64,
+        validator: String,
+        amount: String,
+    },
+    Unbond {
+        tx_hash: String,
+        height: u64,
+        validator: String,
+        amount: String,
+    },
+    Redelegate {
+        tx_hash: String,
+        height: u64,
+        source_validator: String,
+        destination_validator: String,
+        amount: String,
+    },
+    WithdrawRewards {
+        tx_hash: String,
+        height: u64,
+        validator: String,
+        amount: String,
+    },
+    NewBlock {
+        height: u64,
+        /// the block time in RFC 3339
+        time: String,
+    },
+}
+
+/// the attribute groups of the event (older Cosmos SDK versions put several transfers
+/// in one event, so a repeated key starts a new group)
+fn attribute_groups(event: &abci::Event) -> Vec<Vec<(&str, &str)>> {
+    let mut groups: Vec<Vec<(&str, &str)>> = vec![];
+    for attribute in event.attributes.iter() {
+        let key = attribute.key.as_str();
+        match groups.last_mut() {
+            Some(group) if !group.iter().any(|(k, _)| *k == key) => {
+                group.push((key, attribute.value.as_str()))
+            }
+            _ => groups.push(vec![(key, attribute.value.as_str())]),
+        }
+    }
+    groups
+}
+
+fn decode_tx_event(
+    tx_hash: &str,
+    height: u64,
+    event: &abci::Event,
+) -> Vec<CosmosSubscriptionEvent> {
+    attribute_groups(event)
+        .into_iter()
+        .filter_map(|group| {
+            let get = |key: &str| {
+                group
+                    .iter()
+                    .find(|(k, _)| *k == key)
+                    .map(|(_, v)| (*v).to_owned())
+            };
+            let (tx_hash, amount) = (tx_hash.to_owned(), get("amount").unwrap_or_default());
+            match event.kind.as_str() {
+                "transfer" => Some(CosmosSubscriptionEvent::Transfer {
+                    tx_hash,
+                    height,
+                    sender: get("sender")?,
+                    recipient: get("recipient")?,
+                    amount,
+                }),
+                "delegate" => Some(CosmosSubscriptionEvent::Delegate {
+                    tx_hash,
+                    height,
+                    validator: get("validator")?,
+                    amount,
+                }),
+                "unbond" => Some(CosmosSubscriptionEvent::Unbond {
+                    tx_hash,
+                    height,
+                    validator: get("validator")?,
+                    amount,
+                }),
+                "redelegate" => Some(CosmosSubscriptionEvent::Redelegate {
+                    tx_hash,
+                    height,
+                    source_validator: get("source_validator")?,
+                    destination_validator: get("destination_validator")?,
+                    amount,
+                }),
+                "withdraw_rewards" => Some(CosmosSubscriptionEvent::WithdrawRewards {
+                    tx_hash,
+                    height,
+                    validator: get("validator")?,
+                    amount,
+                }),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// decode the transfer and staking events of the subscribed transaction or the new block
+pub fn decode_subscription_event(
+    subscription: &CosmosSubscription,
+    event: Event,
+) -> Vec<CosmosSubscriptionEvent> {
+    match event.data {
+        EventData::Tx { tx_result } => {
+            let tx_hash = hex::encode_upper(Sha256::digest(&tx_result.tx));
+            let height = u64::try_from(tx_result.height).unwrap_or_default();
+            let events = &tx_result.result.events;
+            // the transfers before the first message action are the fee payment of the ante handler
+            let first_message = events
+                .iter()
+                .position(|event| {
+                    event.kind == "message"
+                        && event
+                            .attributes
+                            .iter()
+                            .any(|attribute| attribute.key == "action")
+                })
+                .unwrap_or_default();
+            events
+                .iter()
+                .enumerate()
+                .filter(|(index, event)| *index >= first_message || event.kind != "transfer")
+                .flat_map(|(_, event)| decode_tx_event(&tx_hash, height, event))
+                .filter(|event| subscription.includes(event))
+                .collect()
+        }
+        EventData::NewBlock {
+            block: Some(block), ..
+        } => vec![CosmosSubscriptionEvent::NewBlock {
+            height: block.header.height.value(),
+            time: block.header.time.to_rfc3339(),
+        }],
+        _ => vec![],
+    }
+}
+
+/// the JSON-RPC request subscribing to the events over a websocket
+pub fn subscription_request(subscription: &CosmosSubscription) -> Result<String, RestError> {
+    let request = request::Wrapper::new(subscribe::Request::new(subscription.query().to_string()));
+    serde_json::to_string(&request).map_err(|e| RestError::SubscriptionError(e.to_string()))
+}
+
+/// decode a websocket message of the subscription
+/// (the subscription acknowledgement has no events)
+pub fn parse_subscription_message(
+    subscription: &CosmosSubscription,
+    message: &str,
+) -> Result<Vec<CosmosSubscriptionEvent>, RestError> {
+    let result = serde_json::from_str::<response::Wrapper<serde_json::Value>>(message)
+        .map_err(|e| RestError::SubscriptionError(e.to_string()))?
+        .into_result()
+        .map_err(|e| RestError::SubscriptionError(e.to_string()))?;
+    if result.as_object().map_or(true, |result| result.is_empty()) {
+        return Ok(vec![]);
+    }
+    let event: Event =
+        serde_json::from_value(result).map_err(|e| RestError::SubscriptionError(e.to_string()))?;
+    Ok(decode_subscription_event(subscription, event))
+}
+
+/// the delays between the reconnection attempts
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ReconnectBackoff {
+    /// the delay of the first attempt (in milliseconds)
+    pub initial_delay_ms: u64,
+    /// the delay doubles after each failed attempt up to this one (in milliseconds)
+    pub max_delay_ms: u64,
+    /// the attempts before giving up (`None` to retry forever)
+    pub max_retries: Option<u32>,
+}
+
+impl Default for ReconnectBackoff {
+    fn default() -> Self {
+        Self {
+            initial_delay_ms: 1_000,
+            max_delay_ms: 30_000,
+            max_retries: None,
+        }
+    }
+}
+
+impl ReconnectBackoff {
+    /// the delay before the attempt after `retries` failed ones (in milliseconds)
+    pub fn delay_ms(&self, retries: u32) -> u64 {
+        self.initial_delay_ms
+            .saturating_mul(1_u64 << retries.min(16))
+            .min(self.max_delay_ms)
+    }
+
+    /// if another attempt is allowed after `retries` failed ones
+    pub fn can_retry(&self, retries: u32) -> bool {
+        self.max_retries
+            .map_or(true, |max_retries| retries < max_retries)
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn subscription_error(error: tendermint_rpc::Error) -> RestError {
+    RestError::SubscriptionError(error.to_string())
+}
+
+/// connect and forward the events until the connection is closed
+/// (`retries` is reset once subscribed)
+#[cfg(not(target_arch = "wasm32"))]
+async fn forward_events(
+    tendermint_websocket_url: &str,
+    subscription: &CosmosSubscription,
+    sender: &mpsc::UnboundedSender<Result<CosmosSubscriptionEvent, RestError>>,
+    retries: &mut u32,
+) -> Result<(), RestError> {
+    let url: Url = tendermint_websocket_url
+        .parse()
+        .map_err(subscription_error)?;
+    let (client, driver) = WebSocketClient::new(url)
+        .await
+        .map_err(subscription_error)?;
+    let driver_handle = tokio::spawn(driver.run());
+    let result = async {
+        let mut events = client.subscribe(subscription.query()).await?;
+        *retries = 0;
+        while let Some(event) = events.next().await {
+            for event in decode_subscription_event(subscription, event?) {
+                if sender.unbounded_send(Ok(event)).is_err() {
+                    return Ok(());
+                }
+            }
+        }
+        Ok::<_, tendermint_rpc::Error>(())
+    }
+    .await;
+    let _ = client.close();
+    let _ = driver_handle.await;
+    result.map_err(subscription_error)
+}
+
+/// subscribe to the events over the Tendermint websocket (usually on 26657/websocket).
+/// The connection errors are yielded and followed by a reconnection after the backoff delay;
+/// the stream ends once the retries are exhausted.
+/// (it must be polled in a Tokio runtime)
+#[cfg(not(target_arch = "wasm32"))]
+pub fn subscribe_events(
+    tendermint_websocket_url: String,
+    subscription: CosmosSubscription,
+    backoff: ReconnectBackoff,
+) -> impl Stream<Item = Result<CosmosSubscriptionEvent, RestError>> {
+    let (sender, receiver) = mpsc::unbounded();
+    tokio::spawn(async move {
+        let mut retries = 0;
+        loop {
+            let result = forward_events(
+                &tendermint_websocket_url,
+                &subscription,
+                &sender,
+                &mut retries,
+            )
+            .await;
+            let error = result.err().unwrap_or_else(|| {
+                RestError::SubscriptionError("the connection was closed".to_owned())
+            });
+            if sender.unbounded_send(Err(error)).is_err() || !backoff.can_retry(retries) {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(backoff.delay_ms(retries))).await;
+            retries += 1;
+        }
+    });
+    receiver
+}
+
+/// the receiver of the subscribed events
+#[cfg(not(target_arch = "wasm32"))]
+pub trait CosmosEventListener: Send + Sync {
+    fn on_event(&self, event: CosmosSubscriptionEvent);
+    /// called on the connection errors (the subscription reconnects unless
+    /// its retries are exhausted)
+    fn on_error(&self, error: String);
+}
+
+/// a subscription calling the listener from a background thread until it's unsubscribed
+#[cfg(not(target_arch = "wasm32"))]
+pub struct CosmosEventSubscription {
+    stop: Mutex<Option<oneshot::Sender<()>>>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl CosmosEventSubscription {
+    /// subscribe with the default reconnection backoff
+    pub fn new(
+        tendermint_websocket_url: String,
+        subscription: CosmosSubscription,
+        listener: Box<dyn CosmosEventListener>,
+    ) -> Self {
+        Self::with_backoff(
+            tendermint_websocket_url,
+            subscription,
+            listener,
+            ReconnectBackoff::default(),
+        )
+    }
+
+    pub fn with_backoff(
+        tendermint_websocket_url: String,
+        subscription: CosmosSubscription,
+        listener: Box<dyn CosmosEventListener>,
+        backoff: ReconnectBackoff,
+    ) -> Self {
+        let (stop, stopped) = oneshot::channel();
+        std::thread::spawn(move || {
+            let runtime = match tokio::runtime::Runtime::new() {
+                Ok(runtime) => runtime,
+                Err(e) => return listener.on_error(e.to_string()),
+            };
+            runtime.block_on(async move {
+                let mut events = subscribe_events(tendermint_websocket_url, subscription, backoff)
+                    .take_until(stopped);
+                while let Some(event) = events.next().await {
+                    match event {
+                        Ok(event) => listener.on_event(event),
+                        Err(e) => listener.on_error(e.to_string()),
+                    }
+                }
+            });
+        });
+        Self {
+            stop: Mutex::new(Some(stop)),
+        }
+    }
+
+    /// stop the subscription (no more events will be received)
+    pub fn unsubscribe(&self) {
+        if let Some(stop) = self.stop.lock().ok().and_then(|mut stop| stop.take()) {
+            let _ = stop.send(());
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Drop for CosmosEventSubscription {
+    fn drop(&mut self) {
+        self.unsubscribe();
+    }
+}
+
+#[cfg(test)]
+mod event_subscription_tests {
+    use super::*;
+
+    const TX_EVENT: &str = r#"{
+        "jsonrpc": "2.0",
+        "id": 0,
+        "result": {
+            "query": "tm.event = 'Tx' AND transfer.recipient = 'cro1recipient'",
+            "data": {
+                "type": "tendermint/event/Tx",
+                "value": {
+                    "TxResult": {
+                        "height": "1234",
+                        "index": 0,
+                        "tx": "CgQKAggBEgA=",
+                        "result": {
+                            "log": "[]",
+                            "gas_wanted": "200000",
+                            "gas_used": "81234",
+                            "events": [
+                                {
+                                    "type": "transfer",
+                                    "attributes": [
+                                        {"key": "recipient", "value": "cro1feecollector", "index": true},
+                                        {"key": "sender", "value": "cro1sender", "index": true},
+                                        {"key": "amount", "value": "5000basecro", "index": true}
+                                    ]
+                                },
+                                {
+                                    "type": "tx",
+                                    "attributes": [
+                                        {"key": "fee", "value": "5000basecro", "index": true}
+                                    ]
+                                },
+                                {
+                                    "type": "message",
+                                    "attributes": [
+                                        {"key": "action", "value": "/cosmos.bank.v1beta1.MsgSend", "index": true},
+                                        {"key": "sender", "value": "cro1sender", "index": true}
+                                    ]
+                                },
+                                {
+                                    "type": "transfer",
+                                    "attributes": [
+                                        {"key": "recipient", "value": "cro1recipient", "index": true},
+                                        {"key": "sender", "value": "cro1sender", "index": true},
+                                        {"key": "amount", "value": "100basecro", "index": true}
+                                    ]
+                                }
+                            ]
+                        }
+                    }
+                }
+            },
+            "events": {}
+        }
+    }"#;
+
+    #[test]
+    fn test_subscription_query() {
+        let subscription = CosmosSubscription::Address {
+            address: "cro1recipient".to_owned(),
+            filter: TxHistoryFilter::Received,
+        };
+        assert_eq!(
+            subscription.query().to_string(),
+            "tm.event = 'Tx' AND transfer.recipient = 'cro1recipient'"
+        );
+        assert_eq!(
+            CosmosSubscription::NewBlock.query().to_string(),
+            "tm.event = 'NewBlock'"
+        );
+    }
+
+    #[test]
+    fn test_subscription_message_parsing() {
+        let subscription = CosmosSubscription::Address {
+            address: "cro1recipient".to_owned(),
+            filter: TxHistoryFilter::Received,
+        };
+        let ack = r#"{"jsonrpc": "2.0", "id": 0, "result": {}}"#;
+        assert!(parse_subscription_message(&subscription, ack)
+            .unwrap()
+            .is_empty());
+
+        let events = parse_subscription_message(&subscription, TX_EVENT).unwrap();
+        assert_eq!(events.len(), 1);
+        match &events[0] {
+            CosmosSubscriptionEvent::Transfer {
+                height,
+                sender,
+                amount,
+                ..
+            } => {
+                assert_eq!(*height, 1234);
+                assert_eq!(sender, "cro1sender");
+                assert_eq!(amount, "100basecro");
+            }
+            event => panic!("unexpected event: {:?}", event),
+        }
+    }
+
+    #[test]
+    fn test_sent_subscription_skips_fee_transfer() {
+        let subscription = CosmosSubscription::Address {
+            address: "cro1sender".to_owned(),
+            filter: TxHistoryFilter::Sent,
+        };
+        assert_eq!(
+            subscription.query().to_string(),
+            "tm.event = 'Tx' AND message.sender = 'cro1sender'"
+        );
+        let events = parse_subscription_message(&subscription, TX_EVENT).unwrap();
+        assert_eq!(events.len(), 1);
+        match &events[0] {
+            CosmosSubscriptionEvent::Transfer {
+                recipient, amount, ..
+            } => {
+                assert_eq!(recipient, "cro1recipient");
+                assert_eq!(amount, "100basecro");
+            }
+            event => panic!("unexpected event: {:?}", event),
+        }
+    }
+
+    #[test]
+    fn test_reconnect_backoff() {
+        let backoff = ReconnectBackoff {
+            initial_delay_ms: 1_000,
+            max_delay_ms: 5_000,
+            max_retries: Some(3),
+        };
+        assert_eq!(backoff.delay_ms(0), 1_000);
+        assert_eq!(backoff.delay_ms(2), 4_000);
+        assert_eq!(backoff.delay_ms(40), 5_000);
+        assert!(backoff.can_retry(2));
+        assert!(!backoff.can_retry(3));
+    }
+}
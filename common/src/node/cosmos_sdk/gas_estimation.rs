@@ -0,0 +1,224 @@
+#[cfg(not(target_arch = "wasm32"))]
+use super::block_on;
+use super::{query_rest, query_simulate};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::SecretKey;
+use crate::{
+    get_adjusted_gas_limit, get_signed_msg_tx, get_signer_public_key, get_simulation_msg_tx,
+    CosmosError, CosmosSDKMsg, CosmosSDKTxInfo, GasPrice, PublicKeyBytesWrapper, RestError,
+    SingleCoin, WalletSigner, DEFAULT_GAS_ADJUSTMENT,
+};
+use cosmrs::crypto::{self, secp256k1::VerifyingKey};
+use eyre::eyre;
+use serde::{Deserialize, Serialize};
+#[cfg(not(target_arch = "wasm32"))]
+use std::sync::Arc;
+
+/// where the gas price of the estimated fee comes from
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+pub enum GasPriceSource {
+    /// a fixed gas price
+    Fixed { gas_price: GasPrice },
+    /// the current base fee of the `x/feemarket` module (Ethermint-based chains, e.g. Cronos)
+    FeeMarket { denom: String },
+    /// the minimum gas price of the denomination configured on the node
+    MinGasPrice { denom: String },
+}
+
+/// the estimated gas limit and fee of a transaction
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+pub struct GasEstimate {
+    /// the gas used in the simulation
+    pub gas_used: u64,
+    /// the gas used multiplied by the gas adjustment
+    pub gas_limit: u64,
+    /// the gas price of the fee
+    pub gas_price: GasPrice,
+    /// the fee for the gas limit
+    pub fee_amount: SingleCoin,
+}
+
+/// the raw response from the `x/feemarket` base fee API
+#[derive(Deserialize)]
+struct RawBaseFeeResponse {
+    /// empty if the base fee is disabled
+    base_fee: Option<String>,
+}
+
+/// the raw response from the node config API
+#[derive(Deserialize)]
+struct RawNodeConfigResponse {
+    /// comma-separated decimal coins (e.g. "0.025000000000000000uatom")
+    minimum_gas_price: String,
+}
+
+async fn query_gas_price(
+    api_url: &str,
+    gas_price_source: &GasPriceSource,
+) -> Result<GasPrice, CosmosError> {
+    match gas_price_source {
+        GasPriceSource::Fixed { gas_price } => Ok(gas_price.clone()),
+        GasPriceSource::FeeMarket { denom } => {
+            let response: RawBaseFeeResponse =
+                query_rest(format!("{}/ethermint/feemarket/v1/base_fee", api_url)).await?;
+            Ok(GasPrice {
+                amount: response.base_fee.ok_or(RestError::MissingResult)?,
+                denom: denom.to_owned(),
+            })
+        }
+        GasPriceSource::MinGasPrice { denom } => {
+            let response: RawNodeConfigResponse =
+                query_rest(format!("{}/cosmos/base/node/v1beta1/config", api_url)).await?;
+            if response.minimum_gas_price.is_empty() {
+                return Ok(GasPrice {
+                    amount: "0".to_owned(),
+                    denom: denom.to_owned(),
+                });
+            }
+            for gas_price in response.minimum_gas_price.split(',') {
+                let gas_price = gas_price.parse::<GasPrice>()?;
+                if gas_price.denom == *denom {
+                    return Ok(gas_price);
+                }
+            }
+            Err(eyre!("no minimum gas price in {} on the node", denom).into())
+        }
+    }
+}
+
+/// builds the signed transactions with the gas limit estimated by simulating them
+/// and the fee derived from the gas price
+#[derive(Clone, Debug)]
+pub struct CosmosTxBuilder {
+    /// the Cosmos gRPC (or gRPC-web for JS/WASM) for the simulation
+    grpc_url: String,
+    /// the Cosmos REST API (usually on 1317) for the gas price lookup
+    api_url: String,
+    /// where the gas price comes from
+    gas_price_source: GasPriceSource,
+    /// the multiplier applied to the simulated gas usage
+    gas_adjustment: f64,
+}
+
+impl CosmosTxBuilder {
+    /// a new builder (the gas adjustment defaults to `DEFAULT_GAS_ADJUSTMENT`)
+    pub fn new(
+        grpc_url: String,
+        api_url: String,
+        gas_price_source: GasPriceSource,
+        gas_adjustment: Option<f64>,
+    ) -> Self {
+        Self {
+            grpc_url,
+            api_url,
+            gas_price_source,
+            gas_adjustment: gas_adjustment.unwrap_or(DEFAULT_GAS_ADJUSTMENT),
+        }
+    }
+
+    async fn estimate(
+        &self,
+        tx_info: &CosmosSDKTxInfo,
+        msgs: &[CosmosSDKMsg],
+        sender_public_key: crypto::PublicKey,
+    ) -> Result<GasEstimate, CosmosError> {
+        let tx = get_simulation_msg_tx(tx_info, msgs, sender_public_key)?.to_bytes()?;
+        let gas_used = query_simulate(&self.grpc_url, tx).await?;
+        let gas_limit = get_adjusted_gas_limit(gas_used, self.gas_adjustment)?;
+        let gas_price = query_gas_price(&self.api_url, &self.gas_price_source).await?;
+        let fee_amount = gas_price.get_fee(gas_limit)?;
+        Ok(GasEstimate {
+            gas_used,
+            gas_limit,
+            gas_price,
+            fee_amount,
+        })
+    }
+
+    /// estimate the gas limit and the fee of the transaction
+    /// with some Cosmos SDK messages (async)
+    pub async fn estimate_fee(
+        &self,
+        tx_info: &CosmosSDKTxInfo,
+        msgs: &[CosmosSDKMsg],
+        sender_pubkey: PublicKeyBytesWrapper,
+    ) -> Result<GasEstimate, CosmosError> {
+        let sender_public_key = crypto::PublicKey::from(
+            VerifyingKey::from_bytes(sender_pubkey.into()).map_err(CosmosError::PubkeyError)?,
+        );
+        self.estimate(tx_info, msgs, sender_public_key).await
+    }
+
+    /// creates the signed transaction with some Cosmos SDK messages
+    /// and the estimated gas limit and fee (async)
+    pub async fn build_signed_msg_tx(
+        &self,
+        tx_info: CosmosSDKTxInfo,
+        msgs: Vec<CosmosSDKMsg>,
+        signer: &dyn WalletSigner,
+    ) -> Result<Vec<u8>, CosmosError> {
+        let sender_public_key = get_signer_public_key(signer)?;
+        let estimate = self.estimate(&tx_info, &msgs, sender_public_key).await?;
+        let tx_info = CosmosSDKTxInfo {
+            gas_limit: estimate.gas_limit,
+            fee_amount: vec![estimate.fee_amount],
+            ..tx_info
+        };
+        Ok(get_signed_msg_tx(tx_info, &msgs, signer)?.to_bytes()?)
+    }
+
+    /// estimate the gas limit and the fee of the transaction
+    /// with some Cosmos SDK messages (blocking)
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn estimate_fee_blocking(
+        &self,
+        tx_info: CosmosSDKTxInfo,
+        msgs: Vec<CosmosSDKMsg>,
+        sender_pubkey: PublicKeyBytesWrapper,
+    ) -> Result<GasEstimate, CosmosError> {
+        block_on(self.estimate_fee(&tx_info, &msgs, sender_pubkey))
+    }
+
+    /// creates the signed transaction with some Cosmos SDK messages
+    /// and the estimated gas limit and fee (blocking)
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn build_signed_msg_tx_blocking(
+        &self,
+        tx_info: CosmosSDKTxInfo,
+        msgs: Vec<CosmosSDKMsg>,
+        secret_key: Arc<SecretKey>,
+    ) -> Result<Vec<u8>, CosmosError> {
+        block_on(self.build_signed_msg_tx(tx_info, msgs, secret_key.as_ref()))
+    }
+}
+
+#[cfg(test)]
+mod gas_estimation_tests {
+    use super::*;
+
+    #[test]
+    fn test_gas_price_source_serialization() {
+        let source = GasPriceSource::Fixed {
+            gas_price: "0.025uatom".parse().unwrap(),
+        };
+        let json = serde_json::to_string(&source).unwrap();
+        assert_eq!(
+            json,
+            r#"{"Fixed":{"gas_price":{"amount":"0.025","denom":"uatom"}}}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<GasPriceSource>(&json).unwrap(),
+            source
+        );
+
+        let response: RawNodeConfigResponse =
+            serde_json::from_str(r#"{"minimum_gas_price":"0.025000000000000000uatom"}"#).unwrap();
+        assert_eq!(
+            response.minimum_gas_price.parse::<GasPrice>().unwrap(),
+            GasPrice {
+                amount: "0.025000000000000000".to_owned(),
+                denom: "uatom".to_owned()
+            }
+        );
+    }
+}
@@ -0,0 +1,336 @@
+#[cfg(not(target_arch = "wasm32"))]
+use super::block_on;
+use super::query_rest;
+use crate::{compute_ibc_timeout, CosmosError, DenomTrace, Height, IbcTimeout, RestError};
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+use tendermint_rpc::endpoint::status;
+use tendermint_rpc::{request, response};
+
+/// the counterparty of an IBC channel
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct IbcChannelCounterparty {
+    pub port_id: String,
+    /// empty while the channel handshake isn't complete
+    pub channel_id: String,
+}
+
+/// an IBC channel
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct IbcChannel {
+    /// e.g. "STATE_OPEN"
+    pub state: String,
+    /// e.g. "ORDER_UNORDERED"
+    pub ordering: String,
+    pub counterparty: IbcChannelCounterparty,
+    /// the connections the packets travel through (a single one for now)
+    pub connection_hops: Vec<String>,
+    /// the channel version (e.g. "ics20-1")
+    pub version: String,
+}
+
+/// the counterparty of an IBC connection
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct IbcConnectionCounterparty {
+    pub client_id: String,
+    /// empty while the connection handshake isn't complete
+    pub connection_id: String,
+}
+
+/// an IBC connection
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct IbcConnection {
+    /// the light client of the counterparty chain
+    pub client_id: String,
+    /// e.g. "STATE_OPEN"
+    pub state: String,
+    pub counterparty: IbcConnectionCounterparty,
+    /// the delay period in nanoseconds
+    #[serde_as(as = "DisplayFromStr")]
+    pub delay_period: u64,
+}
+
+/// the light client state of the counterparty chain
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct IbcClientState {
+    pub client_id: String,
+    /// the client state type (e.g. "/ibc.lightclients.tendermint.v1.ClientState")
+    pub client_type_url: String,
+    /// the counterparty chain id (empty if the client type doesn't have it)
+    pub chain_id: String,
+    /// the latest counterparty height known by the client
+    pub latest_height: Height,
+}
+
+#[derive(Deserialize)]
+struct RawDenomTraceResponse {
+    denom_trace: DenomTrace,
+}
+
+#[derive(Deserialize)]
+struct RawChannelResponse {
+    channel: IbcChannel,
+}
+
+#[derive(Deserialize)]
+struct RawConnectionResponse {
+    connection: IbcConnection,
+}
+
+#[serde_as]
+#[derive(Deserialize)]
+struct RawHeight {
+    #[serde_as(as = "DisplayFromStr")]
+    revision_number: u64,
+    #[serde_as(as = "DisplayFromStr")]
+    revision_height: u64,
+}
+
+#[derive(Deserialize)]
+struct RawClientState {
+    #[serde(rename = "@type")]
+    client_type_url: String,
+    #[serde(default)]
+    chain_id: String,
+    latest_height: Option<RawHeight>,
+}
+
+#[derive(Deserialize)]
+struct RawIdentifiedClientState {
+    client_id: String,
+    client_state: RawClientState,
+}
+
+#[derive(Deserialize)]
+struct RawClientStateResponse {
+    identified_client_state: RawIdentifiedClientState,
+}
+
+impl From<RawIdentifiedClientState> for IbcClientState {
+    fn from(state: RawIdentifiedClientState) -> Self {
+        let latest_height = state.client_state.latest_height.unwrap_or(RawHeight {
+            revision_number: 0,
+            revision_height: 0,
+        });
+        Self {
+            client_id: state.client_id,
+            client_type_url: state.client_state.client_type_url,
+            chain_id: state.client_state.chain_id,
+            latest_height: Height {
+                revision_number: latest_height.revision_number,
+                revision_height: latest_height.revision_height,
+            },
+        }
+    }
+}
+
+/// client of the IBC transfer and core queries over the Cosmos REST API
+/// (usually on 1317)
+#[derive(Clone, Debug)]
+pub struct IbcClient {
+    api_url: String,
+}
+
+impl IbcClient {
+    pub fn new(api_url: String) -> Self {
+        Self { api_url }
+    }
+
+    /// DenomTrace queries the trace of the IBC denomination ("ibc/{hash}") or its hash
+    pub async fn denom_trace(&self, hash: &str) -> Result<DenomTrace, RestError> {
+        let hash = hash.strip_prefix("ibc/").unwrap_or(hash);
+        let response: RawDenomTraceResponse = query_rest(format!(
+            "{}/ibc/apps/transfer/v1/denom_traces/{}",
+            self.api_url, hash
+        ))
+        .await?;
+        Ok(response.denom_trace)
+    }
+
+    /// Channel queries the IBC channel of the port
+    pub async fn channel(&self, port_id: &str, channel_id: &str) -> Result<IbcChannel, RestError> {
+        let response: RawChannelResponse = query_rest(format!(
+            "{}/ibc/core/channel/v1/channels/{}/ports/{}",
+            self.api_url, channel_id, port_id
+        ))
+        .await?;
+        Ok(response.channel)
+    }
+
+    /// Connection queries the IBC connection
+    pub async fn connection(&self, connection_id: &str) -> Result<IbcConnection, RestError> {
+        let response: RawConnectionResponse = query_rest(format!(
+            "{}/ibc/core/connection/v1/connections/{}",
+            self.api_url, connection_id
+        ))
+        .await?;
+        Ok(response.connection)
+    }
+
+    /// ChannelClientState queries the light client state of the counterparty chain
+    /// of the IBC channel
+    pub async fn channel_client_state(
+        &self,
+        port_id: &str,
+        channel_id: &str,
+    ) -> Result<IbcClientState, RestError> {
+        let response: RawClientStateResponse = query_rest(format!(
+            "{}/ibc/core/channel/v1/channels/{}/ports/{}/client_state",
+            self.api_url, channel_id, port_id
+        ))
+        .await?;
+        Ok(response.identified_client_state.into())
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    /// DenomTrace queries the trace of the IBC denomination ("ibc/{hash}") or its hash
+    pub fn denom_trace_blocking(&self, hash: &str) -> Result<DenomTrace, RestError> {
+        block_on(self.denom_trace(hash))
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    /// Channel queries the IBC channel of the port
+    pub fn channel_blocking(
+        &self,
+        port_id: &str,
+        channel_id: &str,
+    ) -> Result<IbcChannel, RestError> {
+        block_on(self.channel(port_id, channel_id))
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    /// Connection queries the IBC connection
+    pub fn connection_blocking(&self, connection_id: &str) -> Result<IbcConnection, RestError> {
+        block_on(self.connection(connection_id))
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    /// ChannelClientState queries the light client state of the counterparty chain
+    /// of the IBC channel
+    pub fn channel_client_state_blocking(
+        &self,
+        port_id: &str,
+        channel_id: &str,
+    ) -> Result<IbcClientState, RestError> {
+        block_on(self.channel_client_state(port_id, channel_id))
+    }
+}
+
+/// return the IBC transfer timeout after the number of blocks and seconds from the latest
+/// block of the counterparty chain (a zero offset disables the corresponding timeout)
+/// (async for JS/WASM)
+pub async fn get_ibc_timeout(
+    counterparty_tendermint_rpc_url: &str,
+    height_offset: u64,
+    timeout_seconds: u64,
+) -> Result<IbcTimeout, CosmosError> {
+    let request = request::Wrapper::new(status::Request);
+    let status = reqwest::Client::new()
+        .post(counterparty_tendermint_rpc_url)
+        .json(&request)
+        .send()
+        .await
+        .map_err(RestError::RequestError)?
+        .json::<response::Wrapper<status::Response>>()
+        .await
+        .map_err(RestError::RequestError)?
+        .into_result()
+        .map_err(|_e| RestError::MissingResult)?;
+    let latest_time = u64::try_from(status.sync_info.latest_block_time.unix_timestamp_nanos())
+        .map_err(|_e| RestError::MissingResult)?;
+    compute_ibc_timeout(
+        status.node_info.network.as_str(),
+        status.sync_info.latest_block_height.value(),
+        latest_time,
+        height_offset,
+        timeout_seconds,
+    )
+}
+
+/// return the IBC transfer timeout after the number of blocks and seconds from the latest
+/// block of the counterparty chain (a zero offset disables the corresponding timeout)
+/// (blocking for other platforms)
+#[cfg(not(target_arch = "wasm32"))]
+pub fn get_ibc_timeout_blocking(
+    counterparty_tendermint_rpc_url: &str,
+    height_offset: u64,
+    timeout_seconds: u64,
+) -> Result<IbcTimeout, CosmosError> {
+    block_on(get_ibc_timeout(
+        counterparty_tendermint_rpc_url,
+        height_offset,
+        timeout_seconds,
+    ))
+}
+
+#[cfg(test)]
+mod ibc_query_tests {
+    use super::*;
+
+    #[test]
+    fn test_ibc_responses_parsing() {
+        let response: RawChannelResponse = serde_json::from_str(
+            r#"{
+                "channel": {
+                    "state": "STATE_OPEN",
+                    "ordering": "ORDER_UNORDERED",
+                    "counterparty": {"port_id": "transfer", "channel_id": "channel-141"},
+                    "connection_hops": ["connection-257"],
+                    "version": "ics20-1"
+                },
+                "proof": null,
+                "proof_height": {"revision_number": "4", "revision_height": "100"}
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(response.channel.counterparty.channel_id, "channel-141");
+        assert_eq!(response.channel.connection_hops, vec!["connection-257"]);
+
+        let response: RawClientStateResponse = serde_json::from_str(
+            r#"{
+                "identified_client_state": {
+                    "client_id": "07-tendermint-259",
+                    "client_state": {
+                        "@type": "/ibc.lightclients.tendermint.v1.ClientState",
+                        "chain_id": "osmosis-1",
+                        "trusting_period": "1209600s",
+                        "latest_height": {"revision_number": "1", "revision_height": "9000000"}
+                    }
+                }
+            }"#,
+        )
+        .unwrap();
+        let client_state = IbcClientState::from(response.identified_client_state);
+        assert_eq!(client_state.chain_id, "osmosis-1");
+        assert_eq!(
+            client_state.latest_height,
+            Height {
+                revision_number: 1,
+                revision_height: 9_000_000
+            }
+        );
+
+        let response: RawConnectionResponse = serde_json::from_str(
+            r#"{
+                "connection": {
+                    "client_id": "07-tendermint-0",
+                    "versions": [{"identifier": "1", "features": ["ORDER_ORDERED", "ORDER_UNORDERED"]}],
+                    "state": "STATE_OPEN",
+                    "counterparty": {
+                        "client_id": "07-tendermint-1",
+                        "connection_id": "connection-1",
+                        "prefix": {"key_prefix": "aWJj"}
+                    },
+                    "delay_period": "0"
+                }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            response.connection.counterparty.connection_id,
+            "connection-1"
+        );
+        assert_eq!(response.connection.delay_period, 0);
+    }
+}
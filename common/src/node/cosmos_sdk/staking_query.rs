@@ -0,0 +1,551 @@
+#[cfg(not(target_arch = "wasm32"))]
+use super::block_on;
+use crate::transaction::gov::atomics_to_decimal;
+use crate::{PageRequest, RawRpcBalance, RestError};
+use cosmos_sdk_proto::cosmos::base::v1beta1::DecCoin;
+use cosmos_sdk_proto::cosmos::distribution::v1beta1::{
+    query_client::QueryClient as DistributionQueryClient, QueryDelegationTotalRewardsRequest,
+};
+use cosmos_sdk_proto::cosmos::staking::v1beta1::{
+    query_client::QueryClient as StakingQueryClient, BondStatus, DelegationResponse, Params,
+    QueryDelegatorDelegationsRequest, QueryDelegatorUnbondingDelegationsRequest,
+    QueryParamsRequest, QueryRedelegationsRequest, QueryValidatorsRequest, RedelegationResponse,
+    UnbondingDelegation, Validator,
+};
+use serde::{Deserialize, Serialize};
+
+/// the `sdk.Dec` values are encoded as their atomics in the gRPC responses
+fn decimal(atomics: String) -> String {
+    atomics_to_decimal(&atomics).unwrap_or(atomics)
+}
+
+fn dec_coins(coins: Vec<DecCoin>) -> Vec<RawRpcBalance> {
+    coins
+        .into_iter()
+        .map(|coin| RawRpcBalance {
+            denom: coin.denom,
+            amount: decimal(coin.amount),
+        })
+        .collect()
+}
+
+/// a delegation with its balance
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct DelegationInfo {
+    pub delegator_address: String,
+    pub validator_address: String,
+    /// the delegation shares (decimal)
+    pub shares: String,
+    pub balance: RawRpcBalance,
+}
+
+impl From<DelegationResponse> for DelegationInfo {
+    fn from(response: DelegationResponse) -> Self {
+        let delegation = response.delegation.unwrap_or_default();
+        let balance = response.balance.unwrap_or_default();
+        Self {
+            delegator_address: delegation.delegator_address,
+            validator_address: delegation.validator_address,
+            shares: decimal(delegation.shares),
+            balance: RawRpcBalance {
+                denom: balance.denom,
+                amount: balance.amount,
+            },
+        }
+    }
+}
+
+/// an unbonding entry (the times are in seconds since the Unix epoch)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct UnbondingEntryInfo {
+    pub creation_height: i64,
+    pub completion_time: i64,
+    pub initial_balance: String,
+    pub balance: String,
+}
+
+/// the unbonding entries of a delegator from a validator
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct UnbondingDelegationInfo {
+    pub delegator_address: String,
+    pub validator_address: String,
+    pub entries: Vec<UnbondingEntryInfo>,
+}
+
+impl From<UnbondingDelegation> for UnbondingDelegationInfo {
+    fn from(unbonding: UnbondingDelegation) -> Self {
+        Self {
+            delegator_address: unbonding.delegator_address,
+            validator_address: unbonding.validator_address,
+            entries: unbonding
+                .entries
+                .into_iter()
+                .map(|entry| UnbondingEntryInfo {
+                    creation_height: entry.creation_height,
+                    completion_time: entry.completion_time.unwrap_or_default().seconds,
+                    initial_balance: entry.initial_balance,
+                    balance: entry.balance,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// a redelegation entry (the times are in seconds since the Unix epoch)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct RedelegationEntryInfo {
+    pub creation_height: i64,
+    pub completion_time: i64,
+    pub initial_balance: String,
+    /// the shares created at the destination validator (decimal)
+    pub shares_dst: String,
+    pub balance: String,
+}
+
+/// the redelegation entries of a delegator between two validators
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct RedelegationInfo {
+    pub delegator_address: String,
+    pub validator_src_address: String,
+    pub validator_dst_address: String,
+    pub entries: Vec<RedelegationEntryInfo>,
+}
+
+impl From<RedelegationResponse> for RedelegationInfo {
+    fn from(response: RedelegationResponse) -> Self {
+        let redelegation = response.redelegation.unwrap_or_default();
+        Self {
+            delegator_address: redelegation.delegator_address,
+            validator_src_address: redelegation.validator_src_address,
+            validator_dst_address: redelegation.validator_dst_address,
+            entries: response
+                .entries
+                .into_iter()
+                .map(|entry| {
+                    let redelegation_entry = entry.redelegation_entry.unwrap_or_default();
+                    RedelegationEntryInfo {
+                        creation_height: redelegation_entry.creation_height,
+                        completion_time: redelegation_entry
+                            .completion_time
+                            .unwrap_or_default()
+                            .seconds,
+                        initial_balance: redelegation_entry.initial_balance,
+                        shares_dst: decimal(redelegation_entry.shares_dst),
+                        balance: entry.balance,
+                    }
+                })
+                .collect(),
+        }
+    }
+}
+
+/// the pending rewards from a validator (decimal amounts)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct ValidatorRewards {
+    pub validator_address: String,
+    pub rewards: Vec<RawRpcBalance>,
+}
+
+/// the pending rewards of a delegator (decimal amounts)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct DelegationRewards {
+    /// the rewards per validator
+    pub rewards: Vec<ValidatorRewards>,
+    /// the sum of the rewards from all validators
+    pub total: Vec<RawRpcBalance>,
+}
+
+/// the bond status of a validator
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidatorBondStatus {
+    Unspecified,
+    Unbonded,
+    Unbonding,
+    Bonded,
+}
+
+impl From<ValidatorBondStatus> for BondStatus {
+    fn from(status: ValidatorBondStatus) -> Self {
+        match status {
+            ValidatorBondStatus::Unspecified => BondStatus::Unspecified,
+            ValidatorBondStatus::Unbonded => BondStatus::Unbonded,
+            ValidatorBondStatus::Unbonding => BondStatus::Unbonding,
+            ValidatorBondStatus::Bonded => BondStatus::Bonded,
+        }
+    }
+}
+
+impl From<i32> for ValidatorBondStatus {
+    fn from(status: i32) -> Self {
+        match BondStatus::from_i32(status) {
+            Some(BondStatus::Unbonded) => Self::Unbonded,
+            Some(BondStatus::Unbonding) => Self::Unbonding,
+            Some(BondStatus::Bonded) => Self::Bonded,
+            _ => Self::Unspecified,
+        }
+    }
+}
+
+/// a validator with its description and commission
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct ValidatorInfo {
+    pub operator_address: String,
+    pub moniker: String,
+    pub identity: String,
+    pub website: String,
+    pub details: String,
+    pub status: ValidatorBondStatus,
+    pub jailed: bool,
+    /// the bonded tokens
+    pub tokens: String,
+    /// the total shares of the delegators (decimal)
+    pub delegator_shares: String,
+    /// the commission rate (decimal, e.g. "0.050000000000000000")
+    pub commission_rate: String,
+    pub commission_max_rate: String,
+    pub commission_max_change_rate: String,
+    pub min_self_delegation: String,
+}
+
+impl From<Validator> for ValidatorInfo {
+    fn from(validator: Validator) -> Self {
+        let description = validator.description.unwrap_or_default();
+        let rates = validator
+            .commission
+            .and_then(|commission| commission.commission_rates)
+            .unwrap_or_default();
+        Self {
+            operator_address: validator.operator_address,
+            moniker: description.moniker,
+            identity: description.identity,
+            website: description.website,
+            details: description.details,
+            status: validator.status.into(),
+            jailed: validator.jailed,
+            tokens: validator.tokens,
+            delegator_shares: decimal(validator.delegator_shares),
+            commission_rate: decimal(rates.rate),
+            commission_max_rate: decimal(rates.max_rate),
+            commission_max_change_rate: decimal(rates.max_change_rate),
+            min_self_delegation: validator.min_self_delegation,
+        }
+    }
+}
+
+/// the parameters of the staking module
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct StakingParams {
+    /// the unbonding time (in seconds)
+    pub unbonding_time: i64,
+    pub max_validators: u32,
+    /// the maximum number of unbonding (or redelegation) entries between a delegator and a validator
+    pub max_entries: u32,
+    pub historical_entries: u32,
+    pub bond_denom: String,
+    /// the minimum commission rate of the validators (decimal)
+    pub min_commission_rate: String,
+}
+
+impl From<Params> for StakingParams {
+    fn from(params: Params) -> Self {
+        Self {
+            unbonding_time: params.unbonding_time.unwrap_or_default().seconds,
+            max_validators: params.max_validators,
+            max_entries: params.max_entries,
+            historical_entries: params.historical_entries,
+            bond_denom: params.bond_denom,
+            min_commission_rate: decimal(params.min_commission_rate),
+        }
+    }
+}
+
+async fn query_delegations(
+    grpc_url: &str,
+    delegator_address: &str,
+    pagination: Option<PageRequest>,
+) -> Result<Vec<DelegationInfo>, RestError> {
+    let mut client = connect!(StakingQueryClient, grpc_url)?;
+    let request = QueryDelegatorDelegationsRequest {
+        delegator_addr: delegator_address.to_owned(),
+        pagination,
+    };
+    let res = client
+        .delegator_delegations(request)
+        .await
+        .map_err(RestError::GRPCError)?
+        .into_inner();
+    Ok(res
+        .delegation_responses
+        .into_iter()
+        .map(Into::into)
+        .collect())
+}
+
+async fn query_unbonding_delegations(
+    grpc_url: &str,
+    delegator_address: &str,
+    pagination: Option<PageRequest>,
+) -> Result<Vec<UnbondingDelegationInfo>, RestError> {
+    let mut client = connect!(StakingQueryClient, grpc_url)?;
+    let request = QueryDelegatorUnbondingDelegationsRequest {
+        delegator_addr: delegator_address.to_owned(),
+        pagination,
+    };
+    let res = client
+        .delegator_unbonding_delegations(request)
+        .await
+        .map_err(RestError::GRPCError)?
+        .into_inner();
+    Ok(res
+        .unbonding_responses
+        .into_iter()
+        .map(Into::into)
+        .collect())
+}
+
+async fn query_redelegations(
+    grpc_url: &str,
+    delegator_address: &str,
+    pagination: Option<PageRequest>,
+) -> Result<Vec<RedelegationInfo>, RestError> {
+    let mut client = connect!(StakingQueryClient, grpc_url)?;
+    let request = QueryRedelegationsRequest {
+        delegator_addr: delegator_address.to_owned(),
+        src_validator_addr: "".to_owned(),
+        dst_validator_addr: "".to_owned(),
+        pagination,
+    };
+    let res = client
+        .redelegations(request)
+        .await
+        .map_err(RestError::GRPCError)?
+        .into_inner();
+    Ok(res
+        .redelegation_responses
+        .into_iter()
+        .map(Into::into)
+        .collect())
+}
+
+async fn query_delegation_rewards(
+    grpc_url: &str,
+    delegator_address: &str,
+) -> Result<DelegationRewards, RestError> {
+    let mut client = connect!(DistributionQueryClient, grpc_url)?;
+    let request = QueryDelegationTotalRewardsRequest {
+        delegator_address: delegator_address.to_owned(),
+    };
+    let res = client
+        .delegation_total_rewards(request)
+        .await
+        .map_err(RestError::GRPCError)?
+        .into_inner();
+    Ok(DelegationRewards {
+        rewards: res
+            .rewards
+            .into_iter()
+            .map(|reward| ValidatorRewards {
+                validator_address: reward.validator_address,
+                rewards: dec_coins(reward.reward),
+            })
+            .collect(),
+        total: dec_coins(res.total),
+    })
+}
+
+async fn query_validators(
+    grpc_url: &str,
+    status: Option<ValidatorBondStatus>,
+    pagination: Option<PageRequest>,
+) -> Result<Vec<ValidatorInfo>, RestError> {
+    let mut client = connect!(StakingQueryClient, grpc_url)?;
+    let request = QueryValidatorsRequest {
+        // an empty status returns all the validators
+        status: status
+            .map(|status| BondStatus::from(status).as_str_name().to_owned())
+            .unwrap_or_default(),
+        pagination,
+    };
+    let res = client
+        .validators(request)
+        .await
+        .map_err(RestError::GRPCError)?
+        .into_inner();
+    Ok(res.validators.into_iter().map(Into::into).collect())
+}
+
+async fn query_staking_params(grpc_url: &str) -> Result<StakingParams, RestError> {
+    let mut client = connect!(StakingQueryClient, grpc_url)?;
+    let res = client
+        .params(QueryParamsRequest {})
+        .await
+        .map_err(RestError::GRPCError)?
+        .into_inner();
+    Ok(res.params.ok_or(RestError::MissingResult)?.into())
+}
+
+/// return the delegations of the delegator (async for JS/WASM)
+#[cfg(target_arch = "wasm32")]
+pub async fn get_delegations(
+    grpc_web_url: &str,
+    delegator_address: &str,
+    pagination: Option<PageRequest>,
+) -> Result<Vec<DelegationInfo>, RestError> {
+    query_delegations(grpc_web_url, delegator_address, pagination).await
+}
+
+/// return the delegations of the delegator (blocking for other platforms)
+#[cfg(not(target_arch = "wasm32"))]
+pub fn get_delegations_blocking(
+    grpc_url: &str,
+    delegator_address: &str,
+    pagination: Option<PageRequest>,
+) -> Result<Vec<DelegationInfo>, RestError> {
+    block_on(query_delegations(grpc_url, delegator_address, pagination))
+}
+
+/// return the unbonding delegations of the delegator (async for JS/WASM)
+#[cfg(target_arch = "wasm32")]
+pub async fn get_unbonding_delegations(
+    grpc_web_url: &str,
+    delegator_address: &str,
+    pagination: Option<PageRequest>,
+) -> Result<Vec<UnbondingDelegationInfo>, RestError> {
+    query_unbonding_delegations(grpc_web_url, delegator_address, pagination).await
+}
+
+/// return the unbonding delegations of the delegator (blocking for other platforms)
+#[cfg(not(target_arch = "wasm32"))]
+pub fn get_unbonding_delegations_blocking(
+    grpc_url: &str,
+    delegator_address: &str,
+    pagination: Option<PageRequest>,
+) -> Result<Vec<UnbondingDelegationInfo>, RestError> {
+    block_on(query_unbonding_delegations(
+        grpc_url,
+        delegator_address,
+        pagination,
+    ))
+}
+
+/// return the redelegations of the delegator (async for JS/WASM)
+#[cfg(target_arch = "wasm32")]
+pub async fn get_redelegations(
+    grpc_web_url: &str,
+    delegator_address: &str,
+    pagination: Option<PageRequest>,
+) -> Result<Vec<RedelegationInfo>, RestError> {
+    query_redelegations(grpc_web_url, delegator_address, pagination).await
+}
+
+/// return the redelegations of the delegator (blocking for other platforms)
+#[cfg(not(target_arch = "wasm32"))]
+pub fn get_redelegations_blocking(
+    grpc_url: &str,
+    delegator_address: &str,
+    pagination: Option<PageRequest>,
+) -> Result<Vec<RedelegationInfo>, RestError> {
+    block_on(query_redelegations(grpc_url, delegator_address, pagination))
+}
+
+/// return the pending rewards of the delegator (async for JS/WASM)
+#[cfg(target_arch = "wasm32")]
+pub async fn get_delegation_rewards(
+    grpc_web_url: &str,
+    delegator_address: &str,
+) -> Result<DelegationRewards, RestError> {
+    query_delegation_rewards(grpc_web_url, delegator_address).await
+}
+
+/// return the pending rewards of the delegator (blocking for other platforms)
+#[cfg(not(target_arch = "wasm32"))]
+pub fn get_delegation_rewards_blocking(
+    grpc_url: &str,
+    delegator_address: &str,
+) -> Result<DelegationRewards, RestError> {
+    block_on(query_delegation_rewards(grpc_url, delegator_address))
+}
+
+/// return the validators, optionally filtered by their status (async for JS/WASM)
+#[cfg(target_arch = "wasm32")]
+pub async fn get_validators(
+    grpc_web_url: &str,
+    status: Option<ValidatorBondStatus>,
+    pagination: Option<PageRequest>,
+) -> Result<Vec<ValidatorInfo>, RestError> {
+    query_validators(grpc_web_url, status, pagination).await
+}
+
+/// return the validators, optionally filtered by their status (blocking for other platforms)
+#[cfg(not(target_arch = "wasm32"))]
+pub fn get_validators_blocking(
+    grpc_url: &str,
+    status: Option<ValidatorBondStatus>,
+    pagination: Option<PageRequest>,
+) -> Result<Vec<ValidatorInfo>, RestError> {
+    block_on(query_validators(grpc_url, status, pagination))
+}
+
+/// return the staking parameters (async for JS/WASM)
+#[cfg(target_arch = "wasm32")]
+pub async fn get_staking_params(grpc_web_url: &str) -> Result<StakingParams, RestError> {
+    query_staking_params(grpc_web_url).await
+}
+
+/// return the staking parameters (blocking for other platforms)
+#[cfg(not(target_arch = "wasm32"))]
+pub fn get_staking_params_blocking(grpc_url: &str) -> Result<StakingParams, RestError> {
+    block_on(query_staking_params(grpc_url))
+}
+
+#[cfg(test)]
+mod staking_query_tests {
+    use super::*;
+    use cosmos_sdk_proto::cosmos::staking::v1beta1::{Commission, CommissionRates, Description};
+
+    #[test]
+    fn test_validator_conversion() {
+        let validator = Validator {
+            operator_address: "crocncl1n4t5q77kn9vf73s7ljs96m85jgg49yqpg0chrj".to_owned(),
+            jailed: false,
+            status: BondStatus::Bonded as i32,
+            tokens: "1000000".to_owned(),
+            delegator_shares: "1000000000000000000000000".to_owned(),
+            description: Some(Description {
+                moniker: "node0".to_owned(),
+                ..Default::default()
+            }),
+            commission: Some(Commission {
+                commission_rates: Some(CommissionRates {
+                    rate: "100000000000000000".to_owned(),
+                    max_rate: "200000000000000000".to_owned(),
+                    max_change_rate: "10000000000000000".to_owned(),
+                }),
+                update_time: None,
+            }),
+            min_self_delegation: "1".to_owned(),
+            ..Default::default()
+        };
+        let validator = ValidatorInfo::from(validator);
+        assert_eq!(validator.moniker, "node0");
+        assert_eq!(validator.status, ValidatorBondStatus::Bonded);
+        assert_eq!(validator.delegator_shares, "1000000.000000000000000000");
+        assert_eq!(validator.commission_rate, "0.100000000000000000");
+        assert_eq!(validator.commission_max_change_rate, "0.010000000000000000");
+    }
+
+    #[test]
+    fn test_dec_coins_conversion() {
+        let coins = dec_coins(vec![DecCoin {
+            denom: "basecro".to_owned(),
+            amount: "1234500000000000000000".to_owned(),
+        }]);
+        assert_eq!(
+            coins,
+            vec![RawRpcBalance {
+                denom: "basecro".to_owned(),
+                amount: "1234.500000000000000000".to_owned(),
+            }]
+        );
+    }
+}
@@ -0,0 +1,349 @@
+#[cfg(not(target_arch = "wasm32"))]
+use super::block_on;
+use crate::{CosmosError, CosmosFee, CosmosParser, CosmosTxBody, PageRequest, RestError};
+use cosmos_sdk_proto::cosmos::tx::v1beta1::{
+    service_client::ServiceClient, GetTxsEventRequest, OrderBy, TxRaw,
+};
+use cosmos_sdk_proto::traits::Message;
+use eyre::WrapErr;
+use futures::future::try_join_all;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use tendermint_rpc::endpoint::{block, tx_search};
+use tendermint_rpc::query::Query;
+use tendermint_rpc::{request, response, Order};
+
+/// the transactions of an address to search for
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum TxHistoryFilter {
+    /// the transactions signed by the address (including the staking ones)
+    Sent,
+    /// the transactions transferring tokens to the address
+    Received,
+    /// the staking transactions signed by the address
+    Staking,
+}
+
+impl TxHistoryFilter {
+    /// the event conditions (`{type}.{attribute}`, value) of the transactions
+    pub(super) fn events(self, address: &str) -> Vec<(&'static str, String)> {
+        match self {
+            TxHistoryFilter::Sent => vec![("message.sender", address.to_owned())],
+            TxHistoryFilter::Received => vec![("transfer.recipient", address.to_owned())],
+            TxHistoryFilter::Staking => vec![
+                ("message.sender", address.to_owned()),
+                ("message.module", "staking".to_owned()),
+            ],
+        }
+    }
+}
+
+/// a transaction of the history
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+pub struct CosmosTxHistoryEntry {
+    /// the uppercase hex-encoded transaction hash
+    pub tx_hash: String,
+    pub height: u64,
+    /// the block time in RFC 3339 (empty if unknown)
+    pub time: String,
+    /// the decoded messages and memo
+    pub body: CosmosTxBody,
+    pub fee: CosmosFee,
+    /// the result code (0 if success)
+    pub code: u32,
+    pub raw_log: String,
+    pub gas_wanted: u64,
+    pub gas_used: u64,
+}
+
+/// a page of the transaction history (the latest transactions first)
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+pub struct CosmosTxHistoryPage {
+    /// the number of all transactions matching the filter
+    pub total_count: u64,
+    pub txs: Vec<CosmosTxHistoryEntry>,
+}
+
+/// the execution result of a transaction
+struct TxResultInfo {
+    tx_hash: String,
+    height: u64,
+    time: String,
+    code: u32,
+    raw_log: String,
+    gas_wanted: i64,
+    gas_used: i64,
+}
+
+/// decodes the `TxRaw` (or the wire-compatible `Tx`) bytes with the parser
+fn parse_history_entry(
+    parser: &dyn CosmosParser,
+    tx: &[u8],
+    result: TxResultInfo,
+) -> Result<CosmosTxHistoryEntry, CosmosError> {
+    let tx = TxRaw::decode(tx).wrap_err("Failed to decode TxRaw from Protobuf")?;
+    let body = parser.parse_protobuf_tx_body(&hex::encode(&tx.body_bytes))?;
+    let auth_info = parser.parse_protobuf_auto_info(&hex::encode(&tx.auth_info_bytes))?;
+    Ok(CosmosTxHistoryEntry {
+        tx_hash: result.tx_hash,
+        height: result.height,
+        time: result.time,
+        body,
+        fee: auth_info.fee,
+        code: result.code,
+        raw_log: result.raw_log,
+        gas_wanted: u64::try_from(result.gas_wanted).unwrap_or_default(),
+        gas_used: u64::try_from(result.gas_used).unwrap_or_default(),
+    })
+}
+
+/// client of the transaction history of addresses, decoding the transactions
+/// with the chain's Cosmos parser
+pub struct TxHistoryClient {
+    /// the Tendermint JSON-RPC (usually on 26657)
+    tendermint_rpc_url: String,
+    /// the Cosmos gRPC (usually on 9090) or gRPC-web for JS/WASM
+    grpc_url: String,
+    parser: Arc<dyn CosmosParser + Send + Sync>,
+}
+
+impl TxHistoryClient {
+    pub fn new(
+        tendermint_rpc_url: String,
+        grpc_url: String,
+        parser: Arc<dyn CosmosParser + Send + Sync>,
+    ) -> Self {
+        Self {
+            tendermint_rpc_url,
+            grpc_url,
+            parser,
+        }
+    }
+
+    /// SearchTxs returns a page of the address transactions (latest first) via the Tendermint
+    /// `tx_search` (`page` starts from 1 and `per_page` is at most 100)
+    pub async fn search_txs(
+        &self,
+        address: &str,
+        filter: TxHistoryFilter,
+        page: u32,
+        per_page: u8,
+    ) -> Result<CosmosTxHistoryPage, CosmosError> {
+        let query = filter
+            .events(address)
+            .into_iter()
+            .fold(None, |query: Option<Query>, (key, value)| {
+                Some(match query {
+                    None => Query::eq(key, value),
+                    Some(query) => query.and_eq(key, value),
+                })
+            })
+            .ok_or(RestError::MissingResult)?;
+        let request = request::Wrapper::new(tx_search::Request::new(
+            query,
+            false,
+            page,
+            per_page,
+            Order::Descending,
+        ));
+        let result = reqwest::Client::new()
+            .post(&self.tendermint_rpc_url)
+            .json(&request)
+            .send()
+            .await
+            .map_err(RestError::RequestError)?
+            .json::<response::Wrapper<tx_search::Response>>()
+            .await
+            .map_err(RestError::RequestError)?
+            .into_result()
+            .map_err(|e| RestError::InvalidResponse(e.to_string()))?;
+
+        // `tx_search` doesn't return the block times, so fetch them concurrently per height
+        let heights: HashSet<_> = result.txs.iter().map(|tx| tx.height).collect();
+        let times: HashMap<_, _> = try_join_all(heights.into_iter().map(|height| async move {
+            let time = self.block_time(height).await?;
+            Ok::<_, RestError>((height.value(), time))
+        }))
+        .await?
+        .into_iter()
+        .collect();
+
+        let txs = result
+            .txs
+            .into_iter()
+            .map(|tx| {
+                let info = TxResultInfo {
+                    tx_hash: tx.hash.to_string(),
+                    height: tx.height.value(),
+                    time: times.get(&tx.height.value()).cloned().unwrap_or_default(),
+                    code: tx.tx_result.code.value(),
+                    raw_log: tx.tx_result.log.to_string(),
+                    gas_wanted: tx.tx_result.gas_wanted,
+                    gas_used: tx.tx_result.gas_used,
+                };
+                parse_history_entry(self.parser.as_ref(), &tx.tx, info)
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(CosmosTxHistoryPage {
+            total_count: result.total_count.into(),
+            txs,
+        })
+    }
+
+    /// GetTxsEvent returns a page of the address transactions (latest first) via the gRPC
+    /// `GetTxsEvent` service (`offset` is the number of the skipped transactions)
+    pub async fn get_txs_event(
+        &self,
+        address: &str,
+        filter: TxHistoryFilter,
+        offset: u64,
+        limit: u64,
+    ) -> Result<CosmosTxHistoryPage, CosmosError> {
+        let mut client = connect!(ServiceClient, self.grpc_url)?;
+        let request = GetTxsEventRequest {
+            events: filter
+                .events(address)
+                .into_iter()
+                .map(|(key, value)| format!("{}='{}'", key, value))
+                .collect(),
+            pagination: Some(PageRequest {
+                key: vec![],
+                offset,
+                limit,
+                count_total: true,
+                reverse: false,
+            }),
+            order_by: OrderBy::Desc as i32,
+            ..Default::default()
+        };
+        let response = client
+            .get_txs_event(request)
+            .await
+            .map_err(RestError::GRPCError)?
+            .into_inner();
+        let txs = response
+            .tx_responses
+            .into_iter()
+            .map(|tx_response| {
+                let tx = tx_response.tx.ok_or(RestError::MissingResult)?;
+                let info = TxResultInfo {
+                    tx_hash: tx_response.txhash,
+                    height: u64::try_from(tx_response.height).unwrap_or_default(),
+                    time: tx_response.timestamp,
+                    code: tx_response.code,
+                    raw_log: tx_response.raw_log,
+                    gas_wanted: tx_response.gas_wanted,
+                    gas_used: tx_response.gas_used,
+                };
+                parse_history_entry(self.parser.as_ref(), &tx.value, info)
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(CosmosTxHistoryPage {
+            total_count: response
+                .pagination
+                .map(|pagination| pagination.total)
+                .unwrap_or_default(),
+            txs,
+        })
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    /// SearchTxs returns a page of the address transactions (latest first) via the Tendermint
+    /// `tx_search` (`page` starts from 1 and `per_page` is at most 100)
+    pub fn search_txs_blocking(
+        &self,
+        address: &str,
+        filter: TxHistoryFilter,
+        page: u32,
+        per_page: u8,
+    ) -> Result<CosmosTxHistoryPage, CosmosError> {
+        block_on(self.search_txs(address, filter, page, per_page))
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    /// GetTxsEvent returns a page of the address transactions (latest first) via the gRPC
+    /// `GetTxsEvent` service (`offset` is the number of the skipped transactions)
+    pub fn get_txs_event_blocking(
+        &self,
+        address: &str,
+        filter: TxHistoryFilter,
+        offset: u64,
+        limit: u64,
+    ) -> Result<CosmosTxHistoryPage, CosmosError> {
+        block_on(self.get_txs_event(address, filter, offset, limit))
+    }
+
+    /// the block time in RFC 3339
+    async fn block_time(&self, height: tendermint::block::Height) -> Result<String, RestError> {
+        let request = request::Wrapper::new(block::Request::new(height));
+        let result = reqwest::Client::new()
+            .post(&self.tendermint_rpc_url)
+            .json(&request)
+            .send()
+            .await
+            .map_err(RestError::RequestError)?
+            .json::<response::Wrapper<block::Response>>()
+            .await
+            .map_err(RestError::RequestError)?
+            .into_result()
+            .map_err(|e| RestError::InvalidResponse(e.to_string()))?;
+        Ok(result.block.header.time.to_rfc3339())
+    }
+}
+
+#[cfg(test)]
+mod tx_history_tests {
+    use super::*;
+    use crate::{BaseParser, CosmosRawMsg, CosmosRawNormalMsg, SingleCoin};
+
+    #[test]
+    fn test_history_entry_parsing() {
+        // the signed transaction with two MsgSend of `signing_works_mutimsg` in cosmos_sdk.rs
+        let tx = hex::decode("0aa9020a90010a1c2f636f736d6f732e62616e6b2e763162657461312e4d736753656e6412700a2d636f736d6f73316c357337746e6a323861377a786565636b6867776c686a797338646c7272656667717234706a122d636f736d6f73313964796c3075797a6573346b32336c73636c6130326e3036666332326834757173647771367a1a100a057561746f6d1207313030303030300a90010a1c2f636f736d6f732e62616e6b2e763162657461312e4d736753656e6412700a2d636f736d6f73316c357337746e6a323861377a786565636b6867776c686a797338646c7272656667717234706a122d636f736d6f73316138337839347877773437653332726770797474747563783276657878636e326c6332656b781a100a057561746f6d12073230303030303018a94612680a4e0a460a1f2f636f736d6f732e63727970746f2e736563703235366b312e5075624b657912230a21028c3956de0011d6b9b2c735045647d14b38e63557e497fc025de9a17a5729c52012040a02080112160a100a057561746f6d12073130303030303010a08d061a406be1c153eda9e3ba022d2e9138c0682991ba6cf6b8b7bdc75ae1adb88b8a977b35e18292b569cb66ffff16189f37a5848648f14caa1084cfb4f7041deda737ae").unwrap();
+        let entry = parse_history_entry(
+            &BaseParser,
+            &tx,
+            TxResultInfo {
+                tx_hash: "AB".to_owned(),
+                height: 10,
+                time: "2023-05-16T00:00:00Z".to_owned(),
+                code: 0,
+                raw_log: "[]".to_owned(),
+                gas_wanted: 100_000,
+                gas_used: -1,
+            },
+        )
+        .unwrap();
+        assert_eq!(entry.body.memo, "");
+        assert_eq!(entry.body.timeout_height, 9001);
+        let bank_send = |to_address: &str, amount: &str| CosmosRawMsg::Normal {
+            msg: CosmosRawNormalMsg::BankSend {
+                from_address: "cosmos1l5s7tnj28a7zxeeckhgwlhjys8dlrrefgqr4pj".to_owned(),
+                to_address: to_address.to_owned(),
+                amount: vec![SingleCoin::Other {
+                    amount: amount.to_owned(),
+                    denom: "uatom".to_owned(),
+                }],
+            },
+        };
+        assert_eq!(
+            entry.body.messages,
+            vec![
+                bank_send("cosmos19dyl0uyzes4k23lscla02n06fc22h4uqsdwq6z", "1000000"),
+                bank_send("cosmos1a83x94xww47e32rgpytttucx2vexxcn2lc2ekx", "2000000"),
+            ]
+        );
+        assert_eq!(
+            entry.fee.amount,
+            vec![SingleCoin::Other {
+                amount: "1000000".to_owned(),
+                denom: "uatom".to_owned(),
+            }]
+        );
+        assert_eq!(entry.fee.gas_limit, 100_000);
+        assert_eq!(entry.gas_wanted, 100_000);
+        assert_eq!(entry.gas_used, 0);
+        assert_eq!(entry.height, 10);
+    }
+}
@@ -0,0 +1,257 @@
+#[cfg(not(target_arch = "wasm32"))]
+use super::block_on;
+use super::broadcast_tx_sync;
+use crate::RestError;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tendermint::{abci, Hash};
+use tendermint_rpc::endpoint::tx;
+use tendermint_rpc::{request, response};
+
+/// the default interval between the transaction queries (in milliseconds)
+pub const DEFAULT_TX_POLL_INTERVAL_MS: u64 = 1_000;
+/// the default time to wait for the transaction inclusion (in milliseconds)
+pub const DEFAULT_TX_POLL_TIMEOUT_MS: u64 = 60_000;
+
+/// an attribute of the transaction event
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct CosmosTxEventAttribute {
+    pub key: String,
+    pub value: String,
+}
+
+/// an event emitted by the transaction execution
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct CosmosTxEvent {
+    /// e.g. "transfer"
+    #[serde(rename = "type")]
+    pub event_type: String,
+    pub attributes: Vec<CosmosTxEventAttribute>,
+}
+
+impl From<abci::Event> for CosmosTxEvent {
+    fn from(event: abci::Event) -> Self {
+        Self {
+            event_type: event.kind,
+            attributes: event
+                .attributes
+                .into_iter()
+                .map(|attribute| CosmosTxEventAttribute {
+                    key: attribute.key,
+                    value: attribute.value,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// the result of a broadcasted transaction
+/// (height is 0 if the transaction was rejected by CheckTx)
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct CosmosTxReceipt {
+    /// tendermint transaction hash in hexadecimal
+    pub tx_hash_hex: String,
+    pub height: u64,
+    /// error code (0 if success)
+    pub code: u32,
+    /// the raw log (or the error log)
+    pub raw_log: String,
+    pub gas_wanted: u64,
+    pub gas_used: u64,
+    pub events: Vec<CosmosTxEvent>,
+}
+
+impl From<tx::Response> for CosmosTxReceipt {
+    fn from(resp: tx::Response) -> Self {
+        let result = resp.tx_result;
+        Self {
+            tx_hash_hex: resp.hash.to_string(),
+            height: resp.height.value(),
+            code: result.code.value(),
+            raw_log: result.log.to_string(),
+            gas_wanted: u64::try_from(result.gas_wanted).unwrap_or_default(),
+            gas_used: u64::try_from(result.gas_used).unwrap_or_default(),
+            events: result.events.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+async fn sleep(duration: Duration) {
+    gloo_timers::future::sleep(duration).await
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+async fn sleep(duration: Duration) {
+    tokio::time::sleep(duration).await
+}
+
+/// return the committed transaction or `None` if it isn't found (yet)
+async fn query_tx(
+    tendermint_rpc_url: &str,
+    hash: Hash,
+) -> Result<Option<CosmosTxReceipt>, RestError> {
+    let request = request::Wrapper::new(tx::Request::new(hash, false));
+    let result = reqwest::Client::new()
+        .post(tendermint_rpc_url)
+        .json(&request)
+        .send()
+        .await
+        .map_err(RestError::RequestError)?
+        .json::<response::Wrapper<tx::Response>>()
+        .await
+        .map_err(RestError::RequestError)?
+        .into_result();
+    tx_query_result(result)
+}
+
+/// `None` for the "tx (HASH) not found" error of a transaction that isn't committed yet,
+/// the other errors (e.g. the disabled tx indexing) are returned
+fn tx_query_result(
+    result: Result<tx::Response, tendermint_rpc::Error>,
+) -> Result<Option<CosmosTxReceipt>, RestError> {
+    match result {
+        Ok(response) => Ok(Some(response.into())),
+        Err(e) => {
+            let message = e.to_string();
+            if message.contains("tx (") && message.contains(") not found") {
+                Ok(None)
+            } else {
+                Err(RestError::InvalidResponse(message))
+            }
+        }
+    }
+}
+
+/// broadcast the tx in the "sync" mode and query it by its hash every `interval_ms`
+/// until it's committed or `timeout_ms` elapses (async for JS/WASM)
+pub async fn broadcast_tx_and_wait(
+    tendermint_rpc_url: &str,
+    raw_signed_tx: Vec<u8>,
+    timeout_ms: u64,
+    interval_ms: u64,
+) -> Result<CosmosTxReceipt, RestError> {
+    let check_tx = broadcast_tx_sync(tendermint_rpc_url, raw_signed_tx)
+        .await?
+        .into_result()
+        .map_err(|e| RestError::InvalidResponse(e.to_string()))?;
+    if check_tx.code.is_err() {
+        return Ok(CosmosTxReceipt {
+            tx_hash_hex: check_tx.hash.to_string(),
+            height: 0,
+            code: check_tx.code.value(),
+            raw_log: check_tx.log.to_string(),
+            gas_wanted: 0,
+            gas_used: 0,
+            events: vec![],
+        });
+    }
+
+    let interval_ms = interval_ms.max(1);
+    let mut waited_ms = 0;
+    loop {
+        if let Some(receipt) = query_tx(tendermint_rpc_url, check_tx.hash).await? {
+            return Ok(receipt);
+        }
+        if waited_ms >= timeout_ms {
+            return Err(RestError::Timeout(check_tx.hash.to_string()));
+        }
+        sleep(Duration::from_millis(interval_ms)).await;
+        waited_ms += interval_ms;
+    }
+}
+
+/// broadcast the tx in the "sync" mode and query it by its hash every `interval_ms`
+/// until it's committed or `timeout_ms` elapses (blocking for other platforms)
+#[cfg(not(target_arch = "wasm32"))]
+pub fn broadcast_tx_and_wait_blocking(
+    tendermint_rpc_url: &str,
+    raw_signed_tx: Vec<u8>,
+    timeout_ms: u64,
+    interval_ms: u64,
+) -> Result<CosmosTxReceipt, RestError> {
+    block_on(broadcast_tx_and_wait(
+        tendermint_rpc_url,
+        raw_signed_tx,
+        timeout_ms,
+        interval_ms,
+    ))
+}
+
+#[cfg(test)]
+mod tx_wait_tests {
+    use super::*;
+
+    #[test]
+    fn test_tx_receipt_parsing() {
+        let response: response::Wrapper<tx::Response> = serde_json::from_str(
+            r#"{
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": {
+                    "hash": "6B1D7C8A4F6D2C9E0A7B5D3E1F0C2B4A6D8E0F1A3C5B7D9E1F2A4C6B8D0E2F4A",
+                    "height": "1234",
+                    "index": 0,
+                    "tx_result": {
+                        "code": 0,
+                        "data": null,
+                        "log": "[]",
+                        "info": "",
+                        "gas_wanted": "200000",
+                        "gas_used": "81234",
+                        "events": [
+                            {
+                                "type": "transfer",
+                                "attributes": [
+                                    {"key": "recipient", "value": "cro1abc", "index": true},
+                                    {"key": "amount", "value": "100basecro", "index": true}
+                                ]
+                            }
+                        ],
+                        "codespace": ""
+                    },
+                    "tx": "CgQKAggBEgA="
+                }
+            }"#,
+        )
+        .unwrap();
+        let receipt = CosmosTxReceipt::from(response.into_result().unwrap());
+        assert_eq!(receipt.height, 1234);
+        assert_eq!(receipt.code, 0);
+        assert_eq!(receipt.gas_wanted, 200_000);
+        assert_eq!(receipt.gas_used, 81_234);
+        assert_eq!(receipt.events.len(), 1);
+        assert_eq!(receipt.events[0].event_type, "transfer");
+        assert_eq!(
+            receipt.events[0].attributes[1],
+            CosmosTxEventAttribute {
+                key: "amount".to_owned(),
+                value: "100basecro".to_owned(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_tx_query_errors() {
+        let error_response = |data: &str| {
+            let response: response::Wrapper<tx::Response> =
+                serde_json::from_value(serde_json::json!({
+                    "jsonrpc": "2.0",
+                    "id": 1,
+                    "error": {"code": -32603, "message": "Internal error", "data": data}
+                }))
+                .unwrap();
+            tx_query_result(response.into_result())
+        };
+        assert!(matches!(
+            error_response(
+                "tx (6B1D7C8A4F6D2C9E0A7B5D3E1F0C2B4A6D8E0F1A3C5B7D9E1F2A4C6B8D0E2F4A) not found"
+            ),
+            Ok(None)
+        ));
+        assert!(matches!(
+            error_response("transaction indexing is disabled"),
+            Err(RestError::InvalidResponse(message)) if message.contains("transaction indexing is disabled")
+        ));
+    }
+}
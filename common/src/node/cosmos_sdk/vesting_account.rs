@@ -0,0 +1,651 @@
+use super::RawRpcBaseAccount;
+use crate::proto::luna_classic::vesting::v1beta1 as terra_vesting;
+use crate::transaction::gov::atomics_to_decimal;
+use crate::{parse_scaled_decimal, CosmosError, RawRpcBalance};
+use cosmos_sdk_proto::cosmos::base::v1beta1::Coin;
+use cosmos_sdk_proto::cosmos::vesting::v1beta1 as vesting;
+use cosmos_sdk_proto::traits::Message;
+use cosmrs::Any;
+use eyre::{eyre, WrapErr};
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+
+const CONTINUOUS_VESTING_ACCOUNT_TYPE_URL: &str =
+    "/cosmos.vesting.v1beta1.ContinuousVestingAccount";
+const DELAYED_VESTING_ACCOUNT_TYPE_URL: &str = "/cosmos.vesting.v1beta1.DelayedVestingAccount";
+const PERIODIC_VESTING_ACCOUNT_TYPE_URL: &str = "/cosmos.vesting.v1beta1.PeriodicVestingAccount";
+const PERMANENT_LOCKED_ACCOUNT_TYPE_URL: &str = "/cosmos.vesting.v1beta1.PermanentLockedAccount";
+const LAZY_GRADED_VESTING_ACCOUNT_TYPE_URL: &str =
+    "/terra.vesting.v1beta1.LazyGradedVestingAccount";
+
+/// 10^18 (the scale of the `sdk.Dec` ratios)
+const RATIO_SCALE: u128 = 1_000_000_000_000_000_000;
+
+/// how the coins of a vesting account vest
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub enum VestingSchedule {
+    /// vests linearly from the start time to the end time
+    Continuous,
+    /// vests all at the end time
+    Delayed,
+    /// vests the amount of each period at its end
+    Periodic { periods: Vec<VestingPeriod> },
+    /// never vests (the coins can only be delegated)
+    PermanentLocked,
+    /// vests the ratio of each denomination linearly within its schedules (Terra)
+    LazyGraded {
+        schedules: Vec<LazyGradedVestingSchedule>,
+    },
+}
+
+/// a period of the periodic vesting schedule
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct VestingPeriod {
+    /// the period length in seconds (it starts at the end of the previous one)
+    pub length: i64,
+    /// the coins vested at the end of the period
+    pub amount: Vec<RawRpcBalance>,
+}
+
+/// a schedule of the lazy graded vesting (the times are in seconds since the Unix epoch)
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct LazyGradedVestingSchedule {
+    /// the vested denomination
+    pub denom: String,
+    pub start_time: i64,
+    pub end_time: i64,
+    /// the decimal ratio of the original vesting coins vested within the schedule
+    pub ratio: String,
+}
+
+/// the vesting details of a vesting account (the times are in seconds since the Unix epoch)
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct VestingAccountInfo {
+    /// the coins to vest
+    pub original_vesting: Vec<RawRpcBalance>,
+    /// the delegated vested coins
+    pub delegated_free: Vec<RawRpcBalance>,
+    /// the delegated vesting coins
+    pub delegated_vesting: Vec<RawRpcBalance>,
+    /// 0 if the schedule has no start time (delayed, permanently locked and lazy graded)
+    pub start_time: i64,
+    pub end_time: i64,
+    pub schedule: VestingSchedule,
+}
+
+/// the vesting account coins at a given time
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct VestingBalances {
+    /// the vested coins
+    pub vested: Vec<RawRpcBalance>,
+    /// the coins that are still vesting
+    pub vesting: Vec<RawRpcBalance>,
+    /// the vesting coins that are not delegated (i.e. not spendable)
+    pub locked: Vec<RawRpcBalance>,
+}
+
+/// the raw base vesting account data from the account API
+#[serde_as]
+#[derive(Deserialize)]
+struct RawBaseVestingAccount {
+    base_account: RawRpcBaseAccount,
+    original_vesting: Vec<RawRpcBalance>,
+    delegated_free: Vec<RawRpcBalance>,
+    delegated_vesting: Vec<RawRpcBalance>,
+    #[serde_as(as = "DisplayFromStr")]
+    end_time: i64,
+}
+
+#[serde_as]
+#[derive(Deserialize)]
+struct RawVestingPeriod {
+    #[serde_as(as = "DisplayFromStr")]
+    length: i64,
+    amount: Vec<RawRpcBalance>,
+}
+
+#[serde_as]
+#[derive(Deserialize)]
+struct RawSchedule {
+    #[serde_as(as = "DisplayFromStr")]
+    start_time: i64,
+    #[serde_as(as = "DisplayFromStr")]
+    end_time: i64,
+    ratio: String,
+}
+
+#[derive(Deserialize)]
+struct RawVestingSchedule {
+    denom: String,
+    schedules: Vec<RawSchedule>,
+}
+
+/// the raw vesting account fields from the account API (empty for other accounts)
+#[serde_as]
+#[derive(Deserialize)]
+pub(super) struct RawRpcVestingAccount {
+    #[serde(default)]
+    base_vesting_account: Option<RawBaseVestingAccount>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    start_time: Option<i64>,
+    #[serde(default)]
+    vesting_periods: Vec<RawVestingPeriod>,
+    #[serde(default)]
+    vesting_schedules: Vec<RawVestingSchedule>,
+}
+
+impl RawRpcVestingAccount {
+    /// the base account and the vesting details (`None` if it's not a vesting account
+    /// and no vesting details if its schedule isn't supported)
+    pub(super) fn into_parts(
+        self,
+        account_type: &str,
+    ) -> Option<(RawRpcBaseAccount, Option<VestingAccountInfo>)> {
+        let base_vesting_account = self.base_vesting_account?;
+        let schedule = match account_type {
+            CONTINUOUS_VESTING_ACCOUNT_TYPE_URL => VestingSchedule::Continuous,
+            DELAYED_VESTING_ACCOUNT_TYPE_URL => VestingSchedule::Delayed,
+            PERIODIC_VESTING_ACCOUNT_TYPE_URL => VestingSchedule::Periodic {
+                periods: self
+                    .vesting_periods
+                    .into_iter()
+                    .map(|period| VestingPeriod {
+                        length: period.length,
+                        amount: period.amount,
+                    })
+                    .collect(),
+            },
+            PERMANENT_LOCKED_ACCOUNT_TYPE_URL => VestingSchedule::PermanentLocked,
+            LAZY_GRADED_VESTING_ACCOUNT_TYPE_URL => VestingSchedule::LazyGraded {
+                schedules: self
+                    .vesting_schedules
+                    .into_iter()
+                    .flat_map(|vesting_schedule| {
+                        let denom = vesting_schedule.denom;
+                        vesting_schedule.schedules.into_iter().map(move |schedule| {
+                            LazyGradedVestingSchedule {
+                                denom: denom.clone(),
+                                start_time: schedule.start_time,
+                                end_time: schedule.end_time,
+                                ratio: schedule.ratio,
+                            }
+                        })
+                    })
+                    .collect(),
+            },
+            _ => return Some((base_vesting_account.base_account, None)),
+        };
+        Some((
+            base_vesting_account.base_account,
+            Some(VestingAccountInfo {
+                original_vesting: base_vesting_account.original_vesting,
+                delegated_free: base_vesting_account.delegated_free,
+                delegated_vesting: base_vesting_account.delegated_vesting,
+                start_time: self.start_time.unwrap_or_default(),
+                end_time: base_vesting_account.end_time,
+                schedule,
+            }),
+        ))
+    }
+}
+
+fn balances(coins: Vec<Coin>) -> Vec<RawRpcBalance> {
+    coins
+        .into_iter()
+        .map(|coin| RawRpcBalance {
+            denom: coin.denom,
+            amount: coin.amount,
+        })
+        .collect()
+}
+
+impl VestingAccountInfo {
+    fn from_proto(
+        base_vesting_account: Option<vesting::BaseVestingAccount>,
+        start_time: i64,
+        schedule: VestingSchedule,
+    ) -> Self {
+        let base_vesting_account = base_vesting_account.unwrap_or_default();
+        Self {
+            original_vesting: balances(base_vesting_account.original_vesting),
+            delegated_free: balances(base_vesting_account.delegated_free),
+            delegated_vesting: balances(base_vesting_account.delegated_vesting),
+            start_time,
+            end_time: base_vesting_account.end_time,
+            schedule,
+        }
+    }
+}
+
+/// decodes the vesting account from the Protobuf `Any` (e.g. from the gRPC account query)
+impl TryFrom<&Any> for VestingAccountInfo {
+    type Error = CosmosError;
+
+    fn try_from(any: &Any) -> Result<Self, Self::Error> {
+        let value = any.value.as_slice();
+        Ok(match any.type_url.as_str() {
+            CONTINUOUS_VESTING_ACCOUNT_TYPE_URL => {
+                let account = vesting::ContinuousVestingAccount::decode(value)
+                    .wrap_err("Failed to decode ContinuousVestingAccount from Protobuf")?;
+                Self::from_proto(
+                    account.base_vesting_account,
+                    account.start_time,
+                    VestingSchedule::Continuous,
+                )
+            }
+            DELAYED_VESTING_ACCOUNT_TYPE_URL => {
+                let account = vesting::DelayedVestingAccount::decode(value)
+                    .wrap_err("Failed to decode DelayedVestingAccount from Protobuf")?;
+                Self::from_proto(account.base_vesting_account, 0, VestingSchedule::Delayed)
+            }
+            PERIODIC_VESTING_ACCOUNT_TYPE_URL => {
+                let account = vesting::PeriodicVestingAccount::decode(value)
+                    .wrap_err("Failed to decode PeriodicVestingAccount from Protobuf")?;
+                let periods = account
+                    .vesting_periods
+                    .into_iter()
+                    .map(|period| VestingPeriod {
+                        length: period.length,
+                        amount: balances(period.amount),
+                    })
+                    .collect();
+                Self::from_proto(
+                    account.base_vesting_account,
+                    account.start_time,
+                    VestingSchedule::Periodic { periods },
+                )
+            }
+            PERMANENT_LOCKED_ACCOUNT_TYPE_URL => {
+                let account = vesting::PermanentLockedAccount::decode(value)
+                    .wrap_err("Failed to decode PermanentLockedAccount from Protobuf")?;
+                Self::from_proto(
+                    account.base_vesting_account,
+                    0,
+                    VestingSchedule::PermanentLocked,
+                )
+            }
+            LAZY_GRADED_VESTING_ACCOUNT_TYPE_URL => {
+                let account = terra_vesting::LazyGradedVestingAccount::decode(value)
+                    .wrap_err("Failed to decode LazyGradedVestingAccount from Protobuf")?;
+                let mut schedules = vec![];
+                for vesting_schedule in account.vesting_schedules {
+                    for schedule in vesting_schedule.schedules {
+                        schedules.push(LazyGradedVestingSchedule {
+                            denom: vesting_schedule.denom.clone(),
+                            start_time: schedule.start_time,
+                            end_time: schedule.end_time,
+                            // the `sdk.Dec` values are encoded as their atomics in Protobuf
+                            ratio: atomics_to_decimal(&schedule.ratio)?,
+                        });
+                    }
+                }
+                Self::from_proto(
+                    account.base_vesting_account,
+                    0,
+                    VestingSchedule::LazyGraded { schedules },
+                )
+            }
+            type_url => return Err(eyre!("not a vesting account: {}", type_url).into()),
+        })
+    }
+}
+
+/// the coin amounts by denomination
+type Coins = BTreeMap<String, u128>;
+
+fn parse_coins(coins: &[RawRpcBalance]) -> eyre::Result<Coins> {
+    let mut parsed = Coins::new();
+    for coin in coins {
+        let amount = coin
+            .amount
+            .parse::<u128>()
+            .wrap_err_with(|| format!("invalid coin amount: {}", coin.amount))?;
+        let total = parsed.entry(coin.denom.clone()).or_insert(0);
+        *total = total
+            .checked_add(amount)
+            .ok_or_else(|| eyre!("integer overflow"))?;
+    }
+    Ok(parsed)
+}
+
+fn to_balances(coins: Coins) -> Vec<RawRpcBalance> {
+    coins
+        .into_iter()
+        .filter(|(_, amount)| *amount > 0)
+        .map(|(denom, amount)| RawRpcBalance {
+            denom,
+            amount: amount.to_string(),
+        })
+        .collect()
+}
+
+/// the coins minus the others per denomination (0 if negative)
+fn saturating_sub(coins: &Coins, others: &Coins) -> Coins {
+    coins
+        .iter()
+        .map(|(denom, amount)| {
+            let other = others.get(denom).copied().unwrap_or_default();
+            (denom.clone(), amount.saturating_sub(other))
+        })
+        .collect()
+}
+
+/// amount * numerator / denominator rounded half to even (as `sdk.Dec.RoundInt`)
+fn mul_ratio(amount: u128, numerator: u128, denominator: u128) -> eyre::Result<u128> {
+    let product = amount
+        .checked_mul(numerator)
+        .ok_or_else(|| eyre!("integer overflow"))?;
+    let (quotient, remainder) = (product / denominator, product % denominator);
+    let round_up = match (remainder * 2).cmp(&denominator) {
+        Ordering::Greater => true,
+        Ordering::Equal => quotient % 2 == 1,
+        Ordering::Less => false,
+    };
+    Ok(quotient + u128::from(round_up))
+}
+
+/// the vested coins at the time (as in the Cosmos SDK and Terra `GetVestedCoins`)
+fn get_vested_coins(
+    account: &VestingAccountInfo,
+    original_vesting: &Coins,
+    time: i64,
+) -> eyre::Result<Coins> {
+    let vested = match &account.schedule {
+        VestingSchedule::Continuous => {
+            if time <= account.start_time {
+                Coins::new()
+            } else if time >= account.end_time {
+                original_vesting.clone()
+            } else {
+                let elapsed = (time - account.start_time) as u128;
+                let duration = (account.end_time - account.start_time) as u128;
+                original_vesting
+                    .iter()
+                    .map(|(denom, amount)| {
+                        Ok((denom.clone(), mul_ratio(*amount, elapsed, duration)?))
+                    })
+                    .collect::<eyre::Result<_>>()?
+            }
+        }
+        VestingSchedule::Delayed => {
+            if time >= account.end_time {
+                original_vesting.clone()
+            } else {
+                Coins::new()
+            }
+        }
+        VestingSchedule::Periodic { periods } => {
+            if time <= account.start_time {
+                Coins::new()
+            } else if time >= account.end_time {
+                original_vesting.clone()
+            } else {
+                let mut vested = Coins::new();
+                let mut period_start_time = account.start_time;
+                for period in periods {
+                    if time - period_start_time < period.length {
+                        break;
+                    }
+                    for (denom, amount) in parse_coins(&period.amount)? {
+                        let total = vested.entry(denom).or_insert(0);
+                        *total = total
+                            .checked_add(amount)
+                            .ok_or_else(|| eyre!("integer overflow"))?;
+                    }
+                    period_start_time += period.length;
+                }
+                vested
+            }
+        }
+        VestingSchedule::PermanentLocked => Coins::new(),
+        VestingSchedule::LazyGraded { schedules } => {
+            let mut vested = Coins::new();
+            for (denom, amount) in original_vesting {
+                let mut vested_ratio = 0u128;
+                for schedule in schedules.iter().filter(|s| s.denom == *denom) {
+                    if schedule.start_time > time {
+                        continue;
+                    }
+                    let ratio = parse_scaled_decimal(&schedule.ratio)?;
+                    let ratio = if schedule.end_time <= time {
+                        ratio
+                    } else {
+                        let elapsed = (time - schedule.start_time) as u128;
+                        let duration = (schedule.end_time - schedule.start_time) as u128;
+                        ratio
+                            .checked_mul(elapsed)
+                            .ok_or_else(|| eyre!("integer overflow"))?
+                            / duration
+                    };
+                    vested_ratio = vested_ratio
+                        .checked_add(ratio)
+                        .ok_or_else(|| eyre!("integer overflow"))?;
+                }
+                vested.insert(
+                    denom.clone(),
+                    mul_ratio(*amount, vested_ratio, RATIO_SCALE)?,
+                );
+            }
+            vested
+        }
+    };
+    // the vested coins never exceed the original vesting ones
+    Ok(original_vesting
+        .iter()
+        .map(|(denom, amount)| {
+            let vested = vested.get(denom).copied().unwrap_or_default();
+            (denom.clone(), vested.min(*amount))
+        })
+        .collect())
+}
+
+/// return the vested, vesting and locked coins of the vesting account at the time
+/// (in seconds since the Unix epoch)
+pub fn get_vesting_balances(
+    account: &VestingAccountInfo,
+    time: i64,
+) -> Result<VestingBalances, CosmosError> {
+    let original_vesting = parse_coins(&account.original_vesting)?;
+    let vested = get_vested_coins(account, &original_vesting, time)?;
+    let vesting = saturating_sub(&original_vesting, &vested);
+    let locked = saturating_sub(&vesting, &parse_coins(&account.delegated_vesting)?);
+    Ok(VestingBalances {
+        vested: to_balances(vested),
+        vesting: to_balances(vesting),
+        locked: to_balances(locked),
+    })
+}
+
+#[cfg(test)]
+mod vesting_account_tests {
+    use super::*;
+    use crate::RawRpcAccountResponse;
+
+    fn coins(amount: &str) -> Vec<RawRpcBalance> {
+        vec![RawRpcBalance {
+            denom: "uatom".to_owned(),
+            amount: amount.to_owned(),
+        }]
+    }
+
+    #[test]
+    fn test_continuous_vesting_account_parsing() {
+        let json = r#"{
+            "account": {
+                "@type": "/cosmos.vesting.v1beta1.ContinuousVestingAccount",
+                "base_vesting_account": {
+                    "base_account": {
+                        "address": "cosmos1l5s7tnj28a7zxeeckhgwlhjys8dlrrefgqr4pj",
+                        "pub_key": null,
+                        "account_number": "12",
+                        "sequence": "3"
+                    },
+                    "original_vesting": [{"denom": "uatom", "amount": "1000"}],
+                    "delegated_free": [],
+                    "delegated_vesting": [{"denom": "uatom", "amount": "300"}],
+                    "end_time": "2000"
+                },
+                "start_time": "1000"
+            }
+        }"#;
+        let account = match serde_json::from_str::<RawRpcAccountResponse>(json).unwrap() {
+            RawRpcAccountResponse::OkResponse { account } => account,
+            RawRpcAccountResponse::ErrorResponse { .. } => panic!("unexpected error response"),
+        };
+        assert_eq!(
+            account.address,
+            "cosmos1l5s7tnj28a7zxeeckhgwlhjys8dlrrefgqr4pj"
+        );
+        assert_eq!(account.account_number, 12);
+        assert_eq!(account.sequence, 3);
+        let vesting = account.vesting.clone().unwrap();
+        assert_eq!(vesting.start_time, 1000);
+        assert_eq!(vesting.end_time, 2000);
+        assert_eq!(vesting.schedule, VestingSchedule::Continuous);
+
+        let balances = get_vesting_balances(&vesting, 1250).unwrap();
+        assert_eq!(balances.vested, coins("250"));
+        assert_eq!(balances.vesting, coins("750"));
+        assert_eq!(balances.locked, coins("450"));
+
+        let balances = get_vesting_balances(&vesting, 2000).unwrap();
+        assert_eq!(balances.vested, coins("1000"));
+        assert!(balances.vesting.is_empty());
+        assert!(balances.locked.is_empty());
+
+        // the serialized status can be deserialized again
+        let json = serde_json::to_string(&account).unwrap();
+        assert_eq!(
+            serde_json::from_str::<crate::RawRpcAccountStatus>(&json).unwrap(),
+            account
+        );
+    }
+
+    #[test]
+    fn test_periodic_and_lazy_graded_vesting() {
+        let periodic = VestingAccountInfo {
+            original_vesting: coins("300"),
+            delegated_free: vec![],
+            delegated_vesting: vec![],
+            start_time: 1000,
+            end_time: 1300,
+            schedule: VestingSchedule::Periodic {
+                periods: vec![
+                    VestingPeriod {
+                        length: 100,
+                        amount: coins("100"),
+                    },
+                    VestingPeriod {
+                        length: 200,
+                        amount: coins("200"),
+                    },
+                ],
+            },
+        };
+        assert!(get_vesting_balances(&periodic, 1099)
+            .unwrap()
+            .vested
+            .is_empty());
+        assert_eq!(
+            get_vesting_balances(&periodic, 1100).unwrap().vested,
+            coins("100")
+        );
+        assert_eq!(
+            get_vesting_balances(&periodic, 1299).unwrap().locked,
+            coins("200")
+        );
+
+        let lazy_graded = VestingAccountInfo {
+            original_vesting: coins("1000"),
+            delegated_free: vec![],
+            delegated_vesting: vec![],
+            start_time: 0,
+            end_time: 0,
+            schedule: VestingSchedule::LazyGraded {
+                schedules: vec![
+                    LazyGradedVestingSchedule {
+                        denom: "uatom".to_owned(),
+                        start_time: 100,
+                        end_time: 200,
+                        ratio: "0.5".to_owned(),
+                    },
+                    LazyGradedVestingSchedule {
+                        denom: "uatom".to_owned(),
+                        start_time: 200,
+                        end_time: 300,
+                        ratio: "0.500000000000000000".to_owned(),
+                    },
+                ],
+            },
+        };
+        assert_eq!(
+            get_vesting_balances(&lazy_graded, 150).unwrap().vested,
+            coins("250")
+        );
+        assert_eq!(
+            get_vesting_balances(&lazy_graded, 250).unwrap().vesting,
+            coins("250")
+        );
+
+        let permanent_locked = VestingAccountInfo {
+            schedule: VestingSchedule::PermanentLocked,
+            ..lazy_graded
+        };
+        assert_eq!(
+            get_vesting_balances(&permanent_locked, i64::MAX)
+                .unwrap()
+                .locked,
+            coins("1000")
+        );
+    }
+
+    #[test]
+    fn test_lazy_graded_vesting_account_decoding() {
+        let account = terra_vesting::LazyGradedVestingAccount {
+            base_vesting_account: Some(vesting::BaseVestingAccount {
+                base_account: None,
+                original_vesting: vec![Coin {
+                    denom: "uluna".to_owned(),
+                    amount: "1000".to_owned(),
+                }],
+                delegated_free: vec![],
+                delegated_vesting: vec![],
+                end_time: 0,
+            }),
+            vesting_schedules: vec![terra_vesting::VestingSchedule {
+                denom: "uluna".to_owned(),
+                schedules: vec![terra_vesting::Schedule {
+                    start_time: 100,
+                    end_time: 200,
+                    ratio: "1000000000000000000".to_owned(),
+                }],
+            }],
+        };
+        let any = Any {
+            type_url: LAZY_GRADED_VESTING_ACCOUNT_TYPE_URL.to_owned(),
+            value: account.encode_to_vec(),
+        };
+        let vesting = VestingAccountInfo::try_from(&any).unwrap();
+        assert_eq!(
+            vesting.schedule,
+            VestingSchedule::LazyGraded {
+                schedules: vec![LazyGradedVestingSchedule {
+                    denom: "uluna".to_owned(),
+                    start_time: 100,
+                    end_time: 200,
+                    ratio: "1.000000000000000000".to_owned(),
+                }]
+            }
+        );
+        assert_eq!(
+            get_vesting_balances(&vesting, 175).unwrap().vested,
+            vec![RawRpcBalance {
+                denom: "uluna".to_owned(),
+                amount: "750".to_owned(),
+            }]
+        );
+    }
+}
@@ -12,6 +12,12 @@ pub enum RestError {
     GRPCTransportError(tonic::transport::Error),
     #[error("gRPC error: {0}")]
     GRPCError(tonic::Status),
+    #[error("Transaction {0} not committed before the timeout")]
+    Timeout(String),
+    #[error("Subscription error: {0}")]
+    SubscriptionError(String),
+    #[error("Invalid response: {0}")]
+    InvalidResponse(String),
     #[error("ErrorReport")]
     ErrorReport,
 }
@@ -136,13 +136,19 @@ impl Eip712TypedData {
 
     /// Encode the typed data.
     pub fn encode(&self) -> Result<Vec<u8>> {
-        let domain_separator = self.build_struct_hash(EIP712_DOMAIN_TYPE_NAME, &self.domain)?;
-        let struct_hash = self.build_struct_hash(&self.primary_type, &self.values)?;
+        let (domain_separator, struct_hash) = self.encode_hashes()?;
         let digest_input = [&[0x19, 0x01], &domain_separator[..], &struct_hash[..]].concat();
 
         Ok(keccak256(digest_input).to_vec())
     }
 
+    /// Encode the domain separator and the hash of the message (as hardware wallets sign them).
+    pub fn encode_hashes(&self) -> Result<([u8; 32], [u8; 32])> {
+        let domain_separator = self.build_struct_hash(EIP712_DOMAIN_TYPE_NAME, &self.domain)?;
+        let struct_hash = self.build_struct_hash(&self.primary_type, &self.values)?;
+        Ok((domain_separator, struct_hash))
+    }
+
     /// Build hashes of the all associating struct types when constructing. Since these type hashes
     /// could be reused when encoding primary struct and other referenced sub-structs.
     fn build_all_type_hashes(&mut self) -> Result<()> {
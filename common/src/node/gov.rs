@@ -0,0 +1,579 @@
+// FIXME:
+// It seems to be a `cargo-clippy` issue of Rust `1.61.0`.
+// https://github.com/influxdata/influxdb_iox/commit/b2279fae3984a29e73a7070d0b99ae24675eb606
+#![allow(clippy::await_holding_lock)]
+
+use super::error::RestError;
+use crate::transaction::gov::atomics_to_decimal;
+use crate::{PageRequest, ProposalContent, SingleCoin, VoteOption, WeightedVoteOption};
+use cosmos_sdk_proto::cosmos::gov::v1beta1::{
+    query_client::QueryClient, Deposit, DepositParams, Proposal, ProposalStatus,
+    QueryDepositsRequest, QueryParamsRequest, QueryParamsResponse, QueryProposalRequest,
+    QueryProposalsRequest, QueryTallyResultRequest, QueryVoteRequest, TallyParams, TallyResult,
+    Vote, VotingParams,
+};
+use serde::{Deserialize, Serialize};
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::sync::RwLock;
+
+/// the status of a governance proposal
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum GovProposalStatus {
+    Unspecified,
+    DepositPeriod,
+    VotingPeriod,
+    Passed,
+    Rejected,
+    Failed,
+}
+
+impl From<GovProposalStatus> for ProposalStatus {
+    fn from(status: GovProposalStatus) -> Self {
+        match status {
+            GovProposalStatus::Unspecified => ProposalStatus::Unspecified,
+            GovProposalStatus::DepositPeriod => ProposalStatus::DepositPeriod,
+            GovProposalStatus::VotingPeriod => ProposalStatus::VotingPeriod,
+            GovProposalStatus::Passed => ProposalStatus::Passed,
+            GovProposalStatus::Rejected => ProposalStatus::Rejected,
+            GovProposalStatus::Failed => ProposalStatus::Failed,
+        }
+    }
+}
+
+impl From<i32> for GovProposalStatus {
+    fn from(status: i32) -> Self {
+        match ProposalStatus::from_i32(status) {
+            Some(ProposalStatus::DepositPeriod) => Self::DepositPeriod,
+            Some(ProposalStatus::VotingPeriod) => Self::VotingPeriod,
+            Some(ProposalStatus::Passed) => Self::Passed,
+            Some(ProposalStatus::Rejected) => Self::Rejected,
+            Some(ProposalStatus::Failed) => Self::Failed,
+            _ => Self::Unspecified,
+        }
+    }
+}
+
+/// the tally of a governance proposal (the voting power of each option)
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct GovTallyResult {
+    pub yes: String,
+    pub abstain: String,
+    pub no: String,
+    pub no_with_veto: String,
+}
+
+impl From<TallyResult> for GovTallyResult {
+    fn from(tally: TallyResult) -> Self {
+        Self {
+            yes: tally.yes,
+            abstain: tally.abstain,
+            no: tally.no,
+            no_with_veto: tally.no_with_veto,
+        }
+    }
+}
+
+/// a governance proposal
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct GovProposal {
+    pub proposal_id: u64,
+    /// the type URL of the proposal content
+    pub content_type_url: String,
+    /// the proposal content (`None` if its type is not supported)
+    pub content: Option<ProposalContent>,
+    pub status: GovProposalStatus,
+    /// the final tally (only set once the voting period has ended)
+    pub final_tally_result: Option<GovTallyResult>,
+    /// the times are in seconds since the Unix epoch
+    pub submit_time: Option<i64>,
+    pub deposit_end_time: Option<i64>,
+    pub total_deposit: Vec<SingleCoin>,
+    pub voting_start_time: Option<i64>,
+    pub voting_end_time: Option<i64>,
+}
+
+impl From<Proposal> for GovProposal {
+    fn from(proposal: Proposal) -> Self {
+        Self {
+            proposal_id: proposal.proposal_id,
+            content_type_url: proposal
+                .content
+                .as_ref()
+                .map(|content| content.type_url.clone())
+                .unwrap_or_default(),
+            content: proposal
+                .content
+                .as_ref()
+                .and_then(|content| ProposalContent::try_from(content).ok()),
+            status: proposal.status.into(),
+            final_tally_result: proposal.final_tally_result.map(Into::into),
+            submit_time: proposal.submit_time.map(|time| time.seconds),
+            deposit_end_time: proposal.deposit_end_time.map(|time| time.seconds),
+            total_deposit: proposal.total_deposit.into_iter().map(Into::into).collect(),
+            voting_start_time: proposal.voting_start_time.map(|time| time.seconds),
+            voting_end_time: proposal.voting_end_time.map(|time| time.seconds),
+        }
+    }
+}
+
+/// a deposit on a governance proposal
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct GovDepositInfo {
+    pub proposal_id: u64,
+    pub depositor: String,
+    pub amount: Vec<SingleCoin>,
+}
+
+impl From<Deposit> for GovDepositInfo {
+    fn from(deposit: Deposit) -> Self {
+        Self {
+            proposal_id: deposit.proposal_id,
+            depositor: deposit.depositor,
+            amount: deposit.amount.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// a vote on a governance proposal
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct GovVoteInfo {
+    pub proposal_id: u64,
+    pub voter: String,
+    /// the vote options (a single option with the weight of 1 for a non-weighted vote)
+    pub options: Vec<WeightedVoteOption>,
+}
+
+fn invalid_response(error: impl std::fmt::Display) -> RestError {
+    RestError::InvalidResponse(error.to_string())
+}
+
+impl TryFrom<Vote> for GovVoteInfo {
+    type Error = RestError;
+
+    fn try_from(vote: Vote) -> Result<Self, Self::Error> {
+        #[allow(deprecated)]
+        let options = if vote.options.is_empty() {
+            // the votes stored before the weighted votes only set the deprecated `option` field
+            vec![WeightedVoteOption {
+                option: VoteOption::try_from(vote.option).map_err(invalid_response)?,
+                weight: "1.000000000000000000".to_owned(),
+            }]
+        } else {
+            vote.options
+                .into_iter()
+                .map(|option| {
+                    Ok(WeightedVoteOption {
+                        option: VoteOption::try_from(option.option).map_err(invalid_response)?,
+                        weight: atomics_to_decimal(&option.weight).map_err(invalid_response)?,
+                    })
+                })
+                .collect::<Result<_, RestError>>()?
+        };
+        Ok(Self {
+            proposal_id: vote.proposal_id,
+            voter: vote.voter,
+            options,
+        })
+    }
+}
+
+/// the parameters of the governance module
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct GovParams {
+    /// the minimum deposit for a proposal to enter the voting period
+    pub min_deposit: Vec<SingleCoin>,
+    /// the maximum deposit period (in seconds)
+    pub max_deposit_period: i64,
+    /// the voting period (in seconds)
+    pub voting_period: i64,
+    /// the minimum percentage of the voting power that needs to vote (e.g. "0.334000000000000000")
+    pub quorum: String,
+    /// the minimum proportion of yes votes for a proposal to pass
+    pub threshold: String,
+    /// the minimum proportion of veto votes for a proposal to be vetoed
+    pub veto_threshold: String,
+}
+
+impl GovParams {
+    /// merges the responses of the params queries (each one only sets the params of its type)
+    fn from_responses(responses: Vec<QueryParamsResponse>) -> Result<Self, RestError> {
+        let mut voting_params = None;
+        let mut deposit_params = None;
+        let mut tally_params = None;
+        for res in responses {
+            voting_params = voting_params.or(res.voting_params);
+            deposit_params = deposit_params.or(res.deposit_params);
+            tally_params = tally_params.or(res.tally_params);
+        }
+        Self::new(voting_params, deposit_params, tally_params)
+    }
+
+    fn new(
+        voting_params: Option<VotingParams>,
+        deposit_params: Option<DepositParams>,
+        tally_params: Option<TallyParams>,
+    ) -> Result<Self, RestError> {
+        // the `sdk.Dec` tally parameters are encoded as bytes of their atomics
+        // (and are empty when the tallying parameters were not queried)
+        let decimal = |bytes: Vec<u8>| match String::from_utf8(bytes) {
+            Ok(atomics) if atomics.is_empty() => Ok(atomics),
+            Ok(atomics) => atomics_to_decimal(&atomics).map_err(invalid_response),
+            Err(e) => Err(invalid_response(e)),
+        };
+        let voting_params = voting_params.unwrap_or_default();
+        let deposit_params = deposit_params.unwrap_or_default();
+        let tally_params = tally_params.unwrap_or_default();
+        Ok(Self {
+            min_deposit: deposit_params
+                .min_deposit
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            max_deposit_period: deposit_params
+                .max_deposit_period
+                .map(|period| period.seconds)
+                .unwrap_or_default(),
+            voting_period: voting_params
+                .voting_period
+                .map(|period| period.seconds)
+                .unwrap_or_default(),
+            quorum: decimal(tally_params.quorum)?,
+            threshold: decimal(tally_params.threshold)?,
+            veto_threshold: decimal(tally_params.veto_threshold)?,
+        })
+    }
+}
+
+fn proposals_request(
+    status: Option<GovProposalStatus>,
+    voter: String,
+    depositor: String,
+    pagination: Option<PageRequest>,
+) -> QueryProposalsRequest {
+    QueryProposalsRequest {
+        proposal_status: ProposalStatus::from(status.unwrap_or(GovProposalStatus::Unspecified))
+            as i32,
+        voter,
+        depositor,
+        pagination,
+    }
+}
+
+fn params_request(params_type: &str) -> QueryParamsRequest {
+    QueryParamsRequest {
+        params_type: params_type.to_owned(),
+    }
+}
+
+/// client of the governance module gRPC (or gRPC-web) queries
+pub struct GovClient {
+    #[cfg(target_arch = "wasm32")]
+    pub client: QueryClient<tonic_web_wasm_client::Client>,
+    #[cfg(not(target_arch = "wasm32"))]
+    // uniffi does not support mutable reference, that's why RwLock here
+    pub client: RwLock<QueryClient<tonic::transport::Channel>>,
+    #[cfg(not(target_arch = "wasm32"))]
+    pub rt: tokio::runtime::Runtime,
+}
+
+impl GovClient {
+    #[cfg(target_arch = "wasm32")]
+    pub fn new(grpc_web_url: String) -> Self {
+        let client = QueryClient::new(tonic_web_wasm_client::Client::new(grpc_web_url));
+        Self { client }
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn new_blocking(grpc_url: String) -> Result<Self, RestError> {
+        let rt = tokio::runtime::Runtime::new().map_err(|_err| RestError::AsyncRuntimeError)?;
+        let client = rt.block_on(async move {
+            let client = QueryClient::connect(grpc_url.to_owned())
+                .await
+                .map_err(RestError::GRPCTransportError)?;
+            Ok(client)
+        });
+        Ok(Self {
+            client: RwLock::new(client?),
+            rt,
+        })
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    /// Proposal queries the proposal of the given ID
+    pub async fn proposal(&mut self, proposal_id: u64) -> Result<Option<GovProposal>, RestError> {
+        let request = QueryProposalRequest { proposal_id };
+        let res = self
+            .client
+            .proposal(request)
+            .await
+            .map_err(RestError::GRPCError)?
+            .into_inner();
+        Ok(res.proposal.map(Into::into))
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    /// Proposal queries the proposal of the given ID
+    pub fn proposal_blocking(&self, proposal_id: u64) -> Result<Option<GovProposal>, RestError> {
+        self.rt.block_on(async move {
+            let mut client = self.client.write().unwrap();
+            let request = QueryProposalRequest { proposal_id };
+            let res = (*client)
+                .proposal(request)
+                .await
+                .map_err(RestError::GRPCError)?
+                .into_inner();
+            Ok(res.proposal.map(Into::into))
+        })
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    /// Proposals queries the proposals filtered by the status, the voter and the depositor
+    /// (an empty voter or depositor disables the filter)
+    pub async fn proposals(
+        &mut self,
+        status: Option<GovProposalStatus>,
+        voter: String,
+        depositor: String,
+        pagination: Option<PageRequest>,
+    ) -> Result<Vec<GovProposal>, RestError> {
+        let request = proposals_request(status, voter, depositor, pagination);
+        let res = self
+            .client
+            .proposals(request)
+            .await
+            .map_err(RestError::GRPCError)?
+            .into_inner();
+        Ok(res.proposals.into_iter().map(Into::into).collect())
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    /// Proposals queries the proposals filtered by the status, the voter and the depositor
+    /// (an empty voter or depositor disables the filter)
+    pub fn proposals_blocking(
+        &self,
+        status: Option<GovProposalStatus>,
+        voter: String,
+        depositor: String,
+        pagination: Option<PageRequest>,
+    ) -> Result<Vec<GovProposal>, RestError> {
+        self.rt.block_on(async move {
+            let mut client = self.client.write().unwrap();
+            let request = proposals_request(status, voter, depositor, pagination);
+            let res = (*client)
+                .proposals(request)
+                .await
+                .map_err(RestError::GRPCError)?
+                .into_inner();
+            Ok(res.proposals.into_iter().map(Into::into).collect())
+        })
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    /// TallyResult queries the current tally of the given proposal
+    pub async fn tally_result(
+        &mut self,
+        proposal_id: u64,
+    ) -> Result<Option<GovTallyResult>, RestError> {
+        let request = QueryTallyResultRequest { proposal_id };
+        let res = self
+            .client
+            .tally_result(request)
+            .await
+            .map_err(RestError::GRPCError)?
+            .into_inner();
+        Ok(res.tally.map(Into::into))
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    /// TallyResult queries the current tally of the given proposal
+    pub fn tally_result_blocking(
+        &self,
+        proposal_id: u64,
+    ) -> Result<Option<GovTallyResult>, RestError> {
+        self.rt.block_on(async move {
+            let mut client = self.client.write().unwrap();
+            let request = QueryTallyResultRequest { proposal_id };
+            let res = (*client)
+                .tally_result(request)
+                .await
+                .map_err(RestError::GRPCError)?
+                .into_inner();
+            Ok(res.tally.map(Into::into))
+        })
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    /// Deposits queries the deposits of the given proposal
+    pub async fn deposits(
+        &mut self,
+        proposal_id: u64,
+        pagination: Option<PageRequest>,
+    ) -> Result<Vec<GovDepositInfo>, RestError> {
+        let request = QueryDepositsRequest {
+            proposal_id,
+            pagination,
+        };
+        let res = self
+            .client
+            .deposits(request)
+            .await
+            .map_err(RestError::GRPCError)?
+            .into_inner();
+        Ok(res.deposits.into_iter().map(Into::into).collect())
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    /// Deposits queries the deposits of the given proposal
+    pub fn deposits_blocking(
+        &self,
+        proposal_id: u64,
+        pagination: Option<PageRequest>,
+    ) -> Result<Vec<GovDepositInfo>, RestError> {
+        self.rt.block_on(async move {
+            let mut client = self.client.write().unwrap();
+            let request = QueryDepositsRequest {
+                proposal_id,
+                pagination,
+            };
+            let res = (*client)
+                .deposits(request)
+                .await
+                .map_err(RestError::GRPCError)?
+                .into_inner();
+            Ok(res.deposits.into_iter().map(Into::into).collect())
+        })
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    /// Vote queries the vote of the voter on the given proposal
+    pub async fn vote(
+        &mut self,
+        proposal_id: u64,
+        voter: String,
+    ) -> Result<Option<GovVoteInfo>, RestError> {
+        let request = QueryVoteRequest { proposal_id, voter };
+        let res = self
+            .client
+            .vote(request)
+            .await
+            .map_err(RestError::GRPCError)?
+            .into_inner();
+        res.vote.map(TryInto::try_into).transpose()
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    /// Vote queries the vote of the voter on the given proposal
+    pub fn vote_blocking(
+        &self,
+        proposal_id: u64,
+        voter: String,
+    ) -> Result<Option<GovVoteInfo>, RestError> {
+        self.rt.block_on(async move {
+            let mut client = self.client.write().unwrap();
+            let request = QueryVoteRequest { proposal_id, voter };
+            let res = (*client)
+                .vote(request)
+                .await
+                .map_err(RestError::GRPCError)?
+                .into_inner();
+            res.vote.map(TryInto::try_into).transpose()
+        })
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    /// Params queries the voting, deposit and tallying parameters
+    pub async fn params(&mut self) -> Result<GovParams, RestError> {
+        let mut responses = vec![];
+        for params_type in ["voting", "deposit", "tallying"] {
+            let res = self
+                .client
+                .params(params_request(params_type))
+                .await
+                .map_err(RestError::GRPCError)?
+                .into_inner();
+            responses.push(res);
+        }
+        GovParams::from_responses(responses)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    /// Params queries the voting, deposit and tallying parameters
+    pub fn params_blocking(&self) -> Result<GovParams, RestError> {
+        self.rt.block_on(async move {
+            let mut client = self.client.write().unwrap();
+            let mut responses = vec![];
+            for params_type in ["voting", "deposit", "tallying"] {
+                let res = (*client)
+                    .params(params_request(params_type))
+                    .await
+                    .map_err(RestError::GRPCError)?
+                    .into_inner();
+                responses.push(res);
+            }
+            GovParams::from_responses(responses)
+        })
+    }
+}
+
+#[cfg(test)]
+mod gov_query_tests {
+    use super::*;
+    use cosmos_sdk_proto::cosmos::gov::v1beta1::WeightedVoteOption as ProtoWeightedVoteOption;
+
+    #[test]
+    fn test_vote_conversion() {
+        #[allow(deprecated)]
+        let vote = Vote {
+            proposal_id: 5,
+            voter: "cosmos1l5s7tnj28a7zxeeckhgwlhjys8dlrrefgqr4pj".to_owned(),
+            option: 1,
+            options: vec![],
+        };
+        let vote = GovVoteInfo::try_from(vote).unwrap();
+        assert_eq!(
+            vote.options,
+            vec![WeightedVoteOption {
+                option: VoteOption::Yes,
+                weight: "1.000000000000000000".to_owned(),
+            }]
+        );
+
+        #[allow(deprecated)]
+        let vote = Vote {
+            proposal_id: 5,
+            voter: "cosmos1l5s7tnj28a7zxeeckhgwlhjys8dlrrefgqr4pj".to_owned(),
+            option: 0,
+            options: vec![
+                ProtoWeightedVoteOption {
+                    option: 3,
+                    weight: "250000000000000000".to_owned(),
+                },
+                ProtoWeightedVoteOption {
+                    option: 4,
+                    weight: "750000000000000000".to_owned(),
+                },
+            ],
+        };
+        let vote = GovVoteInfo::try_from(vote).unwrap();
+        assert_eq!(vote.options[0].option, VoteOption::No);
+        assert_eq!(vote.options[1].weight, "0.750000000000000000");
+    }
+
+    #[test]
+    fn test_params_conversion() {
+        let params = GovParams::new(
+            None,
+            None,
+            Some(TallyParams {
+                quorum: b"334000000000000000".to_vec(),
+                threshold: b"500000000000000000".to_vec(),
+                veto_threshold: b"334000000000000000".to_vec(),
+            }),
+        )
+        .unwrap();
+        assert_eq!(params.quorum, "0.334000000000000000");
+        assert_eq!(params.threshold, "0.500000000000000000");
+        assert_eq!(params.voting_period, 0);
+        assert!(params.min_deposit.is_empty());
+    }
+}
@@ -1,18 +1,32 @@
+/// authz and feegrant modules support
+pub(crate) mod authz;
+/// wrapper and helpers for rust-bitcoin (addresses and PSBT signing)
+mod btc;
 /// wrapper and helpers for CosmRS
 mod cosmos_sdk;
 /// wrapper and helpers for ethers
 mod ethereum;
+/// governance module support
+pub(crate) mod gov;
+/// IBC transfer helpers
+mod ibc_transfer;
 /// wrapper and helpers for LunaClassic chain
 mod luna_classic;
 /// wrapper and helper for NFT functionality
 pub mod nft;
 /// UniFFI binding related functions
 mod uniffi_binding;
+/// vesting module support
+pub(crate) mod vesting;
 /// wasm binding related functions
 mod wasm_binding;
 
+pub use authz::*;
+pub use btc::*;
 pub use cosmos_sdk::*;
 pub use ethereum::*;
+pub use gov::*;
+pub use ibc_transfer::*;
 pub use nft::*;
 #[cfg(feature = "uniffi-binding")]
 pub use uniffi_binding::*;
@@ -0,0 +1,475 @@
+// ! Authorization (x/authz) and fee allowance (x/feegrant) module support
+
+use crate::transaction::gov::{proto_coin, proto_coins};
+use crate::{CosmosError, SingleCoin};
+use cosmos_sdk_proto::cosmos::authz::v1beta1 as authz;
+use cosmos_sdk_proto::cosmos::bank::v1beta1 as bank;
+use cosmos_sdk_proto::cosmos::feegrant::v1beta1 as feegrant;
+use cosmos_sdk_proto::cosmos::staking::v1beta1 as staking;
+use cosmos_sdk_proto::traits::Message;
+use cosmrs::Any;
+use eyre::{eyre, WrapErr};
+use prost_types::{Duration, Timestamp};
+use serde::{Deserialize, Serialize};
+use staking::stake_authorization::{Policy, Validators};
+use std::str::FromStr;
+
+pub(crate) const MSG_GRANT_TYPE_URL: &str = "/cosmos.authz.v1beta1.MsgGrant";
+pub(crate) const MSG_EXEC_TYPE_URL: &str = "/cosmos.authz.v1beta1.MsgExec";
+pub(crate) const MSG_REVOKE_TYPE_URL: &str = "/cosmos.authz.v1beta1.MsgRevoke";
+pub(crate) const MSG_GRANT_ALLOWANCE_TYPE_URL: &str = "/cosmos.feegrant.v1beta1.MsgGrantAllowance";
+pub(crate) const MSG_REVOKE_ALLOWANCE_TYPE_URL: &str =
+    "/cosmos.feegrant.v1beta1.MsgRevokeAllowance";
+const GENERIC_AUTHORIZATION_TYPE_URL: &str = "/cosmos.authz.v1beta1.GenericAuthorization";
+const SEND_AUTHORIZATION_TYPE_URL: &str = "/cosmos.bank.v1beta1.SendAuthorization";
+const STAKE_AUTHORIZATION_TYPE_URL: &str = "/cosmos.staking.v1beta1.StakeAuthorization";
+const BASIC_ALLOWANCE_TYPE_URL: &str = "/cosmos.feegrant.v1beta1.BasicAllowance";
+const PERIODIC_ALLOWANCE_TYPE_URL: &str = "/cosmos.feegrant.v1beta1.PeriodicAllowance";
+
+/// the staking messages allowed by `StakeAuthorization`
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum StakeAuthorizationType {
+    /// MsgDelegate
+    #[serde(rename = "AUTHORIZATION_TYPE_DELEGATE")]
+    Delegate,
+    /// MsgUndelegate
+    #[serde(rename = "AUTHORIZATION_TYPE_UNDELEGATE")]
+    Undelegate,
+    /// MsgBeginRedelegate
+    #[serde(rename = "AUTHORIZATION_TYPE_REDELEGATE")]
+    Redelegate,
+}
+
+impl From<StakeAuthorizationType> for i32 {
+    fn from(authorization_type: StakeAuthorizationType) -> Self {
+        match authorization_type {
+            StakeAuthorizationType::Delegate => staking::AuthorizationType::Delegate as i32,
+            StakeAuthorizationType::Undelegate => staking::AuthorizationType::Undelegate as i32,
+            StakeAuthorizationType::Redelegate => staking::AuthorizationType::Redelegate as i32,
+        }
+    }
+}
+
+impl TryFrom<i32> for StakeAuthorizationType {
+    type Error = CosmosError;
+
+    fn try_from(authorization_type: i32) -> Result<Self, Self::Error> {
+        match staking::AuthorizationType::from_i32(authorization_type) {
+            Some(staking::AuthorizationType::Delegate) => Ok(Self::Delegate),
+            Some(staking::AuthorizationType::Undelegate) => Ok(Self::Undelegate),
+            Some(staking::AuthorizationType::Redelegate) => Ok(Self::Redelegate),
+            _ => Err(eyre!("Invalid stake authorization type: {authorization_type}").into()),
+        }
+    }
+}
+
+impl FromStr for StakeAuthorizationType {
+    type Err = CosmosError;
+
+    /// parses "delegate", "undelegate" or "redelegate" (or the proto JSON names)
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s
+            .to_ascii_lowercase()
+            .trim_start_matches("authorization_type_")
+        {
+            "delegate" => Ok(Self::Delegate),
+            "undelegate" => Ok(Self::Undelegate),
+            "redelegate" => Ok(Self::Redelegate),
+            _ => Err(eyre!("Invalid stake authorization type: {s}").into()),
+        }
+    }
+}
+
+/// the authorization granted by `MsgGrant`
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(tag = "@type")]
+pub enum Authorization {
+    /// GenericAuthorization: allows to execute any message of the type
+    #[serde(rename = "/cosmos.authz.v1beta1.GenericAuthorization")]
+    Generic {
+        /// the message type URL, e.g. "/cosmos.gov.v1beta1.MsgVote"
+        msg: String,
+    },
+    /// SendAuthorization: allows to send the coins up to the spend limit
+    #[serde(rename = "/cosmos.bank.v1beta1.SendAuthorization")]
+    Send { spend_limit: Vec<SingleCoin> },
+    /// StakeAuthorization: allows to (un/re)delegate the tokens
+    /// (at most one of the allow and deny lists can be set)
+    #[serde(rename = "/cosmos.staking.v1beta1.StakeAuthorization")]
+    Stake {
+        /// the maximum amount of tokens (unlimited if not set)
+        max_tokens: Option<SingleCoin>,
+        /// the validators which can be (un/re)delegated to
+        allow_list: Vec<String>,
+        /// the validators which cannot be (un/re)delegated to
+        deny_list: Vec<String>,
+        /// the allowed staking message
+        authorization_type: StakeAuthorizationType,
+    },
+}
+
+impl Authorization {
+    pub(crate) fn to_any(&self) -> eyre::Result<Any> {
+        Ok(match self {
+            Self::Generic { msg } => Any {
+                type_url: GENERIC_AUTHORIZATION_TYPE_URL.to_owned(),
+                value: authz::GenericAuthorization {
+                    msg: msg.to_owned(),
+                }
+                .encode_to_vec(),
+            },
+            Self::Send { spend_limit } => Any {
+                type_url: SEND_AUTHORIZATION_TYPE_URL.to_owned(),
+                value: bank::SendAuthorization {
+                    spend_limit: proto_coins(spend_limit)?,
+                }
+                .encode_to_vec(),
+            },
+            Self::Stake {
+                max_tokens,
+                allow_list,
+                deny_list,
+                authorization_type,
+            } => {
+                let validators = match (allow_list.is_empty(), deny_list.is_empty()) {
+                    (true, true) => None,
+                    (false, true) => Some(Policy::AllowList(Validators {
+                        address: allow_list.clone(),
+                    })),
+                    (true, false) => Some(Policy::DenyList(Validators {
+                        address: deny_list.clone(),
+                    })),
+                    (false, false) => {
+                        return Err(eyre!(
+                            "Only one of the allow and deny lists can be set in StakeAuthorization"
+                        ))
+                    }
+                };
+                Any {
+                    type_url: STAKE_AUTHORIZATION_TYPE_URL.to_owned(),
+                    value: staking::StakeAuthorization {
+                        max_tokens: max_tokens.as_ref().map(proto_coin).transpose()?,
+                        authorization_type: (*authorization_type).into(),
+                        validators,
+                    }
+                    .encode_to_vec(),
+                }
+            }
+        })
+    }
+}
+
+impl TryFrom<&Any> for Authorization {
+    type Error = CosmosError;
+
+    fn try_from(any: &Any) -> Result<Self, Self::Error> {
+        match any.type_url.as_str() {
+            GENERIC_AUTHORIZATION_TYPE_URL => {
+                let authorization = authz::GenericAuthorization::decode(any.value.as_slice())
+                    .wrap_err("Failed to decode GenericAuthorization from Protobuf")?;
+                Ok(Self::Generic {
+                    msg: authorization.msg,
+                })
+            }
+            SEND_AUTHORIZATION_TYPE_URL => {
+                let authorization = bank::SendAuthorization::decode(any.value.as_slice())
+                    .wrap_err("Failed to decode SendAuthorization from Protobuf")?;
+                Ok(Self::Send {
+                    spend_limit: authorization
+                        .spend_limit
+                        .into_iter()
+                        .map(Into::into)
+                        .collect(),
+                })
+            }
+            STAKE_AUTHORIZATION_TYPE_URL => {
+                let authorization = staking::StakeAuthorization::decode(any.value.as_slice())
+                    .wrap_err("Failed to decode StakeAuthorization from Protobuf")?;
+                let (allow_list, deny_list) = match authorization.validators {
+                    Some(Policy::AllowList(validators)) => (validators.address, vec![]),
+                    Some(Policy::DenyList(validators)) => (vec![], validators.address),
+                    None => (vec![], vec![]),
+                };
+                Ok(Self::Stake {
+                    max_tokens: authorization.max_tokens.map(Into::into),
+                    allow_list,
+                    deny_list,
+                    authorization_type: authorization.authorization_type.try_into()?,
+                })
+            }
+            type_url => Err(eyre!("Unsupported authorization: {type_url}").into()),
+        }
+    }
+}
+
+/// the fee allowance granted by `MsgGrantAllowance`
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(tag = "@type")]
+pub enum FeeAllowance {
+    /// BasicAllowance: allows to spend the fee coins up to the spend limit
+    #[serde(rename = "/cosmos.feegrant.v1beta1.BasicAllowance")]
+    Basic {
+        /// the maximum fee amount (unlimited if empty)
+        spend_limit: Vec<SingleCoin>,
+        /// the expiration time in seconds since the Unix epoch (never expires if not set)
+        expiration: Option<i64>,
+    },
+    /// PeriodicAllowance: the basic allowance with a spend limit reset every period
+    #[serde(rename = "/cosmos.feegrant.v1beta1.PeriodicAllowance")]
+    Periodic {
+        /// the maximum fee amount in total (unlimited if empty)
+        spend_limit: Vec<SingleCoin>,
+        /// the expiration time in seconds since the Unix epoch (never expires if not set)
+        expiration: Option<i64>,
+        /// the period length in seconds
+        period: u64,
+        /// the maximum fee amount in a period
+        period_spend_limit: Vec<SingleCoin>,
+    },
+}
+
+impl FeeAllowance {
+    pub(crate) fn to_any(&self) -> eyre::Result<Any> {
+        Ok(match self {
+            Self::Basic {
+                spend_limit,
+                expiration,
+            } => Any {
+                type_url: BASIC_ALLOWANCE_TYPE_URL.to_owned(),
+                value: feegrant::BasicAllowance {
+                    spend_limit: proto_coins(spend_limit)?,
+                    expiration: expiration.map(timestamp),
+                }
+                .encode_to_vec(),
+            },
+            Self::Periodic {
+                spend_limit,
+                expiration,
+                period,
+                period_spend_limit,
+            } => {
+                let period_spend_limit = proto_coins(period_spend_limit)?;
+                Any {
+                    type_url: PERIODIC_ALLOWANCE_TYPE_URL.to_owned(),
+                    value: feegrant::PeriodicAllowance {
+                        basic: Some(feegrant::BasicAllowance {
+                            spend_limit: proto_coins(spend_limit)?,
+                            expiration: expiration.map(timestamp),
+                        }),
+                        period: Some(Duration {
+                            seconds: (*period)
+                                .try_into()
+                                .wrap_err("The allowance period is too long")?,
+                            nanos: 0,
+                        }),
+                        // the whole limit can be spent in the first period
+                        // (the chain sets the reset time on the first use)
+                        period_can_spend: period_spend_limit.clone(),
+                        period_spend_limit,
+                        period_reset: None,
+                    }
+                    .encode_to_vec(),
+                }
+            }
+        })
+    }
+}
+
+impl TryFrom<&Any> for FeeAllowance {
+    type Error = CosmosError;
+
+    fn try_from(any: &Any) -> Result<Self, Self::Error> {
+        match any.type_url.as_str() {
+            BASIC_ALLOWANCE_TYPE_URL => {
+                let allowance = feegrant::BasicAllowance::decode(any.value.as_slice())
+                    .wrap_err("Failed to decode BasicAllowance from Protobuf")?;
+                Ok(Self::Basic {
+                    spend_limit: allowance.spend_limit.into_iter().map(Into::into).collect(),
+                    expiration: allowance.expiration.map(|time| time.seconds),
+                })
+            }
+            PERIODIC_ALLOWANCE_TYPE_URL => {
+                let allowance = feegrant::PeriodicAllowance::decode(any.value.as_slice())
+                    .wrap_err("Failed to decode PeriodicAllowance from Protobuf")?;
+                let basic = allowance.basic.unwrap_or_default();
+                Ok(Self::Periodic {
+                    spend_limit: basic.spend_limit.into_iter().map(Into::into).collect(),
+                    expiration: basic.expiration.map(|time| time.seconds),
+                    period: allowance
+                        .period
+                        .map(|period| period.seconds.max(0) as u64)
+                        .unwrap_or_default(),
+                    period_spend_limit: allowance
+                        .period_spend_limit
+                        .into_iter()
+                        .map(Into::into)
+                        .collect(),
+                })
+            }
+            type_url => Err(eyre!("Unsupported fee allowance: {type_url}").into()),
+        }
+    }
+}
+
+fn timestamp(seconds: i64) -> Timestamp {
+    Timestamp { seconds, nanos: 0 }
+}
+
+/// creates `MsgGrant`
+pub(crate) fn get_grant_any(
+    granter: &str,
+    grantee: &str,
+    authorization: &Authorization,
+    expiration: Option<i64>,
+) -> eyre::Result<Any> {
+    Ok(Any {
+        type_url: MSG_GRANT_TYPE_URL.to_owned(),
+        value: authz::MsgGrant {
+            granter: granter.to_owned(),
+            grantee: grantee.to_owned(),
+            grant: Some(authz::Grant {
+                authorization: Some(authorization.to_any()?),
+                expiration: expiration.map(timestamp),
+            }),
+        }
+        .encode_to_vec(),
+    })
+}
+
+/// creates `MsgExec` (the messages are signed by the granter)
+pub(crate) fn get_exec_any(grantee: &str, msgs: Vec<Any>) -> Any {
+    Any {
+        type_url: MSG_EXEC_TYPE_URL.to_owned(),
+        value: authz::MsgExec {
+            grantee: grantee.to_owned(),
+            msgs,
+        }
+        .encode_to_vec(),
+    }
+}
+
+/// creates `MsgRevoke`
+pub(crate) fn get_revoke_any(granter: &str, grantee: &str, msg_type_url: &str) -> Any {
+    Any {
+        type_url: MSG_REVOKE_TYPE_URL.to_owned(),
+        value: authz::MsgRevoke {
+            granter: granter.to_owned(),
+            grantee: grantee.to_owned(),
+            msg_type_url: msg_type_url.to_owned(),
+        }
+        .encode_to_vec(),
+    }
+}
+
+/// creates `MsgGrantAllowance`
+pub(crate) fn get_grant_allowance_any(
+    granter: &str,
+    grantee: &str,
+    allowance: &FeeAllowance,
+) -> eyre::Result<Any> {
+    Ok(Any {
+        type_url: MSG_GRANT_ALLOWANCE_TYPE_URL.to_owned(),
+        value: feegrant::MsgGrantAllowance {
+            granter: granter.to_owned(),
+            grantee: grantee.to_owned(),
+            allowance: Some(allowance.to_any()?),
+        }
+        .encode_to_vec(),
+    })
+}
+
+/// creates `MsgRevokeAllowance`
+pub(crate) fn get_revoke_allowance_any(granter: &str, grantee: &str) -> Any {
+    Any {
+        type_url: MSG_REVOKE_ALLOWANCE_TYPE_URL.to_owned(),
+        value: feegrant::MsgRevokeAllowance {
+            granter: granter.to_owned(),
+            grantee: grantee.to_owned(),
+        }
+        .encode_to_vec(),
+    }
+}
+
+#[cfg(test)]
+mod authz_tests {
+    use super::*;
+
+    #[test]
+    fn test_stake_authorization_type() {
+        assert_eq!(
+            "delegate".parse::<StakeAuthorizationType>().unwrap(),
+            StakeAuthorizationType::Delegate
+        );
+        assert_eq!(
+            "AUTHORIZATION_TYPE_REDELEGATE"
+                .parse::<StakeAuthorizationType>()
+                .unwrap(),
+            StakeAuthorizationType::Redelegate
+        );
+        assert!("withdraw".parse::<StakeAuthorizationType>().is_err());
+        assert_eq!(i32::from(StakeAuthorizationType::Undelegate), 2);
+        assert!(StakeAuthorizationType::try_from(0).is_err());
+    }
+
+    #[test]
+    fn test_authorization_any() {
+        let authorization = Authorization::Stake {
+            max_tokens: Some(SingleCoin::UATOM { amount: 1_000_000 }),
+            allow_list: vec!["cosmosvaloper1sjllsnramtg3ewxqwwrwjxfgc4n4ef9u2lcnj0".to_owned()],
+            deny_list: vec![],
+            authorization_type: StakeAuthorizationType::Delegate,
+        };
+        let any = authorization.to_any().unwrap();
+        assert_eq!(any.type_url, STAKE_AUTHORIZATION_TYPE_URL);
+        assert_eq!(
+            Authorization::try_from(&any).unwrap(),
+            Authorization::Stake {
+                max_tokens: Some(SingleCoin::Other {
+                    amount: "1000000".to_owned(),
+                    denom: "uatom".to_owned(),
+                }),
+                allow_list: vec!["cosmosvaloper1sjllsnramtg3ewxqwwrwjxfgc4n4ef9u2lcnj0".to_owned()],
+                deny_list: vec![],
+                authorization_type: StakeAuthorizationType::Delegate,
+            }
+        );
+
+        let authorization = Authorization::Stake {
+            max_tokens: None,
+            allow_list: vec!["a".to_owned()],
+            deny_list: vec!["b".to_owned()],
+            authorization_type: StakeAuthorizationType::Delegate,
+        };
+        assert!(authorization.to_any().is_err());
+
+        let authorization = Authorization::Generic {
+            msg: "/cosmos.gov.v1beta1.MsgVote".to_owned(),
+        };
+        let any = authorization.to_any().unwrap();
+        assert_eq!(Authorization::try_from(&any).unwrap(), authorization);
+    }
+
+    #[test]
+    fn test_fee_allowance_any() {
+        let allowance = FeeAllowance::Periodic {
+            spend_limit: vec![],
+            expiration: Some(1_700_000_000),
+            period: 86_400,
+            period_spend_limit: vec![SingleCoin::Other {
+                amount: "10000".to_owned(),
+                denom: "uatom".to_owned(),
+            }],
+        };
+        let any = allowance.to_any().unwrap();
+        assert_eq!(any.type_url, PERIODIC_ALLOWANCE_TYPE_URL);
+        let periodic = feegrant::PeriodicAllowance::decode(any.value.as_slice()).unwrap();
+        assert_eq!(periodic.period_can_spend, periodic.period_spend_limit);
+        assert_eq!(FeeAllowance::try_from(&any).unwrap(), allowance);
+
+        assert!(FeeAllowance::try_from(&Any {
+            type_url: "/cosmos.feegrant.v1beta1.AllowedMsgAllowance".to_owned(),
+            value: vec![],
+        })
+        .is_err());
+    }
+}
@@ -0,0 +1,615 @@
+use crate::SecretKey;
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use bitcoin::consensus::encode;
+use bitcoin::psbt::{self, PartiallySignedTransaction};
+use bitcoin::schnorr::TapTweak;
+use bitcoin::secp256k1::{self, KeyPair, Message, Secp256k1, XOnlyPublicKey};
+use bitcoin::util::address::{self, Address};
+use bitcoin::util::sighash::{self, Prevouts, SighashCache};
+use bitcoin::{
+    EcdsaSig, EcdsaSighashType, PublicKey, SchnorrSig, SchnorrSighashType, Script, TxOut,
+};
+use rand_core::{OsRng, RngCore};
+use serde::Serialize;
+use std::sync::Arc;
+
+/// Bitcoin networks
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BitcoinNetwork {
+    /// Bitcoin mainnet
+    #[default]
+    Mainnet,
+    /// Bitcoin testnet
+    Testnet,
+    /// Bitcoin signet
+    Signet,
+    /// Bitcoin regtest
+    Regtest,
+}
+
+impl BitcoinNetwork {
+    /// returns the BIP44 coin type (all the test networks share the testnet one)
+    pub fn get_coin_type(&self) -> u32 {
+        match self {
+            BitcoinNetwork::Mainnet => 0,
+            _ => 1,
+        }
+    }
+}
+
+impl From<BitcoinNetwork> for bitcoin::Network {
+    fn from(network: BitcoinNetwork) -> bitcoin::Network {
+        match network {
+            BitcoinNetwork::Mainnet => bitcoin::Network::Bitcoin,
+            BitcoinNetwork::Testnet => bitcoin::Network::Testnet,
+            BitcoinNetwork::Signet => bitcoin::Network::Signet,
+            BitcoinNetwork::Regtest => bitcoin::Network::Regtest,
+        }
+    }
+}
+
+/// Bitcoin address types (each one has its own derivation purpose)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BitcoinAddressType {
+    /// legacy pay-to-pubkey-hash (BIP44: m/44'/coin_type'/...)
+    P2pkh,
+    /// nested segwit pay-to-witness-pubkey-hash in pay-to-script-hash (BIP49: m/49'/coin_type'/...)
+    P2shP2wpkh,
+    /// native segwit pay-to-witness-pubkey-hash (BIP84: m/84'/coin_type'/...)
+    #[default]
+    P2wpkh,
+    /// taproot key-path pay-to-taproot (BIP86: m/86'/coin_type'/...)
+    P2tr,
+}
+
+impl BitcoinAddressType {
+    /// returns the purpose (the first component of the derivation path)
+    pub fn get_purpose(&self) -> u32 {
+        match self {
+            BitcoinAddressType::P2pkh => 44,
+            BitcoinAddressType::P2shP2wpkh => 49,
+            BitcoinAddressType::P2wpkh => 84,
+            BitcoinAddressType::P2tr => 86,
+        }
+    }
+}
+
+/// wrapper around Bitcoin errors
+#[derive(Debug, thiserror::Error)]
+pub enum BitcoinError {
+    #[error("Invalid public key: {0}")]
+    InvalidPublicKey(bitcoin::util::key::Error),
+    #[error("Invalid private key: {0}")]
+    InvalidPrivateKey(secp256k1::Error),
+    #[error("Address error: {0}")]
+    AddressError(address::Error),
+    #[error("Invalid base64 encoding: {0}")]
+    InvalidBase64(base64::DecodeError),
+    #[error("Invalid PSBT: {0}")]
+    InvalidPsbt(encode::Error),
+    #[error("The UTXO of the input {0} is missing")]
+    MissingUtxo(usize),
+    #[error("The sighash type of the input {0} is not supported")]
+    UnsupportedSighashType(usize),
+    #[error("Sighash error: {0}")]
+    SighashError(sighash::Error),
+    #[error("None of the PSBT inputs can be signed with the key")]
+    NothingToSign,
+}
+
+/// returns the address of a (compressed SEC1) public key for the network and address type
+pub fn get_bitcoin_address(
+    public_key: &[u8],
+    network: BitcoinNetwork,
+    address_type: BitcoinAddressType,
+) -> Result<String, BitcoinError> {
+    let public_key = PublicKey::from_slice(public_key).map_err(BitcoinError::InvalidPublicKey)?;
+    let network = network.into();
+    let address = match address_type {
+        BitcoinAddressType::P2pkh => Address::p2pkh(&public_key, network),
+        BitcoinAddressType::P2shP2wpkh => {
+            Address::p2shwpkh(&public_key, network).map_err(BitcoinError::AddressError)?
+        }
+        BitcoinAddressType::P2wpkh => {
+            Address::p2wpkh(&public_key, network).map_err(BitcoinError::AddressError)?
+        }
+        BitcoinAddressType::P2tr => Address::p2tr(
+            &Secp256k1::verification_only(),
+            XOnlyPublicKey::from(public_key.inner),
+            None,
+            network,
+        ),
+    };
+    Ok(address.to_string())
+}
+
+/// a PSBT input (the amount and address are only known if the UTXO is provided)
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct BitcoinPsbtInput {
+    /// the transaction id of the spent output
+    pub txid: String,
+    /// the index of the spent output
+    pub vout: u32,
+    /// the amount in satoshis
+    pub amount: Option<u64>,
+    /// the address of the spent output
+    pub address: Option<String>,
+}
+
+/// a PSBT output
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct BitcoinPsbtOutput {
+    /// the recipient address (`None` for non-standard scripts, e.g. OP_RETURN)
+    pub address: Option<String>,
+    /// the amount in satoshis
+    pub amount: u64,
+}
+
+/// the summary of a PSBT (e.g. to be displayed before signing)
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct BitcoinPsbtInfo {
+    pub inputs: Vec<BitcoinPsbtInput>,
+    pub outputs: Vec<BitcoinPsbtOutput>,
+    /// the fee in satoshis (only known if all the UTXOs are provided)
+    pub fee: Option<u64>,
+}
+
+/// parses a base64-encoded PSBT (BIP174)
+pub fn parse_psbt(psbt: &str, network: BitcoinNetwork) -> Result<BitcoinPsbtInfo, BitcoinError> {
+    let psbt = decode_psbt(psbt)?;
+    let network = network.into();
+    let inputs: Vec<BitcoinPsbtInput> = psbt
+        .unsigned_tx
+        .input
+        .iter()
+        .enumerate()
+        .map(|(index, txin)| {
+            let utxo = get_utxo(&psbt, index).ok();
+            BitcoinPsbtInput {
+                txid: txin.previous_output.txid.to_string(),
+                vout: txin.previous_output.vout,
+                amount: utxo.as_ref().map(|utxo| utxo.value),
+                address: utxo.and_then(|utxo| {
+                    Address::from_script(&utxo.script_pubkey, network)
+                        .ok()
+                        .map(|x| x.to_string())
+                }),
+            }
+        })
+        .collect();
+    let outputs: Vec<BitcoinPsbtOutput> = psbt
+        .unsigned_tx
+        .output
+        .iter()
+        .map(|txout| BitcoinPsbtOutput {
+            address: Address::from_script(&txout.script_pubkey, network)
+                .ok()
+                .map(|x| x.to_string()),
+            amount: txout.value,
+        })
+        .collect();
+    let total_input = inputs
+        .iter()
+        .try_fold(0u64, |total, input| total.checked_add(input.amount?));
+    let total_output = outputs
+        .iter()
+        .try_fold(0u64, |total, output| total.checked_add(output.amount));
+    let fee = match (total_input, total_output) {
+        (Some(total_input), Some(total_output)) => total_input.checked_sub(total_output),
+        _ => None,
+    };
+    Ok(BitcoinPsbtInfo {
+        inputs,
+        outputs,
+        fee,
+    })
+}
+
+/// signs all the inputs of a base64-encoded PSBT (BIP174) that spend
+/// P2PKH, P2SH-P2WPKH, P2WPKH or P2TR (key path) outputs of the key
+/// and returns the base64-encoded PSBT with the partial signatures
+/// (the key can be obtained from `HDWallet::get_key`;
+/// the inputs without the UTXO information are skipped)
+pub fn sign_psbt(psbt: &str, secret_key: Arc<SecretKey>) -> Result<String, BitcoinError> {
+    let mut psbt = decode_psbt(psbt)?;
+    let secp = Secp256k1::new();
+    let secret_key = secp256k1::SecretKey::from_slice(&secret_key.to_bytes())
+        .map_err(BitcoinError::InvalidPrivateKey)?;
+    let public_key = PublicKey::new(secret_key.public_key(&secp));
+    let keypair = KeyPair::from_secret_key(&secp, &secret_key);
+    let (internal_key, _) = XOnlyPublicKey::from_keypair(&keypair);
+    let wpubkey_hash = public_key
+        .wpubkey_hash()
+        .expect("the public key is compressed");
+
+    let p2pkh_script = Script::new_p2pkh(&public_key.pubkey_hash());
+    let p2wpkh_script = Script::new_v0_p2wpkh(&wpubkey_hash);
+    let p2sh_p2wpkh_script = Script::new_p2sh(&p2wpkh_script.script_hash());
+    let p2tr_script = Script::new_v1_p2tr(&secp, internal_key, None);
+
+    // the P2SH-P2WPKH outputs of the key are known to wrap a segwit program
+    for input in psbt.inputs.iter_mut() {
+        let is_p2sh_p2wpkh = input
+            .witness_utxo
+            .as_ref()
+            .map_or(false, |utxo| utxo.script_pubkey == p2sh_p2wpkh_script);
+        if is_p2sh_p2wpkh && input.redeem_script.is_none() {
+            input.redeem_script = Some(p2wpkh_script.clone());
+        }
+    }
+    let utxos: Vec<Option<TxOut>> = (0..psbt.inputs.len())
+        .map(|index| get_utxo(&psbt, index).ok())
+        .collect();
+    // taproot sighashes commit to all the spent outputs
+    let all_utxos: Option<Vec<TxOut>> = utxos.iter().cloned().collect();
+    let missing_utxo = utxos.iter().position(Option::is_none);
+    let mut signed = 0;
+    let unsigned_tx = psbt.unsigned_tx.clone();
+    let mut cache = SighashCache::new(&unsigned_tx);
+    for (index, utxo) in utxos.iter().enumerate() {
+        let utxo = match utxo {
+            Some(utxo) => utxo,
+            None => continue,
+        };
+        let script_pubkey = &utxo.script_pubkey;
+        let input = &mut psbt.inputs[index];
+        if *script_pubkey == p2tr_script {
+            let hash_ty = match input.sighash_type {
+                Some(sighash_type) => sighash_type
+                    .schnorr_hash_ty()
+                    .map_err(|_| BitcoinError::UnsupportedSighashType(index))?,
+                None => SchnorrSighashType::Default,
+            };
+            let all_utxos = all_utxos
+                .as_ref()
+                .ok_or(BitcoinError::MissingUtxo(missing_utxo.unwrap_or_default()))?;
+            let sighash = cache
+                .taproot_key_spend_signature_hash(index, &Prevouts::All(all_utxos), hash_ty)
+                .map_err(BitcoinError::SighashError)?;
+            let tweaked_keypair = keypair.tap_tweak(&secp, None).to_inner();
+            let mut aux_rand = [0u8; 32];
+            OsRng.fill_bytes(&mut aux_rand);
+            let sig = secp.sign_schnorr_with_aux_rand(
+                &Message::from(sighash),
+                &tweaked_keypair,
+                &aux_rand,
+            );
+            input.tap_key_sig = Some(SchnorrSig { sig, hash_ty });
+            input.tap_internal_key = Some(internal_key);
+        } else if *script_pubkey == p2pkh_script
+            || *script_pubkey == p2wpkh_script
+            || *script_pubkey == p2sh_p2wpkh_script
+        {
+            let hash_ty = match input.sighash_type {
+                Some(sighash_type) => sighash_type
+                    .ecdsa_hash_ty()
+                    .map_err(|_| BitcoinError::UnsupportedSighashType(index))?,
+                None => EcdsaSighashType::All,
+            };
+            let sighash = if *script_pubkey == p2pkh_script {
+                cache
+                    .legacy_signature_hash(index, script_pubkey, hash_ty.to_u32())
+                    .map_err(BitcoinError::SighashError)?
+            } else {
+                // the script code of P2WPKH is the P2PKH script as per BIP143
+                cache
+                    .segwit_signature_hash(index, &p2pkh_script, utxo.value, hash_ty)
+                    .map_err(BitcoinError::SighashError)?
+            };
+            if *script_pubkey == p2sh_p2wpkh_script {
+                input.redeem_script = Some(p2wpkh_script.clone());
+            }
+            let message = Message::from_slice(&sighash[..]).expect("32-byte sighash");
+            let sig = secp.sign_ecdsa(&message, &secret_key);
+            input
+                .partial_sigs
+                .insert(public_key, EcdsaSig { sig, hash_ty });
+        } else {
+            continue;
+        }
+        signed += 1;
+    }
+    if signed == 0 {
+        return Err(match missing_utxo {
+            Some(index) => BitcoinError::MissingUtxo(index),
+            None => BitcoinError::NothingToSign,
+        });
+    }
+    Ok(STANDARD.encode(encode::serialize(&psbt)))
+}
+
+fn decode_psbt(psbt: &str) -> Result<PartiallySignedTransaction, BitcoinError> {
+    let bytes = STANDARD
+        .decode(psbt.trim())
+        .map_err(BitcoinError::InvalidBase64)?;
+    encode::deserialize(&bytes).map_err(BitcoinError::InvalidPsbt)
+}
+
+/// returns the output spent by the input from the full previous transaction (whose txid is checked)
+/// or the witness UTXO, which is only trusted for the segwit outputs, as the legacy sighash doesn't
+/// commit to the spent amount
+fn get_utxo(psbt: &PartiallySignedTransaction, index: usize) -> Result<TxOut, BitcoinError> {
+    let input = &psbt.inputs[index];
+    let previous_output = psbt.unsigned_tx.input[index].previous_output;
+    match (&input.non_witness_utxo, &input.witness_utxo) {
+        (Some(tx), _) => Some(tx)
+            .filter(|tx| tx.txid() == previous_output.txid)
+            .and_then(|tx| tx.output.get(previous_output.vout as usize))
+            .cloned(),
+        (None, Some(utxo)) if is_segwit_utxo(input, utxo) => Some(utxo.clone()),
+        _ => None,
+    }
+    .ok_or(BitcoinError::MissingUtxo(index))
+}
+
+/// whether the output is a native segwit (or taproot) one or a P2SH wrapping the input redeem script
+/// of a segwit program
+fn is_segwit_utxo(input: &psbt::Input, utxo: &TxOut) -> bool {
+    let script_pubkey = &utxo.script_pubkey;
+    script_pubkey.is_witness_program()
+        || (script_pubkey.is_p2sh()
+            && input.redeem_script.as_ref().map_or(false, |redeem_script| {
+                redeem_script.is_witness_program()
+                    && Script::new_p2sh(&redeem_script.script_hash()) == *script_pubkey
+            }))
+}
+
+#[cfg(test)]
+mod btc_tests {
+    use super::*;
+    use crate::{HDWallet, WalletCoin};
+    use bitcoin::hashes::Hash;
+    use bitcoin::{OutPoint, PackedLockTime, Sequence, Transaction, TxIn, Witness};
+
+    const WORDS: &str =
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    fn bitcoin_coin(network: BitcoinNetwork, address_type: BitcoinAddressType) -> WalletCoin {
+        WalletCoin::Bitcoin {
+            network,
+            address_type,
+        }
+    }
+
+    // test vectors from BIP44/49/84/86 (via the reference implementations)
+    #[test]
+    fn test_bitcoin_addresses() {
+        let wallet = HDWallet::recover_wallet(WORDS.to_owned(), None, None).unwrap();
+        let cases = [
+            (
+                BitcoinAddressType::P2pkh,
+                "1LqBGSKuX5yYUonjxT5qGfpUsXKYYWeabA",
+            ),
+            (
+                BitcoinAddressType::P2shP2wpkh,
+                "37VucYSaXLCAsxYyAPfbSi9eh4iEcbShgf",
+            ),
+            (
+                BitcoinAddressType::P2wpkh,
+                "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu",
+            ),
+            (
+                BitcoinAddressType::P2tr,
+                "bc1p5cyxnuxmeuwuvkwfem96lqzszd02n6xdcjrs20cac6yqjjwudpxqkedrcr",
+            ),
+        ];
+        for (address_type, expected) in cases {
+            assert_eq!(
+                wallet
+                    .get_default_address(bitcoin_coin(BitcoinNetwork::Mainnet, address_type))
+                    .unwrap(),
+                expected
+            );
+        }
+        assert_eq!(
+            wallet
+                .get_address(
+                    bitcoin_coin(BitcoinNetwork::Mainnet, BitcoinAddressType::P2wpkh),
+                    1
+                )
+                .unwrap(),
+            "bc1qnjg0jd8228aq7egyzacy8cys3knf9xvrerkf9g"
+        );
+        assert_eq!(
+            wallet
+                .get_default_address(bitcoin_coin(
+                    BitcoinNetwork::Testnet,
+                    BitcoinAddressType::P2wpkh
+                ))
+                .unwrap(),
+            "tb1q6rz28mcfaxtmd6v789l9rrlrusdprr9pqcpvkl"
+        );
+        assert_eq!(
+            wallet
+                .get_account_extended_public_key(
+                    bitcoin_coin(BitcoinNetwork::Mainnet, BitcoinAddressType::P2pkh),
+                    0
+                )
+                .unwrap(),
+            "xpub6BosfCnifzxcFwrSzQiqu2DBVTshkCXacvNsWGYJVVhhawA7d4R5WSWGFNbi8Aw6ZRc1brxMyWMzG3DSSSSoekkudhUd9yLb6qx39T9nMdj"
+        );
+    }
+
+    fn funding_tx(script_pubkey: Script) -> Transaction {
+        Transaction {
+            version: 2,
+            lock_time: PackedLockTime::ZERO,
+            input: vec![TxIn::default()],
+            output: vec![TxOut {
+                value: 100_000,
+                script_pubkey,
+            }],
+        }
+    }
+
+    fn spending_psbt(funding_txs: &[Transaction]) -> PartiallySignedTransaction {
+        let tx = Transaction {
+            version: 2,
+            lock_time: PackedLockTime::ZERO,
+            input: funding_txs
+                .iter()
+                .map(|tx| TxIn {
+                    previous_output: OutPoint::new(tx.txid(), 0),
+                    script_sig: Script::new(),
+                    sequence: Sequence::ENABLE_RBF_NO_LOCKTIME,
+                    witness: Witness::new(),
+                })
+                .collect(),
+            output: vec![TxOut {
+                value: 90_000,
+                script_pubkey: Script::new_v0_p2wpkh(
+                    &bitcoin::WPubkeyHash::from_slice(&[1; 20]).unwrap(),
+                ),
+            }],
+        };
+        PartiallySignedTransaction::from_unsigned_tx(tx).unwrap()
+    }
+
+    #[test]
+    fn test_sign_psbt() {
+        let wallet = HDWallet::recover_wallet(WORDS.to_owned(), None, None).unwrap();
+        let secp = Secp256k1::new();
+        let address_types = [
+            BitcoinAddressType::P2pkh,
+            BitcoinAddressType::P2shP2wpkh,
+            BitcoinAddressType::P2wpkh,
+            BitcoinAddressType::P2tr,
+        ];
+        for address_type in address_types {
+            let coin = bitcoin_coin(BitcoinNetwork::Mainnet, address_type);
+            let key = wallet.get_key_from_index(coin.clone(), 0).unwrap();
+            let address: Address = wallet.get_default_address(coin).unwrap().parse().unwrap();
+            let funding_txs = [funding_tx(address.script_pubkey())];
+            let mut psbt = spending_psbt(&funding_txs);
+            if address_type == BitcoinAddressType::P2pkh {
+                psbt.inputs[0].non_witness_utxo = Some(funding_txs[0].clone());
+            } else {
+                psbt.inputs[0].witness_utxo = Some(funding_txs[0].output[0].clone());
+            }
+            if address_type == BitcoinAddressType::P2shP2wpkh {
+                let public_key = PublicKey::from_slice(&key.get_public_key_bytes()).unwrap();
+                psbt.inputs[0].redeem_script =
+                    Some(Script::new_v0_p2wpkh(&public_key.wpubkey_hash().unwrap()));
+            }
+            let unsigned = STANDARD.encode(encode::serialize(&psbt));
+
+            let info = parse_psbt(&unsigned, BitcoinNetwork::Mainnet).unwrap();
+            assert_eq!(info.inputs[0].address, Some(address.to_string()));
+            assert_eq!(info.inputs[0].amount, Some(100_000));
+            assert_eq!(info.outputs[0].amount, 90_000);
+            assert_eq!(info.fee, Some(10_000));
+
+            let signed = decode_psbt(&sign_psbt(&unsigned, key.clone()).unwrap()).unwrap();
+            let utxos = [funding_txs[0].output[0].clone()];
+            let mut cache = SighashCache::new(&signed.unsigned_tx);
+            let public_key = PublicKey::from_slice(&key.get_public_key_bytes()).unwrap();
+            match address_type {
+                BitcoinAddressType::P2tr => {
+                    let sig = signed.inputs[0].tap_key_sig.unwrap();
+                    let sighash = cache
+                        .taproot_key_spend_signature_hash(0, &Prevouts::All(&utxos), sig.hash_ty)
+                        .unwrap();
+                    let (output_key, _) =
+                        XOnlyPublicKey::from(public_key.inner).tap_tweak(&secp, None);
+                    secp.verify_schnorr(&sig.sig, &Message::from(sighash), &output_key.to_inner())
+                        .unwrap();
+                }
+                _ => {
+                    let sig = signed.inputs[0].partial_sigs[&public_key];
+                    let sighash = if address_type == BitcoinAddressType::P2pkh {
+                        cache
+                            .legacy_signature_hash(0, &utxos[0].script_pubkey, sig.hash_ty.to_u32())
+                            .unwrap()
+                    } else {
+                        cache
+                            .segwit_signature_hash(
+                                0,
+                                &Script::new_p2pkh(&public_key.pubkey_hash()),
+                                utxos[0].value,
+                                sig.hash_ty,
+                            )
+                            .unwrap()
+                    };
+                    let message = Message::from_slice(&sighash[..]).unwrap();
+                    secp.verify_ecdsa(&message, &sig.sig, &public_key.inner)
+                        .unwrap();
+                    assert_eq!(
+                        signed.inputs[0].redeem_script.is_some(),
+                        address_type == BitcoinAddressType::P2shP2wpkh
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_sign_psbt_errors() {
+        let key = Arc::new(SecretKey::new());
+        assert!(matches!(
+            sign_psbt("not base64!", key.clone()),
+            Err(BitcoinError::InvalidBase64(_))
+        ));
+        assert!(matches!(
+            sign_psbt(&STANDARD.encode(b"psbt"), key.clone()),
+            Err(BitcoinError::InvalidPsbt(_))
+        ));
+
+        let funding_txs = [funding_tx(Script::new_v0_p2wpkh(
+            &bitcoin::WPubkeyHash::from_slice(&[2; 20]).unwrap(),
+        ))];
+        let psbt = spending_psbt(&funding_txs);
+        let unsigned = STANDARD.encode(encode::serialize(&psbt));
+        assert!(matches!(
+            sign_psbt(&unsigned, key.clone()),
+            Err(BitcoinError::MissingUtxo(0))
+        ));
+        let info = parse_psbt(&unsigned, BitcoinNetwork::Mainnet).unwrap();
+        assert_eq!(info.inputs[0].txid, funding_txs[0].txid().to_string());
+        assert_eq!(info.fee, None);
+
+        let mut psbt = psbt;
+        psbt.inputs[0].witness_utxo = Some(funding_txs[0].output[0].clone());
+        let unsigned = STANDARD.encode(encode::serialize(&psbt));
+        assert!(matches!(
+            sign_psbt(&unsigned, key.clone()),
+            Err(BitcoinError::NothingToSign)
+        ));
+    }
+
+    #[test]
+    fn test_legacy_input_spoofed_witness_utxo() {
+        let wallet = HDWallet::recover_wallet(WORDS.to_owned(), None, None).unwrap();
+        let coin = bitcoin_coin(BitcoinNetwork::Mainnet, BitcoinAddressType::P2pkh);
+        let key = wallet.get_key_from_index(coin.clone(), 0).unwrap();
+        let address: Address = wallet.get_default_address(coin).unwrap().parse().unwrap();
+        let funding_txs = [funding_tx(address.script_pubkey())];
+        let mut psbt = spending_psbt(&funding_txs);
+        // the legacy sighash doesn't commit to the amount, so it can't be trusted
+        psbt.inputs[0].witness_utxo = Some(TxOut {
+            value: 90_001,
+            script_pubkey: address.script_pubkey(),
+        });
+        let unsigned = STANDARD.encode(encode::serialize(&psbt));
+        let info = parse_psbt(&unsigned, BitcoinNetwork::Mainnet).unwrap();
+        assert_eq!(info.inputs[0].amount, None);
+        assert_eq!(info.fee, None);
+        assert!(matches!(
+            sign_psbt(&unsigned, key.clone()),
+            Err(BitcoinError::MissingUtxo(0))
+        ));
+
+        // the full previous transaction takes precedence
+        psbt.inputs[0].non_witness_utxo = Some(funding_txs[0].clone());
+        let unsigned = STANDARD.encode(encode::serialize(&psbt));
+        let info = parse_psbt(&unsigned, BitcoinNetwork::Mainnet).unwrap();
+        assert_eq!(info.inputs[0].amount, Some(100_000));
+        assert_eq!(info.fee, Some(10_000));
+        assert!(sign_psbt(&unsigned, key).is_ok());
+
+        // the previous transaction must match the txid of the input
+        psbt.inputs[0].non_witness_utxo = Some(funding_tx(Script::new()));
+        let unsigned = STANDARD.encode(encode::serialize(&psbt));
+        let info = parse_psbt(&unsigned, BitcoinNetwork::Mainnet).unwrap();
+        assert_eq!(info.inputs[0].amount, None);
+    }
+}
@@ -1,9 +1,12 @@
+use super::authz::*;
+use super::gov::*;
+use super::ibc_transfer::append_ibc_transfer_memo;
 use super::luna_classic::*;
 use super::nft::*;
-use crate::SecretKey;
+use super::vesting::*;
+use crate::{RestError, SecretKey, SignerError, WalletSigner};
 use cosmrs::bank::MsgSend;
-use cosmrs::bip32::secp256k1::ecdsa::SigningKey;
-use cosmrs::bip32::{PrivateKey, PublicKey, PublicKeyBytes, KEY_SIZE};
+use cosmrs::bip32::{PublicKey, PublicKeyBytes, KEY_SIZE};
 use cosmrs::crypto::{self, secp256k1::VerifyingKey};
 use cosmrs::distribution::{MsgSetWithdrawAddress, MsgWithdrawDelegatorReward};
 use cosmrs::staking::{MsgBeginRedelegate, MsgDelegate, MsgUndelegate};
@@ -19,12 +22,23 @@ use ibc::tx_msg::Msg as IbcMsg;
 use ibc_proto::cosmos::base::v1beta1::Coin as IbcCoin;
 use ibc_proto::ibc::core::client::v1::Height;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::str::FromStr;
 use std::sync::Arc;
 
+mod amino;
+mod chain_registry;
+mod decimal_coin;
+mod fee;
+mod multisig;
 mod parser;
 mod signer;
 
+pub use amino::*;
+pub use chain_registry::*;
+pub use decimal_coin::*;
+pub use fee::*;
+pub use multisig::*;
 pub use parser::*;
 pub use signer::*;
 
@@ -118,10 +132,33 @@ pub enum SingleCoin {
     UATOM { amount: u64 },
     /// 1 ATOM = 10^6 uatom
     ATOM { amount: u64 },
+    /// decimal amount in a unit of the denomination (see `DecimalCoin`)
+    Decimal {
+        amount: String,
+        denom: String,
+        base_denom: String,
+        exponent: u32,
+    },
     /// other coin unit
     Other { amount: String, denom: String },
 }
 
+/// the base amount of `SingleCoin::Decimal`
+fn decimal_base_amount(
+    amount: &str,
+    denom: &str,
+    base_denom: &str,
+    exponent: u32,
+) -> Result<String, CosmosError> {
+    DecimalCoin {
+        amount: amount.to_owned(),
+        denom: denom.to_owned(),
+        base_denom: base_denom.to_owned(),
+        exponent,
+    }
+    .base_amount()
+}
+
 impl TryFrom<&SingleCoin> for Coin {
     type Error = CosmosError;
 
@@ -171,6 +208,17 @@ impl TryFrom<&SingleCoin> for Coin {
                     denom: "uatom".parse()?,
                 }
             }
+            SingleCoin::Decimal {
+                amount,
+                denom,
+                base_denom,
+                exponent,
+            } => Coin {
+                amount: decimal_base_amount(amount, denom, base_denom, *exponent)?
+                    .parse()
+                    .wrap_err("amount parse error")?,
+                denom: base_denom.parse()?,
+            },
             SingleCoin::Other { amount, denom } => Coin {
                 amount: amount.parse().wrap_err("amount parse error")?,
                 denom: denom.parse()?,
@@ -228,6 +276,15 @@ impl TryFrom<&SingleCoin> for IbcCoin {
                     denom: "uatom".to_owned(),
                 }
             }
+            SingleCoin::Decimal {
+                amount,
+                denom,
+                base_denom,
+                exponent,
+            } => IbcCoin {
+                amount: decimal_base_amount(amount, denom, base_denom, *exponent)?,
+                denom: base_denom.to_owned(),
+            },
             SingleCoin::Other { amount, denom } => IbcCoin {
                 amount: amount.to_owned(),
                 denom: denom.to_owned(),
@@ -287,6 +344,7 @@ impl From<PublicKeyBytesWrapper> for PublicKeyBytes {
 }
 
 /// the common transaction data needed for Cosmos SDK transactions
+#[derive(Clone)]
 pub struct CosmosSDKTxInfo {
     /// global account number of the sender
     pub account_number: u64,
@@ -295,17 +353,22 @@ pub struct CosmosSDKTxInfo {
     /// the maximum gas limit
     pub gas_limit: u64,
     /// the fee to be paid (gas_limit * gas_price)
-    pub fee_amount: SingleCoin,
+    /// (several coins for the chains accepting several fee denominations)
+    pub fee_amount: Vec<SingleCoin>,
     /// transaction timeout
     pub timeout_height: u32,
     /// optional memo
     pub memo_note: Option<String>,
     /// the network to use
     pub network: Network,
+    /// optional account paying the fee (it must sign the transaction as well)
+    pub payer: Option<String>,
+    /// optional account granting the fee allowance to the signer (x/feegrant)
+    pub granter: Option<String>,
 }
 
 /// Cosmos SDK message types
-#[derive(Deserialize, Serialize)]
+#[derive(Clone, Deserialize, Serialize)]
 pub enum CosmosSDKMsg {
     /// MsgSend
     BankSend {
@@ -416,6 +479,8 @@ pub enum CosmosSDKMsg {
         /// Timeout timestamp (in nanoseconds) relative to the current block timestamp.
         /// The timeout is disabled when set to 0.
         timeout_timestamp: u64,
+        /// the memo (e.g. a packet forward middleware or IBC hooks JSON memo)
+        memo: Option<String>,
     },
 
     /// MsgExecuteContract
@@ -428,6 +493,83 @@ pub enum CosmosSDKMsg {
         coins: SingleCoin,
     },
 
+    /// MsgVote
+    GovVote {
+        /// the proposal to vote on
+        proposal_id: u64,
+        /// the vote option
+        option: VoteOption,
+    },
+    /// MsgVoteWeighted
+    GovVoteWeighted {
+        /// the proposal to vote on
+        proposal_id: u64,
+        /// the weighted vote options (the weights must add up to 1)
+        options: Vec<WeightedVoteOption>,
+    },
+    /// MsgDeposit
+    GovDeposit {
+        /// the proposal to deposit on
+        proposal_id: u64,
+        /// amount to deposit
+        amount: SingleCoin,
+    },
+    /// MsgSubmitProposal
+    GovSubmitProposal {
+        /// the proposal content
+        content: ProposalContent,
+        /// the initial deposit (the zero amount coins are omitted)
+        initial_deposit: Vec<SingleCoin>,
+    },
+
+    /// MsgGrant
+    AuthzGrant {
+        /// grantee address in bech32
+        grantee: String,
+        /// the granted authorization
+        authorization: Authorization,
+        /// the expiration time in seconds since the Unix epoch (never expires if not set)
+        expiration: Option<i64>,
+    },
+    /// MsgExec
+    AuthzExec {
+        /// granter address in bech32 (the signer of the executed messages)
+        granter: String,
+        /// the messages to execute on behalf of the granter
+        msgs: Vec<CosmosSDKMsg>,
+    },
+    /// MsgRevoke
+    AuthzRevoke {
+        /// grantee address in bech32
+        grantee: String,
+        /// the message type URL of the revoked authorization
+        msg_type_url: String,
+    },
+    /// MsgGrantAllowance
+    FeegrantGrantAllowance {
+        /// grantee address in bech32
+        grantee: String,
+        /// the granted fee allowance
+        allowance: FeeAllowance,
+    },
+    /// MsgRevokeAllowance
+    FeegrantRevokeAllowance {
+        /// grantee address in bech32
+        grantee: String,
+    },
+
+    /// MsgCreateVestingAccount
+    VestingCreateAccount {
+        /// the new vesting account address in bech32
+        to_address: String,
+        /// the coins to vest
+        amount: Vec<SingleCoin>,
+        /// the vesting end time in seconds since the Unix epoch
+        end_time: i64,
+        /// vests all coins at the end time if set, otherwise vests them linearly until then
+        delayed: bool,
+    },
+
     /// Raw message which is not constructed by fields (may be parsed from `CosmosParser`) or an
     /// unsupported message.
     /// It could also be serialized and added to a transaction.
@@ -588,6 +730,7 @@ impl CosmosSDKMsg {
                 token,
                 timeout_height,
                 timeout_timestamp,
+                memo,
             } => {
                 let any = MsgTransfer {
                     sender: Signer::from_str(sender_address.as_ref())
@@ -604,6 +747,8 @@ impl CosmosSDKMsg {
                         .map_err(|e| eyre::eyre!("{e}"))?,
                 }
                 .to_any();
+                let mut value = any.value;
+                append_ibc_transfer_memo(&mut value, memo.as_deref().unwrap_or_default());
                 // FIXME:
                 // ibc-proto used Google's Protobuf type definitions instead of
                 // prost_types in `0.17`. But cosmrs still used prost_types. So
@@ -612,7 +757,7 @@ impl CosmosSDKMsg {
                 // https://github.com/cosmos/cosmos-rust/issues/185
                 Ok(cosmrs::Any {
                     type_url: any.type_url,
-                    value: any.value,
+                    value,
                 })
             }
             CosmosSDKMsg::ExecuteContract {
@@ -630,14 +775,125 @@ impl CosmosSDKMsg {
                 };
                 msg_send.to_any()
             }
+            CosmosSDKMsg::GovVote {
+                proposal_id,
+                option,
+            } => Ok(get_vote_any(*proposal_id, sender_address.as_ref(), *option)),
+            CosmosSDKMsg::GovVoteWeighted {
+                proposal_id,
+                options,
+            } => get_vote_weighted_any(*proposal_id, sender_address.as_ref(), options),
+            CosmosSDKMsg::GovDeposit {
+                proposal_id,
+                amount,
+            } => get_deposit_any(
+                *proposal_id,
+                sender_address.as_ref(),
+                std::slice::from_ref(amount),
+            ),
+            CosmosSDKMsg::GovSubmitProposal {
+                content,
+                initial_deposit,
+            } => get_submit_proposal_any(content, initial_deposit, sender_address.as_ref()),
+            CosmosSDKMsg::AuthzGrant {
+                grantee,
+                authorization,
+                expiration,
+            } => get_grant_any(
+                sender_address.as_ref(),
+                grantee.parse::<AccountId>()?.as_ref(),
+                authorization,
+                *expiration,
+            ),
+            CosmosSDKMsg::AuthzExec { granter, msgs } => {
+                let granter = granter.parse::<AccountId>()?;
+                let msgs = msgs
+                    .iter()
+                    .map(|msg| msg.to_any(granter.clone()))
+                    .collect::<eyre::Result<_>>()?;
+                Ok(get_exec_any(sender_address.as_ref(), msgs))
+            }
+            CosmosSDKMsg::AuthzRevoke {
+                grantee,
+                msg_type_url,
+            } => Ok(get_revoke_any(
+                sender_address.as_ref(),
+                grantee.parse::<AccountId>()?.as_ref(),
+                msg_type_url,
+            )),
+            CosmosSDKMsg::FeegrantGrantAllowance { grantee, allowance } => get_grant_allowance_any(
+                sender_address.as_ref(),
+                grantee.parse::<AccountId>()?.as_ref(),
+                allowance,
+            ),
+            CosmosSDKMsg::FeegrantRevokeAllowance { grantee } => Ok(get_revoke_allowance_any(
+                sender_address.as_ref(),
+                grantee.parse::<AccountId>()?.as_ref(),
+            )),
+            CosmosSDKMsg::VestingCreateAccount {
+                to_address,
+                amount,
+                end_time,
+                delayed,
+            } => get_create_vesting_account_any(
+                sender_address.as_ref(),
+                to_address.parse::<AccountId>()?.as_ref(),
+                amount,
+                *end_time,
+                *delayed,
+            ),
             CosmosSDKMsg::Raw { raw_msg } => raw_msg.to_any(),
         }
     }
 }
 
+/// the fee coins sorted by denomination, with the amounts of the same denomination summed
+/// (as the Cosmos SDK rejects unsorted or duplicate coins)
+pub(crate) fn get_fee_coins(fee_amount: &[SingleCoin]) -> Result<Vec<Coin>, CosmosError> {
+    let mut amounts = BTreeMap::<String, u128>::new();
+    for coin in fee_amount {
+        let coin = Coin::try_from(coin)?;
+        let amount: u128 = coin
+            .amount
+            .to_string()
+            .parse()
+            .wrap_err("amount parse error")?;
+        let total = amounts.entry(coin.denom.to_string()).or_default();
+        *total = total
+            .checked_add(amount)
+            .ok_or_else(|| eyre!("integer overflow"))?;
+    }
+    amounts
+        .into_iter()
+        .map(|(denom, amount)| {
+            Ok(Coin {
+                amount: amount.to_string().parse().wrap_err("amount parse error")?,
+                denom: denom.parse()?,
+            })
+        })
+        .collect()
+}
+
+fn get_fee(tx_info: &CosmosSDKTxInfo) -> eyre::Result<Fee> {
+    Ok(Fee {
+        amount: get_fee_coins(&tx_info.fee_amount)?,
+        gas_limit: tx_info.gas_limit,
+        payer: tx_info
+            .payer
+            .as_ref()
+            .map(|payer| payer.parse::<AccountId>())
+            .transpose()?,
+        granter: tx_info
+            .granter
+            .as_ref()
+            .map(|granter| granter.parse::<AccountId>())
+            .transpose()?,
+    })
+}
+
 fn get_msg_signdoc(
     tx_info: CosmosSDKTxInfo,
-    msgs: Vec<CosmosSDKMsg>,
+    msgs: &[CosmosSDKMsg],
     sender_public_key: crypto::PublicKey,
 ) -> eyre::Result<SignDoc> {
     let chain_id = tx_info.network.get_chain_id()?;
@@ -648,30 +904,43 @@ fn get_msg_signdoc(
         msgs_any.push(value.to_any(sender_account_id.clone())?);
     }
 
+    let fee = get_fee(&tx_info)?;
     let tx_body = tx::Body::new(
         msgs_any,
         tx_info.memo_note.unwrap_or_default(),
         tx_info.timeout_height,
     );
     let signer_info = SignerInfo::single_direct(Some(sender_public_key), tx_info.sequence_number);
-    let auth_info = signer_info.auth_info(Fee::from_amount_and_gas(
-        (&tx_info.fee_amount).try_into()?,
-        tx_info.gas_limit,
-    ));
+    let auth_info = signer_info.auth_info(fee);
 
     SignDoc::new(&tx_body, &auth_info, &chain_id, tx_info.account_number)
 }
 
-fn get_signed_msg_tx(
+pub(crate) fn get_signer_public_key(
+    signer: &dyn WalletSigner,
+) -> Result<crypto::PublicKey, CosmosError> {
+    let sender_pubkey: PublicKeyBytes = signer
+        .get_public_key_bytes()?
+        .try_into()
+        .map_err(|_| SignerError::InvalidPublicKey)?;
+    Ok(crypto::PublicKey::from(
+        VerifyingKey::from_bytes(sender_pubkey).map_err(CosmosError::PubkeyError)?,
+    ))
+}
+
+pub(crate) fn get_signed_msg_tx(
     tx_info: CosmosSDKTxInfo,
-    msgs: Vec<CosmosSDKMsg>,
-    sender_private_key: SigningKey,
-) -> eyre::Result<Raw> {
-    let sender_pubkey = crypto::PublicKey::from(sender_private_key.public_key());
+    msgs: &[CosmosSDKMsg],
+    signer: &dyn WalletSigner,
+) -> Result<Raw, CosmosError> {
+    let sender_pubkey = get_signer_public_key(signer)?;
     let sign_doc = get_msg_signdoc(tx_info, msgs, sender_pubkey)?;
-    sign_doc.sign(&cosmrs::crypto::secp256k1::SigningKey::new(Box::new(
-        sender_private_key,
-    )))
+    let signature = signer.sign_cosmos(&sign_doc.clone().into_bytes()?)?;
+    Ok(Raw::from(cosmrs::proto::cosmos::tx::v1beta1::TxRaw {
+        body_bytes: sign_doc.body_bytes,
+        auth_info_bytes: sign_doc.auth_info_bytes,
+        signatures: vec![signature],
+    }))
 }
 
 /// UniFFI 0.15.2 doesn't support external types for Kotlin yet
@@ -681,6 +950,10 @@ pub enum CosmosError {
     EyreReport(#[from] eyre::Report),
     #[error("Public key error: {0}")]
     PubkeyError(cosmrs::bip32::Error),
+    #[error("Signer error: {0}")]
+    SignerError(#[from] SignerError),
+    #[error("Node error: {0}")]
+    NodeError(#[from] RestError),
 }
 
 /// creates the transaction signing payload (`SignDoc`)
@@ -700,7 +973,7 @@ pub fn build_signed_single_msg_tx(
     msg: CosmosSDKMsg,
     secret_key: Arc<SecretKey>,
 ) -> Result<Vec<u8>, CosmosError> {
-    Ok(get_signed_msg_tx(tx_info, vec![msg], secret_key.get_signing_key())?.to_bytes()?)
+    build_signed_msg_tx_with_signer(tx_info, vec![msg], secret_key.as_ref())
 }
 
 /// creates the transaction signing payload (`SignDoc`)
@@ -713,7 +986,7 @@ pub fn get_msg_sign_payload(
     let sender_public_key: crypto::PublicKey = crypto::PublicKey::from(
         VerifyingKey::from_bytes(sender_pubkey.into()).map_err(CosmosError::PubkeyError)?,
     );
-    Ok(get_msg_signdoc(tx_info, msgs, sender_public_key).and_then(|doc| doc.into_bytes())?)
+    Ok(get_msg_signdoc(tx_info, &msgs, sender_public_key).and_then(|doc| doc.into_bytes())?)
 }
 
 /// creates the signed transaction
@@ -723,7 +996,18 @@ pub fn build_signed_msg_tx(
     msgs: Vec<CosmosSDKMsg>,
     secret_key: Arc<SecretKey>,
 ) -> Result<Vec<u8>, CosmosError> {
-    Ok(get_signed_msg_tx(tx_info, msgs, secret_key.get_signing_key())?.to_bytes()?)
+    build_signed_msg_tx_with_signer(tx_info, msgs, secret_key.as_ref())
+}
+
+/// creates the signed transaction
+/// with some Cosmos SDK messages signed by the signer
+/// (the Ledger Cosmos app requires `build_signed_amino_msg_tx_with_signer`)
+pub fn build_signed_msg_tx_with_signer(
+    tx_info: CosmosSDKTxInfo,
+    msgs: Vec<CosmosSDKMsg>,
+    signer: &dyn WalletSigner,
+) -> Result<Vec<u8>, CosmosError> {
+    Ok(get_signed_msg_tx(tx_info, &msgs, signer)?.to_bytes()?)
 }
 
 #[cfg(test)]
@@ -738,15 +1022,19 @@ mod tests {
     use cosmrs::Coin;
     use cosmrs::Tx;
 
-    const TX_INFO: CosmosSDKTxInfo = CosmosSDKTxInfo {
-        account_number: 1,
-        sequence_number: 0,
-        gas_limit: 100_000,
-        timeout_height: 9001,
-        fee_amount: SingleCoin::ATOM { amount: 1 },
-        memo_note: None,
-        network: Network::CosmosHub,
-    };
+    fn tx_info() -> CosmosSDKTxInfo {
+        CosmosSDKTxInfo {
+            account_number: 1,
+            sequence_number: 0,
+            gas_limit: 100_000,
+            timeout_height: 9001,
+            fee_amount: vec![SingleCoin::ATOM { amount: 1 }],
+            memo_note: None,
+            network: Network::CosmosHub,
+            payer: None,
+            granter: None,
+        }
+    }
 
     const WORDS: &str = "apple elegant knife hawk there screen vehicle lounge tube sun engage bus custom market pioneer casual wink present cat metal ride shallow fork brief";
 
@@ -756,7 +1044,7 @@ mod tests {
         let sender_public_key = sender_private_key.public_key();
 
         let sign_doc_raw = get_single_msg_sign_payload(
-            TX_INFO,
+            tx_info(),
             CosmosSDKMsg::BankSend {
                 recipient_address: "cosmos19dyl0uyzes4k23lscla02n06fc22h4uqsdwq6z".to_string(),
                 amount: SingleCoin::ATOM { amount: 1 },
@@ -783,7 +1071,7 @@ mod tests {
         ];
 
         let sign_doc_raw = get_msg_sign_payload(
-            TX_INFO,
+            tx_info(),
             msgs,
             PublicKeyBytesWrapper(sender_public_key.to_bytes()),
         )
@@ -796,7 +1084,7 @@ mod tests {
         let secret_key = SecretKey::new();
 
         let tx_raw = build_signed_single_msg_tx(
-            TX_INFO,
+            tx_info(),
             CosmosSDKMsg::BankSend {
                 recipient_address: "cosmos19dyl0uyzes4k23lscla02n06fc22h4uqsdwq6z".to_string(),
                 amount: SingleCoin::ATOM { amount: 1 },
@@ -809,7 +1097,7 @@ mod tests {
 
     #[test]
     fn signing_works_mutimsg() {
-        let wallet = HDWallet::recover_wallet(WORDS.to_string(), None).expect("wallet");
+        let wallet = HDWallet::recover_wallet(WORDS.to_string(), None, None).expect("wallet");
         let private_key = wallet
             .get_key("m/44'/118'/0'/0/0".to_string())
             .expect("key");
@@ -825,7 +1113,7 @@ mod tests {
             },
         ];
 
-        let tx_raw = build_signed_msg_tx(TX_INFO, msgs, private_key).expect("ok signed tx");
+        let tx_raw = build_signed_msg_tx(tx_info(), msgs, private_key).expect("ok signed tx");
         assert_eq!(
             hex::encode(tx_raw),
             "0aa9020a90010a1c2f636f736d6f732e62616e6b2e763162657461312e4d736753656e6412700a2d636f736d6f73316c357337746e6a323861377a786565636b6867776c686a797338646c7272656667717234706a122d636f736d6f73313964796c3075797a6573346b32336c73636c6130326e3036666332326834757173647771367a1a100a057561746f6d1207313030303030300a90010a1c2f636f736d6f732e62616e6b2e763162657461312e4d736753656e6412700a2d636f736d6f73316c357337746e6a323861377a786565636b6867776c686a797338646c7272656667717234706a122d636f736d6f73316138337839347877773437653332726770797474747563783276657878636e326c6332656b781a100a057561746f6d12073230303030303018a94612680a4e0a460a1f2f636f736d6f732e63727970746f2e736563703235366b312e5075624b657912230a21028c3956de0011d6b9b2c735045647d14b38e63557e497fc025de9a17a5729c52012040a02080112160a100a057561746f6d12073130303030303010a08d061a406be1c153eda9e3ba022d2e9138c0682991ba6cf6b8b7bdc75ae1adb88b8a977b35e18292b569cb66ffff16189f37a5848648f14caa1084cfb4f7041deda737ae"
@@ -837,7 +1125,7 @@ mod tests {
 
     #[test]
     fn signing_check() {
-        let wallet = HDWallet::recover_wallet(WORDS.to_string(), None).expect("wallet");
+        let wallet = HDWallet::recover_wallet(WORDS.to_string(), None, None).expect("wallet");
 
         let private_key = wallet
             .get_key("m/44'/118'/0'/0/0".to_string())
@@ -869,7 +1157,7 @@ mod tests {
         );
 
         let payload_raw = get_single_msg_sign_payload(
-            TX_INFO,
+            tx_info(),
             CosmosSDKMsg::BankSend {
                 recipient_address: "cosmos19dyl0uyzes4k23lscla02n06fc22h4uqsdwq6z".to_string(),
                 amount: SingleCoin::ATOM { amount: 1 },
@@ -884,7 +1172,7 @@ mod tests {
         );
 
         let tx_raw = build_signed_single_msg_tx(
-            TX_INFO,
+            tx_info(),
             CosmosSDKMsg::BankSend {
                 recipient_address: "cosmos19dyl0uyzes4k23lscla02n06fc22h4uqsdwq6z".to_string(),
                 amount: SingleCoin::ATOM { amount: 1 },
@@ -901,14 +1189,14 @@ mod tests {
 
     #[test]
     fn staking_delegate_check() {
-        let wallet = HDWallet::recover_wallet(WORDS.to_string(), None).expect("wallet");
+        let wallet = HDWallet::recover_wallet(WORDS.to_string(), None, None).expect("wallet");
 
         let private_key = wallet
             .get_key("m/44'/118'/0'/0/0".to_string())
             .expect("key");
 
         let payload_raw = get_single_msg_sign_payload(
-            TX_INFO,
+            tx_info(),
             CosmosSDKMsg::StakingDelegate {
                 validator_address: "cosmosvaloper19dyl0uyzes4k23lscla02n06fc22h4uq4e64k3"
                     .to_string(),
@@ -924,7 +1212,7 @@ mod tests {
         );
 
         let tx_raw = build_signed_single_msg_tx(
-            TX_INFO,
+            tx_info(),
             CosmosSDKMsg::StakingDelegate {
                 validator_address: "cosmosvaloper19dyl0uyzes4k23lscla02n06fc22h4uq4e64k3"
                     .to_string(),
@@ -942,14 +1230,14 @@ mod tests {
 
     #[test]
     fn staking_undelegate_check() {
-        let wallet = HDWallet::recover_wallet(WORDS.to_string(), None).expect("wallet");
+        let wallet = HDWallet::recover_wallet(WORDS.to_string(), None, None).expect("wallet");
 
         let private_key = wallet
             .get_key("m/44'/118'/0'/0/0".to_string())
             .expect("key");
 
         let payload_raw = get_single_msg_sign_payload(
-            TX_INFO,
+            tx_info(),
             CosmosSDKMsg::StakingUndelegate {
                 validator_address: "cosmosvaloper19dyl0uyzes4k23lscla02n06fc22h4uq4e64k3"
                     .to_string(),
@@ -965,7 +1253,7 @@ mod tests {
         );
 
         let tx_raw = build_signed_single_msg_tx(
-            TX_INFO,
+            tx_info(),
             CosmosSDKMsg::StakingUndelegate {
                 validator_address: "cosmosvaloper19dyl0uyzes4k23lscla02n06fc22h4uq4e64k3"
                     .to_string(),
@@ -983,14 +1271,14 @@ mod tests {
 
     #[test]
     fn staking_begin_redelegate_check() {
-        let wallet = HDWallet::recover_wallet(WORDS.to_string(), None).expect("wallet");
+        let wallet = HDWallet::recover_wallet(WORDS.to_string(), None, None).expect("wallet");
 
         let private_key = wallet
             .get_key("m/44'/118'/0'/0/0".to_string())
             .expect("key");
 
         let payload_raw = get_single_msg_sign_payload(
-            TX_INFO,
+            tx_info(),
             CosmosSDKMsg::StakingBeginRedelegate {
                 validator_src_address: "cosmosvaloper1l5s7tnj28a7zxeeckhgwlhjys8dlrrefd5hqdp"
                     .to_string(),
@@ -1008,7 +1296,7 @@ mod tests {
         );
 
         let tx_raw = build_signed_single_msg_tx(
-            TX_INFO,
+            tx_info(),
             CosmosSDKMsg::StakingBeginRedelegate {
                 validator_src_address: "cosmosvaloper1l5s7tnj28a7zxeeckhgwlhjys8dlrrefd5hqdp"
                     .to_string(),
@@ -1028,14 +1316,14 @@ mod tests {
 
     #[test]
     fn distribution_setwithdraw_address_check() {
-        let wallet = HDWallet::recover_wallet(WORDS.to_string(), None).expect("wallet");
+        let wallet = HDWallet::recover_wallet(WORDS.to_string(), None, None).expect("wallet");
 
         let private_key = wallet
             .get_key("m/44'/118'/0'/0/0".to_string())
             .expect("key");
 
         let payload_raw = get_single_msg_sign_payload(
-            TX_INFO,
+            tx_info(),
             CosmosSDKMsg::DistributionSetWithdrawAddress {
                 withdraw_address: "cosmos19dyl0uyzes4k23lscla02n06fc22h4uqsdwq6z".to_string(),
             },
@@ -1049,7 +1337,7 @@ mod tests {
         );
 
         let tx_raw = build_signed_single_msg_tx(
-            TX_INFO,
+            tx_info(),
             CosmosSDKMsg::DistributionSetWithdrawAddress {
                 withdraw_address: "cosmos19dyl0uyzes4k23lscla02n06fc22h4uqsdwq6z".to_string(),
             },
@@ -1065,14 +1353,14 @@ mod tests {
 
     #[test]
     fn distribution_withdraw_delegator_reward_check() {
-        let wallet = HDWallet::recover_wallet(WORDS.to_string(), None).expect("wallet");
+        let wallet = HDWallet::recover_wallet(WORDS.to_string(), None, None).expect("wallet");
 
         let private_key = wallet
             .get_key("m/44'/118'/0'/0/0".to_string())
             .expect("key");
 
         let payload_raw = get_single_msg_sign_payload(
-            TX_INFO,
+            tx_info(),
             CosmosSDKMsg::DistributionWithdrawDelegatorReward {
                 validator_address: "cosmosvaloper19dyl0uyzes4k23lscla02n06fc22h4uq4e64k3"
                     .to_string(),
@@ -1087,7 +1375,7 @@ mod tests {
         );
 
         let tx_raw = build_signed_single_msg_tx(
-            TX_INFO,
+            tx_info(),
             CosmosSDKMsg::DistributionWithdrawDelegatorReward {
                 validator_address: "cosmosvaloper19dyl0uyzes4k23lscla02n06fc22h4uq4e64k3"
                     .to_string(),
@@ -1104,14 +1392,14 @@ mod tests {
 
     #[test]
     fn nft_issue_denom_check() {
-        let wallet = HDWallet::recover_wallet(WORDS.to_string(), None).expect("wallet");
+        let wallet = HDWallet::recover_wallet(WORDS.to_string(), None, None).expect("wallet");
 
         let private_key = wallet
             .get_key("m/44'/118'/0'/0/0".to_string())
             .expect("key");
 
         let payload_raw = get_single_msg_sign_payload(
-            TX_INFO,
+            tx_info(),
             CosmosSDKMsg::NftIssueDenom {
                 id: "edition01".to_string(),
                 name: "domingo1".to_string(),
@@ -1127,7 +1415,7 @@ mod tests {
         );
 
         let tx_raw = build_signed_single_msg_tx(
-            TX_INFO,
+            tx_info(),
             CosmosSDKMsg::NftIssueDenom {
                 id: "edition01".to_string(),
                 name: "domingo1".to_string(),
@@ -1145,14 +1433,14 @@ mod tests {
 
     #[test]
     fn nft_transfer_check() {
-        let wallet = HDWallet::recover_wallet(WORDS.to_string(), None).expect("wallet");
+        let wallet = HDWallet::recover_wallet(WORDS.to_string(), None, None).expect("wallet");
 
         let private_key = wallet
             .get_key("m/44'/118'/0'/0/0".to_string())
             .expect("key");
 
         let payload_raw = get_single_msg_sign_payload(
-            TX_INFO,
+            tx_info(),
             CosmosSDKMsg::NftTransfer {
                 id: "edition01".to_string(),
                 denom_id: "domingo1".to_string(),
@@ -1168,7 +1456,7 @@ mod tests {
         );
 
         let tx_raw = build_signed_single_msg_tx(
-            TX_INFO,
+            tx_info(),
             CosmosSDKMsg::NftTransfer {
                 id: "edition01".to_string(),
                 denom_id: "domingo1".to_string(),
@@ -1186,14 +1474,14 @@ mod tests {
 
     #[test]
     fn nft_mint_check() {
-        let wallet = HDWallet::recover_wallet(WORDS.to_string(), None).expect("wallet");
+        let wallet = HDWallet::recover_wallet(WORDS.to_string(), None, None).expect("wallet");
 
         let private_key = wallet
             .get_key("m/44'/118'/0'/0/0".to_string())
             .expect("key");
 
         let payload_raw = get_single_msg_sign_payload(
-            TX_INFO,
+            tx_info(),
             CosmosSDKMsg::NftMint {
                 id: "edition01".to_string(),
                 denom_id: "domingo1".to_string(),
@@ -1212,7 +1500,7 @@ mod tests {
         );
 
         let tx_raw = build_signed_single_msg_tx(
-            TX_INFO,
+            tx_info(),
             CosmosSDKMsg::NftMint {
                 id: "edition01".to_string(),
                 denom_id: "domingo1".to_string(),
@@ -1233,14 +1521,14 @@ mod tests {
 
     #[test]
     fn nft_edit_check() {
-        let wallet = HDWallet::recover_wallet(WORDS.to_string(), None).expect("wallet");
+        let wallet = HDWallet::recover_wallet(WORDS.to_string(), None, None).expect("wallet");
 
         let private_key = wallet
             .get_key("m/44'/118'/0'/0/0".to_string())
             .expect("key");
 
         let payload_raw = get_single_msg_sign_payload(
-            TX_INFO,
+            tx_info(),
             CosmosSDKMsg::NftEdit {
                 id: "edition01".to_string(),
                 denom_id: "domingo1".to_string(),
@@ -1258,7 +1546,7 @@ mod tests {
         );
 
         let tx_raw = build_signed_single_msg_tx(
-            TX_INFO,
+            tx_info(),
             CosmosSDKMsg::NftEdit {
                 id: "edition01".to_string(),
                 denom_id: "domingo1".to_string(),
@@ -1278,14 +1566,14 @@ mod tests {
 
     #[test]
     fn nft_burn_check() {
-        let wallet = HDWallet::recover_wallet(WORDS.to_string(), None).expect("wallet");
+        let wallet = HDWallet::recover_wallet(WORDS.to_string(), None, None).expect("wallet");
 
         let private_key = wallet
             .get_key("m/44'/118'/0'/0/0".to_string())
             .expect("key");
 
         let payload_raw = get_single_msg_sign_payload(
-            TX_INFO,
+            tx_info(),
             CosmosSDKMsg::NftBurn {
                 id: "edition01".to_string(),
                 denom_id: "domingo1".to_string(),
@@ -1300,7 +1588,7 @@ mod tests {
         );
 
         let tx_raw = build_signed_single_msg_tx(
-            TX_INFO,
+            tx_info(),
             CosmosSDKMsg::NftBurn {
                 id: "edition01".to_string(),
                 denom_id: "domingo1".to_string(),
@@ -1317,14 +1605,14 @@ mod tests {
 
     #[test]
     fn ibc_transfer_check() {
-        let wallet = HDWallet::recover_wallet(WORDS.to_string(), None).expect("wallet");
+        let wallet = HDWallet::recover_wallet(WORDS.to_string(), None, None).expect("wallet");
 
         let private_key = wallet
             .get_key("m/44'/118'/0'/0/0".to_string())
             .expect("key");
 
         let payload_raw = get_single_msg_sign_payload(
-            TX_INFO,
+            tx_info(),
             CosmosSDKMsg::IbcTransfer {
                 source_channel: "channel-3".to_string(),
                 source_port: "transfer".to_string(),
@@ -1338,6 +1626,7 @@ mod tests {
                     revision_height: 0,
                 },
                 timeout_timestamp: 1645800000000000000,
+                memo: None,
             },
             PublicKeyBytesWrapper(private_key.get_public_key_bytes()),
         )
@@ -1349,7 +1638,7 @@ mod tests {
         );
 
         let tx_raw = build_signed_single_msg_tx(
-            TX_INFO,
+            tx_info(),
             CosmosSDKMsg::IbcTransfer {
                 source_channel: "channel-3".to_string(),
                 source_port: "transfer".to_string(),
@@ -1363,6 +1652,7 @@ mod tests {
                     revision_height: 0,
                 },
                 timeout_timestamp: 1645800000000000000,
+                memo: None,
             },
             private_key,
         )
@@ -1374,6 +1664,99 @@ mod tests {
         );
     }
 
+    #[test]
+    fn authz_exec_with_fee_granter_check() {
+        let wallet = HDWallet::recover_wallet(WORDS.to_string(), None, None).expect("wallet");
+        let private_key = wallet
+            .get_key("m/44'/118'/0'/0/0".to_string())
+            .expect("key");
+        let granter = "cosmos19dyl0uyzes4k23lscla02n06fc22h4uqsdwq6z";
+
+        let payload_raw = get_msg_sign_payload(
+            CosmosSDKTxInfo {
+                granter: Some(granter.to_string()),
+                ..tx_info()
+            },
+            vec![CosmosSDKMsg::AuthzExec {
+                granter: granter.to_string(),
+                msgs: vec![CosmosSDKMsg::StakingDelegate {
+                    validator_address: "cosmosvaloper19dyl0uyzes4k23lscla02n06fc22h4uq4e64k3"
+                        .to_string(),
+                    amount: SingleCoin::UATOM { amount: 100 },
+                }],
+            }],
+            PublicKeyBytesWrapper(private_key.get_public_key_bytes()),
+        )
+        .expect("ok signed payload");
+
+        let sign_doc = proto::cosmos::tx::v1beta1::SignDoc::decode(payload_raw.as_slice()).unwrap();
+        let auth_info =
+            proto::cosmos::tx::v1beta1::AuthInfo::decode(sign_doc.auth_info_bytes.as_slice())
+                .unwrap();
+        assert_eq!(auth_info.fee.unwrap().granter, granter);
+
+        let body =
+            proto::cosmos::tx::v1beta1::TxBody::decode(sign_doc.body_bytes.as_slice()).unwrap();
+        assert_eq!(body.messages[0].type_url, "/cosmos.authz.v1beta1.MsgExec");
+        let msg_exec =
+            proto::cosmos::authz::v1beta1::MsgExec::decode(body.messages[0].value.as_slice())
+                .unwrap();
+        assert_eq!(
+            msg_exec.grantee,
+            "cosmos1l5s7tnj28a7zxeeckhgwlhjys8dlrrefgqr4pj"
+        );
+        // the executed messages are signed by the granter
+        let msg_delegate =
+            proto::cosmos::staking::v1beta1::MsgDelegate::decode(msg_exec.msgs[0].value.as_slice())
+                .unwrap();
+        assert_eq!(msg_delegate.delegator_address, granter);
+    }
+
+    #[test]
+    fn multi_coin_fee_check() {
+        let wallet = HDWallet::recover_wallet(WORDS.to_string(), None, None).expect("wallet");
+        let private_key = wallet
+            .get_key("m/44'/118'/0'/0/0".to_string())
+            .expect("key");
+
+        let payload_raw = get_single_msg_sign_payload(
+            CosmosSDKTxInfo {
+                fee_amount: vec![
+                    SingleCoin::UATOM { amount: 2500 },
+                    SingleCoin::Other {
+                        amount: "10".to_string(),
+                        denom:
+                            "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2"
+                                .to_string(),
+                    },
+                    SingleCoin::UATOM { amount: 500 },
+                ],
+                payer: Some("cosmos19dyl0uyzes4k23lscla02n06fc22h4uqsdwq6z".to_string()),
+                ..tx_info()
+            },
+            CosmosSDKMsg::BankSend {
+                recipient_address: "cosmos19dyl0uyzes4k23lscla02n06fc22h4uqsdwq6z".to_string(),
+                amount: SingleCoin::UATOM { amount: 1 },
+            },
+            PublicKeyBytesWrapper(private_key.get_public_key_bytes()),
+        )
+        .expect("ok signed payload");
+
+        let sign_doc = proto::cosmos::tx::v1beta1::SignDoc::decode(payload_raw.as_slice()).unwrap();
+        let fee = proto::cosmos::tx::v1beta1::AuthInfo::decode(sign_doc.auth_info_bytes.as_slice())
+            .unwrap()
+            .fee
+            .unwrap();
+        // sorted by denomination and summed
+        assert_eq!(fee.amount.len(), 2);
+        assert_eq!(fee.amount[0].amount, "10");
+        assert_eq!(fee.amount[1].denom, "uatom");
+        assert_eq!(fee.amount[1].amount, "3000");
+        assert_eq!(fee.gas_limit, 100_000);
+        assert_eq!(fee.payer, "cosmos19dyl0uyzes4k23lscla02n06fc22h4uqsdwq6z");
+        assert_eq!(fee.granter, "");
+    }
+
     #[test]
     fn message_check() {
         let amount = &SingleCoin::ATOM { amount: 1 };
@@ -0,0 +1,898 @@
+// Legacy Amino JSON (`SIGN_MODE_LEGACY_AMINO_JSON`) sign docs. They are required by the Ledger
+// Cosmos app and by some dApps. The sign bytes are the canonical JSON of `StdSignDoc` (sorted keys,
+// no whitespace and HTML characters escaped as Go's `encoding/json` does).
+
+use super::{
+    get_fee, get_fee_coins, CosmosError, CosmosSDKMsg, CosmosSDKTxInfo, PublicKeyBytesWrapper,
+    SingleCoin,
+};
+use super::{CosmosAminoMsg, CosmosAminoSignDoc, CosmosFee};
+use crate::transaction::gov::{atomics_to_decimal, decimal_to_atomics, non_zero_proto_coins};
+use crate::{ProposalContent, SecretKey, SignerError, WalletSigner};
+use cosmrs::bip32::{PublicKey, PublicKeyBytes};
+use cosmrs::crypto::{self, secp256k1::VerifyingKey};
+use cosmrs::tx::{self, ModeInfo, SignMode, SignerInfo};
+use cosmrs::AccountId;
+use eyre::WrapErr;
+use ibc_proto::cosmos::base::v1beta1::Coin as IbcCoin;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::sync::Arc;
+
+/// `StdSignDoc` of the Cosmos SDK `x/auth/migrations/legacytx`
+#[derive(Debug, Deserialize, Serialize)]
+struct StdSignDoc {
+    account_number: String,
+    chain_id: String,
+    fee: StdFee,
+    memo: String,
+    msgs: Vec<AminoMsg>,
+    sequence: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    timeout_height: Option<String>,
+}
+
+/// `StdFee` of the Cosmos SDK `x/auth/migrations/legacytx`
+#[derive(Debug, Deserialize, Serialize)]
+struct StdFee {
+    #[serde(default)]
+    amount: Vec<AminoCoin>,
+    gas: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    payer: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    granter: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct AminoCoin {
+    amount: String,
+    denom: String,
+}
+
+/// a message in the Amino JSON encoding, e.g. `{"type":"cosmos-sdk/MsgSend","value":{...}}`
+#[derive(Debug, Deserialize, Serialize)]
+struct AminoMsg {
+    #[serde(rename = "type")]
+    msg_type: String,
+    value: Value,
+}
+
+impl TryFrom<&SingleCoin> for AminoCoin {
+    type Error = CosmosError;
+
+    fn try_from(single_coin: &SingleCoin) -> Result<Self, Self::Error> {
+        let coin = IbcCoin::try_from(single_coin)?;
+        Ok(Self {
+            amount: coin.amount,
+            denom: coin.denom,
+        })
+    }
+}
+
+fn amino_coin(coin: &SingleCoin) -> Result<Value, CosmosError> {
+    Ok(serde_json::to_value(AminoCoin::try_from(coin)?).wrap_err("Failed to encode coin")?)
+}
+
+/// builds a JSON object without the empty string fields (as Amino skips the `omitempty` ones)
+fn amino_object(fields: Vec<(&str, Value)>) -> Value {
+    Value::Object(
+        fields
+            .into_iter()
+            .filter(|(_, value)| value.as_str() != Some(""))
+            .map(|(key, value)| (key.to_owned(), value))
+            .collect(),
+    )
+}
+
+impl CosmosSDKMsg {
+    fn to_amino(&self, sender_address: &AccountId) -> Result<AminoMsg, CosmosError> {
+        let sender = json!(sender_address.as_ref());
+        let (msg_type, value) = match self {
+            CosmosSDKMsg::BankSend {
+                recipient_address,
+                amount,
+            } => (
+                "cosmos-sdk/MsgSend",
+                amino_object(vec![
+                    ("from_address", sender),
+                    ("to_address", json!(recipient_address)),
+                    ("amount", json!([amino_coin(amount)?])),
+                ]),
+            ),
+            CosmosSDKMsg::NftIssueDenom { id, name, schema } => (
+                "chainmain/nft/MsgIssueDenom",
+                amino_object(vec![
+                    ("id", json!(id)),
+                    ("name", json!(name)),
+                    ("schema", json!(schema)),
+                    ("sender", sender),
+                ]),
+            ),
+            CosmosSDKMsg::NftMint {
+                id,
+                denom_id,
+                name,
+                uri,
+                data,
+                recipient,
+            } => (
+                "chainmain/nft/MsgMintNFT",
+                amino_object(vec![
+                    ("id", json!(id)),
+                    ("denom_id", json!(denom_id)),
+                    ("name", json!(name)),
+                    ("uri", json!(uri)),
+                    ("data", json!(data)),
+                    ("sender", sender),
+                    ("recipient", json!(recipient)),
+                ]),
+            ),
+            CosmosSDKMsg::NftEdit {
+                id,
+                denom_id,
+                name,
+                uri,
+                data,
+            } => (
+                "chainmain/nft/MsgEditNFT",
+                amino_object(vec![
+                    ("id", json!(id)),
+                    ("denom_id", json!(denom_id)),
+                    ("name", json!(name)),
+                    ("uri", json!(uri)),
+                    ("data", json!(data)),
+                    ("sender", sender),
+                ]),
+            ),
+            CosmosSDKMsg::NftTransfer {
+                id,
+                denom_id,
+                recipient,
+            } => (
+                "chainmain/nft/MsgTransferNFT",
+                amino_object(vec![
+                    ("id", json!(id)),
+                    ("denom_id", json!(denom_id)),
+                    ("sender", sender),
+                    ("recipient", json!(recipient)),
+                ]),
+            ),
+            CosmosSDKMsg::NftBurn { id, denom_id } => (
+                "chainmain/nft/MsgBurnNFT",
+                amino_object(vec![
+                    ("id", json!(id)),
+                    ("denom_id", json!(denom_id)),
+                    ("sender", sender),
+                ]),
+            ),
+            CosmosSDKMsg::StakingBeginRedelegate {
+                validator_src_address,
+                validator_dst_address,
+                amount,
+            } => (
+                "cosmos-sdk/MsgBeginRedelegate",
+                amino_object(vec![
+                    ("delegator_address", sender),
+                    ("validator_src_address", json!(validator_src_address)),
+                    ("validator_dst_address", json!(validator_dst_address)),
+                    ("amount", amino_coin(amount)?),
+                ]),
+            ),
+            CosmosSDKMsg::StakingDelegate {
+                validator_address,
+                amount,
+            } => (
+                "cosmos-sdk/MsgDelegate",
+                amino_object(vec![
+                    ("delegator_address", sender),
+                    ("validator_address", json!(validator_address)),
+                    ("amount", amino_coin(amount)?),
+                ]),
+            ),
+            CosmosSDKMsg::StakingUndelegate {
+                validator_address,
+                amount,
+            } => (
+                "cosmos-sdk/MsgUndelegate",
+                amino_object(vec![
+                    ("delegator_address", sender),
+                    ("validator_address", json!(validator_address)),
+                    ("amount", amino_coin(amount)?),
+                ]),
+            ),
+            CosmosSDKMsg::DistributionSetWithdrawAddress { withdraw_address } => (
+                "cosmos-sdk/MsgModifyWithdrawAddress",
+                amino_object(vec![
+                    ("delegator_address", sender),
+                    ("withdraw_address", json!(withdraw_address)),
+                ]),
+            ),
+            CosmosSDKMsg::DistributionWithdrawDelegatorReward { validator_address } => (
+                "cosmos-sdk/MsgWithdrawDelegationReward",
+                amino_object(vec![
+                    ("delegator_address", sender),
+                    ("validator_address", json!(validator_address)),
+                ]),
+            ),
+            CosmosSDKMsg::IbcTransfer {
+                receiver,
+                source_port,
+                source_channel,
+                token,
+                timeout_height,
+                timeout_timestamp,
+                memo,
+            } => {
+                // the zero uint64 fields are omitted and the non-zero ones are encoded as strings
+                let uint = |value: u64| match value {
+                    0 => json!(""),
+                    value => json!(value.to_string()),
+                };
+                (
+                    "cosmos-sdk/MsgTransfer",
+                    amino_object(vec![
+                        ("source_port", json!(source_port)),
+                        ("source_channel", json!(source_channel)),
+                        ("token", amino_coin(token)?),
+                        ("sender", sender),
+                        ("receiver", json!(receiver)),
+                        (
+                            "timeout_height",
+                            amino_object(vec![
+                                ("revision_number", uint(timeout_height.revision_number)),
+                                ("revision_height", uint(timeout_height.revision_height)),
+                            ]),
+                        ),
+                        ("timeout_timestamp", uint(*timeout_timestamp)),
+                        ("memo", json!(memo.as_deref().unwrap_or_default())),
+                    ]),
+                )
+            }
+            CosmosSDKMsg::ExecuteContract {
+                contract,
+                execute_msg,
+                coins,
+            } => {
+                let execute_msg: Value = serde_json::from_slice(execute_msg)
+                    .wrap_err("ExecuteMsg must be a JSON encoded message")?;
+                (
+                    "wasm/MsgExecuteContract",
+                    amino_object(vec![
+                        ("sender", sender),
+                        ("contract", json!(contract)),
+                        ("execute_msg", execute_msg),
+                        ("coins", json!([amino_coin(coins)?])),
+                    ]),
+                )
+            }
+            CosmosSDKMsg::GovVote {
+                proposal_id,
+                option,
+            } => (
+                "cosmos-sdk/MsgVote",
+                amino_object(vec![
+                    ("proposal_id", json!(proposal_id.to_string())),
+                    ("voter", sender),
+                    ("option", json!(i32::from(*option))),
+                ]),
+            ),
+            CosmosSDKMsg::GovVoteWeighted {
+                proposal_id,
+                options,
+            } => {
+                let options = options
+                    .iter()
+                    .map(|option| {
+                        let weight = atomics_to_decimal(&decimal_to_atomics(&option.weight)?)?;
+                        Ok(json!({
+                            "option": i32::from(option.option),
+                            "weight": weight,
+                        }))
+                    })
+                    .collect::<eyre::Result<Vec<_>>>()?;
+                (
+                    "cosmos-sdk/MsgVoteWeighted",
+                    amino_object(vec![
+                        ("proposal_id", json!(proposal_id.to_string())),
+                        ("voter", sender),
+                        ("options", json!(options)),
+                    ]),
+                )
+            }
+            CosmosSDKMsg::GovDeposit {
+                proposal_id,
+                amount,
+            } => (
+                "cosmos-sdk/MsgDeposit",
+                amino_object(vec![
+                    ("proposal_id", json!(proposal_id.to_string())),
+                    ("depositor", sender),
+                    ("amount", json!([amino_coin(amount)?])),
+                ]),
+            ),
+            CosmosSDKMsg::GovSubmitProposal {
+                content,
+                initial_deposit,
+            } => {
+                let content = match content {
+                    ProposalContent::Text { title, description } => json!({
+                        "type": "cosmos-sdk/TextProposal",
+                        "value": amino_object(vec![
+                            ("title", json!(title)),
+                            ("description", json!(description)),
+                        ]),
+                    }),
+                    ProposalContent::ParameterChange {
+                        title,
+                        description,
+                        changes,
+                    } => json!({
+                        "type": "cosmos-sdk/ParameterChangeProposal",
+                        "value": amino_object(vec![
+                            ("title", json!(title)),
+                            ("description", json!(description)),
+                            ("changes", json!(changes)),
+                        ]),
+                    }),
+                };
+                (
+                    "cosmos-sdk/MsgSubmitProposal",
+                    amino_object(vec![
+                        ("content", content),
+                        (
+                            "initial_deposit",
+                            json!(non_zero_proto_coins(initial_deposit)?
+                                .into_iter()
+                                .map(|coin| AminoCoin {
+                                    amount: coin.amount,
+                                    denom: coin.denom,
+                                })
+                                .collect::<Vec<_>>()),
+                        ),
+                        ("proposer", sender),
+                    ]),
+                )
+            }
+            CosmosSDKMsg::VestingCreateAccount {
+                to_address,
+                amount,
+                end_time,
+                delayed,
+            } => {
+                let mut fields = vec![
+                    ("from_address", sender),
+                    ("to_address", json!(to_address)),
+                    (
+                        "amount",
+                        json!(amount
+                            .iter()
+                            .map(amino_coin)
+                            .collect::<Result<Vec<_>, _>>()?),
+                    ),
+                    ("end_time", json!(end_time.to_string())),
+                ];
+                // `delayed` is omitted when false
+                if *delayed {
+                    fields.push(("delayed", json!(true)));
+                }
+                ("cosmos-sdk/MsgCreateVestingAccount", amino_object(fields))
+            }
+            CosmosSDKMsg::AuthzGrant { .. }
+            | CosmosSDKMsg::AuthzExec { .. }
+            | CosmosSDKMsg::AuthzRevoke { .. }
+            | CosmosSDKMsg::FeegrantGrantAllowance { .. }
+            | CosmosSDKMsg::FeegrantRevokeAllowance { .. } => {
+                return Err(eyre::eyre!(
+                    "Amino JSON encoding of authz and feegrant messages is not supported"
+                )
+                .into())
+            }
+            CosmosSDKMsg::Raw { .. } => {
+                return Err(
+                    eyre::eyre!("Amino JSON encoding of raw messages is not supported").into(),
+                )
+            }
+        };
+        Ok(AminoMsg {
+            msg_type: msg_type.to_owned(),
+            value,
+        })
+    }
+}
+
+impl StdSignDoc {
+    fn new(
+        tx_info: &CosmosSDKTxInfo,
+        msgs: &[CosmosSDKMsg],
+        sender_address: &AccountId,
+    ) -> Result<Self, CosmosError> {
+        let msgs = msgs
+            .iter()
+            .map(|msg| msg.to_amino(sender_address))
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            account_number: tx_info.account_number.to_string(),
+            chain_id: tx_info.network.get_chain_id()?.to_string(),
+            fee: StdFee {
+                amount: get_fee_coins(&tx_info.fee_amount)?
+                    .into_iter()
+                    .map(|coin| AminoCoin {
+                        amount: coin.amount.to_string(),
+                        denom: coin.denom.to_string(),
+                    })
+                    .collect(),
+                gas: tx_info.gas_limit.to_string(),
+                payer: tx_info.payer.clone(),
+                granter: tx_info.granter.clone(),
+            },
+            memo: tx_info.memo_note.clone().unwrap_or_default(),
+            msgs,
+            sequence: tx_info.sequence_number.to_string(),
+            timeout_height: match tx_info.timeout_height {
+                0 => None,
+                height => Some(height.to_string()),
+            },
+        })
+    }
+
+    /// the canonical JSON that is signed
+    fn to_sign_bytes(&self) -> Result<Vec<u8>, CosmosError> {
+        let value = serde_json::to_value(self).wrap_err("Failed to encode StdSignDoc")?;
+        let json = serde_json::to_string(&sort_json(value)).wrap_err("Failed to encode JSON")?;
+        Ok(escape_html(&json).into_bytes())
+    }
+}
+
+/// sorts the keys of the JSON objects recursively
+fn sort_json(value: Value) -> Value {
+    match value {
+        Value::Object(map) => {
+            let mut entries: Vec<(String, Value)> = map.into_iter().collect();
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+            Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| (key, sort_json(value)))
+                    .collect::<Map<_, _>>(),
+            )
+        }
+        Value::Array(values) => Value::Array(values.into_iter().map(sort_json).collect()),
+        value => value,
+    }
+}
+
+/// escapes `<`, `>` and `&` as Go's `encoding/json` does
+/// (they can only appear inside of the JSON strings)
+fn escape_html(json: &str) -> String {
+    json.replace('<', "\\u003c")
+        .replace('>', "\\u003e")
+        .replace('&', "\\u0026")
+}
+
+pub(super) fn get_sender_public_key(public_key: Vec<u8>) -> Result<crypto::PublicKey, CosmosError> {
+    let public_key: PublicKeyBytes = public_key
+        .try_into()
+        .map_err(|_| SignerError::InvalidPublicKey)?;
+    Ok(crypto::PublicKey::from(
+        VerifyingKey::from_bytes(public_key).map_err(CosmosError::PubkeyError)?,
+    ))
+}
+
+/// returns the Amino JSON sign bytes of the transaction sent by the sender
+pub(super) fn get_amino_sign_bytes(
+    tx_info: &CosmosSDKTxInfo,
+    msgs: &[CosmosSDKMsg],
+    sender_address: &AccountId,
+) -> Result<Vec<u8>, CosmosError> {
+    StdSignDoc::new(tx_info, msgs, sender_address)?.to_sign_bytes()
+}
+
+/// creates the Amino JSON transaction signing payload (`StdSignDoc`)
+/// with some Cosmos SDK messages
+pub fn get_amino_msg_sign_payload(
+    tx_info: CosmosSDKTxInfo,
+    msgs: Vec<CosmosSDKMsg>,
+    sender_pubkey: PublicKeyBytesWrapper,
+) -> Result<Vec<u8>, CosmosError> {
+    let sender_public_key = get_sender_public_key(sender_pubkey.0)?;
+    let sender_address = sender_public_key.account_id(tx_info.network.get_bech32_hrp())?;
+    StdSignDoc::new(&tx_info, &msgs, &sender_address)?.to_sign_bytes()
+}
+
+/// creates the signed transaction (in the `SIGN_MODE_LEGACY_AMINO_JSON` mode)
+/// with some Cosmos SDK messages
+pub fn build_signed_amino_msg_tx(
+    tx_info: CosmosSDKTxInfo,
+    msgs: Vec<CosmosSDKMsg>,
+    secret_key: Arc<SecretKey>,
+) -> Result<Vec<u8>, CosmosError> {
+    build_signed_amino_msg_tx_with_signer(tx_info, msgs, secret_key.as_ref())
+}
+
+/// creates the signed transaction (in the `SIGN_MODE_LEGACY_AMINO_JSON` mode)
+/// with some Cosmos SDK messages signed by the signer (e.g. a Ledger device)
+pub fn build_signed_amino_msg_tx_with_signer(
+    tx_info: CosmosSDKTxInfo,
+    msgs: Vec<CosmosSDKMsg>,
+    signer: &dyn WalletSigner,
+) -> Result<Vec<u8>, CosmosError> {
+    let sender_public_key = get_sender_public_key(signer.get_public_key_bytes()?)?;
+    let sender_address = sender_public_key.account_id(tx_info.network.get_bech32_hrp())?;
+    let sign_bytes = StdSignDoc::new(&tx_info, &msgs, &sender_address)?.to_sign_bytes()?;
+    let signature = signer.sign_cosmos(&sign_bytes)?;
+
+    let msgs_any = msgs
+        .iter()
+        .map(|msg| msg.to_any(sender_address.clone()))
+        .collect::<eyre::Result<Vec<_>>>()?;
+    let fee = get_fee(&tx_info)?;
+    let tx_body = tx::Body::new(
+        msgs_any,
+        tx_info.memo_note.unwrap_or_default(),
+        tx_info.timeout_height,
+    );
+    let signer_info = SignerInfo {
+        public_key: Some(sender_public_key.into()),
+        mode_info: ModeInfo::single(SignMode::LegacyAminoJson),
+        sequence: tx_info.sequence_number,
+    };
+    let auth_info = signer_info.auth_info(fee);
+    let tx_raw = tx::Raw::from(cosmrs::proto::cosmos::tx::v1beta1::TxRaw {
+        body_bytes: tx_body.into_bytes()?,
+        auth_info_bytes: auth_info.into_bytes()?,
+        signatures: vec![signature],
+    });
+    Ok(tx_raw.to_bytes()?)
+}
+
+/// returns the canonical sign bytes of an Amino JSON sign doc (e.g. received from a dApp)
+pub(crate) fn get_amino_json_sign_bytes(json_string: &str) -> Result<Vec<u8>, CosmosError> {
+    let sign_doc: StdSignDoc = serde_json::from_str(json_string)
+        .wrap_err("Failed to decode StdSignDoc from Amino JSON")?;
+    sign_doc.to_sign_bytes()
+}
+
+/// parses an Amino JSON sign doc for displaying it to the user
+pub(crate) fn parse_amino_json_sign_doc(
+    json_string: &str,
+) -> Result<CosmosAminoSignDoc, CosmosError> {
+    let sign_doc: StdSignDoc = serde_json::from_str(json_string)
+        .wrap_err("Failed to decode StdSignDoc from Amino JSON")?;
+    let parse_u64 = |name: &str, value: &str| -> Result<u64, CosmosError> {
+        Ok(value
+            .parse::<u64>()
+            .wrap_err_with(|| format!("Field {name} must be an u64: {value}"))?)
+    };
+
+    let msgs = sign_doc
+        .msgs
+        .into_iter()
+        .map(|msg| {
+            Ok(CosmosAminoMsg {
+                msg_type: msg.msg_type,
+                value: serde_json::to_string_pretty(&sort_json(msg.value))
+                    .wrap_err("Failed to encode the message value")?,
+            })
+        })
+        .collect::<Result<_, CosmosError>>()?;
+    Ok(CosmosAminoSignDoc {
+        account_number: parse_u64("account_number", &sign_doc.account_number)?,
+        chain_id: sign_doc.chain_id,
+        fee: CosmosFee {
+            amount: sign_doc
+                .fee
+                .amount
+                .into_iter()
+                .map(|coin| SingleCoin::Other {
+                    amount: coin.amount,
+                    denom: coin.denom,
+                })
+                .collect(),
+            gas_limit: parse_u64("gas", &sign_doc.fee.gas)?,
+            payer: sign_doc.fee.payer,
+            granter: sign_doc.fee.granter,
+        },
+        memo: sign_doc.memo,
+        msgs,
+        sequence: parse_u64("sequence", &sign_doc.sequence)?,
+        timeout_height: sign_doc
+            .timeout_height
+            .map(|height| parse_u64("timeout_height", &height))
+            .transpose()?
+            .unwrap_or_default(),
+    })
+}
+
+#[cfg(test)]
+mod amino_tests {
+    use super::*;
+    use crate::{HDWallet, Network, VoteOption, WeightedVoteOption};
+    use cosmrs::tx::mode_info::Single;
+    use cosmrs::Tx;
+    use ethers::core::k256::ecdsa::signature::hazmat::PrehashVerifier;
+    use ethers::core::k256::ecdsa::{Signature, VerifyingKey as EthVerifyingKey};
+    use ibc_proto::ibc::core::client::v1::Height;
+    use sha2::{Digest, Sha256};
+
+    const WORDS: &str = "apple elegant knife hawk there screen vehicle lounge tube sun engage bus custom market pioneer casual wink present cat metal ride shallow fork brief";
+
+    fn tx_info(memo_note: Option<String>) -> CosmosSDKTxInfo {
+        CosmosSDKTxInfo {
+            account_number: 1,
+            sequence_number: 0,
+            gas_limit: 100_000,
+            timeout_height: 0,
+            fee_amount: vec![SingleCoin::UATOM { amount: 2500 }],
+            memo_note,
+            network: Network::CosmosHub,
+            payer: None,
+            granter: None,
+        }
+    }
+
+    fn get_key() -> Arc<SecretKey> {
+        let wallet = HDWallet::recover_wallet(WORDS.to_owned(), None, None).unwrap();
+        wallet.get_key("m/44'/118'/0'/0/0".to_owned()).unwrap()
+    }
+
+    #[test]
+    fn test_amino_sign_payload() {
+        let key = get_key();
+        let sign_bytes = get_amino_msg_sign_payload(
+            tx_info(Some("<memo> & more".to_owned())),
+            vec![
+                CosmosSDKMsg::BankSend {
+                    recipient_address: "cosmos19dyl0uyzes4k23lscla02n06fc22h4uqsdwq6z".to_owned(),
+                    amount: SingleCoin::UATOM { amount: 1000 },
+                },
+                CosmosSDKMsg::StakingDelegate {
+                    validator_address: "cosmosvaloper1qs8tnw2t8l6amtzvdemnnsq9dzk0ag0z52uzay"
+                        .to_owned(),
+                    amount: SingleCoin::UATOM { amount: 10 },
+                },
+            ],
+            PublicKeyBytesWrapper(key.get_public_key_bytes()),
+        )
+        .unwrap();
+        assert_eq!(
+            String::from_utf8(sign_bytes).unwrap(),
+            concat!(
+                r#"{"account_number":"1","chain_id":"cosmoshub-4","#,
+                r#""fee":{"amount":[{"amount":"2500","denom":"uatom"}],"gas":"100000"},"#,
+                r#""memo":"\u003cmemo\u003e \u0026 more","msgs":["#,
+                r#"{"type":"cosmos-sdk/MsgSend","value":{"amount":[{"amount":"1000","denom":"uatom"}],"#,
+                r#""from_address":"cosmos1l5s7tnj28a7zxeeckhgwlhjys8dlrrefgqr4pj","#,
+                r#""to_address":"cosmos19dyl0uyzes4k23lscla02n06fc22h4uqsdwq6z"}},"#,
+                r#"{"type":"cosmos-sdk/MsgDelegate","value":{"amount":{"amount":"10","denom":"uatom"},"#,
+                r#""delegator_address":"cosmos1l5s7tnj28a7zxeeckhgwlhjys8dlrrefgqr4pj","#,
+                r#""validator_address":"cosmosvaloper1qs8tnw2t8l6amtzvdemnnsq9dzk0ag0z52uzay"}}],"#,
+                r#""sequence":"0"}"#
+            )
+        );
+    }
+
+    #[test]
+    fn test_amino_fee_granter() {
+        let key = get_key();
+        let msgs = || {
+            vec![CosmosSDKMsg::DistributionWithdrawDelegatorReward {
+                validator_address: "cosmosvaloper1qs8tnw2t8l6amtzvdemnnsq9dzk0ag0z52uzay"
+                    .to_owned(),
+            }]
+        };
+        let mut info = tx_info(None);
+        info.granter = Some("cosmos19dyl0uyzes4k23lscla02n06fc22h4uqsdwq6z".to_owned());
+        let sign_bytes = get_amino_msg_sign_payload(
+            info,
+            msgs(),
+            PublicKeyBytesWrapper(key.get_public_key_bytes()),
+        )
+        .unwrap();
+        assert!(String::from_utf8(sign_bytes).unwrap().contains(concat!(
+            r#""fee":{"amount":[{"amount":"2500","denom":"uatom"}],"gas":"100000","#,
+            r#""granter":"cosmos19dyl0uyzes4k23lscla02n06fc22h4uqsdwq6z"}"#
+        )));
+
+        // authz and feegrant messages have no Amino JSON encoding here
+        assert!(get_amino_msg_sign_payload(
+            tx_info(None),
+            vec![CosmosSDKMsg::FeegrantRevokeAllowance {
+                grantee: "cosmos19dyl0uyzes4k23lscla02n06fc22h4uqsdwq6z".to_owned(),
+            }],
+            PublicKeyBytesWrapper(key.get_public_key_bytes()),
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_amino_ibc_transfer_and_nft_msgs() {
+        let sender: AccountId = "cosmos1l5s7tnj28a7zxeeckhgwlhjys8dlrrefgqr4pj"
+            .parse()
+            .unwrap();
+        let msg = CosmosSDKMsg::IbcTransfer {
+            receiver: "cro1l5s7tnj28a7zxeeckhgwlhjys8dlrrefsmtvar".to_owned(),
+            source_port: "transfer".to_owned(),
+            source_channel: "channel-187".to_owned(),
+            token: SingleCoin::UATOM { amount: 5 },
+            timeout_height: Height {
+                revision_number: 0,
+                revision_height: 12_000_000,
+            },
+            timeout_timestamp: 0,
+            memo: Some("{\"forward\":{}}".to_owned()),
+        }
+        .to_amino(&sender)
+        .unwrap();
+        assert_eq!(msg.msg_type, "cosmos-sdk/MsgTransfer");
+        assert_eq!(
+            msg.value["timeout_height"],
+            json!({"revision_height": "12000000"})
+        );
+        assert!(msg.value.get("timeout_timestamp").is_none());
+        assert_eq!(msg.value["memo"], json!("{\"forward\":{}}"));
+
+        let msg = CosmosSDKMsg::NftMint {
+            id: "edition01".to_owned(),
+            denom_id: "artworks".to_owned(),
+            name: "".to_owned(),
+            uri: "".to_owned(),
+            data: "".to_owned(),
+            recipient: "cosmos19dyl0uyzes4k23lscla02n06fc22h4uqsdwq6z".to_owned(),
+        }
+        .to_amino(&sender)
+        .unwrap();
+        assert_eq!(msg.msg_type, "chainmain/nft/MsgMintNFT");
+        assert_eq!(
+            msg.value,
+            json!({
+                "id": "edition01",
+                "denom_id": "artworks",
+                "sender": "cosmos1l5s7tnj28a7zxeeckhgwlhjys8dlrrefgqr4pj",
+                "recipient": "cosmos19dyl0uyzes4k23lscla02n06fc22h4uqsdwq6z",
+            })
+        );
+    }
+
+    #[test]
+    fn test_amino_gov_msgs() {
+        let sender: AccountId = "cosmos1l5s7tnj28a7zxeeckhgwlhjys8dlrrefgqr4pj"
+            .parse()
+            .unwrap();
+        let msg = CosmosSDKMsg::GovVoteWeighted {
+            proposal_id: 82,
+            options: vec![
+                WeightedVoteOption {
+                    option: VoteOption::Yes,
+                    weight: "0.7".to_owned(),
+                },
+                WeightedVoteOption {
+                    option: VoteOption::Abstain,
+                    weight: "0.3".to_owned(),
+                },
+            ],
+        }
+        .to_amino(&sender)
+        .unwrap();
+        assert_eq!(msg.msg_type, "cosmos-sdk/MsgVoteWeighted");
+        assert_eq!(
+            msg.value,
+            json!({
+                "proposal_id": "82",
+                "voter": "cosmos1l5s7tnj28a7zxeeckhgwlhjys8dlrrefgqr4pj",
+                "options": [
+                    {"option": 1, "weight": "0.700000000000000000"},
+                    {"option": 2, "weight": "0.300000000000000000"},
+                ],
+            })
+        );
+
+        let msg = CosmosSDKMsg::GovSubmitProposal {
+            content: ProposalContent::Text {
+                title: "Signaling proposal".to_owned(),
+                description: "".to_owned(),
+            },
+            initial_deposit: vec![SingleCoin::UATOM { amount: 1000 }],
+        }
+        .to_amino(&sender)
+        .unwrap();
+        assert_eq!(msg.msg_type, "cosmos-sdk/MsgSubmitProposal");
+        assert_eq!(
+            msg.value,
+            json!({
+                "content": {
+                    "type": "cosmos-sdk/TextProposal",
+                    "value": {"title": "Signaling proposal"},
+                },
+                "initial_deposit": [{"amount": "1000", "denom": "uatom"}],
+                "proposer": "cosmos1l5s7tnj28a7zxeeckhgwlhjys8dlrrefgqr4pj",
+            })
+        );
+
+        let msg = CosmosSDKMsg::GovSubmitProposal {
+            content: ProposalContent::Text {
+                title: "Signaling proposal".to_owned(),
+                description: "".to_owned(),
+            },
+            initial_deposit: vec![SingleCoin::UATOM { amount: 0 }],
+        }
+        .to_amino(&sender)
+        .unwrap();
+        assert_eq!(msg.value["initial_deposit"], json!([]));
+    }
+
+    #[test]
+    fn test_amino_signing() {
+        let key = get_key();
+        let msgs = || {
+            vec![CosmosSDKMsg::DistributionWithdrawDelegatorReward {
+                validator_address: "cosmosvaloper1qs8tnw2t8l6amtzvdemnnsq9dzk0ag0z52uzay"
+                    .to_owned(),
+            }]
+        };
+        let tx_raw = build_signed_amino_msg_tx(tx_info(None), msgs(), key.clone()).unwrap();
+        let tx = Tx::from_bytes(&tx_raw).unwrap();
+        assert!(matches!(
+            tx.auth_info.signer_infos[0].mode_info,
+            ModeInfo::Single(Single {
+                mode: SignMode::LegacyAminoJson
+            })
+        ));
+
+        let sign_bytes = get_amino_msg_sign_payload(
+            tx_info(None),
+            msgs(),
+            PublicKeyBytesWrapper(key.get_public_key_bytes()),
+        )
+        .unwrap();
+        let verifying_key = EthVerifyingKey::from_sec1_bytes(&key.get_public_key_bytes()).unwrap();
+        let signature = Signature::from_slice(&tx.signatures[0]).unwrap();
+        assert!(verifying_key
+            .verify_prehash(&Sha256::digest(&sign_bytes), &signature)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_amino_sign_doc_parsing() {
+        let json = r#"{
+            "chain_id": "cosmoshub-4",
+            "account_number": "7",
+            "sequence": "3",
+            "fee": {"gas": "200000", "amount": [{"denom": "uatom", "amount": "5000"}]},
+            "msgs": [{"type": "cosmos-sdk/MsgSend", "value": {"to_address": "cosmos19dyl0uyzes4k23lscla02n06fc22h4uqsdwq6z", "from_address": "cosmos1l5s7tnj28a7zxeeckhgwlhjys8dlrrefgqr4pj", "amount": [{"denom": "uatom", "amount": "1"}]}}],
+            "memo": "hi"
+        }"#;
+        let sign_doc = parse_amino_json_sign_doc(json).unwrap();
+        assert_eq!(sign_doc.chain_id, "cosmoshub-4");
+        assert_eq!(sign_doc.account_number, 7);
+        assert_eq!(sign_doc.sequence, 3);
+        assert_eq!(sign_doc.timeout_height, 0);
+        assert_eq!(sign_doc.memo, "hi");
+        assert_eq!(sign_doc.fee.gas_limit, 200_000);
+        assert_eq!(
+            sign_doc.fee.amount,
+            vec![SingleCoin::Other {
+                amount: "5000".to_owned(),
+                denom: "uatom".to_owned()
+            }]
+        );
+        assert_eq!(sign_doc.msgs[0].msg_type, "cosmos-sdk/MsgSend");
+        assert!(sign_doc.msgs[0]
+            .value
+            .starts_with("{\n  \"amount\": [\n    {\n      \"amount\": \"1\""));
+
+        assert_eq!(
+            String::from_utf8(get_amino_json_sign_bytes(json).unwrap()).unwrap(),
+            concat!(
+                r#"{"account_number":"7","chain_id":"cosmoshub-4","#,
+                r#""fee":{"amount":[{"amount":"5000","denom":"uatom"}],"gas":"200000"},"#,
+                r#""memo":"hi","msgs":[{"type":"cosmos-sdk/MsgSend","value":{"#,
+                r#""amount":[{"amount":"1","denom":"uatom"}],"#,
+                r#""from_address":"cosmos1l5s7tnj28a7zxeeckhgwlhjys8dlrrefgqr4pj","#,
+                r#""to_address":"cosmos19dyl0uyzes4k23lscla02n06fc22h4uqsdwq6z"}}],"#,
+                r#""sequence":"3"}"#
+            )
+        );
+        assert!(get_amino_json_sign_bytes("{\"msgs\": []}").is_err());
+    }
+}
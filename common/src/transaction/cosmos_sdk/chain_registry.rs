@@ -0,0 +1,405 @@
+use crate::{
+    CosmosError, GasPrice, Network, COSMOS_BECH32_HRP, COSMOS_CHAIN_ID, CRONOS_BECH32_HRP,
+    CRONOS_CHAIN_ID, CRYPTO_ORG_BECH32_HRP, CRYPTO_ORG_CHAIN_ID, CRYPTO_ORG_CHAIN_TESTNET_ID,
+    CRYPTO_ORG_TESTNET_BECH32_HRP,
+};
+use eyre::WrapErr;
+use serde::{Deserialize, Serialize};
+
+/// a fee token of the chain (the gas prices are decimal strings, e.g. "0.025")
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+pub struct ChainFeeToken {
+    pub denom: String,
+    pub fixed_min_gas_price: Option<String>,
+    pub low_gas_price: Option<String>,
+    pub average_gas_price: Option<String>,
+    pub high_gas_price: Option<String>,
+}
+
+/// an asset of the chain
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+pub struct ChainAsset {
+    /// the base denomination, e.g. "basecro"
+    pub base: String,
+    /// the display denomination, e.g. "cro"
+    pub display: String,
+    pub symbol: String,
+    pub name: String,
+    /// the exponent of the display denomination (e.g. 8 for 1 CRO = 10^8 basecro)
+    pub decimals: u32,
+}
+
+/// the chain definition from the cosmos chain-registry
+/// (https://github.com/cosmos/chain-registry)
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+pub struct ChainInfo {
+    pub chain_name: String,
+    pub chain_id: String,
+    pub pretty_name: String,
+    pub bech32_prefix: String,
+    /// the HD wallet coin type
+    pub slip44: u32,
+    /// the network resolved from the chain id, coin type and bech32 prefix
+    pub network: Network,
+    pub fee_tokens: Vec<ChainFeeToken>,
+    /// the Tendermint JSON-RPC endpoints
+    pub rpc_endpoints: Vec<String>,
+    /// the Cosmos REST API endpoints
+    pub rest_endpoints: Vec<String>,
+    /// the Cosmos gRPC endpoints
+    pub grpc_endpoints: Vec<String>,
+    /// the assets from `assetlist.json` (empty if not supplied)
+    pub assets: Vec<ChainAsset>,
+}
+
+#[derive(Deserialize)]
+struct RawFeeToken {
+    denom: String,
+    fixed_min_gas_price: Option<serde_json::Number>,
+    low_gas_price: Option<serde_json::Number>,
+    average_gas_price: Option<serde_json::Number>,
+    high_gas_price: Option<serde_json::Number>,
+}
+
+impl From<RawFeeToken> for ChainFeeToken {
+    fn from(token: RawFeeToken) -> Self {
+        let price = |price: Option<serde_json::Number>| {
+            price.map(|price| plain_decimal(&price.to_string()))
+        };
+        Self {
+            denom: token.denom,
+            fixed_min_gas_price: price(token.fixed_min_gas_price),
+            low_gas_price: price(token.low_gas_price),
+            average_gas_price: price(token.average_gas_price),
+            high_gas_price: price(token.high_gas_price),
+        }
+    }
+}
+
+/// expands the exponent form of a JSON number (e.g. "1e-8" for 0.00000001)
+/// to a plain decimal string (e.g. "0.00000001")
+fn plain_decimal(number: &str) -> String {
+    let (mantissa, exponent) = match number.split_once(|c| c == 'e' || c == 'E') {
+        Some((mantissa, exponent)) => (mantissa, exponent.parse::<i64>().unwrap_or_default()),
+        None => return number.to_owned(),
+    };
+    let (sign, mantissa) = match mantissa.strip_prefix('-') {
+        Some(mantissa) => ("-", mantissa),
+        None => ("", mantissa),
+    };
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let digits = format!("{integer}{fraction}");
+    let point = integer.len() as i64 + exponent;
+    let (integer, fraction) = if point <= 0 {
+        (
+            "0".to_owned(),
+            format!("{}{digits}", "0".repeat(point.unsigned_abs() as usize)),
+        )
+    } else if point as usize >= digits.len() {
+        (
+            format!("{digits}{}", "0".repeat(point as usize - digits.len())),
+            String::new(),
+        )
+    } else {
+        let (integer, fraction) = digits.split_at(point as usize);
+        (integer.to_owned(), fraction.to_owned())
+    };
+    let integer = match integer.trim_start_matches('0') {
+        "" => "0",
+        integer => integer,
+    };
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.is_empty() {
+        format!("{sign}{integer}")
+    } else {
+        format!("{sign}{integer}.{fraction}")
+    }
+}
+
+#[derive(Default, Deserialize)]
+struct RawFees {
+    #[serde(default)]
+    fee_tokens: Vec<RawFeeToken>,
+}
+
+#[derive(Deserialize)]
+struct RawEndpoint {
+    address: String,
+}
+
+#[derive(Default, Deserialize)]
+struct RawApis {
+    #[serde(default)]
+    rpc: Vec<RawEndpoint>,
+    #[serde(default)]
+    rest: Vec<RawEndpoint>,
+    #[serde(default)]
+    grpc: Vec<RawEndpoint>,
+}
+
+#[derive(Deserialize)]
+struct RawChain {
+    chain_name: String,
+    chain_id: String,
+    #[serde(default)]
+    pretty_name: String,
+    bech32_prefix: String,
+    slip44: u32,
+    #[serde(default)]
+    fees: RawFees,
+    #[serde(default)]
+    apis: RawApis,
+}
+
+#[derive(Deserialize)]
+struct RawDenomUnit {
+    denom: String,
+    exponent: u32,
+}
+
+#[derive(Deserialize)]
+struct RawAsset {
+    base: String,
+    display: String,
+    #[serde(default)]
+    symbol: String,
+    #[serde(default)]
+    name: String,
+    denom_units: Vec<RawDenomUnit>,
+}
+
+impl From<RawAsset> for ChainAsset {
+    fn from(asset: RawAsset) -> Self {
+        let decimals = asset
+            .denom_units
+            .iter()
+            .find(|unit| unit.denom == asset.display)
+            .map(|unit| unit.exponent)
+            .unwrap_or_default();
+        Self {
+            base: asset.base,
+            display: asset.display,
+            symbol: asset.symbol,
+            name: asset.name,
+            decimals,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct RawAssetList {
+    chain_name: String,
+    assets: Vec<RawAsset>,
+}
+
+/// the built-in network if it has the same chain id, coin type and bech32 prefix
+fn resolve_network(chain_id: &str, coin_type: u32, bech32hrp: &str) -> Network {
+    [
+        (
+            Network::CryptoOrgMainnet,
+            CRYPTO_ORG_CHAIN_ID,
+            CRYPTO_ORG_BECH32_HRP,
+        ),
+        (
+            Network::CryptoOrgTestnet,
+            CRYPTO_ORG_CHAIN_TESTNET_ID,
+            CRYPTO_ORG_TESTNET_BECH32_HRP,
+        ),
+        (Network::CronosMainnet, CRONOS_CHAIN_ID, CRONOS_BECH32_HRP),
+        (Network::CosmosHub, COSMOS_CHAIN_ID, COSMOS_BECH32_HRP),
+    ]
+    .into_iter()
+    .find(|(network, id, hrp)| {
+        *id == chain_id && *hrp == bech32hrp && network.get_coin_type() == coin_type
+    })
+    .map(|(network, _, _)| network)
+    .unwrap_or_else(|| Network::Other {
+        chain_id: chain_id.to_owned(),
+        coin_type,
+        bech32hrp: bech32hrp.to_owned(),
+    })
+}
+
+impl ChainInfo {
+    /// parse the chain-registry `chain.json` and optionally `assetlist.json`
+    /// (e.g. bundled with `include_str!` or fetched from the registry)
+    pub fn from_registry(
+        chain_json: &str,
+        assetlist_json: Option<&str>,
+    ) -> Result<Self, CosmosError> {
+        let chain: RawChain = serde_json::from_str(chain_json).wrap_err("invalid chain.json")?;
+        let assets = match assetlist_json {
+            Some(assetlist_json) => {
+                let assetlist: RawAssetList =
+                    serde_json::from_str(assetlist_json).wrap_err("invalid assetlist.json")?;
+                if assetlist.chain_name != chain.chain_name {
+                    return Err(eyre::eyre!(
+                        "assetlist.json of {} instead of {}",
+                        assetlist.chain_name,
+                        chain.chain_name
+                    )
+                    .into());
+                }
+                assetlist.assets.into_iter().map(Into::into).collect()
+            }
+            None => vec![],
+        };
+        let endpoints = |endpoints: Vec<RawEndpoint>| -> Vec<String> {
+            endpoints.into_iter().map(|e| e.address).collect()
+        };
+        Ok(Self {
+            network: resolve_network(&chain.chain_id, chain.slip44, &chain.bech32_prefix),
+            chain_name: chain.chain_name,
+            chain_id: chain.chain_id,
+            pretty_name: chain.pretty_name,
+            bech32_prefix: chain.bech32_prefix,
+            slip44: chain.slip44,
+            fee_tokens: chain.fees.fee_tokens.into_iter().map(Into::into).collect(),
+            rpc_endpoints: endpoints(chain.apis.rpc),
+            rest_endpoints: endpoints(chain.apis.rest),
+            grpc_endpoints: endpoints(chain.apis.grpc),
+            assets,
+        })
+    }
+
+    /// the gas price of the first fee token (its average, low or fixed minimum one)
+    pub fn default_gas_price(&self) -> Option<GasPrice> {
+        self.fee_tokens.iter().find_map(|token| {
+            token
+                .average_gas_price
+                .as_ref()
+                .or(token.low_gas_price.as_ref())
+                .or(token.fixed_min_gas_price.as_ref())
+                .map(|amount| GasPrice {
+                    amount: amount.clone(),
+                    denom: token.denom.clone(),
+                })
+        })
+    }
+
+    /// the decimals of the asset with the base denomination
+    pub fn asset_decimals(&self, base_denom: &str) -> Option<u32> {
+        self.assets
+            .iter()
+            .find(|asset| asset.base == base_denom)
+            .map(|asset| asset.decimals)
+    }
+}
+
+/// parse the chain-registry `chain.json` and optionally `assetlist.json`
+pub fn parse_chain_registry(
+    chain_json: &str,
+    assetlist_json: Option<String>,
+) -> Result<ChainInfo, CosmosError> {
+    ChainInfo::from_registry(chain_json, assetlist_json.as_deref())
+}
+
+#[cfg(test)]
+mod chain_registry_tests {
+    use super::*;
+
+    const CHAIN_JSON: &str = r#"{
+        "$schema": "../chain.schema.json",
+        "chain_name": "cryptoorgchain",
+        "status": "live",
+        "network_type": "mainnet",
+        "pretty_name": "Crypto.org",
+        "chain_id": "crypto-org-chain-mainnet-1",
+        "bech32_prefix": "cro",
+        "slip44": 394,
+        "fees": {
+            "fee_tokens": [
+                {
+                    "denom": "basecro",
+                    "fixed_min_gas_price": 0.025,
+                    "low_gas_price": 0.03,
+                    "average_gas_price": 0.04,
+                    "high_gas_price": 0.05
+                }
+            ]
+        },
+        "apis": {
+            "rpc": [{"address": "https://rpc.mainnet.crypto.org", "provider": "crypto.org"}],
+            "rest": [{"address": "https://rest.mainnet.crypto.org", "provider": "crypto.org"}],
+            "grpc": [{"address": "grpc.mainnet.crypto.org:443", "provider": "crypto.org"}]
+        }
+    }"#;
+
+    const ASSETLIST_JSON: &str = r#"{
+        "chain_name": "cryptoorgchain",
+        "assets": [
+            {
+                "denom_units": [
+                    {"denom": "basecro", "exponent": 0},
+                    {"denom": "cro", "exponent": 8}
+                ],
+                "base": "basecro",
+                "name": "Cronos POS Chain",
+                "display": "cro",
+                "symbol": "CRO"
+            }
+        ]
+    }"#;
+
+    #[test]
+    fn test_chain_registry_parsing() {
+        let info = ChainInfo::from_registry(CHAIN_JSON, Some(ASSETLIST_JSON)).unwrap();
+        assert_eq!(info.network, Network::CryptoOrgMainnet);
+        assert_eq!(info.rpc_endpoints, vec!["https://rpc.mainnet.crypto.org"]);
+        assert_eq!(info.grpc_endpoints, vec!["grpc.mainnet.crypto.org:443"]);
+        assert_eq!(
+            info.default_gas_price(),
+            Some(GasPrice {
+                amount: "0.04".to_owned(),
+                denom: "basecro".to_owned(),
+            })
+        );
+        assert_eq!(
+            info.fee_tokens[0].fixed_min_gas_price.as_deref(),
+            Some("0.025")
+        );
+        assert_eq!(info.asset_decimals("basecro"), Some(8));
+        assert_eq!(info.asset_decimals("uatom"), None);
+
+        let other_chain = CHAIN_JSON
+            .replace("crypto-org-chain-mainnet-1", "my-chain-1")
+            .replace("\"cro\"", "\"my\"");
+        let info = ChainInfo::from_registry(&other_chain, None).unwrap();
+        assert_eq!(
+            info.network,
+            Network::Other {
+                chain_id: "my-chain-1".to_owned(),
+                coin_type: 394,
+                bech32hrp: "my".to_owned(),
+            }
+        );
+        assert!(info.assets.is_empty());
+
+        let other_assets = ASSETLIST_JSON.replace("cryptoorgchain", "cosmoshub");
+        assert!(ChainInfo::from_registry(CHAIN_JSON, Some(&other_assets)).is_err());
+    }
+
+    #[test]
+    fn test_exponent_form_gas_prices() {
+        let chain_json = CHAIN_JSON
+            .replace("0.025", "1e-8")
+            .replace("0.03", "0.00000002")
+            .replace("0.04", "2.5E-7")
+            .replace("0.05", "1.5e-7");
+        let info = ChainInfo::from_registry(&chain_json, None).unwrap();
+        let token = &info.fee_tokens[0];
+        assert_eq!(token.fixed_min_gas_price.as_deref(), Some("0.00000001"));
+        assert_eq!(token.low_gas_price.as_deref(), Some("0.00000002"));
+        assert_eq!(token.average_gas_price.as_deref(), Some("0.00000025"));
+        assert_eq!(token.high_gas_price.as_deref(), Some("0.00000015"));
+        let gas_price = info.default_gas_price().unwrap();
+        assert_eq!(
+            super::super::fee::parse_scaled_decimal(&gas_price.amount).unwrap(),
+            super::super::fee::parse_scaled_decimal("0.00000025").unwrap()
+        );
+
+        assert_eq!(plain_decimal("1.5e-3"), "0.0015");
+        assert_eq!(plain_decimal("1.25e1"), "12.5");
+        assert_eq!(plain_decimal("0.04"), "0.04");
+    }
+}
@@ -0,0 +1,270 @@
+#[cfg(not(target_arch = "wasm32"))]
+use crate::DenomMetadata;
+use crate::{CosmosError, SingleCoin};
+use eyre::{eyre, WrapErr};
+use serde::{Deserialize, Serialize};
+
+/// a decimal amount in a unit of the denomination (e.g. "1.5" cro),
+/// converted to the base denomination (e.g. "150000000" basecro) without precision loss
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+pub struct DecimalCoin {
+    /// the decimal amount in the unit
+    pub amount: String,
+    /// the unit denomination (e.g. "cro")
+    pub denom: String,
+    /// the base denomination (e.g. "basecro")
+    pub base_denom: String,
+    /// 1 unit = 10^exponent base units
+    pub exponent: u32,
+}
+
+/// the largest supported exponent of a unit
+pub const MAX_DECIMAL_EXPONENT: u32 = 36;
+
+/// a unit of `DenomMetadata::denom_units`
+#[derive(Deserialize)]
+struct RawDenomUnit {
+    denom: String,
+    exponent: u32,
+    #[serde(default)]
+    aliases: Vec<String>,
+}
+
+/// the exponent of the unit (its denomination or one of its aliases)
+fn unit_exponent(denom_units_json: &str, denom: &str) -> eyre::Result<u32> {
+    let units: Vec<RawDenomUnit> =
+        serde_json::from_str(denom_units_json).wrap_err("invalid denomination units")?;
+    units
+        .into_iter()
+        .find(|unit| unit.denom == denom || unit.aliases.iter().any(|alias| alias == denom))
+        .map(|unit| unit.exponent)
+        .ok_or_else(|| eyre!("unknown denomination unit: {}", denom))
+}
+
+fn check_exponent(exponent: u32) -> eyre::Result<()> {
+    if exponent > MAX_DECIMAL_EXPONENT {
+        return Err(eyre!(
+            "the exponent {} is larger than {}",
+            exponent,
+            MAX_DECIMAL_EXPONENT
+        ));
+    }
+    Ok(())
+}
+
+/// shift the decimal amount by the exponent into the integer base amount
+/// (it fails if the amount has more decimals than the exponent)
+fn to_base_amount(amount: &str, exponent: u32) -> eyre::Result<String> {
+    check_exponent(exponent)?;
+    let (integer, fraction) = amount.split_once('.').unwrap_or((amount, ""));
+    if (integer.is_empty() && fraction.is_empty())
+        || !integer
+            .chars()
+            .chain(fraction.chars())
+            .all(|c| c.is_ascii_digit())
+    {
+        return Err(eyre!("invalid decimal amount: {}", amount));
+    }
+    let fraction = fraction.trim_end_matches('0');
+    let exponent = exponent as usize;
+    if fraction.len() > exponent {
+        return Err(eyre!(
+            "the amount {} has more than {} decimals",
+            amount,
+            exponent
+        ));
+    }
+    let base_amount = format!("{}{:0<width$}", integer, fraction, width = exponent);
+    let base_amount = base_amount.trim_start_matches('0');
+    Ok(if base_amount.is_empty() {
+        "0".to_owned()
+    } else {
+        base_amount.to_owned()
+    })
+}
+
+/// shift the integer base amount by the exponent into the decimal amount
+fn from_base_amount(base_amount: &str, exponent: u32) -> eyre::Result<String> {
+    check_exponent(exponent)?;
+    if base_amount.is_empty() || !base_amount.chars().all(|c| c.is_ascii_digit()) {
+        return Err(eyre!("invalid base amount: {}", base_amount));
+    }
+    let exponent = exponent as usize;
+    let digits = format!("{:0>width$}", base_amount, width = exponent + 1);
+    let (integer, fraction) = digits.split_at(digits.len() - exponent);
+    let integer = match integer.trim_start_matches('0') {
+        "" => "0",
+        integer => integer,
+    };
+    Ok(match fraction.trim_end_matches('0') {
+        "" => integer.to_owned(),
+        fraction => format!("{}.{}", integer, fraction),
+    })
+}
+
+impl DecimalCoin {
+    /// the amount in the unit (its denomination or alias) of the denomination units
+    /// (`DenomMetadata::denom_units` in json)
+    pub fn from_denom_units(
+        amount: &str,
+        denom: &str,
+        base_denom: &str,
+        denom_units_json: &str,
+    ) -> Result<Self, CosmosError> {
+        let coin = Self {
+            amount: amount.to_owned(),
+            denom: denom.to_owned(),
+            base_denom: base_denom.to_owned(),
+            exponent: unit_exponent(denom_units_json, denom)?,
+        };
+        coin.base_amount()?;
+        Ok(coin)
+    }
+
+    /// the base amount converted to the unit of the denomination units
+    /// (`DenomMetadata::denom_units` in json)
+    pub fn from_base_amount(
+        base_amount: &str,
+        denom: &str,
+        base_denom: &str,
+        denom_units_json: &str,
+    ) -> Result<Self, CosmosError> {
+        let exponent = unit_exponent(denom_units_json, denom)?;
+        Ok(Self {
+            amount: from_base_amount(base_amount, exponent)?,
+            denom: denom.to_owned(),
+            base_denom: base_denom.to_owned(),
+            exponent,
+        })
+    }
+
+    /// the amount in the unit of the denomination metadata
+    /// (its display unit if `denom` is empty)
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_metadata(
+        amount: &str,
+        denom: &str,
+        metadata: &DenomMetadata,
+    ) -> Result<Self, CosmosError> {
+        let denom = if denom.is_empty() {
+            &metadata.display
+        } else {
+            denom
+        };
+        Self::from_denom_units(amount, denom, &metadata.base, &metadata.denom_units)
+    }
+
+    /// the base amount converted to the unit of the denomination metadata
+    /// (its display unit if `denom` is empty)
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_metadata_base_amount(
+        base_amount: &str,
+        denom: &str,
+        metadata: &DenomMetadata,
+    ) -> Result<Self, CosmosError> {
+        let denom = if denom.is_empty() {
+            &metadata.display
+        } else {
+            denom
+        };
+        Self::from_base_amount(base_amount, denom, &metadata.base, &metadata.denom_units)
+    }
+
+    /// the integer amount in the base denomination
+    pub fn base_amount(&self) -> Result<String, CosmosError> {
+        Ok(to_base_amount(&self.amount, self.exponent)?)
+    }
+}
+
+impl From<DecimalCoin> for SingleCoin {
+    fn from(coin: DecimalCoin) -> Self {
+        SingleCoin::Decimal {
+            amount: coin.amount,
+            denom: coin.denom,
+            base_denom: coin.base_denom,
+            exponent: coin.exponent,
+        }
+    }
+}
+
+/// the amount in the unit of the denomination metadata (its display unit if `denom` is empty)
+#[cfg(not(target_arch = "wasm32"))]
+pub fn get_decimal_coin(
+    amount: &str,
+    denom: &str,
+    metadata: &DenomMetadata,
+) -> Result<DecimalCoin, CosmosError> {
+    DecimalCoin::from_metadata(amount, denom, metadata)
+}
+
+/// the base amount converted to the unit of the denomination metadata
+/// (its display unit if `denom` is empty)
+#[cfg(not(target_arch = "wasm32"))]
+pub fn get_decimal_coin_from_base_amount(
+    base_amount: &str,
+    denom: &str,
+    metadata: &DenomMetadata,
+) -> Result<DecimalCoin, CosmosError> {
+    DecimalCoin::from_metadata_base_amount(base_amount, denom, metadata)
+}
+
+/// the integer amount of the decimal coin in the base denomination
+pub fn get_decimal_coin_base_amount(coin: &DecimalCoin) -> Result<String, CosmosError> {
+    coin.base_amount()
+}
+
+#[cfg(test)]
+mod decimal_coin_tests {
+    use super::*;
+
+    const DENOM_UNITS: &str = r#"[{"denom":"basecro","exponent":0,"aliases":["carson"]},{"denom":"cro","exponent":8,"aliases":[]}]"#;
+
+    #[test]
+    fn test_decimal_coin_conversion() {
+        let coin = DecimalCoin::from_denom_units("1.5", "cro", "basecro", DENOM_UNITS).unwrap();
+        assert_eq!(coin.exponent, 8);
+        assert_eq!(coin.base_amount().unwrap(), "150000000");
+
+        let coin = DecimalCoin::from_denom_units(
+            "123456789012345678901234567890.00000001",
+            "cro",
+            "basecro",
+            DENOM_UNITS,
+        )
+        .unwrap();
+        assert_eq!(
+            coin.base_amount().unwrap(),
+            "12345678901234567890123456789000000001"
+        );
+
+        let coin = DecimalCoin::from_denom_units("42", "carson", "basecro", DENOM_UNITS).unwrap();
+        assert_eq!(coin.base_amount().unwrap(), "42");
+        assert_eq!(to_base_amount("0.0", 8).unwrap(), "0");
+        assert_eq!(to_base_amount(".5", 1).unwrap(), "5");
+
+        assert!(
+            DecimalCoin::from_denom_units("0.000000001", "cro", "basecro", DENOM_UNITS).is_err()
+        );
+        assert!(DecimalCoin::from_denom_units("1.5", "atom", "basecro", DENOM_UNITS).is_err());
+        assert!(to_base_amount("-1", 8).is_err());
+        assert!(to_base_amount("1.2.3", 8).is_err());
+        assert!(to_base_amount(".", 8).is_err());
+
+        let coin =
+            DecimalCoin::from_base_amount("150000000", "cro", "basecro", DENOM_UNITS).unwrap();
+        assert_eq!(coin.amount, "1.5");
+        assert_eq!(from_base_amount("1", 8).unwrap(), "0.00000001");
+        assert_eq!(from_base_amount("2000", 0).unwrap(), "2000");
+        assert_eq!(from_base_amount("0", 6).unwrap(), "0");
+
+        assert!(to_base_amount("1", u32::MAX).is_err());
+        assert!(from_base_amount("1", MAX_DECIMAL_EXPONENT + 1).is_err());
+        let coin = SingleCoin::Decimal {
+            amount: "1".to_owned(),
+            denom: "cro".to_owned(),
+            base_denom: "basecro".to_owned(),
+            exponent: u32::MAX,
+        };
+        assert!(cosmrs::Coin::try_from(&coin).is_err());
+    }
+}
@@ -0,0 +1,235 @@
+use super::{
+    get_msg_signdoc, CosmosError, CosmosSDKMsg, CosmosSDKTxInfo, PublicKeyBytesWrapper, SingleCoin,
+};
+use cosmrs::crypto::{self, secp256k1::VerifyingKey};
+use cosmrs::tx::Raw;
+use eyre::{eyre, Context};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// the default multiplier applied to the simulated gas usage
+pub const DEFAULT_GAS_ADJUSTMENT: f64 = 1.3;
+
+/// the decimal places of the gas prices (as `sdk.Dec` in Cosmos SDK)
+const GAS_PRICE_DECIMALS: usize = 18;
+
+/// the length of the placeholder signature in the simulated transactions
+const SIMULATION_SIGNATURE_LEN: usize = 64;
+
+/// the price of a gas unit (e.g. "0.025uatom")
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+pub struct GasPrice {
+    /// the decimal amount per gas unit
+    pub amount: String,
+    /// the fee denomination
+    pub denom: String,
+}
+
+/// parses the decimal amount (as `sdk.Dec` with up to 18 decimals) scaled by 10^18
+pub(crate) fn parse_scaled_decimal(amount: &str) -> eyre::Result<u128> {
+    let (integer, fraction) = amount.split_once('.').unwrap_or((amount, ""));
+    if (integer.is_empty() && fraction.is_empty())
+        || fraction.len() > GAS_PRICE_DECIMALS
+        || !integer
+            .chars()
+            .chain(fraction.chars())
+            .all(|c| c.is_ascii_digit())
+    {
+        return Err(eyre!("invalid decimal amount: {}", amount));
+    }
+    let integer = if integer.is_empty() {
+        0
+    } else {
+        integer
+            .parse::<u128>()
+            .wrap_err("decimal amount parse error")?
+    };
+    let fraction = format!("{:0<width$}", fraction, width = GAS_PRICE_DECIMALS)
+        .parse::<u128>()
+        .wrap_err("decimal amount parse error")?;
+    integer
+        .checked_mul(10u128.pow(GAS_PRICE_DECIMALS as u32))
+        .and_then(|amount| amount.checked_add(fraction))
+        .ok_or_else(|| eyre!("integer overflow"))
+}
+
+impl GasPrice {
+    /// the amount scaled by 10^18
+    fn scaled_amount(&self) -> eyre::Result<u128> {
+        parse_scaled_decimal(&self.amount).wrap_err("invalid gas price amount")
+    }
+
+    /// return the fee for the gas limit at this price (rounded up)
+    pub fn get_fee(&self, gas_limit: u64) -> eyre::Result<SingleCoin> {
+        let scale = 10u128.pow(GAS_PRICE_DECIMALS as u32);
+        let scaled_fee = self
+            .scaled_amount()?
+            .checked_mul(gas_limit.into())
+            .ok_or_else(|| eyre!("integer overflow"))?;
+        let amount = scaled_fee / scale + u128::from(scaled_fee % scale != 0);
+        Ok(SingleCoin::Other {
+            amount: amount.to_string(),
+            denom: self.denom.clone(),
+        })
+    }
+}
+
+impl FromStr for GasPrice {
+    type Err = eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let denom_start = s
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .ok_or_else(|| eyre!("missing gas price denomination: {}", s))?;
+        let (amount, denom) = s.split_at(denom_start);
+        let gas_price = GasPrice {
+            amount: amount.to_owned(),
+            denom: denom.to_owned(),
+        };
+        gas_price.scaled_amount()?;
+        Ok(gas_price)
+    }
+}
+
+impl fmt::Display for GasPrice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.amount, self.denom)
+    }
+}
+
+/// return the gas limit for the simulated gas usage
+/// multiplied by the gas adjustment (rounded up)
+pub fn get_adjusted_gas_limit(gas_used: u64, gas_adjustment: f64) -> Result<u64, CosmosError> {
+    if !gas_adjustment.is_finite() || gas_adjustment <= 0.0 {
+        return Err(eyre!("invalid gas adjustment: {}", gas_adjustment).into());
+    }
+    let gas_limit = (gas_used as f64 * gas_adjustment).ceil();
+    if gas_limit >= u64::MAX as f64 {
+        return Err(eyre!("integer overflow").into());
+    }
+    Ok(gas_limit as u64)
+}
+
+pub(crate) fn get_simulation_msg_tx(
+    tx_info: &CosmosSDKTxInfo,
+    msgs: &[CosmosSDKMsg],
+    sender_public_key: crypto::PublicKey,
+) -> eyre::Result<Raw> {
+    // the gas meter is unlimited and the fee isn't checked in the simulation
+    let tx_info = CosmosSDKTxInfo {
+        gas_limit: 0,
+        fee_amount: vec![],
+        ..tx_info.clone()
+    };
+    let sign_doc = get_msg_signdoc(tx_info, msgs, sender_public_key)?;
+    Ok(Raw::from(cosmrs::proto::cosmos::tx::v1beta1::TxRaw {
+        body_bytes: sign_doc.body_bytes,
+        auth_info_bytes: sign_doc.auth_info_bytes,
+        signatures: vec![vec![0; SIMULATION_SIGNATURE_LEN]],
+    }))
+}
+
+/// creates the transaction for simulating the execution of some Cosmos SDK messages
+/// (without a fee and with a placeholder signature that isn't verified in the simulation)
+pub fn build_simulation_msg_tx(
+    tx_info: CosmosSDKTxInfo,
+    msgs: Vec<CosmosSDKMsg>,
+    sender_pubkey: PublicKeyBytesWrapper,
+) -> Result<Vec<u8>, CosmosError> {
+    let sender_public_key = crypto::PublicKey::from(
+        VerifyingKey::from_bytes(sender_pubkey.into()).map_err(CosmosError::PubkeyError)?,
+    );
+    Ok(get_simulation_msg_tx(&tx_info, &msgs, sender_public_key)?.to_bytes()?)
+}
+
+#[cfg(test)]
+mod fee_tests {
+    use super::*;
+    use crate::{Network, SecretKey};
+    use cosmrs::tx::Tx;
+
+    #[test]
+    fn test_gas_price_parsing() {
+        let gas_price = "0.025uatom".parse::<GasPrice>().unwrap();
+        assert_eq!(gas_price.amount, "0.025");
+        assert_eq!(gas_price.denom, "uatom");
+        assert_eq!(gas_price.to_string(), "0.025uatom");
+
+        let gas_price = "5000000000000basetcro".parse::<GasPrice>().unwrap();
+        assert_eq!(gas_price.amount, "5000000000000");
+        assert_eq!(gas_price.denom, "basetcro");
+
+        assert!("uatom".parse::<GasPrice>().is_err());
+        assert!("0.025".parse::<GasPrice>().is_err());
+        assert!("0.0.25uatom".parse::<GasPrice>().is_err());
+        assert!("0.0000000000000000001uatom".parse::<GasPrice>().is_err());
+    }
+
+    #[test]
+    fn test_fee_calculation() {
+        let gas_price = "0.025uatom".parse::<GasPrice>().unwrap();
+        assert_eq!(
+            gas_price.get_fee(200_000).unwrap(),
+            SingleCoin::Other {
+                amount: "5000".to_owned(),
+                denom: "uatom".to_owned()
+            }
+        );
+        // rounded up
+        assert_eq!(
+            gas_price.get_fee(100_001).unwrap(),
+            SingleCoin::Other {
+                amount: "2501".to_owned(),
+                denom: "uatom".to_owned()
+            }
+        );
+        let gas_price = "5000000000000basecro".parse::<GasPrice>().unwrap();
+        assert_eq!(
+            gas_price.get_fee(300_000).unwrap(),
+            SingleCoin::Other {
+                amount: "1500000000000000000".to_owned(),
+                denom: "basecro".to_owned()
+            }
+        );
+
+        assert_eq!(get_adjusted_gas_limit(100_000, 1.3).unwrap(), 130_000);
+        assert_eq!(get_adjusted_gas_limit(100_001, 1.5).unwrap(), 150_002);
+        assert!(get_adjusted_gas_limit(100_000, 0.0).is_err());
+        assert!(get_adjusted_gas_limit(100_000, f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_simulation_tx() {
+        let secret_key = SecretKey::new();
+        let tx_info = CosmosSDKTxInfo {
+            account_number: 1,
+            sequence_number: 2,
+            gas_limit: 100_000,
+            fee_amount: vec![SingleCoin::ATOM { amount: 1 }],
+            timeout_height: 0,
+            memo_note: None,
+            network: Network::CosmosHub,
+            payer: None,
+            granter: None,
+        };
+        let msgs = vec![CosmosSDKMsg::BankSend {
+            recipient_address: "cosmos1l5s7tnj28a7zxeeckhgwlhjys8dlrrefgqr4pj".to_owned(),
+            amount: SingleCoin::ATOM { amount: 1 },
+        }];
+        let tx_raw = build_simulation_msg_tx(
+            tx_info,
+            msgs,
+            PublicKeyBytesWrapper(secret_key.get_public_key_bytes()),
+        )
+        .unwrap();
+
+        let tx = Tx::from_bytes(&tx_raw).unwrap();
+        assert_eq!(tx.body.messages.len(), 1);
+        assert_eq!(tx.auth_info.fee.gas_limit, 0);
+        assert!(tx.auth_info.fee.amount.is_empty());
+        assert_eq!(tx.auth_info.signer_infos[0].sequence, 2);
+        assert_eq!(tx.signatures, vec![vec![0; SIMULATION_SIGNATURE_LEN]]);
+    }
+}
@@ -0,0 +1,408 @@
+// Legacy Amino multisig (`LegacyAminoPubKey`) accounts. As in the Cosmos SDK CLI, each member signs
+// the Amino JSON sign doc of the multisig account and the partial signatures are combined into
+// a `MultiSignature` with the `CompactBitArray` of the members that signed.
+
+use super::amino::{get_amino_sign_bytes, get_sender_public_key};
+use super::{get_fee, CosmosError, CosmosSDKMsg, CosmosSDKTxInfo};
+use crate::{SecretKey, WalletSigner};
+use cosmrs::proto::cosmos::crypto::multisig::v1beta1::{CompactBitArray, MultiSignature};
+use cosmrs::proto::cosmos::crypto::multisig::LegacyAminoPubKey;
+use cosmrs::proto::cosmos::crypto::secp256k1::PubKey as Secp256k1PubKey;
+use cosmrs::proto::cosmos::tx::signing::v1beta1::SignMode;
+use cosmrs::proto::cosmos::tx::v1beta1::{mode_info, AuthInfo, ModeInfo, SignerInfo, TxRaw};
+use cosmrs::tx;
+use cosmrs::{AccountId, Any};
+use ethers::core::k256::ecdsa::signature::hazmat::PrehashVerifier;
+use ethers::core::k256::ecdsa::{Signature, VerifyingKey};
+use eyre::eyre;
+use prost::Message;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+
+/// the legacy Amino prefix of the multisig threshold public keys
+const MULTISIG_AMINO_PREFIX: [u8; 4] = [0x22, 0xc1, 0xf7, 0xe2];
+/// the legacy Amino prefix of the secp256k1 public keys
+const SECP256K1_AMINO_PREFIX: [u8; 4] = [0xeb, 0x5a, 0xe9, 0x87];
+/// the type URL of the multisig public keys
+const LEGACY_AMINO_PUBKEY_TYPE_URL: &str = "/cosmos.crypto.multisig.LegacyAminoPubKey";
+/// the type URL of the secp256k1 public keys
+const SECP256K1_PUBKEY_TYPE_URL: &str = "/cosmos.crypto.secp256k1.PubKey";
+/// the length of the addresses derived from the public keys
+const ADDRESS_LEN: usize = 20;
+
+/// k-of-n multisig public key (`LegacyAminoPubKey`) of secp256k1 public keys
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MultisigPublicKey {
+    threshold: u32,
+    public_keys: Vec<Vec<u8>>,
+}
+
+impl MultisigPublicKey {
+    /// creates the k-of-n multisig public key from the compressed public keys of the members
+    /// (in the given order; the Cosmos SDK CLI sorts them by address unless `--nosort` is set)
+    pub fn new(threshold: u32, public_keys: Vec<Vec<u8>>) -> Result<Self, CosmosError> {
+        if threshold == 0 || threshold as usize > public_keys.len() {
+            return Err(eyre!(
+                "invalid threshold {} of {} public keys",
+                threshold,
+                public_keys.len()
+            )
+            .into());
+        }
+        for (i, public_key) in public_keys.iter().enumerate() {
+            get_sender_public_key(public_key.clone())?;
+            if public_keys[..i].contains(public_key) {
+                return Err(eyre!("duplicate public key: {}", hex::encode(public_key)).into());
+            }
+        }
+        Ok(Self {
+            threshold,
+            public_keys,
+        })
+    }
+
+    /// the number of signatures required
+    pub fn get_threshold(&self) -> u32 {
+        self.threshold
+    }
+
+    /// the compressed public keys of the members
+    pub fn get_public_keys(&self) -> Vec<Vec<u8>> {
+        self.public_keys.clone()
+    }
+
+    /// the legacy Amino encoding (hashed into the address)
+    pub fn to_amino_bytes(&self) -> Vec<u8> {
+        let mut bytes = MULTISIG_AMINO_PREFIX.to_vec();
+        bytes.push(0x08);
+        prost::encoding::encode_varint(self.threshold.into(), &mut bytes);
+        for public_key in self.public_keys.iter() {
+            let mut key_bytes = SECP256K1_AMINO_PREFIX.to_vec();
+            prost::encoding::encode_varint(public_key.len() as u64, &mut key_bytes);
+            key_bytes.extend_from_slice(public_key);
+
+            bytes.push(0x12);
+            prost::encoding::encode_varint(key_bytes.len() as u64, &mut bytes);
+            bytes.extend_from_slice(&key_bytes);
+        }
+        bytes
+    }
+
+    fn get_account_id(&self, bech32_hrp: &str) -> Result<AccountId, CosmosError> {
+        let hash = Sha256::digest(self.to_amino_bytes());
+        Ok(AccountId::new(bech32_hrp, &hash[..ADDRESS_LEN])?)
+    }
+
+    /// the bech32 address of the multisig account
+    pub fn get_address(&self, bech32_hrp: &str) -> Result<String, CosmosError> {
+        Ok(self.get_account_id(bech32_hrp)?.to_string())
+    }
+
+    fn to_any(&self) -> Any {
+        let public_keys = self
+            .public_keys
+            .iter()
+            .map(|public_key| Any {
+                type_url: SECP256K1_PUBKEY_TYPE_URL.to_owned(),
+                value: Secp256k1PubKey {
+                    key: public_key.clone(),
+                }
+                .encode_to_vec(),
+            })
+            .collect();
+        Any {
+            type_url: LEGACY_AMINO_PUBKEY_TYPE_URL.to_owned(),
+            value: LegacyAminoPubKey {
+                threshold: self.threshold,
+                public_keys,
+            }
+            .encode_to_vec(),
+        }
+    }
+}
+
+/// the signature of a multisig transaction by one of the members
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+pub struct MultisigPartialSignature {
+    /// the compressed public key of the member
+    pub public_key: Vec<u8>,
+    /// the signature of the Amino JSON sign doc
+    pub signature: Vec<u8>,
+}
+
+/// creates the partial signature of the multisig transaction with some Cosmos SDK messages
+/// (in the `SIGN_MODE_LEGACY_AMINO_JSON` mode as in the Cosmos SDK CLI)
+pub fn build_multisig_partial_signature(
+    tx_info: CosmosSDKTxInfo,
+    msgs: Vec<CosmosSDKMsg>,
+    multisig: Arc<MultisigPublicKey>,
+    secret_key: Arc<SecretKey>,
+) -> Result<MultisigPartialSignature, CosmosError> {
+    build_multisig_partial_signature_with_signer(tx_info, msgs, &multisig, secret_key.as_ref())
+}
+
+/// creates the partial signature of the multisig transaction with some Cosmos SDK messages
+/// signed by the signer (e.g. a Ledger device)
+pub fn build_multisig_partial_signature_with_signer(
+    tx_info: CosmosSDKTxInfo,
+    msgs: Vec<CosmosSDKMsg>,
+    multisig: &MultisigPublicKey,
+    signer: &dyn WalletSigner,
+) -> Result<MultisigPartialSignature, CosmosError> {
+    let public_key = signer.get_public_key_bytes()?;
+    if !multisig.public_keys.contains(&public_key) {
+        return Err(eyre!("the signer is not a member of the multisig").into());
+    }
+    let multisig_address = multisig.get_account_id(tx_info.network.get_bech32_hrp())?;
+    let sign_bytes = get_amino_sign_bytes(&tx_info, &msgs, &multisig_address)?;
+    let signature = signer.sign_cosmos(&sign_bytes)?;
+    Ok(MultisigPartialSignature {
+        public_key,
+        signature,
+    })
+}
+
+fn verify_partial_signature(
+    partial_signature: &MultisigPartialSignature,
+    sign_bytes: &[u8],
+) -> Result<(), CosmosError> {
+    let invalid_signature = || {
+        eyre!(
+            "invalid signature of {}",
+            hex::encode(&partial_signature.public_key)
+        )
+    };
+    let verifying_key = VerifyingKey::from_sec1_bytes(&partial_signature.public_key)
+        .map_err(|_| invalid_signature())?;
+    let signature =
+        Signature::from_slice(&partial_signature.signature).map_err(|_| invalid_signature())?;
+    verifying_key
+        .verify_prehash(&Sha256::digest(sign_bytes), &signature)
+        .map_err(|_| invalid_signature())?;
+    Ok(())
+}
+
+/// combines the partial signatures (at least the threshold) into the signed multisig transaction
+/// with some Cosmos SDK messages
+pub fn build_signed_multisig_tx(
+    tx_info: CosmosSDKTxInfo,
+    msgs: Vec<CosmosSDKMsg>,
+    multisig: Arc<MultisigPublicKey>,
+    partial_signatures: Vec<MultisigPartialSignature>,
+) -> Result<Vec<u8>, CosmosError> {
+    if let Some(partial_signature) = partial_signatures
+        .iter()
+        .find(|partial_signature| !multisig.public_keys.contains(&partial_signature.public_key))
+    {
+        return Err(eyre!(
+            "{} is not a member of the multisig",
+            hex::encode(&partial_signature.public_key)
+        )
+        .into());
+    }
+
+    let multisig_address = multisig.get_account_id(tx_info.network.get_bech32_hrp())?;
+    let sign_bytes = get_amino_sign_bytes(&tx_info, &msgs, &multisig_address)?;
+
+    // the signatures are in the order of the members set in the bit array
+    let members = multisig.public_keys.len();
+    let mut bitarray = CompactBitArray {
+        extra_bits_stored: (members % 8) as u32,
+        elems: vec![0; (members + 7) / 8],
+    };
+    let mut signatures = vec![];
+    for (i, public_key) in multisig.public_keys.iter().enumerate() {
+        if let Some(partial_signature) = partial_signatures
+            .iter()
+            .find(|partial_signature| &partial_signature.public_key == public_key)
+        {
+            verify_partial_signature(partial_signature, &sign_bytes)?;
+            bitarray.elems[i / 8] |= 1 << (7 - i % 8);
+            signatures.push(partial_signature.signature.clone());
+        }
+    }
+    if signatures.len() < multisig.threshold as usize {
+        return Err(eyre!(
+            "{} signatures are below the threshold {}",
+            signatures.len(),
+            multisig.threshold
+        )
+        .into());
+    }
+
+    let msgs_any = msgs
+        .iter()
+        .map(|msg| msg.to_any(multisig_address.clone()))
+        .collect::<eyre::Result<Vec<_>>>()?;
+    let fee = get_fee(&tx_info)?;
+    let tx_body = tx::Body::new(
+        msgs_any,
+        tx_info.memo_note.unwrap_or_default(),
+        tx_info.timeout_height,
+    );
+    let mut auth_info: AuthInfo = tx::SignerInfo::single_direct(None, tx_info.sequence_number)
+        .auth_info(fee)
+        .into();
+    auth_info.signer_infos = vec![SignerInfo {
+        public_key: Some(multisig.to_any()),
+        mode_info: Some(ModeInfo {
+            sum: Some(mode_info::Sum::Multi(mode_info::Multi {
+                bitarray: Some(bitarray),
+                mode_infos: signatures
+                    .iter()
+                    .map(|_| ModeInfo {
+                        sum: Some(mode_info::Sum::Single(mode_info::Single {
+                            mode: SignMode::LegacyAminoJson as i32,
+                        })),
+                    })
+                    .collect(),
+            })),
+        }),
+        sequence: tx_info.sequence_number,
+    }];
+    let tx_raw = TxRaw {
+        body_bytes: tx_body.into_bytes()?,
+        auth_info_bytes: auth_info.encode_to_vec(),
+        signatures: vec![MultiSignature { signatures }.encode_to_vec()],
+    };
+    Ok(tx_raw.encode_to_vec())
+}
+
+#[cfg(test)]
+mod multisig_tests {
+    use super::*;
+    use crate::{Network, SingleCoin};
+    use cosmrs::tx::{SignerPublicKey, Tx};
+
+    fn tx_info() -> CosmosSDKTxInfo {
+        CosmosSDKTxInfo {
+            account_number: 1,
+            sequence_number: 0,
+            gas_limit: 200_000,
+            fee_amount: vec![SingleCoin::UATOM { amount: 5000 }],
+            timeout_height: 0,
+            memo_note: None,
+            network: Network::CosmosHub,
+            payer: None,
+            granter: None,
+        }
+    }
+
+    fn msgs() -> Vec<CosmosSDKMsg> {
+        vec![CosmosSDKMsg::BankSend {
+            recipient_address: "cosmos1l5s7tnj28a7zxeeckhgwlhjys8dlrrefgqr4pj".to_owned(),
+            amount: SingleCoin::UATOM { amount: 1000 },
+        }]
+    }
+
+    #[test]
+    fn test_multisig_public_key() {
+        let key = SecretKey::new();
+        let public_key = key.get_public_key_bytes();
+        let multisig = MultisigPublicKey::new(1, vec![public_key.clone()]).unwrap();
+
+        let mut expected = vec![0x22, 0xc1, 0xf7, 0xe2, 0x08, 0x01, 0x12, 0x26];
+        expected.extend_from_slice(&[0xeb, 0x5a, 0xe9, 0x87, 0x21]);
+        expected.extend_from_slice(&public_key);
+        assert_eq!(multisig.to_amino_bytes(), expected);
+        assert!(multisig
+            .get_address("cosmos")
+            .unwrap()
+            .starts_with("cosmos1"));
+
+        assert!(MultisigPublicKey::new(0, vec![public_key.clone()]).is_err());
+        assert!(MultisigPublicKey::new(2, vec![public_key.clone()]).is_err());
+        assert!(MultisigPublicKey::new(1, vec![public_key.clone(), public_key]).is_err());
+        assert!(MultisigPublicKey::new(1, vec![vec![0; 33]]).is_err());
+    }
+
+    #[test]
+    fn test_multisig_signing() {
+        let keys: Vec<Arc<SecretKey>> = (0..3).map(|_| Arc::new(SecretKey::new())).collect();
+        let multisig = Arc::new(
+            MultisigPublicKey::new(
+                2,
+                keys.iter().map(|key| key.get_public_key_bytes()).collect(),
+            )
+            .unwrap(),
+        );
+
+        let partial_signatures: Vec<MultisigPartialSignature> = [&keys[0], &keys[2]]
+            .iter()
+            .map(|key| {
+                build_multisig_partial_signature(
+                    tx_info(),
+                    msgs(),
+                    multisig.clone(),
+                    (*key).clone(),
+                )
+                .unwrap()
+            })
+            .collect();
+
+        // below the threshold
+        assert!(build_signed_multisig_tx(
+            tx_info(),
+            msgs(),
+            multisig.clone(),
+            partial_signatures[..1].to_vec()
+        )
+        .is_err());
+        // not a member
+        let other_key = Arc::new(SecretKey::new());
+        assert!(
+            build_multisig_partial_signature(tx_info(), msgs(), multisig.clone(), other_key)
+                .is_err()
+        );
+
+        let tx_raw =
+            build_signed_multisig_tx(tx_info(), msgs(), multisig.clone(), partial_signatures)
+                .unwrap();
+        let tx = Tx::from_bytes(&tx_raw).unwrap();
+        match &tx.auth_info.signer_infos[0].public_key {
+            Some(SignerPublicKey::LegacyAminoMultisig(key)) => {
+                assert_eq!(key.threshold, 2);
+                assert_eq!(key.public_keys.len(), 3);
+            }
+            _ => panic!("expected the multisig public key"),
+        }
+
+        let raw = TxRaw::decode(tx_raw.as_slice()).unwrap();
+        let multi_signature = MultiSignature::decode(raw.signatures[0].as_slice()).unwrap();
+        assert_eq!(multi_signature.signatures.len(), 2);
+        let auth_info = AuthInfo::decode(raw.auth_info_bytes.as_slice()).unwrap();
+        match auth_info.signer_infos[0]
+            .mode_info
+            .as_ref()
+            .and_then(|mode_info| mode_info.sum.as_ref())
+        {
+            Some(mode_info::Sum::Multi(multi)) => {
+                let bitarray = multi.bitarray.as_ref().unwrap();
+                assert_eq!(bitarray.extra_bits_stored, 3);
+                assert_eq!(bitarray.elems, vec![0b1010_0000]);
+                assert_eq!(multi.mode_infos.len(), 2);
+            }
+            _ => panic!("expected the multi mode info"),
+        }
+    }
+
+    #[test]
+    fn test_invalid_partial_signature() {
+        let keys: Vec<Arc<SecretKey>> = (0..2).map(|_| Arc::new(SecretKey::new())).collect();
+        let multisig = Arc::new(
+            MultisigPublicKey::new(
+                1,
+                keys.iter().map(|key| key.get_public_key_bytes()).collect(),
+            )
+            .unwrap(),
+        );
+        let mut partial_signature =
+            build_multisig_partial_signature(tx_info(), msgs(), multisig.clone(), keys[0].clone())
+                .unwrap();
+        partial_signature.public_key = keys[1].get_public_key_bytes();
+        assert!(
+            build_signed_multisig_tx(tx_info(), msgs(), multisig, vec![partial_signature]).is_err()
+        );
+    }
+}
@@ -2,7 +2,7 @@
 // parsed instances could be encoded to a JSON string for display, and `CosmosRawMsg`s could be
 // used to build a new transaction.
 
-use crate::transaction::cosmos_sdk::CosmosError;
+use crate::transaction::cosmos_sdk::{parse_amino_json_sign_doc, CosmosError};
 use crate::utils::hex_decode;
 use cosmos_sdk_proto::traits::Message;
 use cosmrs::tx::{AuthInfo, Body};
@@ -14,6 +14,7 @@ mod luna_classic_parser;
 mod structs;
 mod uniffi_binding;
 
+pub use base_parser::BaseParser;
 pub use structs::*;
 #[cfg(feature = "uniffi-binding")]
 pub use uniffi_binding::*;
@@ -49,6 +50,14 @@ pub trait CosmosParser {
         Ok(tx_body)
     }
 
+    /// Parse `CosmosAminoSignDoc` from a legacy Amino JSON sign doc (e.g. requested by a dApp).
+    fn parse_amino_json_sign_doc(
+        &self,
+        json_string: &str,
+    ) -> Result<CosmosAminoSignDoc, CosmosError> {
+        parse_amino_json_sign_doc(json_string)
+    }
+
     /// Parse `CosmosRawMsg` from data of proto JSON mapping.
     fn parse_proto_json_msg(&self, json_string: &str) -> Result<CosmosRawMsg, CosmosError>;
 
@@ -1,10 +1,26 @@
-use crate::transaction::cosmos_sdk::parser::structs::{CosmosRawMsg, CosmosTxBody};
+use crate::transaction::authz::{
+    MSG_EXEC_TYPE_URL, MSG_GRANT_ALLOWANCE_TYPE_URL, MSG_GRANT_TYPE_URL,
+    MSG_REVOKE_ALLOWANCE_TYPE_URL, MSG_REVOKE_TYPE_URL,
+};
+use crate::transaction::cosmos_sdk::parser::structs::{
+    CosmosRawMsg, CosmosRawNormalMsg, CosmosTxBody,
+};
 use crate::transaction::cosmos_sdk::parser::CosmosParser;
 use crate::transaction::cosmos_sdk::CosmosError;
+use crate::transaction::gov::{
+    MSG_DEPOSIT_TYPE_URL, MSG_SUBMIT_PROPOSAL_TYPE_URL, MSG_VOTE_TYPE_URL,
+    MSG_VOTE_WEIGHTED_TYPE_URL,
+};
+use crate::transaction::ibc_transfer::decode_ibc_transfer_memo;
+use cosmos_sdk_proto::cosmos::authz::v1beta1::{MsgExec, MsgGrant, MsgRevoke};
 use cosmos_sdk_proto::cosmos::bank::v1beta1::MsgSend;
 use cosmos_sdk_proto::cosmos::distribution::v1beta1::{
     MsgSetWithdrawAddress, MsgWithdrawDelegatorReward,
 };
+use cosmos_sdk_proto::cosmos::feegrant::v1beta1::{MsgGrantAllowance, MsgRevokeAllowance};
+use cosmos_sdk_proto::cosmos::gov::v1beta1::{
+    MsgDeposit, MsgSubmitProposal, MsgVote, MsgVoteWeighted,
+};
 use cosmos_sdk_proto::cosmos::staking::v1beta1::{MsgBeginRedelegate, MsgDelegate, MsgUndelegate};
 use cosmos_sdk_proto::traits::{Message, TypeUrl};
 use eyre::WrapErr;
@@ -56,9 +72,63 @@ fn transform_msg(msg: &CosmosRawMsg) -> Result<CosmosRawMsg, CosmosError> {
                     .wrap_err("Failed to decode MsgWithdrawDelegatorReward from Protobuf")?
                     .into()
             }
-            transfer::TYPE_URL => transfer::MsgTransfer::decode(value.as_slice())
-                .wrap_err("Failed to decode MsgTransfer from Protobuf")?
+            transfer::TYPE_URL => (
+                transfer::MsgTransfer::decode(value.as_slice())
+                    .wrap_err("Failed to decode MsgTransfer from Protobuf")?,
+                decode_ibc_transfer_memo(value)
+                    .wrap_err("Failed to decode MsgTransfer memo from Protobuf")?,
+            )
+                .try_into()?,
+            MSG_VOTE_TYPE_URL => MsgVote::decode(value.as_slice())
+                .wrap_err("Failed to decode MsgVote from Protobuf")?
+                .try_into()?,
+            MSG_VOTE_WEIGHTED_TYPE_URL => MsgVoteWeighted::decode(value.as_slice())
+                .wrap_err("Failed to decode MsgVoteWeighted from Protobuf")?
                 .try_into()?,
+            MSG_DEPOSIT_TYPE_URL => MsgDeposit::decode(value.as_slice())
+                .wrap_err("Failed to decode MsgDeposit from Protobuf")?
+                .into(),
+            // keep the `Any` message if its proposal content is not supported
+            MSG_SUBMIT_PROPOSAL_TYPE_URL => MsgSubmitProposal::decode(value.as_slice())
+                .wrap_err("Failed to decode MsgSubmitProposal from Protobuf")?
+                .try_into()
+                .unwrap_or_else(|_| msg.clone()),
+            // keep the `Any` message if its authorization is not supported
+            MSG_GRANT_TYPE_URL => MsgGrant::decode(value.as_slice())
+                .wrap_err("Failed to decode MsgGrant from Protobuf")?
+                .try_into()
+                .unwrap_or_else(|_| msg.clone()),
+            MSG_EXEC_TYPE_URL => {
+                let msg_exec = MsgExec::decode(value.as_slice())
+                    .wrap_err("Failed to decode MsgExec from Protobuf")?;
+                let msgs = msg_exec
+                    .msgs
+                    .into_iter()
+                    .map(|any| {
+                        transform_msg(&CosmosRawMsg::Any {
+                            type_url: any.type_url,
+                            value: any.value,
+                        })
+                    })
+                    .collect::<Result<_, _>>()?;
+                CosmosRawMsg::Normal {
+                    msg: CosmosRawNormalMsg::AuthzExec {
+                        grantee: msg_exec.grantee,
+                        msgs,
+                    },
+                }
+            }
+            MSG_REVOKE_TYPE_URL => MsgRevoke::decode(value.as_slice())
+                .wrap_err("Failed to decode MsgRevoke from Protobuf")?
+                .into(),
+            // keep the `Any` message if its allowance is not supported
+            MSG_GRANT_ALLOWANCE_TYPE_URL => MsgGrantAllowance::decode(value.as_slice())
+                .wrap_err("Failed to decode MsgGrantAllowance from Protobuf")?
+                .try_into()
+                .unwrap_or_else(|_| msg.clone()),
+            MSG_REVOKE_ALLOWANCE_TYPE_URL => MsgRevokeAllowance::decode(value.as_slice())
+                .wrap_err("Failed to decode MsgRevokeAllowance from Protobuf")?
+                .into(),
             _ => msg.clone(),
         })
     } else {
@@ -71,6 +141,7 @@ mod cosmos_base_parsing_tests {
     use super::*;
     use crate::transaction::cosmos_sdk::parser::structs::{CosmosRawMsg, CosmosRawNormalMsg};
     use crate::transaction::cosmos_sdk::SingleCoin;
+    use crate::transaction::gov::{VoteOption, WeightedVoteOption};
 
     #[test]
     fn test_proto_json_msg_parsing() {
@@ -94,6 +165,150 @@ mod cosmos_base_parsing_tests {
         );
     }
 
+    #[test]
+    fn test_gov_msg_parsing() {
+        let json_msg = r#"{"@type":"/cosmos.gov.v1beta1.MsgVoteWeighted","proposal_id":"82","voter":"cosmos1pkptre7fdkl6gfrzlesjjvhxhlc3r4gmmk8rs6","options":[{"option":"VOTE_OPTION_YES","weight":"0.700000000000000000"},{"option":"VOTE_OPTION_NO","weight":"0.300000000000000000"}]}"#;
+
+        let parser = BaseParser {};
+        let msg = parser.parse_proto_json_msg(json_msg).unwrap();
+        let expected = CosmosRawMsg::Normal {
+            msg: CosmosRawNormalMsg::GovVoteWeighted {
+                proposal_id: 82,
+                voter: "cosmos1pkptre7fdkl6gfrzlesjjvhxhlc3r4gmmk8rs6".to_string(),
+                options: vec![
+                    WeightedVoteOption {
+                        option: VoteOption::Yes,
+                        weight: "0.700000000000000000".to_string(),
+                    },
+                    WeightedVoteOption {
+                        option: VoteOption::No,
+                        weight: "0.300000000000000000".to_string(),
+                    },
+                ],
+            },
+        };
+        assert_eq!(msg, expected);
+
+        // the Protobuf encoding is decoded back to the same message
+        let any = msg.to_any().unwrap();
+        assert_eq!(
+            transform_msg(&CosmosRawMsg::Any {
+                type_url: any.type_url,
+                value: any.value,
+            })
+            .unwrap(),
+            expected
+        );
+
+        // the proposals with unsupported content are kept as `Any`
+        let msg = CosmosRawMsg::Any {
+            type_url: MSG_SUBMIT_PROPOSAL_TYPE_URL.to_string(),
+            value: MsgSubmitProposal {
+                content: Some(cosmrs::Any {
+                    type_url: "/cosmos.upgrade.v1beta1.SoftwareUpgradeProposal".to_string(),
+                    value: vec![],
+                }),
+                initial_deposit: vec![],
+                proposer: "cosmos1pkptre7fdkl6gfrzlesjjvhxhlc3r4gmmk8rs6".to_string(),
+            }
+            .encode_to_vec(),
+        };
+        assert_eq!(transform_msg(&msg).unwrap(), msg);
+    }
+
+    #[test]
+    fn test_authz_msg_parsing() {
+        let grantee = "cosmos1pkptre7fdkl6gfrzlesjjvhxhlc3r4gmmk8rs6";
+        let granter = "cosmos1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5lzv7xu";
+        let msg_send = MsgSend {
+            from_address: granter.to_string(),
+            to_address: grantee.to_string(),
+            amount: vec![],
+        };
+        let msg_exec = MsgExec {
+            grantee: grantee.to_string(),
+            msgs: vec![
+                cosmrs::Any {
+                    type_url: MsgSend::TYPE_URL.to_string(),
+                    value: msg_send.encode_to_vec(),
+                },
+                cosmrs::Any {
+                    type_url: "/cosmos.unknown.v1beta1.MsgUnknown".to_string(),
+                    value: vec![1, 2, 3],
+                },
+            ],
+        };
+        let msg = CosmosRawMsg::Any {
+            type_url: MSG_EXEC_TYPE_URL.to_string(),
+            value: msg_exec.encode_to_vec(),
+        };
+
+        // the executed messages are transformed as well
+        let expected = CosmosRawMsg::Normal {
+            msg: CosmosRawNormalMsg::AuthzExec {
+                grantee: grantee.to_string(),
+                msgs: vec![
+                    CosmosRawMsg::Normal {
+                        msg: CosmosRawNormalMsg::BankSend {
+                            from_address: granter.to_string(),
+                            to_address: grantee.to_string(),
+                            amount: vec![],
+                        },
+                    },
+                    CosmosRawMsg::Any {
+                        type_url: "/cosmos.unknown.v1beta1.MsgUnknown".to_string(),
+                        value: vec![1, 2, 3],
+                    },
+                ],
+            },
+        };
+        let parsed = transform_msg(&msg).unwrap();
+        assert_eq!(parsed, expected);
+        assert_eq!(parsed.to_any().unwrap().value, msg_exec.encode_to_vec());
+
+        let json_msg = r#"{"@type":"/cosmos.feegrant.v1beta1.MsgRevokeAllowance","granter":"cosmos1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5lzv7xu","grantee":"cosmos1pkptre7fdkl6gfrzlesjjvhxhlc3r4gmmk8rs6"}"#;
+        let parser = BaseParser {};
+        assert_eq!(
+            parser.parse_proto_json_msg(json_msg).unwrap(),
+            CosmosRawMsg::Normal {
+                msg: CosmosRawNormalMsg::FeegrantRevokeAllowance {
+                    granter: granter.to_string(),
+                    grantee: grantee.to_string(),
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn test_ibc_transfer_memo_parsing() {
+        let expected = CosmosRawMsg::Normal {
+            msg: CosmosRawNormalMsg::IbcTransfer {
+                sender: "cosmos1pkptre7fdkl6gfrzlesjjvhxhlc3r4gmmk8rs6".to_string(),
+                receiver: "osmo1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5afv7ye".to_string(),
+                source_port: "transfer".to_string(),
+                source_channel: "channel-141".to_string(),
+                token: SingleCoin::Other {
+                    amount: "1000".to_string(),
+                    denom: "uosmo".to_string(),
+                },
+                timeout_height: Default::default(),
+                timeout_timestamp: 1645800000000000000,
+                memo: r#"{"forward":{"receiver":"cro1l5s7tnj28a7zxeeckhgwlhjys8dlrrefsmtvar","port":"transfer","channel":"channel-5"}}"#.to_string(),
+            },
+        };
+
+        // the memo is kept in the Protobuf encoding
+        let any = expected.to_any().unwrap();
+        assert_eq!(
+            transform_msg(&CosmosRawMsg::Any {
+                type_url: any.type_url,
+                value: any.value,
+            })
+            .unwrap(),
+            expected
+        );
+    }
+
     #[test]
     fn test_protobuf_tx_body_parsing() {
         let tx_body_bytes = "0a90010a1c2f636f736d6f732e62616e6b2e763162657461312e4d736753656e6412700a2d636f736d6f7331706b707472653766646b6c366766727a6c65736a6a766878686c63337234676d6d6b38727336122d636f736d6f7331717970717870713971637273737a673270767871367273307a716733797963356c7a763778751a100a0575636f736d120731323334353637";
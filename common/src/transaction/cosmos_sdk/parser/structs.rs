@@ -215,6 +215,34 @@ pub struct CosmosTxBody {
     pub non_critical_extension_options: Vec<CosmosAny>,
 }
 
+/// Legacy Amino JSON sign doc (`StdSignDoc`) for displaying to the user before signing.
+#[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct CosmosAminoSignDoc {
+    /// Chain ID
+    pub chain_id: String,
+    /// Account number
+    pub account_number: u64,
+    /// Sequence
+    pub sequence: u64,
+    /// Fee and gas limit
+    pub fee: CosmosFee,
+    /// Memo
+    pub memo: String,
+    /// Timeout (0 if not set)
+    pub timeout_height: u64,
+    /// Message list
+    pub msgs: Vec<CosmosAminoMsg>,
+}
+
+/// Legacy Amino JSON message.
+#[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct CosmosAminoMsg {
+    /// Amino type name (e.g. `cosmos-sdk/MsgSend`)
+    pub msg_type: String,
+    /// Pretty-printed JSON value
+    pub value: String,
+}
+
 // This conversion directly transforms messages to type `CosmosRawMsg::Any`. The detailed messages
 // (as `BankSend`) should be transformed in specified parser.
 impl From<Body> for CosmosTxBody {
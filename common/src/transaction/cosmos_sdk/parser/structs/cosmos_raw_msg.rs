@@ -1,10 +1,19 @@
 use crate::proto::chainmain;
+use crate::transaction::authz::{
+    get_exec_any, get_grant_allowance_any, get_grant_any, get_revoke_allowance_any, get_revoke_any,
+    Authorization, FeeAllowance,
+};
 use crate::transaction::cosmos_sdk::{CosmosError, SingleCoin};
+use crate::transaction::gov::{
+    atomics_to_decimal, get_deposit_any, get_submit_proposal_any, get_vote_any,
+    get_vote_weighted_any, ProposalContent, VoteOption, WeightedVoteOption,
+};
+use crate::transaction::ibc_transfer::append_ibc_transfer_memo;
 use crate::transaction::nft::{
     DenomId, DenomName, MsgBurnNft, MsgEditNft, MsgIssueDenom, MsgMintNft, MsgTransferNft, TokenId,
     TokenUri,
 };
-use cosmos_sdk_proto::cosmos::{bank, distribution, staking};
+use cosmos_sdk_proto::cosmos::{authz, bank, distribution, feegrant, gov, staking};
 use cosmrs::bank::MsgSend;
 use cosmrs::distribution::{MsgSetWithdrawAddress, MsgWithdrawDelegatorReward};
 use cosmrs::staking::{MsgBeginRedelegate, MsgDelegate, MsgUndelegate};
@@ -137,10 +146,11 @@ impl From<distribution::v1beta1::MsgWithdrawDelegatorReward> for CosmosRawMsg {
     }
 }
 
-impl TryFrom<MsgTransfer> for CosmosRawMsg {
+/// `MsgTransfer` and its memo (not in the `ibc` 0.31 domain type)
+impl TryFrom<(MsgTransfer, String)> for CosmosRawMsg {
     type Error = CosmosError;
 
-    fn try_from(msg: MsgTransfer) -> Result<Self, Self::Error> {
+    fn try_from((msg, memo): (MsgTransfer, String)) -> Result<Self, Self::Error> {
         Ok(Self::Normal {
             msg: CosmosRawNormalMsg::IbcTransfer {
                 sender: msg.sender.to_string(),
@@ -153,11 +163,140 @@ impl TryFrom<MsgTransfer> for CosmosRawMsg {
                     TimeoutHeight::At(height) => height.into(),
                 },
                 timeout_timestamp: msg.timeout_timestamp_on_b.nanoseconds(),
+                memo,
+            },
+        })
+    }
+}
+
+impl TryFrom<gov::v1beta1::MsgVote> for CosmosRawMsg {
+    type Error = CosmosError;
+
+    fn try_from(msg: gov::v1beta1::MsgVote) -> Result<Self, Self::Error> {
+        Ok(Self::Normal {
+            msg: CosmosRawNormalMsg::GovVote {
+                proposal_id: msg.proposal_id,
+                voter: msg.voter,
+                option: msg.option.try_into()?,
+            },
+        })
+    }
+}
+
+impl TryFrom<gov::v1beta1::MsgVoteWeighted> for CosmosRawMsg {
+    type Error = CosmosError;
+
+    fn try_from(msg: gov::v1beta1::MsgVoteWeighted) -> Result<Self, Self::Error> {
+        let options = msg
+            .options
+            .into_iter()
+            .map(|option| {
+                Ok(WeightedVoteOption {
+                    option: option.option.try_into()?,
+                    weight: atomics_to_decimal(&option.weight)?,
+                })
+            })
+            .collect::<Result<_, CosmosError>>()?;
+        Ok(Self::Normal {
+            msg: CosmosRawNormalMsg::GovVoteWeighted {
+                proposal_id: msg.proposal_id,
+                voter: msg.voter,
+                options,
+            },
+        })
+    }
+}
+
+impl From<gov::v1beta1::MsgDeposit> for CosmosRawMsg {
+    fn from(msg: gov::v1beta1::MsgDeposit) -> Self {
+        Self::Normal {
+            msg: CosmosRawNormalMsg::GovDeposit {
+                proposal_id: msg.proposal_id,
+                depositor: msg.depositor,
+                amount: msg.amount.into_iter().map(Into::into).collect(),
+            },
+        }
+    }
+}
+
+impl TryFrom<gov::v1beta1::MsgSubmitProposal> for CosmosRawMsg {
+    type Error = CosmosError;
+
+    fn try_from(msg: gov::v1beta1::MsgSubmitProposal) -> Result<Self, Self::Error> {
+        let content = msg
+            .content
+            .ok_or_else(|| eyre::eyre!("Missing content of MsgSubmitProposal"))?;
+        Ok(Self::Normal {
+            msg: CosmosRawNormalMsg::GovSubmitProposal {
+                content: ProposalContent::try_from(&content)?,
+                initial_deposit: msg.initial_deposit.into_iter().map(Into::into).collect(),
+                proposer: msg.proposer,
+            },
+        })
+    }
+}
+
+impl TryFrom<authz::v1beta1::MsgGrant> for CosmosRawMsg {
+    type Error = CosmosError;
+
+    fn try_from(msg: authz::v1beta1::MsgGrant) -> Result<Self, Self::Error> {
+        let grant = msg
+            .grant
+            .ok_or_else(|| eyre::eyre!("Missing grant of MsgGrant"))?;
+        let authorization = grant
+            .authorization
+            .ok_or_else(|| eyre::eyre!("Missing authorization of MsgGrant"))?;
+        Ok(Self::Normal {
+            msg: CosmosRawNormalMsg::AuthzGrant {
+                granter: msg.granter,
+                grantee: msg.grantee,
+                authorization: Authorization::try_from(&authorization)?,
+                expiration: grant.expiration.map(|time| time.seconds),
             },
         })
     }
 }
 
+impl From<authz::v1beta1::MsgRevoke> for CosmosRawMsg {
+    fn from(msg: authz::v1beta1::MsgRevoke) -> Self {
+        Self::Normal {
+            msg: CosmosRawNormalMsg::AuthzRevoke {
+                granter: msg.granter,
+                grantee: msg.grantee,
+                msg_type_url: msg.msg_type_url,
+            },
+        }
+    }
+}
+
+impl TryFrom<feegrant::v1beta1::MsgGrantAllowance> for CosmosRawMsg {
+    type Error = CosmosError;
+
+    fn try_from(msg: feegrant::v1beta1::MsgGrantAllowance) -> Result<Self, Self::Error> {
+        let allowance = msg
+            .allowance
+            .ok_or_else(|| eyre::eyre!("Missing allowance of MsgGrantAllowance"))?;
+        Ok(Self::Normal {
+            msg: CosmosRawNormalMsg::FeegrantGrantAllowance {
+                granter: msg.granter,
+                grantee: msg.grantee,
+                allowance: FeeAllowance::try_from(&allowance)?,
+            },
+        })
+    }
+}
+
+impl From<feegrant::v1beta1::MsgRevokeAllowance> for CosmosRawMsg {
+    fn from(msg: feegrant::v1beta1::MsgRevokeAllowance) -> Self {
+        Self::Normal {
+            msg: CosmosRawNormalMsg::FeegrantRevokeAllowance {
+                granter: msg.granter,
+                grantee: msg.grantee,
+            },
+        }
+    }
+}
+
 impl From<chainmain::nft::v1::MsgIssueDenom> for CosmosRawMsg {
     fn from(msg: chainmain::nft::v1::MsgIssueDenom) -> Self {
         Self::CryptoOrg {
@@ -307,6 +446,100 @@ pub enum CosmosRawNormalMsg {
         /// Timeout timestamp (in nanoseconds) relative to the current block timestamp.
         /// The timeout is disabled when set to 0.
         timeout_timestamp: u64,
+        /// the memo (e.g. a packet forward middleware JSON memo)
+        #[serde(default)]
+        memo: String,
+    },
+    /// MsgVote
+    #[serde(rename = "/cosmos.gov.v1beta1.MsgVote")]
+    GovVote {
+        /// the proposal to vote on
+        #[serde(deserialize_with = "super::deserialize_from_str")]
+        proposal_id: u64,
+        /// voter address in bech32
+        voter: String,
+        /// the vote option
+        option: VoteOption,
+    },
+    /// MsgVoteWeighted
+    #[serde(rename = "/cosmos.gov.v1beta1.MsgVoteWeighted")]
+    GovVoteWeighted {
+        /// the proposal to vote on
+        #[serde(deserialize_with = "super::deserialize_from_str")]
+        proposal_id: u64,
+        /// voter address in bech32
+        voter: String,
+        /// the weighted vote options
+        options: Vec<WeightedVoteOption>,
+    },
+    /// MsgDeposit
+    #[serde(rename = "/cosmos.gov.v1beta1.MsgDeposit")]
+    GovDeposit {
+        /// the proposal to deposit on
+        #[serde(deserialize_with = "super::deserialize_from_str")]
+        proposal_id: u64,
+        /// depositor address in bech32
+        depositor: String,
+        /// amount to deposit
+        amount: Vec<SingleCoin>,
+    },
+    /// MsgSubmitProposal
+    #[serde(rename = "/cosmos.gov.v1beta1.MsgSubmitProposal")]
+    GovSubmitProposal {
+        /// the proposal content
+        content: ProposalContent,
+        /// the initial deposit
+        initial_deposit: Vec<SingleCoin>,
+        /// proposer address in bech32
+        proposer: String,
+    },
+    /// MsgGrant
+    #[serde(rename = "/cosmos.authz.v1beta1.MsgGrant")]
+    AuthzGrant {
+        /// granter address in bech32
+        granter: String,
+        /// grantee address in bech32
+        grantee: String,
+        /// the granted authorization
+        authorization: Authorization,
+        /// the expiration time in seconds since the Unix epoch
+        expiration: Option<i64>,
+    },
+    /// MsgExec
+    #[serde(rename = "/cosmos.authz.v1beta1.MsgExec")]
+    AuthzExec {
+        /// grantee address in bech32
+        grantee: String,
+        /// the messages executed on behalf of the granter
+        msgs: Vec<CosmosRawMsg>,
+    },
+    /// MsgRevoke
+    #[serde(rename = "/cosmos.authz.v1beta1.MsgRevoke")]
+    AuthzRevoke {
+        /// granter address in bech32
+        granter: String,
+        /// grantee address in bech32
+        grantee: String,
+        /// the message type URL of the revoked authorization
+        msg_type_url: String,
+    },
+    /// MsgGrantAllowance
+    #[serde(rename = "/cosmos.feegrant.v1beta1.MsgGrantAllowance")]
+    FeegrantGrantAllowance {
+        /// granter address in bech32
+        granter: String,
+        /// grantee address in bech32
+        grantee: String,
+        /// the granted fee allowance
+        allowance: FeeAllowance,
+    },
+    /// MsgRevokeAllowance
+    #[serde(rename = "/cosmos.feegrant.v1beta1.MsgRevokeAllowance")]
+    FeegrantRevokeAllowance {
+        /// granter address in bech32
+        granter: String,
+        /// grantee address in bech32
+        grantee: String,
     },
 }
 
@@ -382,6 +615,7 @@ impl CosmosRawNormalMsg {
                 token,
                 timeout_height,
                 timeout_timestamp,
+                memo,
             } => {
                 let any = MsgTransfer {
                     sender: Signer::from_str(sender).map_err(|e| eyre::eyre!("{e}"))?,
@@ -397,6 +631,8 @@ impl CosmosRawNormalMsg {
                         .map_err(|e| eyre::eyre!("{e}"))?,
                 }
                 .to_any();
+                let mut value = any.value;
+                append_ibc_transfer_memo(&mut value, memo);
                 // FIXME:
                 // ibc-proto used Google's Protobuf type definitions instead of
                 // prost_types in `0.17`. But cosmrs still used prost_types. So
@@ -405,9 +641,81 @@ impl CosmosRawNormalMsg {
                 // https://github.com/cosmos/cosmos-rust/issues/185
                 Ok(cosmrs::Any {
                     type_url: any.type_url,
-                    value: any.value,
+                    value,
                 })
             }
+            Self::GovVote {
+                proposal_id,
+                voter,
+                option,
+            } => Ok(get_vote_any(
+                *proposal_id,
+                voter.parse::<AccountId>()?.as_ref(),
+                *option,
+            )),
+            Self::GovVoteWeighted {
+                proposal_id,
+                voter,
+                options,
+            } => get_vote_weighted_any(*proposal_id, voter.parse::<AccountId>()?.as_ref(), options),
+            Self::GovDeposit {
+                proposal_id,
+                depositor,
+                amount,
+            } => get_deposit_any(
+                *proposal_id,
+                depositor.parse::<AccountId>()?.as_ref(),
+                amount,
+            ),
+            Self::GovSubmitProposal {
+                content,
+                initial_deposit,
+                proposer,
+            } => get_submit_proposal_any(
+                content,
+                initial_deposit,
+                proposer.parse::<AccountId>()?.as_ref(),
+            ),
+            Self::AuthzGrant {
+                granter,
+                grantee,
+                authorization,
+                expiration,
+            } => get_grant_any(
+                granter.parse::<AccountId>()?.as_ref(),
+                grantee.parse::<AccountId>()?.as_ref(),
+                authorization,
+                *expiration,
+            ),
+            Self::AuthzExec { grantee, msgs } => {
+                let msgs = msgs
+                    .iter()
+                    .map(CosmosRawMsg::to_any)
+                    .collect::<eyre::Result<_>>()?;
+                Ok(get_exec_any(grantee.parse::<AccountId>()?.as_ref(), msgs))
+            }
+            Self::AuthzRevoke {
+                granter,
+                grantee,
+                msg_type_url,
+            } => Ok(get_revoke_any(
+                granter.parse::<AccountId>()?.as_ref(),
+                grantee.parse::<AccountId>()?.as_ref(),
+                msg_type_url,
+            )),
+            Self::FeegrantGrantAllowance {
+                granter,
+                grantee,
+                allowance,
+            } => get_grant_allowance_any(
+                granter.parse::<AccountId>()?.as_ref(),
+                grantee.parse::<AccountId>()?.as_ref(),
+                allowance,
+            ),
+            Self::FeegrantRevokeAllowance { granter, grantee } => Ok(get_revoke_allowance_any(
+                granter.parse::<AccountId>()?.as_ref(),
+                grantee.parse::<AccountId>()?.as_ref(),
+            )),
         }
     }
 }
@@ -4,7 +4,7 @@ use crate::transaction::cosmos_sdk::parser::base_parser::BaseParser;
 use crate::transaction::cosmos_sdk::parser::crypto_org_parser::CryptoOrgParser;
 use crate::transaction::cosmos_sdk::parser::luna_classic_parser::LunaClassicParser;
 use crate::transaction::cosmos_sdk::parser::structs::{
-    CosmosAuthInfo, CosmosFee, CosmosRawMsg, CosmosTxBody,
+    CosmosAminoSignDoc, CosmosAuthInfo, CosmosFee, CosmosRawMsg, CosmosTxBody,
 };
 use crate::transaction::cosmos_sdk::parser::CosmosParser;
 use crate::transaction::cosmos_sdk::CosmosError;
@@ -61,4 +61,23 @@ impl CosmosParserWrapper {
     pub fn parse_protobuf_tx_body(&self, hex_string: &str) -> Result<CosmosTxBody, CosmosError> {
         self.inner.parse_protobuf_tx_body(hex_string)
     }
+
+    /// Parse `CosmosAminoSignDoc` from a legacy Amino JSON sign doc.
+    pub fn parse_amino_json_sign_doc(
+        &self,
+        json_string: &str,
+    ) -> Result<CosmosAminoSignDoc, CosmosError> {
+        self.inner.parse_amino_json_sign_doc(json_string)
+    }
+}
+
+/// the wrapped parser decodes the transactions of `TxHistoryClient`
+impl CosmosParser for CosmosParserWrapper {
+    fn parse_proto_json_msg(&self, json_string: &str) -> Result<CosmosRawMsg, CosmosError> {
+        self.inner.parse_proto_json_msg(json_string)
+    }
+
+    fn transform_tx_body(&self, tx_body: &mut CosmosTxBody) -> Result<(), CosmosError> {
+        self.inner.transform_tx_body(tx_body)
+    }
 }
@@ -1,8 +1,7 @@
-use crate::transaction::cosmos_sdk::CosmosError;
+use crate::transaction::cosmos_sdk::{get_amino_json_sign_bytes, CosmosError};
 use crate::utils::hex_decode;
-use crate::wallet::SecretKey;
-use cosmrs::crypto::secp256k1::SigningKey;
-use cosmrs::tx::SignDoc;
+use crate::wallet::{SecretKey, WalletSigner};
+use cosmrs::tx::{Raw, SignDoc};
 use ethers::utils::hex;
 use eyre::WrapErr;
 use std::sync::Arc;
@@ -10,13 +9,18 @@ use tendermint::chain;
 
 /// Cosmos Signer
 pub struct CosmosSigner {
-    secret_key: Arc<SecretKey>,
+    signer: Arc<dyn WalletSigner>,
 }
 
 impl CosmosSigner {
     /// Create an instance via a secret key.
     pub fn new(secret_key: Arc<SecretKey>) -> Self {
-        Self { secret_key }
+        Self { signer: secret_key }
+    }
+
+    /// Create an instance via a signer.
+    pub fn with_signer(signer: Arc<dyn WalletSigner>) -> Self {
+        Self { signer }
     }
 
     /// Sign the protobuf bytes directly.
@@ -42,10 +46,18 @@ impl CosmosSigner {
 
         let signed_bytes =
             CosmosProtoSignDoc::new(body_bytes, auth_info_bytes, chain_id, account_number)
-                .sign_into(&self.secret_key)?;
+                .sign_into(self.signer.as_ref())?;
 
         Ok(hex::encode(signed_bytes))
     }
+
+    /// Sign a legacy Amino JSON sign doc (`SIGN_MODE_LEGACY_AMINO_JSON`).
+    /// Return the 64-byte signature as a HEX string.
+    pub fn sign_amino(&self, sign_doc_json: &str) -> Result<String, CosmosError> {
+        let sign_bytes = get_amino_json_sign_bytes(sign_doc_json)?;
+        let signature = self.signer.sign_cosmos(&sign_bytes)?;
+        Ok(hex::encode(signature))
+    }
 }
 
 /// SignDoc for generating sign bytes from protobuf
@@ -73,9 +85,15 @@ impl CosmosProtoSignDoc {
 
     /// Sign this SignDoc and produce a Raw transaction. The protobuf bytes are
     /// moved out after calling this function.
-    fn sign_into(self, secret_key: &SecretKey) -> Result<Vec<u8>, CosmosError> {
-        let signing_key = SigningKey::new(Box::new(secret_key.get_signing_key()));
-        Ok(self.inner.sign(&signing_key)?.to_bytes()?)
+    fn sign_into(self, signer: &dyn WalletSigner) -> Result<Vec<u8>, CosmosError> {
+        let signature = signer.sign_cosmos(&self.inner.clone().into_bytes()?)?;
+        let raw: Raw = cosmrs::proto::cosmos::tx::v1beta1::TxRaw {
+            body_bytes: self.inner.body_bytes,
+            auth_info_bytes: self.inner.auth_info_bytes,
+            signatures: vec![signature],
+        }
+        .into();
+        Ok(raw.to_bytes()?)
     }
 }
 
@@ -83,6 +101,7 @@ impl CosmosProtoSignDoc {
 mod cosmos_signing_tests {
     use super::*;
     use crate::wallet::HDWallet;
+    use cosmrs::crypto::secp256k1::SigningKey;
 
     const MNEMONIC: &str = "apple elegant knife hawk there screen vehicle lounge tube sun engage bus custom market pioneer casual wink present cat metal ride shallow fork brief";
 
@@ -91,7 +110,7 @@ mod cosmos_signing_tests {
         let auth_info_bytes = "0a0a0a0012040a020801180112130a0d0a0575636f736d12043230303010c09a0c";
         let body_bytes = "0a90010a1c2f636f736d6f732e62616e6b2e763162657461312e4d736753656e6412700a2d636f736d6f7331706b707472653766646b6c366766727a6c65736a6a766878686c63337234676d6d6b38727336122d636f736d6f7331717970717870713971637273737a673270767871367273307a716733797963356c7a763778751a100a0575636f736d120731323334353637";
 
-        let wallet = HDWallet::recover_wallet(MNEMONIC.to_string(), None).unwrap();
+        let wallet = HDWallet::recover_wallet(MNEMONIC.to_string(), None, None).unwrap();
         let secret_key = wallet.get_key("m/44'/118'/0'/0/0".to_string()).unwrap();
         let signer = CosmosSigner::new(secret_key);
         let signature = signer
@@ -105,7 +124,7 @@ mod cosmos_signing_tests {
     fn test_direct_doc() {
         let mnemonic =
             "lumber flower voice hood obvious behave relax chief warm they they mountain";
-        let wallet = HDWallet::recover_wallet(mnemonic.to_string(), None).unwrap();
+        let wallet = HDWallet::recover_wallet(mnemonic.to_string(), None, None).unwrap();
         let secret_key = wallet.get_key("m/44'/118'/0'/0/0".to_string()).unwrap();
         let signing_key = SigningKey::new(Box::new(secret_key.get_signing_key()));
         let signer = CosmosSigner::new(secret_key);
@@ -133,4 +152,22 @@ mod cosmos_signing_tests {
         let signature = signing_key.sign(&signed_bytes).unwrap();
         assert_eq!(hex::encode(signature.to_vec()),"cc782d8685e320962a3b8379f32119056eab979c7e33f697519c50c0d60aef602c8e97c0155a6e1f99553a5a6bc39e513fe576ce43fa877a459c6c382aa03c2a");
     }
+
+    #[test]
+    fn test_amino_signing() {
+        let wallet = HDWallet::recover_wallet(MNEMONIC.to_string(), None, None).unwrap();
+        let secret_key = wallet.get_key("m/44'/118'/0'/0/0".to_string()).unwrap();
+        let signing_key = SigningKey::new(Box::new(secret_key.get_signing_key()));
+        let signer = CosmosSigner::new(secret_key);
+
+        // the keys are sorted and the whitespaces are removed before signing
+        let sign_doc = r#"{"sequence": "0", "msgs": [], "memo": "", "fee": {"gas": "1", "amount": []}, "chain_id": "cosmoshub-4", "account_number": "1"}"#;
+        let canonical_sign_doc = r#"{"account_number":"1","chain_id":"cosmoshub-4","fee":{"amount":[],"gas":"1"},"memo":"","msgs":[],"sequence":"0"}"#;
+        let signature = signing_key.sign(canonical_sign_doc.as_bytes()).unwrap();
+        assert_eq!(
+            signer.sign_amino(sign_doc).unwrap(),
+            hex::encode(signature.to_vec())
+        );
+        assert!(signer.sign_amino("{}").is_err());
+    }
 }
@@ -1,4 +1,4 @@
-use crate::{SecretKey, WalletCoin, WalletCoinFunc};
+use crate::{SecretKey, WalletSigner};
 use ethers::prelude::{
     Address, Chain, Eip1559TransactionRequest, Eip2930TransactionRequest, LocalWallet, Signer,
     TransactionRequest, U256,
@@ -172,16 +172,19 @@ pub fn build_signed_eth_tx(
     tx_info: EthTxInfo,
     network: EthNetwork,
     secret_key: Arc<SecretKey>,
+) -> Result<Vec<u8>, EthError> {
+    build_signed_eth_tx_with_signer(tx_info, network, secret_key.as_ref())
+}
+
+/// builds a signed ethereum transaction given the inputs and the signer (e.g. a Ledger device)
+pub fn build_signed_eth_tx_with_signer(
+    tx_info: EthTxInfo,
+    network: EthNetwork,
+    signer: &dyn WalletSigner,
 ) -> Result<Vec<u8>, EthError> {
     let (chain_id, legacy) = network.to_chain_params()?;
 
-    let from_address = WalletCoinFunc {
-        coin: WalletCoin::Ethereum {
-            network: EthNetwork::Mainnet,
-        },
-    }
-    .derive_address(secret_key.as_ref())
-    .map_err(|_| EthError::HexConversion)?;
+    let from_address = format!("{:?}", signer.get_eth_address()?);
     let mut tx: TypedTransaction = construct_simple_eth_transfer_tx(
         &from_address,
         &tx_info.to_address,
@@ -197,15 +200,7 @@ pub fn build_signed_eth_tx(
         tx.set_data(data.into());
     }
     tx.set_chain_id(chain_id);
-    let wallet = LocalWallet::from(
-        secret_key
-            .get_eth_signing_key()
-            .map_err(|_| EthError::SignatureError)?,
-    )
-    .with_chain_id(chain_id);
-    let sig: Signature = wallet
-        .sign_transaction_sync(&tx)
-        .map_err(|_| EthError::SignatureError)?;
+    let sig: Signature = signer.sign_eth_transaction(&tx)?;
     let signed_tx = &tx.rlp_signed(&sig);
     Ok(signed_tx.to_vec())
 }
@@ -419,7 +414,7 @@ mod tests {
     fn eip1559_tx_test() {
         let words = "lumber flower voice hood obvious behave relax chief warm they they mountain";
 
-        let wallet = HDWallet::recover_wallet(words.to_owned(), Some("".to_owned()))
+        let wallet = HDWallet::recover_wallet(words.to_owned(), Some("".to_owned()), None)
             .expect("Failed to recover wallet");
         let secret_key = wallet
             .get_key_from_index(
@@ -447,7 +442,7 @@ mod tests {
     fn eip2930_tx_test() {
         let words = "lumber flower voice hood obvious behave relax chief warm they they mountain";
 
-        let wallet = HDWallet::recover_wallet(words.to_owned(), Some("".to_owned()))
+        let wallet = HDWallet::recover_wallet(words.to_owned(), Some("".to_owned()), None)
             .expect("Failed to recover wallet");
         let secret_key = wallet
             .get_key_from_index(
@@ -467,7 +462,7 @@ mod tests {
     fn legacy_tx_test() {
         let words = "lumber flower voice hood obvious behave relax chief warm they they mountain";
 
-        let wallet = HDWallet::recover_wallet(words.to_owned(), Some("".to_owned()))
+        let wallet = HDWallet::recover_wallet(words.to_owned(), Some("".to_owned()), None)
             .expect("Failed to recover wallet");
         let secret_key = wallet
             .get_key_from_index(
@@ -574,7 +569,7 @@ mod tests {
     fn polygon_tx_test() {
         let words = "lumber flower voice hood obvious behave relax chief warm they they mountain";
 
-        let wallet = HDWallet::recover_wallet(words.to_owned(), Some("".to_owned()))
+        let wallet = HDWallet::recover_wallet(words.to_owned(), Some("".to_owned()), None)
             .expect("Failed to recover wallet");
         let secret_key = wallet
             .get_key_from_index(
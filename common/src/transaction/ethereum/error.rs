@@ -5,7 +5,7 @@ use ethers::prelude::{abi, Http, ParseChainError, Provider, ProviderError, Walle
 use ethers::types::transaction::eip712;
 use ethers::utils::ConversionError;
 
-use crate::HdWrapError;
+use crate::{HdWrapError, SignerError};
 
 /// Possible errors from Ethereum transaction construction and broadcasting
 #[derive(Debug, thiserror::Error)]
@@ -48,6 +48,8 @@ pub enum EthError {
     GetTransactionError(String),
     #[error("Signature error")]
     SignatureError,
+    #[error("Signer error: {0}")]
+    SignerError(#[from] SignerError),
     #[error("Chainid error: {0}")]
     ChainidError(#[from] ParseChainError),
     #[error("Incorrect chain name error: {0}")]
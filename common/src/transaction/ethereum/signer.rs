@@ -3,24 +3,25 @@
 
 use crate::node::ethereum::eip712::Eip712TypedData;
 use crate::transaction::ethereum::EthError;
-use crate::wallet::SecretKey;
-use ethers::prelude::{LocalWallet, H256};
-use ethers::utils::hash_message;
+use crate::wallet::{SecretKey, WalletSigner};
+use ethers::prelude::H256;
 use std::str::FromStr;
 use std::sync::Arc;
 
 /// Ethereum Signer
 pub struct EthSigner {
-    wallet: LocalWallet,
+    signer: Arc<dyn WalletSigner>,
 }
 
 impl EthSigner {
     /// Create an instance via a secret key.
     pub fn new(secret_key: Arc<SecretKey>) -> Self {
-        Self {
-            // TODO remove unwrap
-            wallet: secret_key.get_eth_signing_key().unwrap().into(),
-        }
+        Self { signer: secret_key }
+    }
+
+    /// Create an instance via a signer (e.g. a Ledger device).
+    pub fn with_signer(signer: Arc<dyn WalletSigner>) -> Self {
+        Self { signer }
     }
 
     /// Sign a hash value directly.
@@ -35,8 +36,8 @@ impl EthSigner {
         let hash = hash.strip_prefix("0x").unwrap_or(hash);
         let hash = H256::from_str(hash).map_err(|_| EthError::HexConversion)?;
         let signature = self
-            .wallet
-            .sign_hash(hash)
+            .signer
+            .sign_eth_hash(hash.0)
             .map_err(|_| EthError::SignatureError)?
             .to_string();
         Ok(format!("0x{signature}"))
@@ -44,11 +45,12 @@ impl EthSigner {
 
     /// Sign an arbitrary message as per EIP-191.
     /// Return a signature of hex string with prefix `0x`.
-    pub fn personal_sign(&self, message: &str) -> String {
-        let hash = hash_message(message);
-        // TODO : remove unwrap
-        let signature = self.wallet.sign_hash(hash).unwrap().to_string();
-        format!("0x{signature}")
+    pub fn personal_sign(&self, message: &str) -> Result<String, EthError> {
+        let signature = self
+            .signer
+            .sign_eth_message(message.as_bytes())?
+            .to_string();
+        Ok(format!("0x{signature}"))
     }
 
     /// Sign an EIP-712 typed data from a JSON string of specified schema as below. The field
@@ -94,10 +96,11 @@ impl EthSigner {
     ///   }
     /// }
     pub fn sign_typed_data(&self, json_typed_data: &str) -> Result<String, EthError> {
-        let encoded_data = Eip712TypedData::new(json_typed_data)?.encode()?;
+        let (domain_separator, struct_hash) =
+            Eip712TypedData::new(json_typed_data)?.encode_hashes()?;
         let signature = self
-            .wallet
-            .sign_hash(H256::from_slice(&encoded_data))
+            .signer
+            .sign_eth_typed_data(domain_separator, struct_hash)
             .map_err(|_| EthError::SignatureError)? // TODO: better error handling
             .to_string();
         Ok(format!("0x{signature}"))
@@ -178,7 +181,7 @@ mod ethereum_signing_tests {
         }"#;
 
     fn get_signer() -> EthSigner {
-        let wallet = HDWallet::recover_wallet(MNEMONIC.to_string(), None).unwrap();
+        let wallet = HDWallet::recover_wallet(MNEMONIC.to_string(), None, None).unwrap();
         let secret_key = wallet.get_key("m/44'/118'/0'/0/0".to_string()).unwrap();
         EthSigner::new(secret_key)
     }
@@ -193,7 +196,7 @@ mod ethereum_signing_tests {
 
     #[test]
     fn test_eip191_personal_sign() {
-        let signature = get_signer().personal_sign("Hello World!");
+        let signature = get_signer().personal_sign("Hello World!").unwrap();
         assert_eq!(signature, "0xb2aba6568054aff557402a3a9369309687019a29bb6180146d7a44043d6f8b19797e9a27c8c2b416a98cab29822927e76602924062725940e4bad56a9971faca1b");
     }
 
@@ -0,0 +1,431 @@
+// ! Governance (x/gov v1beta1) module support
+
+use crate::{CosmosError, SingleCoin};
+use cosmos_sdk_proto::cosmos::base::v1beta1::Coin as ProtoCoin;
+use cosmos_sdk_proto::cosmos::gov::v1beta1 as gov;
+use cosmos_sdk_proto::cosmos::params::v1beta1 as params;
+use cosmos_sdk_proto::traits::Message;
+use cosmrs::Any;
+use eyre::{eyre, WrapErr};
+use ibc_proto::cosmos::base::v1beta1::Coin as IbcCoin;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+pub(crate) const MSG_VOTE_TYPE_URL: &str = "/cosmos.gov.v1beta1.MsgVote";
+pub(crate) const MSG_VOTE_WEIGHTED_TYPE_URL: &str = "/cosmos.gov.v1beta1.MsgVoteWeighted";
+pub(crate) const MSG_DEPOSIT_TYPE_URL: &str = "/cosmos.gov.v1beta1.MsgDeposit";
+pub(crate) const MSG_SUBMIT_PROPOSAL_TYPE_URL: &str = "/cosmos.gov.v1beta1.MsgSubmitProposal";
+const TEXT_PROPOSAL_TYPE_URL: &str = "/cosmos.gov.v1beta1.TextProposal";
+const PARAMETER_CHANGE_PROPOSAL_TYPE_URL: &str = "/cosmos.params.v1beta1.ParameterChangeProposal";
+
+/// the precision of `sdk.Dec` (used by the vote weights)
+const DEC_PRECISION: usize = 18;
+
+/// vote options (the `VOTE_OPTION_UNSPECIFIED` one is rejected by the chain)
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum VoteOption {
+    #[serde(rename = "VOTE_OPTION_YES")]
+    Yes,
+    #[serde(rename = "VOTE_OPTION_ABSTAIN")]
+    Abstain,
+    #[serde(rename = "VOTE_OPTION_NO")]
+    No,
+    #[serde(rename = "VOTE_OPTION_NO_WITH_VETO")]
+    NoWithVeto,
+}
+
+impl From<VoteOption> for i32 {
+    fn from(option: VoteOption) -> Self {
+        match option {
+            VoteOption::Yes => gov::VoteOption::Yes as i32,
+            VoteOption::Abstain => gov::VoteOption::Abstain as i32,
+            VoteOption::No => gov::VoteOption::No as i32,
+            VoteOption::NoWithVeto => gov::VoteOption::NoWithVeto as i32,
+        }
+    }
+}
+
+impl TryFrom<i32> for VoteOption {
+    type Error = CosmosError;
+
+    fn try_from(option: i32) -> Result<Self, Self::Error> {
+        match gov::VoteOption::from_i32(option) {
+            Some(gov::VoteOption::Yes) => Ok(Self::Yes),
+            Some(gov::VoteOption::Abstain) => Ok(Self::Abstain),
+            Some(gov::VoteOption::No) => Ok(Self::No),
+            Some(gov::VoteOption::NoWithVeto) => Ok(Self::NoWithVeto),
+            _ => Err(eyre!("Invalid vote option: {option}").into()),
+        }
+    }
+}
+
+impl FromStr for VoteOption {
+    type Err = CosmosError;
+
+    /// parses "yes", "abstain", "no" or "no_with_veto" (or the proto JSON names)
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().trim_start_matches("vote_option_") {
+            "yes" => Ok(Self::Yes),
+            "abstain" => Ok(Self::Abstain),
+            "no" => Ok(Self::No),
+            "no_with_veto" | "nowithveto" => Ok(Self::NoWithVeto),
+            _ => Err(eyre!("Invalid vote option: {s}").into()),
+        }
+    }
+}
+
+/// a vote option with its weight (the weights of a vote must add up to 1)
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct WeightedVoteOption {
+    /// the vote option
+    pub option: VoteOption,
+    /// the weight as a decimal string, e.g. "0.5"
+    pub weight: String,
+}
+
+/// a parameter change of the `ParameterChangeProposal`
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ParamChange {
+    /// the module subspace, e.g. "staking"
+    pub subspace: String,
+    /// the parameter key, e.g. "MaxValidators"
+    pub key: String,
+    /// the JSON encoded value, e.g. "105"
+    pub value: String,
+}
+
+/// the content of a governance proposal
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(tag = "@type")]
+pub enum ProposalContent {
+    /// TextProposal
+    #[serde(rename = "/cosmos.gov.v1beta1.TextProposal")]
+    Text { title: String, description: String },
+    /// ParameterChangeProposal
+    #[serde(rename = "/cosmos.params.v1beta1.ParameterChangeProposal")]
+    ParameterChange {
+        title: String,
+        description: String,
+        changes: Vec<ParamChange>,
+    },
+}
+
+impl ProposalContent {
+    fn to_any(&self) -> Any {
+        match self {
+            Self::Text { title, description } => Any {
+                type_url: TEXT_PROPOSAL_TYPE_URL.to_owned(),
+                value: gov::TextProposal {
+                    title: title.to_owned(),
+                    description: description.to_owned(),
+                }
+                .encode_to_vec(),
+            },
+            Self::ParameterChange {
+                title,
+                description,
+                changes,
+            } => Any {
+                type_url: PARAMETER_CHANGE_PROPOSAL_TYPE_URL.to_owned(),
+                value: params::ParameterChangeProposal {
+                    title: title.to_owned(),
+                    description: description.to_owned(),
+                    changes: changes
+                        .iter()
+                        .map(|change| params::ParamChange {
+                            subspace: change.subspace.to_owned(),
+                            key: change.key.to_owned(),
+                            value: change.value.to_owned(),
+                        })
+                        .collect(),
+                }
+                .encode_to_vec(),
+            },
+        }
+    }
+}
+
+impl TryFrom<&Any> for ProposalContent {
+    type Error = CosmosError;
+
+    fn try_from(any: &Any) -> Result<Self, Self::Error> {
+        match any.type_url.as_str() {
+            TEXT_PROPOSAL_TYPE_URL => {
+                let proposal = gov::TextProposal::decode(any.value.as_slice())
+                    .wrap_err("Failed to decode TextProposal from Protobuf")?;
+                Ok(Self::Text {
+                    title: proposal.title,
+                    description: proposal.description,
+                })
+            }
+            PARAMETER_CHANGE_PROPOSAL_TYPE_URL => {
+                let proposal = params::ParameterChangeProposal::decode(any.value.as_slice())
+                    .wrap_err("Failed to decode ParameterChangeProposal from Protobuf")?;
+                Ok(Self::ParameterChange {
+                    title: proposal.title,
+                    description: proposal.description,
+                    changes: proposal
+                        .changes
+                        .into_iter()
+                        .map(|change| ParamChange {
+                            subspace: change.subspace,
+                            key: change.key,
+                            value: change.value,
+                        })
+                        .collect(),
+                })
+            }
+            type_url => Err(eyre!("Unsupported proposal content: {type_url}").into()),
+        }
+    }
+}
+
+/// converts a decimal string (e.g. "0.5") to the `sdk.Dec` Protobuf encoding
+/// (the integer with 18 decimals, e.g. "500000000000000000")
+pub(crate) fn decimal_to_atomics(decimal: &str) -> eyre::Result<String> {
+    let (integer, fraction) = decimal.split_once('.').unwrap_or((decimal, ""));
+    if integer.is_empty()
+        || fraction.len() > DEC_PRECISION
+        || !integer
+            .chars()
+            .chain(fraction.chars())
+            .all(|c| c.is_ascii_digit())
+    {
+        return Err(eyre!("Invalid decimal: {decimal}"));
+    }
+    let atomics = format!("{integer}{fraction:0<DEC_PRECISION$}");
+    let atomics = atomics.trim_start_matches('0');
+    Ok(if atomics.is_empty() { "0" } else { atomics }.to_owned())
+}
+
+/// converts the `sdk.Dec` Protobuf encoding (e.g. "500000000000000000")
+/// to the decimal string with 18 decimals (e.g. "0.500000000000000000")
+pub(crate) fn atomics_to_decimal(atomics: &str) -> eyre::Result<String> {
+    if atomics.is_empty() || !atomics.chars().all(|c| c.is_ascii_digit()) {
+        return Err(eyre!("Invalid decimal: {atomics}"));
+    }
+    let atomics = format!("{atomics:0>width$}", width = DEC_PRECISION + 1);
+    let (integer, fraction) = atomics.split_at(atomics.len() - DEC_PRECISION);
+    Ok(format!("{integer}.{fraction}"))
+}
+
+pub(crate) fn proto_coin(coin: &SingleCoin) -> eyre::Result<ProtoCoin> {
+    let coin = IbcCoin::try_from(coin)?;
+    Ok(ProtoCoin {
+        denom: coin.denom,
+        amount: coin.amount,
+    })
+}
+
+pub(crate) fn proto_coins(coins: &[SingleCoin]) -> eyre::Result<Vec<ProtoCoin>> {
+    coins.iter().map(proto_coin).collect()
+}
+
+/// converts the coins without the zero amounts, which the SDK rejects as invalid coins
+pub(crate) fn non_zero_proto_coins(coins: &[SingleCoin]) -> eyre::Result<Vec<ProtoCoin>> {
+    Ok(proto_coins(coins)?
+        .into_iter()
+        .filter(|coin| !coin.amount.chars().all(|c| c == '0'))
+        .collect())
+}
+
+/// creates `MsgVote`
+pub(crate) fn get_vote_any(proposal_id: u64, voter: &str, option: VoteOption) -> Any {
+    Any {
+        type_url: MSG_VOTE_TYPE_URL.to_owned(),
+        value: gov::MsgVote {
+            proposal_id,
+            voter: voter.to_owned(),
+            option: option.into(),
+        }
+        .encode_to_vec(),
+    }
+}
+
+/// creates `MsgVoteWeighted`
+/// (the weights must be positive and sum up to exactly 1, as the chain requires)
+pub(crate) fn get_vote_weighted_any(
+    proposal_id: u64,
+    voter: &str,
+    options: &[WeightedVoteOption],
+) -> eyre::Result<Any> {
+    let options = options
+        .iter()
+        .map(|option| {
+            Ok(gov::WeightedVoteOption {
+                option: option.option.into(),
+                weight: decimal_to_atomics(&option.weight)?,
+            })
+        })
+        .collect::<eyre::Result<Vec<_>>>()?;
+    let mut total_weight: u128 = 0;
+    for option in options.iter() {
+        let weight = option
+            .weight
+            .parse::<u128>()
+            .map_err(|_| eyre!("Invalid vote weight: {}", option.weight))?;
+        if weight == 0 {
+            return Err(eyre!("Vote weights must be positive"));
+        }
+        total_weight = total_weight
+            .checked_add(weight)
+            .ok_or_else(|| eyre!("Vote weights must sum up to 1"))?;
+    }
+    if total_weight != 10u128.pow(DEC_PRECISION as u32) {
+        return Err(eyre!("Vote weights must sum up to 1"));
+    }
+    Ok(Any {
+        type_url: MSG_VOTE_WEIGHTED_TYPE_URL.to_owned(),
+        value: gov::MsgVoteWeighted {
+            proposal_id,
+            voter: voter.to_owned(),
+            options,
+        }
+        .encode_to_vec(),
+    })
+}
+
+/// creates `MsgDeposit`
+pub(crate) fn get_deposit_any(
+    proposal_id: u64,
+    depositor: &str,
+    amount: &[SingleCoin],
+) -> eyre::Result<Any> {
+    Ok(Any {
+        type_url: MSG_DEPOSIT_TYPE_URL.to_owned(),
+        value: gov::MsgDeposit {
+            proposal_id,
+            depositor: depositor.to_owned(),
+            amount: proto_coins(amount)?,
+        }
+        .encode_to_vec(),
+    })
+}
+
+/// creates `MsgSubmitProposal`
+pub(crate) fn get_submit_proposal_any(
+    content: &ProposalContent,
+    initial_deposit: &[SingleCoin],
+    proposer: &str,
+) -> eyre::Result<Any> {
+    Ok(Any {
+        type_url: MSG_SUBMIT_PROPOSAL_TYPE_URL.to_owned(),
+        value: gov::MsgSubmitProposal {
+            content: Some(content.to_any()),
+            initial_deposit: non_zero_proto_coins(initial_deposit)?,
+            proposer: proposer.to_owned(),
+        }
+        .encode_to_vec(),
+    })
+}
+
+#[cfg(test)]
+mod gov_tests {
+    use super::*;
+
+    #[test]
+    fn test_decimal_conversion() {
+        assert_eq!(decimal_to_atomics("0.5").unwrap(), "500000000000000000");
+        assert_eq!(decimal_to_atomics("1").unwrap(), "1000000000000000000");
+        assert_eq!(decimal_to_atomics("0.0").unwrap(), "0");
+        assert!(decimal_to_atomics(".5").is_err());
+        assert!(decimal_to_atomics("-1").is_err());
+        assert!(decimal_to_atomics("0.1234567890123456789").is_err());
+
+        assert_eq!(
+            atomics_to_decimal("500000000000000000").unwrap(),
+            "0.500000000000000000"
+        );
+        assert_eq!(
+            atomics_to_decimal("1000000000000000000").unwrap(),
+            "1.000000000000000000"
+        );
+        assert!(atomics_to_decimal("0.5").is_err());
+    }
+
+    #[test]
+    fn test_vote_weights() {
+        let options = |weights: &[&str]| -> Vec<WeightedVoteOption> {
+            weights
+                .iter()
+                .map(|weight| WeightedVoteOption {
+                    option: VoteOption::Yes,
+                    weight: weight.to_string(),
+                })
+                .collect()
+        };
+        let voter = "cosmos1pkptre7fdkl6gfrzlesjjvhxhlc3r4gmmk8rs6";
+        assert!(get_vote_weighted_any(1, voter, &options(&["0.7", "0.3"])).is_ok());
+        assert!(get_vote_weighted_any(1, voter, &options(&["1"])).is_ok());
+        assert!(get_vote_weighted_any(1, voter, &options(&["0.7", "0.2"])).is_err());
+        assert!(get_vote_weighted_any(1, voter, &options(&["0.7", "0.4"])).is_err());
+        assert!(get_vote_weighted_any(1, voter, &options(&["1", "0"])).is_err());
+        assert!(get_vote_weighted_any(1, voter, &options(&[])).is_err());
+    }
+
+    #[test]
+    fn test_vote_option() {
+        assert_eq!("yes".parse::<VoteOption>().unwrap(), VoteOption::Yes);
+        assert_eq!(
+            "VOTE_OPTION_NO_WITH_VETO".parse::<VoteOption>().unwrap(),
+            VoteOption::NoWithVeto
+        );
+        assert!("maybe".parse::<VoteOption>().is_err());
+        assert_eq!(i32::from(VoteOption::Abstain), 2);
+        assert_eq!(VoteOption::try_from(3).unwrap(), VoteOption::No);
+        assert!(VoteOption::try_from(0).is_err());
+    }
+
+    #[test]
+    fn test_submit_proposal_zero_deposit() {
+        let content = ProposalContent::Text {
+            title: "Signaling proposal".to_owned(),
+            description: "".to_owned(),
+        };
+        let proposer = "cosmos1l5s7tnj28a7zxeeckhgwlhjys8dlrrefgqr4pj";
+        for initial_deposit in [vec![], vec![SingleCoin::UATOM { amount: 0 }]] {
+            let any = get_submit_proposal_any(&content, &initial_deposit, proposer).unwrap();
+            let msg = gov::MsgSubmitProposal::decode(any.value.as_slice()).unwrap();
+            assert!(msg.initial_deposit.is_empty());
+        }
+
+        let any = get_submit_proposal_any(
+            &content,
+            &[
+                SingleCoin::UATOM { amount: 0 },
+                SingleCoin::UATOM { amount: 1000 },
+            ],
+            proposer,
+        )
+        .unwrap();
+        let msg = gov::MsgSubmitProposal::decode(any.value.as_slice()).unwrap();
+        assert_eq!(
+            msg.initial_deposit,
+            vec![ProtoCoin {
+                denom: "uatom".to_owned(),
+                amount: "1000".to_owned(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_proposal_content_any() {
+        let content = ProposalContent::ParameterChange {
+            title: "Increase the validator set".to_owned(),
+            description: "Increase the validator set to 180".to_owned(),
+            changes: vec![ParamChange {
+                subspace: "staking".to_owned(),
+                key: "MaxValidators".to_owned(),
+                value: "180".to_owned(),
+            }],
+        };
+        let any = content.to_any();
+        assert_eq!(any.type_url, PARAMETER_CHANGE_PROPOSAL_TYPE_URL);
+        assert_eq!(ProposalContent::try_from(&any).unwrap(), content);
+        assert!(ProposalContent::try_from(&Any {
+            type_url: "/cosmos.upgrade.v1beta1.SoftwareUpgradeProposal".to_owned(),
+            value: vec![],
+        })
+        .is_err());
+    }
+}
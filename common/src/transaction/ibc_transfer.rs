@@ -0,0 +1,280 @@
+// ! IBC fungible token transfer (ICS-20) support
+
+use crate::{CosmosError, Height};
+use eyre::eyre;
+use prost::Message;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// the default number of blocks after the counterparty's latest block before the transfer times out
+pub const DEFAULT_IBC_TIMEOUT_HEIGHT_OFFSET: u64 = 1000;
+/// the default number of seconds after the counterparty's latest block time
+/// before the transfer times out
+pub const DEFAULT_IBC_TIMEOUT_SECONDS: u64 = 600;
+
+const NANOS_PER_SECOND: u64 = 1_000_000_000;
+
+/// the timeout of an IBC transfer on the counterparty (destination) chain
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct IbcTimeout {
+    /// the absolute timeout height (zero if disabled)
+    pub timeout_height: Height,
+    /// the absolute timeout timestamp in nanoseconds since the Unix epoch (0 if disabled)
+    pub timeout_timestamp: u64,
+}
+
+/// the trace of an IBC voucher denomination
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct DenomTrace {
+    /// the ports and channels the tokens were transferred through, e.g. "transfer/channel-0"
+    pub path: String,
+    /// the denomination on the source chain, e.g. "uatom"
+    pub base_denom: String,
+}
+
+impl DenomTrace {
+    /// the IBC denomination of the trace ("ibc/{hash}", or the base one without a path)
+    pub fn get_ibc_denom(&self) -> String {
+        get_ibc_denom(&self.path, &self.base_denom)
+    }
+}
+
+/// return the IBC denomination ("ibc/{hash}" where the hash is the uppercase hex-encoded
+/// SHA-256 of "{path}/{base_denom}") or the base denomination if the path is empty
+pub fn get_ibc_denom(path: &str, base_denom: &str) -> String {
+    if path.is_empty() {
+        return base_denom.to_owned();
+    }
+    let hash = Sha256::digest(format!("{}/{}", path, base_denom).as_bytes());
+    format!("ibc/{}", hex::encode_upper(hash))
+}
+
+/// the revision number of the chain id in the "{name}-{revision}" format (0 otherwise)
+fn get_revision_number(chain_id: &str) -> u64 {
+    match chain_id.rsplit_once('-') {
+        Some((name, revision))
+            if !name.is_empty() && !revision.starts_with('0') && !revision.is_empty() =>
+        {
+            revision.parse().unwrap_or_default()
+        }
+        _ => 0,
+    }
+}
+
+/// return the IBC transfer timeout after the number of blocks and seconds
+/// from the latest block (height and time in nanoseconds) of the counterparty chain
+/// (a zero offset disables the corresponding timeout, but not both)
+pub fn compute_ibc_timeout(
+    counterparty_chain_id: &str,
+    latest_height: u64,
+    latest_time: u64,
+    height_offset: u64,
+    timeout_seconds: u64,
+) -> Result<IbcTimeout, CosmosError> {
+    if height_offset == 0 && timeout_seconds == 0 {
+        return Err(eyre!("the timeout height and timestamp cannot both be disabled").into());
+    }
+    let timeout_height = if height_offset == 0 {
+        Height {
+            revision_number: 0,
+            revision_height: 0,
+        }
+    } else {
+        Height {
+            revision_number: get_revision_number(counterparty_chain_id),
+            revision_height: latest_height
+                .checked_add(height_offset)
+                .ok_or_else(|| eyre!("integer overflow"))?,
+        }
+    };
+    let timeout_timestamp = if timeout_seconds == 0 {
+        0
+    } else {
+        timeout_seconds
+            .checked_mul(NANOS_PER_SECOND)
+            .and_then(|timeout| timeout.checked_add(latest_time))
+            .ok_or_else(|| eyre!("integer overflow"))?
+    };
+    Ok(IbcTimeout {
+        timeout_height,
+        timeout_timestamp,
+    })
+}
+
+/// the `memo` field of `MsgTransfer` (not in the `ibc` 0.31 domain type)
+#[derive(Clone, PartialEq, Message)]
+struct RawMsgTransferMemo {
+    #[prost(string, tag = "8")]
+    memo: String,
+}
+
+/// append the memo to the Protobuf-encoded `MsgTransfer`
+/// (an appended field is merged into the message when decoding)
+pub(crate) fn append_ibc_transfer_memo(value: &mut Vec<u8>, memo: &str) {
+    if !memo.is_empty() {
+        value.extend(
+            RawMsgTransferMemo {
+                memo: memo.to_owned(),
+            }
+            .encode_to_vec(),
+        );
+    }
+}
+
+/// decode the memo of the Protobuf-encoded `MsgTransfer` (empty if not set)
+pub(crate) fn decode_ibc_transfer_memo(value: &[u8]) -> eyre::Result<String> {
+    Ok(RawMsgTransferMemo::decode(value)?.memo)
+}
+
+/// a hop of a multi-hop transfer via the packet forward middleware
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct PacketForwardHop {
+    /// the recipient address on the chain the tokens are forwarded to
+    pub receiver: String,
+    /// the port the tokens are forwarded from (usually "transfer")
+    pub port: String,
+    /// the channel the tokens are forwarded through
+    pub channel: String,
+    /// the timeout of the forwarded transfer in seconds (0 for the middleware's default)
+    pub timeout_seconds: u64,
+    /// the number of retries on timeout (the middleware's default if not set)
+    pub retries: Option<u8>,
+}
+
+#[derive(Serialize)]
+struct RawForwardMemo {
+    forward: RawForwardMetadata,
+}
+
+#[derive(Serialize)]
+struct RawForwardMetadata {
+    receiver: String,
+    port: String,
+    channel: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    timeout: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    retries: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    next: Option<Box<RawForwardMemo>>,
+}
+
+/// return the packet forward middleware memo (`{"forward": {..., "next": {"forward": ...}}}`)
+/// forwarding the transferred tokens through the hops in order
+/// (the receiver of the `MsgTransfer` is the address on the first intermediate chain)
+pub fn build_packet_forward_memo(hops: &[PacketForwardHop]) -> Result<String, CosmosError> {
+    let memo = hops
+        .iter()
+        .rev()
+        .fold(None, |next, hop| {
+            Some(Box::new(RawForwardMemo {
+                forward: RawForwardMetadata {
+                    receiver: hop.receiver.clone(),
+                    port: hop.port.clone(),
+                    channel: hop.channel.clone(),
+                    // the Go duration format
+                    timeout: match hop.timeout_seconds {
+                        0 => None,
+                        seconds => Some(format!("{}s", seconds)),
+                    },
+                    retries: hop.retries,
+                    next,
+                },
+            }))
+        })
+        .ok_or_else(|| eyre!("no packet forward hops"))?;
+    Ok(serde_json::to_string(&memo).map_err(|e| eyre!(e))?)
+}
+
+#[cfg(test)]
+mod ibc_transfer_tests {
+    use super::*;
+
+    #[test]
+    fn test_ibc_denom() {
+        assert_eq!(
+            get_ibc_denom("transfer/channel-0", "uatom"),
+            "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2"
+        );
+        assert_eq!(get_ibc_denom("", "uatom"), "uatom");
+        let trace = DenomTrace {
+            path: "transfer/channel-0".to_owned(),
+            base_denom: "uatom".to_owned(),
+        };
+        assert_eq!(trace.get_ibc_denom(), get_ibc_denom(&trace.path, "uatom"));
+    }
+
+    #[test]
+    fn test_ibc_timeout() {
+        let timeout =
+            compute_ibc_timeout("osmosis-1", 100, 5 * NANOS_PER_SECOND, 1000, 600).unwrap();
+        assert_eq!(
+            timeout.timeout_height,
+            Height {
+                revision_number: 1,
+                revision_height: 1100
+            }
+        );
+        assert_eq!(timeout.timeout_timestamp, 605 * NANOS_PER_SECOND);
+
+        let timeout = compute_ibc_timeout("testing", 100, 0, 10, 0).unwrap();
+        assert_eq!(timeout.timeout_height.revision_number, 0);
+        assert_eq!(timeout.timeout_height.revision_height, 110);
+        assert_eq!(timeout.timeout_timestamp, 0);
+
+        assert_eq!(get_revision_number("cronosmainnet_25-1"), 1);
+        assert_eq!(get_revision_number("chain-01"), 0);
+        assert!(compute_ibc_timeout("cosmoshub-4", 100, 0, 0, 0).is_err());
+    }
+
+    #[test]
+    fn test_ibc_transfer_memo() {
+        let mut value = vec![0x0a, 0x08, b't', b'r', b'a', b'n', b's', b'f', b'e', b'r'];
+        append_ibc_transfer_memo(&mut value, "");
+        assert_eq!(decode_ibc_transfer_memo(&value).unwrap(), "");
+        append_ibc_transfer_memo(&mut value, "{\"wasm\":{}}");
+        assert_eq!(decode_ibc_transfer_memo(&value).unwrap(), "{\"wasm\":{}}");
+    }
+
+    #[test]
+    fn test_packet_forward_memo() {
+        let hops = vec![
+            PacketForwardHop {
+                receiver: "osmo1l5s7tnj28a7zxeeckhgwlhjys8dlrref9kfl5c".to_owned(),
+                port: "transfer".to_owned(),
+                channel: "channel-141".to_owned(),
+                timeout_seconds: 600,
+                retries: Some(2),
+            },
+            PacketForwardHop {
+                receiver: "cro1l5s7tnj28a7zxeeckhgwlhjys8dlrrefsmtvar".to_owned(),
+                port: "transfer".to_owned(),
+                channel: "channel-5".to_owned(),
+                timeout_seconds: 0,
+                retries: None,
+            },
+        ];
+        let memo: serde_json::Value =
+            serde_json::from_str(&build_packet_forward_memo(&hops).unwrap()).unwrap();
+        assert_eq!(
+            memo,
+            serde_json::json!({
+                "forward": {
+                    "receiver": "osmo1l5s7tnj28a7zxeeckhgwlhjys8dlrref9kfl5c",
+                    "port": "transfer",
+                    "channel": "channel-141",
+                    "timeout": "600s",
+                    "retries": 2,
+                    "next": {
+                        "forward": {
+                            "receiver": "cro1l5s7tnj28a7zxeeckhgwlhjys8dlrrefsmtvar",
+                            "port": "transfer",
+                            "channel": "channel-5"
+                        }
+                    }
+                }
+            })
+        );
+        assert!(build_packet_forward_memo(&[]).is_err());
+    }
+}
@@ -0,0 +1,60 @@
+// ! Vesting (x/auth/vesting) module support
+
+use super::gov::proto_coins;
+use crate::SingleCoin;
+use cosmos_sdk_proto::cosmos::vesting::v1beta1 as vesting;
+use cosmos_sdk_proto::traits::Message;
+use cosmrs::Any;
+
+pub(crate) const MSG_CREATE_VESTING_ACCOUNT_TYPE_URL: &str =
+    "/cosmos.vesting.v1beta1.MsgCreateVestingAccount";
+
+/// creates `MsgCreateVestingAccount`
+pub(crate) fn get_create_vesting_account_any(
+    from_address: &str,
+    to_address: &str,
+    amount: &[SingleCoin],
+    end_time: i64,
+    delayed: bool,
+) -> eyre::Result<Any> {
+    Ok(Any {
+        type_url: MSG_CREATE_VESTING_ACCOUNT_TYPE_URL.to_owned(),
+        value: vesting::MsgCreateVestingAccount {
+            from_address: from_address.to_owned(),
+            to_address: to_address.to_owned(),
+            amount: proto_coins(amount)?,
+            end_time,
+            delayed,
+        }
+        .encode_to_vec(),
+    })
+}
+
+#[cfg(test)]
+mod vesting_tests {
+    use super::*;
+
+    #[test]
+    fn test_create_vesting_account_any() {
+        let any = get_create_vesting_account_any(
+            "cosmos1l5s7tnj28a7zxeeckhgwlhjys8dlrrefgqr4pj",
+            "cosmos19dyl0uyzes4k23lscla02n06fc22h4uqsdwq6z",
+            &[SingleCoin::UATOM { amount: 10 }],
+            1_700_000_000,
+            true,
+        )
+        .unwrap();
+        assert_eq!(any.type_url, MSG_CREATE_VESTING_ACCOUNT_TYPE_URL);
+
+        let msg = vesting::MsgCreateVestingAccount::decode(any.value.as_slice()).unwrap();
+        assert_eq!(
+            msg.to_address,
+            "cosmos19dyl0uyzes4k23lscla02n06fc22h4uqsdwq6z"
+        );
+        assert_eq!(msg.amount.len(), 1);
+        assert_eq!(msg.amount[0].denom, "uatom");
+        assert_eq!(msg.amount[0].amount, "10");
+        assert_eq!(msg.end_time, 1_700_000_000);
+        assert!(msg.delayed);
+    }
+}
@@ -1,6 +1,6 @@
 #![cfg(target_arch = "wasm32")]
 
-use crate::{CosmosError, EthError};
+use crate::{BitcoinError, CosmosError, EthError};
 use wasm_bindgen::JsValue;
 
 impl From<CosmosError> for JsValue {
@@ -14,3 +14,9 @@ impl From<EthError> for JsValue {
         JsValue::from_str(&format!("error: {error}"))
     }
 }
+
+impl From<BitcoinError> for JsValue {
+    fn from(error: BitcoinError) -> Self {
+        JsValue::from_str(&format!("error: {error}"))
+    }
+}
@@ -1,7 +1,7 @@
-use crate::{EthNetwork, Network};
+use crate::{get_bitcoin_address, BitcoinAddressType, BitcoinNetwork, EthNetwork, Network};
 use bip39::{Language, Mnemonic};
 use cosmrs::bip32::secp256k1::ecdsa::SigningKey;
-use cosmrs::bip32::{self, DerivationPath, PrivateKey, Seed, XPrv};
+use cosmrs::bip32::{self, DerivationPath, Prefix, PrivateKey, XPrv};
 use cosmrs::crypto::PublicKey;
 use ethers::core::k256::ecdsa;
 use ethers::prelude::{LocalWallet, Signature, Signer, H256};
@@ -9,12 +9,37 @@ use ethers::utils::hex::{self, FromHexError, ToHex};
 use ethers::utils::{hash_message, secret_key_to_address};
 use ethers_core::k256::ecdsa::SigningKey as EthSigningKey;
 use rand_core::{OsRng, RngCore};
-use secrecy::{ExposeSecret, SecretString, Zeroize};
-use std::str::FromStr;
+use secrecy::{ExposeSecret, SecretString, SecretVec, Zeroize};
+use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
+/// gap-limit discovery of the used addresses
+mod discovery;
+/// ed25519 keys (SLIP-0010 derivation) for Solana and Aptos
+mod ed25519;
+/// Web3 Secret Storage (V3) keystore
+mod keystore;
+/// Ledger hardware wallet signers (APDU)
+mod ledger;
+/// BIP39 phrase validation and word suggestions
+mod mnemonic;
+/// signer abstraction over in-memory and hardware wallet keys
+mod signer;
+/// SLIP-39 Shamir secret sharing backup
+mod slip39;
 /// wasm binding related functions
 mod wasm_binding;
+/// watch-only wallet derived from an extended public key
+mod watch_only;
+
+pub use discovery::*;
+pub use ed25519::*;
+pub use keystore::*;
+pub use ledger::*;
+pub use mnemonic::*;
+pub use signer::*;
+pub use slip39::*;
+pub use watch_only::*;
 
 #[cfg(target_arch = "wasm32")]
 pub use wasm_binding::*;
@@ -22,8 +47,27 @@ pub use wasm_binding::*;
 /// describes what coin type to use (for HD derivation or address generation)
 #[derive(Clone)]
 pub enum WalletCoin {
-    CosmosSDK { network: Network },
-    Ethereum { network: EthNetwork },
+    CosmosSDK {
+        network: Network,
+    },
+    Ethereum {
+        network: EthNetwork,
+    },
+    Bitcoin {
+        network: BitcoinNetwork,
+        address_type: BitcoinAddressType,
+    },
+    Solana,
+    Aptos,
+}
+
+/// describes the signature scheme of the keys of a coin
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeyScheme {
+    /// BIP32 derivation (Cosmos SDK, Ethereum and Bitcoin)
+    Secp256k1,
+    /// SLIP-0010 hardened derivation (Solana and Aptos)
+    Ed25519,
 }
 
 /// describes the number of words in mnemonic
@@ -36,6 +80,73 @@ pub enum MnemonicWordCount {
     TwentyFour,
 }
 
+/// describes the language of the BIP39 mnemonic wordlist
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MnemonicLanguage {
+    #[default]
+    English,
+    ChineseSimplified,
+    ChineseTraditional,
+    Czech,
+    French,
+    Italian,
+    Japanese,
+    Korean,
+    Spanish,
+}
+
+impl From<MnemonicLanguage> for Language {
+    fn from(language: MnemonicLanguage) -> Language {
+        match language {
+            MnemonicLanguage::English => Language::English,
+            MnemonicLanguage::ChineseSimplified => Language::SimplifiedChinese,
+            MnemonicLanguage::ChineseTraditional => Language::TraditionalChinese,
+            MnemonicLanguage::Czech => Language::Czech,
+            MnemonicLanguage::French => Language::French,
+            MnemonicLanguage::Italian => Language::Italian,
+            MnemonicLanguage::Japanese => Language::Japanese,
+            MnemonicLanguage::Korean => Language::Korean,
+            MnemonicLanguage::Spanish => Language::Spanish,
+        }
+    }
+}
+
+impl From<Language> for MnemonicLanguage {
+    fn from(language: Language) -> MnemonicLanguage {
+        match language {
+            Language::English => MnemonicLanguage::English,
+            Language::SimplifiedChinese => MnemonicLanguage::ChineseSimplified,
+            Language::TraditionalChinese => MnemonicLanguage::ChineseTraditional,
+            Language::Czech => MnemonicLanguage::Czech,
+            Language::French => MnemonicLanguage::French,
+            Language::Italian => MnemonicLanguage::Italian,
+            Language::Japanese => MnemonicLanguage::Japanese,
+            Language::Korean => MnemonicLanguage::Korean,
+            Language::Spanish => MnemonicLanguage::Spanish,
+        }
+    }
+}
+
+/// parses a BIP39 phrase in the given language (or detects the language if not provided)
+/// the phrase is normalized to UTF-8 NFKD first
+pub(crate) fn parse_mnemonic(
+    phrase: &str,
+    language: Option<MnemonicLanguage>,
+) -> Result<Mnemonic, bip39::Error> {
+    match language {
+        Some(language) => Mnemonic::parse_in(language.into(), phrase),
+        None => match Mnemonic::parse(phrase) {
+            // e.g. a Chinese phrase can consist of characters present in both
+            // the simplified and the traditional wordlists
+            Err(bip39::Error::AmbiguousLanguages(languages)) => languages
+                .iter()
+                .find_map(|language| Mnemonic::parse_in(language, phrase).ok())
+                .ok_or(bip39::Error::AmbiguousLanguages(languages)),
+            result => result,
+        },
+    }
+}
+
 impl From<MnemonicWordCount> for usize {
     fn from(word_count: MnemonicWordCount) -> usize {
         match word_count {
@@ -71,6 +182,12 @@ impl WalletCoinFunc {
                 let address_hex: String = address.encode_hex();
                 Ok(format!("0x{}", address_hex))
             }
+            WalletCoin::Bitcoin {
+                network,
+                address_type,
+            } => get_bitcoin_address(&private_key.get_public_key_bytes(), *network, *address_type)
+                .map_err(|_| HdWrapError::InvalidPublicKey),
+            WalletCoin::Solana | WalletCoin::Aptos => Err(HdWrapError::UnsupportedKeyScheme),
         }
     }
 
@@ -79,6 +196,27 @@ impl WalletCoinFunc {
         match &self.coin {
             WalletCoin::CosmosSDK { network } => network.get_coin_type(),
             WalletCoin::Ethereum { .. } => 60,
+            WalletCoin::Bitcoin { network, .. } => network.get_coin_type(),
+            WalletCoin::Solana => 501,
+            WalletCoin::Aptos => 637,
+        }
+    }
+
+    /// return the HD purpose (44 as per BIP44 except for the Bitcoin segwit and taproot addresses)
+    pub fn get_purpose(&self) -> u32 {
+        match &self.coin {
+            WalletCoin::Bitcoin { address_type, .. } => address_type.get_purpose(),
+            _ => 44,
+        }
+    }
+
+    /// return the signature scheme of the keys
+    pub fn get_key_scheme(&self) -> KeyScheme {
+        match &self.coin {
+            WalletCoin::CosmosSDK { .. }
+            | WalletCoin::Ethereum { .. }
+            | WalletCoin::Bitcoin { .. } => KeyScheme::Secp256k1,
+            WalletCoin::Solana | WalletCoin::Aptos => KeyScheme::Ed25519,
         }
     }
 
@@ -93,7 +231,8 @@ impl WalletCoinFunc {
 
 /// BIP32-style wallet that can be backed up to and recovered from BIP39
 pub struct HDWallet {
-    seed: Seed,
+    /// the BIP32 seed
+    seed: SecretVec<u8>,
     mnemonic: Option<Mnemonic>,
 }
 
@@ -108,60 +247,69 @@ pub enum HdWrapError {
     HDErrorBip32(bip32::Error),
     #[error("AccountId error: {0}")]
     AccountId(eyre::Report),
+    #[error("Invalid public key")]
+    InvalidPublicKey,
+    #[error("The operation is not supported by the key scheme of the coin")]
+    UnsupportedKeyScheme,
+    #[error("Ed25519 keys can only be derived from hardened indices")]
+    Ed25519NonHardenedIndex,
 }
 
 impl HDWallet {
     /// constructs a new HD wallet from the seed value
     /// returns an error if the seed doesn't have a correct length
-    pub fn new(mut seed_val: Vec<u8>) -> Result<Self, HdWrapError> {
+    pub fn new(seed_val: Vec<u8>) -> Result<Self, HdWrapError> {
         const SEED_LEN: usize = 64;
         if seed_val.len() != SEED_LEN {
             Err(HdWrapError::InvalidLength)
         } else {
-            let mut seed = [0u8; SEED_LEN];
-            seed.copy_from_slice(&seed_val);
-            seed_val.zeroize();
             Ok(HDWallet {
-                seed: Seed::new(seed),
+                seed: SecretVec::new(seed_val),
                 mnemonic: None,
             })
         }
     }
 
-    /// generates the HD wallet with a BIP39 backup phrase (English words)
+    /// generates the HD wallet with a BIP39 backup phrase (English words by default)
     pub fn generate_wallet(
         password: Option<String>,
         word_count: Option<MnemonicWordCount>,
+        language: Option<MnemonicLanguage>,
     ) -> Result<Self, HdWrapError> {
         let pass = SecretString::new(password.unwrap_or_default());
         let word_count = word_count.unwrap_or(MnemonicWordCount::TwentyFour);
-        HDWallet::generate_english(pass, word_count)
+        HDWallet::generate_in(pass, word_count, language.unwrap_or_default())
     }
 
-    /// build new HD wallet with a BIP39 backup phrase (English words) and password
+    /// build new HD wallet with a BIP39 backup phrase (English words by default) and password
     /// used in extension
     pub fn new_wallet(
         password: Option<String>,
         word_count: Option<MnemonicWordCount>,
+        language: Option<MnemonicLanguage>,
     ) -> Result<Self, HdWrapError> {
         let pass = SecretString::new(password.unwrap_or_default());
         let mut entropy = [0u8; 32];
         OsRng.fill_bytes(&mut entropy);
         let size: usize = word_count.unwrap_or(MnemonicWordCount::TwentyFour).into();
         let entropy_bytes = (size / 3) * 4;
-        let phrase = Mnemonic::from_entropy_in(Language::English, &entropy[0..entropy_bytes])
+        let language = language.unwrap_or_default();
+        let phrase = Mnemonic::from_entropy_in(language.into(), &entropy[0..entropy_bytes])
             .map_err(HdWrapError::HDErrorBip39)?;
-        Self::recover_english(SecretString::new(phrase.to_string()), pass)
+        entropy.zeroize();
+        Self::recover_in(SecretString::new(phrase.to_string()), pass, Some(language))
     }
 
-    /// recovers/imports HD wallet from a BIP39 backup phrase (English words) and password
+    /// recovers/imports HD wallet from a BIP39 backup phrase and password
+    /// the language of the phrase is detected automatically if it is not provided
     pub fn recover_wallet(
         mnemonic_phrase: String,
         password: Option<String>,
+        language: Option<MnemonicLanguage>,
     ) -> Result<Self, HdWrapError> {
         let phrase = SecretString::new(mnemonic_phrase);
         let pass = SecretString::new(password.unwrap_or_default());
-        Self::recover_english(phrase, pass)
+        Self::recover_in(phrase, pass, language)
     }
 
     /// returns the backup mnemonic phrase (if any)
@@ -169,10 +317,16 @@ impl HDWallet {
         self.mnemonic.as_ref().map(|m| m.to_string())
     }
 
+    /// returns the language of the backup mnemonic phrase (if any)
+    pub fn get_mnemonic_language(&self) -> Option<MnemonicLanguage> {
+        self.mnemonic.as_ref().map(|m| m.language().into())
+    }
+
     /// generates the HD wallet and returns the backup phrase
-    fn generate_english(
+    fn generate_in(
         password: SecretString,
         word_count: MnemonicWordCount,
+        language: MnemonicLanguage,
     ) -> Result<Self, HdWrapError> {
         let mut rng = OsRng;
         let word_count_usize: usize = word_count.into();
@@ -180,10 +334,10 @@ impl HDWallet {
         const MAX_NB_WORDS: usize = 24;
         let mut entropy = [0u8; (MAX_NB_WORDS / 3) * 4];
         rand_core::RngCore::fill_bytes(&mut rng, &mut entropy[0..entropy_bytes]);
-        let mnemonic = Mnemonic::from_entropy_in(Language::English, &entropy[0..entropy_bytes])
+        let mnemonic = Mnemonic::from_entropy_in(language.into(), &entropy[0..entropy_bytes])
             .map_err(HdWrapError::HDErrorBip39)?;
-        let seed = mnemonic.to_seed_normalized(password.expose_secret());
-        let seed = Seed::new(seed);
+        entropy.zeroize();
+        let seed = SecretVec::new(mnemonic.to_seed(password.expose_secret().as_str()).to_vec());
         Ok(Self {
             seed,
             mnemonic: Some(mnemonic),
@@ -191,14 +345,14 @@ impl HDWallet {
     }
 
     /// recovers the HD wallet from a backup phrase
-    fn recover_english(
+    fn recover_in(
         mnemonic_phrase: SecretString,
         password: SecretString,
+        language: Option<MnemonicLanguage>,
     ) -> Result<Self, HdWrapError> {
-        let mnemonic = Mnemonic::from_str(mnemonic_phrase.expose_secret())
+        let mnemonic = parse_mnemonic(mnemonic_phrase.expose_secret(), language)
             .map_err(HdWrapError::HDErrorBip39)?;
-        let seed = mnemonic.to_seed_normalized(password.expose_secret());
-        let seed = Seed::new(seed);
+        let seed = SecretVec::new(mnemonic.to_seed(password.expose_secret().as_str()).to_vec());
 
         Ok(Self {
             seed,
@@ -208,8 +362,16 @@ impl HDWallet {
 
     /// returns the address from index in wallet
     pub fn get_address(&self, coin: WalletCoin, index: u32) -> Result<String, HdWrapError> {
-        let pkey = self.get_key_from_index(coin.clone(), index)?;
-        pkey.to_address(coin)
+        match WalletCoinFunc::new(coin.clone()).get_key_scheme() {
+            KeyScheme::Secp256k1 => {
+                let pkey = self.get_key_from_index(coin.clone(), index)?;
+                pkey.to_address(coin)
+            }
+            KeyScheme::Ed25519 => {
+                let pkey = self.get_ed25519_key_from_index(coin.clone(), index)?;
+                pkey.to_address(coin)
+            }
+        }
     }
 
     /// returns the default address of the wallet
@@ -221,7 +383,7 @@ impl HDWallet {
     pub fn get_key(&self, derivation_path: String) -> Result<Arc<SecretKey>, HdWrapError> {
         let derivation_path: DerivationPath =
             derivation_path.parse().map_err(HdWrapError::HDErrorBip32)?;
-        let child_xprv = XPrv::derive_from_path(&self.seed, &derivation_path)
+        let child_xprv = XPrv::derive_from_path(self.seed.expose_secret(), &derivation_path)
             .map_err(HdWrapError::HDErrorBip32)?;
         Ok(Arc::new(SecretKey(child_xprv.private_key().clone())))
     }
@@ -232,14 +394,104 @@ impl HDWallet {
         coin: WalletCoin,
         index: u32,
     ) -> Result<Arc<SecretKey>, HdWrapError> {
-        let coin_type = WalletCoinFunc { coin }.get_coin_type();
-        let derivation_path: DerivationPath = format!("m/44'/{}'/0'/0/{}", coin_type, index)
-            .parse()
-            .map_err(HdWrapError::HDErrorBip32)?;
-        let child_xprv = XPrv::derive_from_path(&self.seed, &derivation_path)
+        let coin_func = WalletCoinFunc { coin };
+        if coin_func.get_key_scheme() != KeyScheme::Secp256k1 {
+            return Err(HdWrapError::UnsupportedKeyScheme);
+        }
+        let derivation_path: DerivationPath = format!(
+            "m/{}'/{}'/0'/0/{}",
+            coin_func.get_purpose(),
+            coin_func.get_coin_type(),
+            index
+        )
+        .parse()
+        .map_err(HdWrapError::HDErrorBip32)?;
+        let child_xprv = XPrv::derive_from_path(self.seed.expose_secret(), &derivation_path)
             .map_err(HdWrapError::HDErrorBip32)?;
         Ok(Arc::new(SecretKey(child_xprv.private_key().clone())))
     }
+
+    /// return the extended private key for a given derivation path
+    /// (xprv, yprv for BIP49 or zprv for BIP84; tprv, uprv or vprv for the testnet coin type)
+    pub fn get_extended_private_key(&self, derivation_path: String) -> Result<String, HdWrapError> {
+        let derivation_path: DerivationPath =
+            derivation_path.parse().map_err(HdWrapError::HDErrorBip32)?;
+        let (private_prefix, _) = extended_key_prefixes(&derivation_path);
+        let xprv = self.derive_xprv(&derivation_path)?;
+        Ok(xprv.to_string(private_prefix).as_str().to_owned())
+    }
+
+    /// return the extended public key for a given derivation path
+    /// (xpub, ypub for BIP49 or zpub for BIP84; tpub, upub or vpub for the testnet coin type)
+    pub fn get_extended_public_key(&self, derivation_path: String) -> Result<String, HdWrapError> {
+        let derivation_path: DerivationPath =
+            derivation_path.parse().map_err(HdWrapError::HDErrorBip32)?;
+        let (_, public_prefix) = extended_key_prefixes(&derivation_path);
+        let xprv = self.derive_xprv(&derivation_path)?;
+        Ok(xprv.public_key().to_string(public_prefix))
+    }
+
+    /// return the account-level (m/purpose'/coin_type'/account') extended private key
+    pub fn get_account_extended_private_key(
+        &self,
+        coin: WalletCoin,
+        account: u32,
+    ) -> Result<String, HdWrapError> {
+        self.get_extended_private_key(account_derivation_path(coin, account)?)
+    }
+
+    /// return the account-level (m/purpose'/coin_type'/account') extended public key
+    /// which can be used to construct a `WatchOnlyWallet`
+    pub fn get_account_extended_public_key(
+        &self,
+        coin: WalletCoin,
+        account: u32,
+    ) -> Result<String, HdWrapError> {
+        self.get_extended_public_key(account_derivation_path(coin, account)?)
+    }
+
+    fn derive_xprv(&self, derivation_path: &DerivationPath) -> Result<XPrv, HdWrapError> {
+        XPrv::derive_from_path(self.seed.expose_secret(), derivation_path)
+            .map_err(HdWrapError::HDErrorBip32)
+    }
+}
+
+/// the SLIP-44 coin type shared by all the test networks
+const TESTNET_COIN_TYPE: u32 = 1;
+
+/// SLIP-0132 version prefixes missing in `bip32`
+const UPRV: Prefix = Prefix::from_parts_unchecked("uprv", 0x044a4e28);
+const UPUB: Prefix = Prefix::from_parts_unchecked("upub", 0x044a5262);
+const VPRV: Prefix = Prefix::from_parts_unchecked("vprv", 0x045f18bc);
+const VPUB: Prefix = Prefix::from_parts_unchecked("vpub", 0x045f1cf6);
+
+/// the (private, public) extended key prefixes for the purpose and the coin type of a derivation path
+fn extended_key_prefixes(derivation_path: &DerivationPath) -> (Prefix, Prefix) {
+    let mut path = derivation_path.iter().map(|child| child.index());
+    let purpose = path.next();
+    let testnet = path.next() == Some(TESTNET_COIN_TYPE);
+    match (purpose, testnet) {
+        (Some(49), false) => (Prefix::YPRV, Prefix::YPUB),
+        (Some(49), true) => (UPRV, UPUB),
+        (Some(84), false) => (Prefix::ZPRV, Prefix::ZPUB),
+        (Some(84), true) => (VPRV, VPUB),
+        (_, false) => (Prefix::XPRV, Prefix::XPUB),
+        (_, true) => (Prefix::TPRV, Prefix::TPUB),
+    }
+}
+
+/// the account-level derivation path for a given (secp256k1) coin and account
+fn account_derivation_path(coin: WalletCoin, account: u32) -> Result<String, HdWrapError> {
+    let coin_func = WalletCoinFunc { coin };
+    if coin_func.get_key_scheme() != KeyScheme::Secp256k1 {
+        return Err(HdWrapError::UnsupportedKeyScheme);
+    }
+    Ok(format!(
+        "m/{}'/{}'/{}'",
+        coin_func.get_purpose(),
+        coin_func.get_coin_type(),
+        account
+    ))
 }
 
 /// wrapper around Secret Key errors
@@ -249,6 +501,8 @@ pub enum SecretKeyWrapError {
     InvalidBytes(ecdsa::Error),
     #[error("Invalid hex: {0}")]
     InvalidHex(FromHexError),
+    #[error("Invalid ed25519 key bytes")]
+    InvalidEd25519Bytes,
 }
 
 /// wrapper around secp256k1 signing key
@@ -365,7 +619,8 @@ mod hd_wallet_tests {
 
     #[test]
     fn test_generate_24_word_mnemonic_wallet_as_default() {
-        let wallet = HDWallet::generate_wallet(None, None).expect("Failed to generate wallet");
+        let wallet =
+            HDWallet::generate_wallet(None, None, None).expect("Failed to generate wallet");
         let mnemonic_phrase = wallet
             .get_backup_mnemonic_phrase()
             .expect("Failed to get backup mnemonic phrase");
@@ -375,7 +630,7 @@ mod hd_wallet_tests {
 
     #[test]
     fn test_generate_wallet_for_12_word_mnemonic() {
-        let wallet = HDWallet::generate_wallet(None, Some(MnemonicWordCount::Twelve))
+        let wallet = HDWallet::generate_wallet(None, Some(MnemonicWordCount::Twelve), None)
             .expect("Failed to generate wallet");
         let mnemonic_phrase = wallet
             .get_backup_mnemonic_phrase()
@@ -386,7 +641,7 @@ mod hd_wallet_tests {
 
     #[test]
     fn test_generate_wallet_for_18_word_mnemonic() {
-        let wallet = HDWallet::generate_wallet(None, Some(MnemonicWordCount::Eighteen))
+        let wallet = HDWallet::generate_wallet(None, Some(MnemonicWordCount::Eighteen), None)
             .expect("Failed to generate wallet");
         let mnemonic_phrase = wallet
             .get_backup_mnemonic_phrase()
@@ -397,7 +652,7 @@ mod hd_wallet_tests {
 
     #[test]
     fn test_generate_wallet_for_24_word_mnemonic() {
-        let wallet = HDWallet::generate_wallet(None, Some(MnemonicWordCount::TwentyFour))
+        let wallet = HDWallet::generate_wallet(None, Some(MnemonicWordCount::TwentyFour), None)
             .expect("Failed to generate wallet");
         let mnemonic_phrase = wallet
             .get_backup_mnemonic_phrase()
@@ -410,7 +665,7 @@ mod hd_wallet_tests {
     fn test_wallet_recovered_from_12_word_mnemonic() {
         let words = "guard input oyster oyster slot doctor repair shed soon assist blame power";
 
-        let wallet = HDWallet::recover_wallet(words.to_owned(), Some("".to_owned()))
+        let wallet = HDWallet::recover_wallet(words.to_owned(), Some("".to_owned()), None)
             .expect("Failed to recover wallet");
         assert_eq!(wallet.get_backup_mnemonic_phrase(), Some(words.to_owned()));
 
@@ -470,7 +725,7 @@ mod hd_wallet_tests {
     fn test_wallet_recovered_from_18_word_mnemonic() {
         let words = "kingdom donate chunk chapter hotel cigar diagram steel sunny grab allow ranch witness reveal window grunt slogan damp";
 
-        let wallet = HDWallet::recover_wallet(words.to_owned(), Some("".to_owned()))
+        let wallet = HDWallet::recover_wallet(words.to_owned(), Some("".to_owned()), None)
             .expect("Failed to recover wallet");
         assert_eq!(wallet.get_backup_mnemonic_phrase(), Some(words.to_owned()));
 
@@ -530,7 +785,7 @@ mod hd_wallet_tests {
     fn test_wallet_recovered_from_24_word_mnemonic() {
         let words = "dune car envelope chuckle elbow slight proud fury remove candy uphold puzzle call select sibling sport gadget please want vault glance verb damage gown";
 
-        let wallet = HDWallet::recover_wallet(words.to_owned(), Some("".to_owned()))
+        let wallet = HDWallet::recover_wallet(words.to_owned(), Some("".to_owned()), None)
             .expect("Failed to recover wallet");
         assert_eq!(wallet.get_backup_mnemonic_phrase(), Some(words.to_owned()));
 
@@ -590,7 +845,7 @@ mod hd_wallet_tests {
     fn test_get_key_from_index() {
         let words = "lumber flower voice hood obvious behave relax chief warm they they mountain";
 
-        let wallet = HDWallet::recover_wallet(words.to_owned(), Some("".to_owned()))
+        let wallet = HDWallet::recover_wallet(words.to_owned(), Some("".to_owned()), None)
             .expect("Failed to recover wallet");
         let key = wallet
             .get_key_from_index(
@@ -611,6 +866,79 @@ mod hd_wallet_tests {
             .expect("address error");
         assert_eq!(address, "0x68418d0fdb846e8736aa613159035a9d9fde11f0");
     }
+
+    #[test]
+    fn test_generate_and_recover_wallet_in_all_languages() {
+        let languages = [
+            MnemonicLanguage::English,
+            MnemonicLanguage::ChineseSimplified,
+            MnemonicLanguage::ChineseTraditional,
+            MnemonicLanguage::Czech,
+            MnemonicLanguage::French,
+            MnemonicLanguage::Italian,
+            MnemonicLanguage::Japanese,
+            MnemonicLanguage::Korean,
+            MnemonicLanguage::Spanish,
+        ];
+        let coin = WalletCoin::CosmosSDK {
+            network: Network::CosmosHub,
+        };
+        for language in languages {
+            let wallet = HDWallet::generate_wallet(
+                Some("password".to_owned()),
+                Some(MnemonicWordCount::Twelve),
+                Some(language),
+            )
+            .expect("Failed to generate wallet");
+            assert_eq!(wallet.get_mnemonic_language(), Some(language));
+            let phrase = wallet.get_backup_mnemonic_phrase().unwrap();
+
+            // recover with the explicit language
+            let recovered = HDWallet::recover_wallet(
+                phrase.clone(),
+                Some("password".to_owned()),
+                Some(language),
+            )
+            .expect("Failed to recover wallet");
+            assert_eq!(
+                recovered.get_default_address(coin.clone()).unwrap(),
+                wallet.get_default_address(coin.clone()).unwrap()
+            );
+
+            // recover with the detected language
+            let recovered = HDWallet::recover_wallet(phrase, Some("password".to_owned()), None)
+                .expect("Failed to recover wallet");
+            assert_eq!(
+                recovered.get_default_address(coin.clone()).unwrap(),
+                wallet.get_default_address(coin.clone()).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_recover_wallet_detects_language() {
+        let words = "あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　\
+                     あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あおぞら";
+        let wallet = HDWallet::recover_wallet(words.to_owned(), None, None)
+            .expect("Failed to recover wallet");
+        assert_eq!(
+            wallet.get_mnemonic_language(),
+            Some(MnemonicLanguage::Japanese)
+        );
+
+        let words = "lumber flower voice hood obvious behave relax chief warm they they mountain";
+        let wallet = HDWallet::recover_wallet(words.to_owned(), None, None)
+            .expect("Failed to recover wallet");
+        assert_eq!(
+            wallet.get_mnemonic_language(),
+            Some(MnemonicLanguage::English)
+        );
+
+        assert!(matches!(
+            HDWallet::recover_wallet(words.to_owned(), None, Some(MnemonicLanguage::Spanish)),
+            Err(HdWrapError::HDErrorBip39(bip39::Error::UnknownWord(_)))
+        ));
+    }
 }
 
 #[cfg(test)]
@@ -717,7 +1045,7 @@ mod secret_key_tests {
     fn test_eth_sign() {
         let words = "lumber flower voice hood obvious behave relax chief warm they they mountain";
 
-        let wallet = HDWallet::recover_wallet(words.to_owned(), Some("".to_owned()))
+        let wallet = HDWallet::recover_wallet(words.to_owned(), Some("".to_owned()), None)
             .expect("Failed to recover wallet");
         let key = wallet
             .get_key_from_index(
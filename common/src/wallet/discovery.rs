@@ -0,0 +1,200 @@
+#[cfg(not(target_arch = "wasm32"))]
+use super::WalletCoin;
+use super::{HDWallet, HdWrapError};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::node::{
+    get_eth_balance_blocking, get_eth_transaction_count_blocking, CosmosSDKClient,
+    RawRpcAccountResponse,
+};
+use crate::{EthError, RestError};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::{EthNetwork, Network};
+use serde::Serialize;
+
+/// the number of consecutive unused addresses after which the discovery stops (as in BIP44)
+pub const DEFAULT_GAP_LIMIT: u32 = 20;
+
+/// the first hardened index (addresses are derived from non-hardened indices only)
+const HARDENED_INDEX: u32 = 1 << 31;
+
+/// wrapper around account discovery errors
+#[derive(Debug, thiserror::Error)]
+pub enum AccountDiscoveryError {
+    #[error("HD wallet error: {0}")]
+    HdWrapError(HdWrapError),
+    #[error("Cosmos SDK node error: {0}")]
+    RestError(RestError),
+    #[error("Ethereum node error: {0}")]
+    EthError(EthError),
+}
+
+/// an address of the wallet with on-chain activity
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct DiscoveredAddress {
+    /// the address index (i.e. the last component of the derivation path)
+    pub index: u32,
+    /// the address
+    pub address: String,
+    /// the balance in base units (e.g. basecro or wei)
+    pub balance: String,
+    /// the account sequence (Cosmos SDK) or nonce (Ethereum)
+    pub sequence: u64,
+}
+
+impl HDWallet {
+    /// walks the addresses of the wallet (m/44'/coin_type'/0'/0/index) and returns the ones
+    /// that exist on-chain or hold a balance of the denomination.
+    /// the discovery stops after `gap_limit` (20 by default) consecutive unused addresses.
+    /// (blocking; not compiled to wasm)
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn discover_cosmos_accounts(
+        &self,
+        network: Network,
+        client: &CosmosSDKClient,
+        denom: &str,
+        gap_limit: Option<u32>,
+    ) -> Result<Vec<DiscoveredAddress>, AccountDiscoveryError> {
+        let coin = WalletCoin::CosmosSDK { network };
+        discover_used_addresses(gap_limit.unwrap_or(DEFAULT_GAP_LIMIT), |index| {
+            let address = self
+                .get_address(coin.clone(), index)
+                .map_err(AccountDiscoveryError::HdWrapError)?;
+            let balance = client
+                .get_account_balance(&address, denom)
+                .map_err(AccountDiscoveryError::RestError)?
+                .amount;
+            let sequence = match client
+                .get_account_details(&address)
+                .map_err(AccountDiscoveryError::RestError)?
+            {
+                RawRpcAccountResponse::OkResponse { account } => Some(account.sequence),
+                RawRpcAccountResponse::ErrorResponse { .. } => None,
+            };
+            Ok(
+                (sequence.is_some() || !is_zero_amount(&balance)).then(|| DiscoveredAddress {
+                    index,
+                    address,
+                    balance,
+                    sequence: sequence.unwrap_or_default(),
+                }),
+            )
+        })
+    }
+
+    /// walks the addresses of the wallet (m/44'/60'/0'/0/index) and returns the ones
+    /// that have sent a transaction or hold a balance.
+    /// the discovery stops after `gap_limit` (20 by default) consecutive unused addresses.
+    /// (blocking; not compiled to wasm)
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn discover_eth_accounts(
+        &self,
+        network: EthNetwork,
+        web3api_url: &str,
+        gap_limit: Option<u32>,
+    ) -> Result<Vec<DiscoveredAddress>, AccountDiscoveryError> {
+        let coin = WalletCoin::Ethereum { network };
+        discover_used_addresses(gap_limit.unwrap_or(DEFAULT_GAP_LIMIT), |index| {
+            let address = self
+                .get_address(coin.clone(), index)
+                .map_err(AccountDiscoveryError::HdWrapError)?;
+            let balance = get_eth_balance_blocking(&address, web3api_url)
+                .map_err(AccountDiscoveryError::EthError)?;
+            let nonce = get_eth_transaction_count_blocking(&address, web3api_url)
+                .map_err(AccountDiscoveryError::EthError)?;
+            let sequence = u64::try_from(nonce)
+                .map_err(|_| AccountDiscoveryError::EthError(EthError::Overflow))?;
+            Ok(
+                (sequence > 0 || !is_zero_amount(&balance)).then(|| DiscoveredAddress {
+                    index,
+                    address,
+                    balance,
+                    sequence,
+                }),
+            )
+        })
+    }
+}
+
+/// checks the address indices in order until `gap_limit` consecutive ones are unused
+/// (`check` returns `None` for an unused index)
+fn discover_used_addresses<F, E>(gap_limit: u32, mut check: F) -> Result<Vec<DiscoveredAddress>, E>
+where
+    F: FnMut(u32) -> Result<Option<DiscoveredAddress>, E>,
+{
+    let mut used = vec![];
+    let mut gap = 0;
+    let mut index = 0;
+    while gap < gap_limit.max(1) && index < HARDENED_INDEX {
+        match check(index)? {
+            Some(address) => {
+                used.push(address);
+                gap = 0;
+            }
+            None => gap += 1,
+        }
+        index += 1;
+    }
+    Ok(used)
+}
+
+/// an empty balance is returned as "" or "0"
+fn is_zero_amount(amount: &str) -> bool {
+    amount.trim_start_matches('0').is_empty()
+}
+
+#[cfg(test)]
+mod discovery_tests {
+    use super::*;
+
+    fn mock_address(index: u32) -> DiscoveredAddress {
+        DiscoveredAddress {
+            index,
+            address: format!("address{}", index),
+            balance: "100".to_owned(),
+            sequence: 1,
+        }
+    }
+
+    #[test]
+    fn test_discover_used_addresses_with_gap_limit() {
+        let used_indices = [0, 3, 7, 25];
+        let mut checked = vec![];
+        let result = discover_used_addresses::<_, ()>(5, |index| {
+            checked.push(index);
+            Ok(used_indices.contains(&index).then(|| mock_address(index)))
+        })
+        .unwrap();
+        assert_eq!(
+            result.iter().map(|x| x.index).collect::<Vec<_>>(),
+            vec![0, 3, 7]
+        );
+        // stops after 5 unused addresses following index 7
+        assert_eq!(checked.last(), Some(&12));
+
+        let result = discover_used_addresses::<_, ()>(DEFAULT_GAP_LIMIT, |index| {
+            Ok(used_indices.contains(&index).then(|| mock_address(index)))
+        })
+        .unwrap();
+        assert_eq!(result.len(), 4);
+    }
+
+    #[test]
+    fn test_discover_used_addresses_stops_on_error() {
+        let result = discover_used_addresses(DEFAULT_GAP_LIMIT, |index| {
+            if index == 2 {
+                Err("node error")
+            } else {
+                Ok(Some(mock_address(index)))
+            }
+        });
+        assert_eq!(result, Err("node error"));
+    }
+
+    #[test]
+    fn test_is_zero_amount() {
+        assert!(is_zero_amount(""));
+        assert!(is_zero_amount("0"));
+        assert!(!is_zero_amount("100"));
+        assert!(!is_zero_amount("10"));
+    }
+}
@@ -0,0 +1,241 @@
+use super::{HDWallet, HdWrapError, SecretKeyWrapError, WalletCoin};
+use cosmrs::bip32::DerivationPath;
+use ed25519_dalek::{Signer, SigningKey};
+use ethers::utils::hex;
+use hmac::{Hmac, Mac};
+use rand_core::{OsRng, RngCore};
+use secrecy::{ExposeSecret, Zeroize};
+use sha2::Sha512;
+use sha3::{Digest, Sha3_256};
+use std::sync::Arc;
+
+/// the HMAC key of the SLIP-0010 master key generation for ed25519
+const SLIP10_ED25519_SEED_KEY: &[u8] = b"ed25519 seed";
+/// the authentication key scheme identifier of a single ed25519 key on Aptos
+const APTOS_ED25519_SCHEME: u8 = 0;
+
+/// wrapper around ed25519 signing key (used by Solana and Aptos)
+pub struct Ed25519SecretKey(SigningKey);
+
+impl Ed25519SecretKey {
+    /// generates a random secret key
+    pub fn new() -> Self {
+        let mut bytes = [0u8; 32];
+        OsRng.fill_bytes(&mut bytes);
+        let key = SigningKey::from_bytes(&bytes);
+        bytes.zeroize();
+        Self(key)
+    }
+
+    /// constructs secret key from the 32-byte seed
+    pub fn from_bytes(mut bytes: Vec<u8>) -> Result<Self, SecretKeyWrapError> {
+        let result = SigningKey::try_from(bytes.as_slice())
+            .map(Self)
+            .map_err(|_| SecretKeyWrapError::InvalidEd25519Bytes);
+        bytes.zeroize();
+        result
+    }
+
+    /// constructs secret key from hex
+    pub fn from_hex(hex: String) -> Result<Self, SecretKeyWrapError> {
+        let bytes = hex::decode(hex).map_err(SecretKeyWrapError::InvalidHex)?;
+        Self::from_bytes(bytes)
+    }
+
+    /// gets public key to byte array
+    pub fn get_public_key_bytes(&self) -> Vec<u8> {
+        self.0.verifying_key().to_bytes().to_vec()
+    }
+
+    /// gets public key to a hex string without the 0x prefix
+    pub fn get_public_key_hex(&self) -> String {
+        hex::encode(self.0.verifying_key().to_bytes())
+    }
+
+    /// converts private key to byte array
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.0.to_bytes().to_vec()
+    }
+
+    /// converts private key to a hex string without the 0x prefix
+    pub fn to_hex(&self) -> String {
+        hex::encode(self.0.to_bytes())
+    }
+
+    /// signs an arbitrary message (e.g. a serialized Solana message
+    /// or an Aptos signing message) and returns the 64-byte signature
+    pub fn sign(&self, message: Vec<u8>) -> Vec<u8> {
+        self.0.sign(&message).to_bytes().to_vec()
+    }
+
+    /// converts private key to address with coin type
+    pub fn to_address(&self, coin: WalletCoin) -> Result<String, HdWrapError> {
+        let public_key = self.0.verifying_key().to_bytes();
+        match coin {
+            WalletCoin::Solana => Ok(bs58::encode(public_key).into_string()),
+            WalletCoin::Aptos => {
+                let mut hasher = Sha3_256::new();
+                hasher.update(public_key);
+                hasher.update([APTOS_ED25519_SCHEME]);
+                Ok(format!("0x{}", hex::encode(hasher.finalize())))
+            }
+            _ => Err(HdWrapError::UnsupportedKeyScheme),
+        }
+    }
+}
+
+impl Default for Ed25519SecretKey {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HDWallet {
+    /// return the ed25519 secret key for a given derivation path as per SLIP-0010
+    /// (all the path components should be hardened)
+    pub fn get_ed25519_key(
+        &self,
+        derivation_path: String,
+    ) -> Result<Arc<Ed25519SecretKey>, HdWrapError> {
+        let derivation_path: DerivationPath =
+            derivation_path.parse().map_err(HdWrapError::HDErrorBip32)?;
+        let mut key = slip10_ed25519_master_key(self.seed.expose_secret());
+        for child_number in derivation_path.iter() {
+            if !child_number.is_hardened() {
+                return Err(HdWrapError::Ed25519NonHardenedIndex);
+            }
+            let child = slip10_ed25519_child_key(&key, u32::from(child_number));
+            key.zeroize();
+            key = child;
+        }
+        let secret_key = SigningKey::from_bytes(key[..32].try_into().expect("32-byte key"));
+        key.zeroize();
+        Ok(Arc::new(Ed25519SecretKey(secret_key)))
+    }
+
+    /// return the ed25519 secret key for a given coin and index
+    /// Solana: m/44'/501'/index'/0', Aptos: m/44'/637'/index'/0'/0'
+    pub fn get_ed25519_key_from_index(
+        &self,
+        coin: WalletCoin,
+        index: u32,
+    ) -> Result<Arc<Ed25519SecretKey>, HdWrapError> {
+        let derivation_path = match coin {
+            WalletCoin::Solana => format!("m/44'/501'/{}'/0'", index),
+            WalletCoin::Aptos => format!("m/44'/637'/{}'/0'/0'", index),
+            _ => return Err(HdWrapError::UnsupportedKeyScheme),
+        };
+        self.get_ed25519_key(derivation_path)
+    }
+}
+
+/// the 32-byte secret key followed by the 32-byte chain code
+fn slip10_ed25519_master_key(seed: &[u8]) -> [u8; 64] {
+    hmac_sha512(SLIP10_ED25519_SEED_KEY, &[seed])
+}
+
+/// the hardened child of a parent key (ed25519 only supports hardened derivation)
+fn slip10_ed25519_child_key(parent: &[u8; 64], index: u32) -> [u8; 64] {
+    let (secret_key, chain_code) = parent.split_at(32);
+    hmac_sha512(chain_code, &[&[0], secret_key, &index.to_be_bytes()])
+}
+
+fn hmac_sha512(key: &[u8], data: &[&[u8]]) -> [u8; 64] {
+    let mut mac =
+        <Hmac<Sha512> as Mac>::new_from_slice(key).expect("HMAC can take key of any size");
+    for chunk in data {
+        mac.update(chunk);
+    }
+    let mut output = [0u8; 64];
+    output.copy_from_slice(&mac.finalize().into_bytes());
+    output
+}
+
+#[cfg(test)]
+mod ed25519_tests {
+    use super::*;
+    use secrecy::SecretVec;
+
+    const WORDS: &str = "guard input oyster oyster slot doctor repair shed soon assist blame power";
+
+    // test vector 1 from https://github.com/satoshilabs/slips/blob/master/slip-0010.md
+    #[test]
+    fn test_slip10_ed25519_derivation() {
+        let wallet = HDWallet {
+            seed: SecretVec::new(hex::decode("000102030405060708090a0b0c0d0e0f").unwrap()),
+            mnemonic: None,
+        };
+        let key = wallet.get_ed25519_key("m".to_owned()).unwrap();
+        assert_eq!(
+            key.to_hex(),
+            "2b4be7f19ee27bbf30c667b642d5f4aa69fd169872f8fc3059c08ebae2eb19e7"
+        );
+        assert_eq!(
+            key.get_public_key_hex(),
+            "a4b2856bfec510abab89753fac1ac0e1112364e7d250545963f135f2a33188ed"
+        );
+        let key = wallet.get_ed25519_key("m/0'/1'".to_owned()).unwrap();
+        assert_eq!(
+            key.to_hex(),
+            "b1d0bad404bf35da785a64ca1ac54b2617211d2777696fbffaf208f746ae84f2"
+        );
+        assert_eq!(
+            key.get_public_key_hex(),
+            "1932a5270f335bed617d5b935c80aedb1a35bd9fc1e31acafd5372c30f5c1187"
+        );
+        assert!(matches!(
+            wallet.get_ed25519_key("m/0'/1".to_owned()),
+            Err(HdWrapError::Ed25519NonHardenedIndex)
+        ));
+    }
+
+    #[test]
+    fn test_ed25519_addresses() {
+        let wallet = HDWallet::recover_wallet(WORDS.to_owned(), None, None).unwrap();
+        assert_eq!(
+            wallet.get_default_address(WalletCoin::Solana).unwrap(),
+            "8fny4seuouTt6t7JEZKJXvntiJA57Z2bKaxSUStSM5R8"
+        );
+        assert_eq!(
+            wallet.get_address(WalletCoin::Solana, 1).unwrap(),
+            "Eo4teQGKdULoAdixovcZJbtUkkMB87ELLLbPcUQuSvEB"
+        );
+        assert_eq!(
+            wallet.get_default_address(WalletCoin::Aptos).unwrap(),
+            "0x2c9375faa2763c75c849e29df5ef3c83b666fd4c3dffaa81674ba01352985079"
+        );
+        assert_eq!(
+            wallet.get_address(WalletCoin::Aptos, 1).unwrap(),
+            "0x6190ef33f482e72f433c1438d39765efd4835a164cf16bb31fcef1b9e26c3636"
+        );
+        assert!(matches!(
+            wallet.get_key_from_index(WalletCoin::Solana, 0),
+            Err(HdWrapError::UnsupportedKeyScheme)
+        ));
+    }
+
+    #[test]
+    fn test_ed25519_sign() {
+        let key = Ed25519SecretKey::from_hex(
+            "a54491f1a4f08ba65566bb9a1fd5105ddeb9671ebbb5e34a41e4247f0c2fc0bb".to_owned(),
+        )
+        .unwrap();
+        assert_eq!(
+            key.to_address(WalletCoin::Solana).unwrap(),
+            "8fny4seuouTt6t7JEZKJXvntiJA57Z2bKaxSUStSM5R8"
+        );
+        assert_eq!(
+            hex::encode(key.sign(b"hello".to_vec())),
+            "9a25cd5e28988c24fbe6c9718c913a6943e68885c04d6d77588d9ed12557d40d990f2dbd73af802ed887015c9d01b7a3b36f431a57821d9db5a8f3d2f0cd970b"
+        );
+        assert!(Ed25519SecretKey::from_bytes(vec![0u8; 31]).is_err());
+        let random_key = Ed25519SecretKey::new();
+        assert_eq!(random_key.get_public_key_bytes().len(), 32);
+        assert_eq!(
+            Ed25519SecretKey::from_bytes(random_key.to_bytes())
+                .unwrap()
+                .get_public_key_hex(),
+            random_key.get_public_key_hex()
+        );
+    }
+}
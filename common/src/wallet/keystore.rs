@@ -0,0 +1,593 @@
+use super::{parse_mnemonic, HDWallet, SecretKey};
+use aes::cipher::{KeyIvInit, StreamCipher};
+use ethers::utils::hex::{self, FromHex, ToHex};
+use ethers::utils::keccak256;
+use hmac::Hmac;
+use rand_core::{OsRng, RngCore};
+use secrecy::zeroize::Zeroizing;
+use secrecy::{ExposeSecret, SecretVec, Zeroize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use sha2::Sha256;
+use subtle::ConstantTimeEq;
+
+type Aes128Ctr = ctr::Ctr128BE<aes::Aes128>;
+
+const KEYSTORE_VERSION: u8 = 3;
+const DEFAULT_CIPHER: &str = "aes-128-ctr";
+const DEFAULT_PRF: &str = "hmac-sha256";
+const DEFAULT_DKLEN: u8 = 32;
+const DEFAULT_SALT_SIZE: usize = 32;
+const DEFAULT_IV_SIZE: usize = 16;
+const DEFAULT_SCRYPT_LOG_N: u8 = 13;
+const DEFAULT_SCRYPT_R: u32 = 8;
+const DEFAULT_SCRYPT_P: u32 = 1;
+const DEFAULT_PBKDF2_C: u32 = 262_144;
+// upper bounds of the imported KDF parameters, so that a crafted keystore can't exhaust
+// the time or memory of the wallet
+const MAX_SCRYPT_LOG_N: u32 = 20;
+const MAX_SCRYPT_R_P: u64 = 16;
+const MAX_PBKDF2_C: u32 = 10_000_000;
+const BIP39_SEED_SIZE: usize = 64;
+
+/// wrapper around keystore errors
+#[derive(Debug, thiserror::Error)]
+pub enum KeystoreError {
+    #[error("Invalid keystore JSON: {0}")]
+    InvalidJson(serde_json::Error),
+    #[error("Unsupported keystore version: {0}")]
+    UnsupportedVersion(u8),
+    #[error("Unsupported cipher: {0}")]
+    UnsupportedCipher(String),
+    #[error("Unsupported KDF parameters")]
+    UnsupportedKdf,
+    #[error("Invalid KDF parameters")]
+    InvalidKdfParams,
+    #[error("MAC mismatch: wrong password or corrupted keystore")]
+    MacMismatch,
+    #[error("Invalid keystore content: {0}")]
+    InvalidContent(String),
+}
+
+/// the key derivation function used to encrypt a keystore
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum KeystoreKdf {
+    /// scrypt (n = 2^13, r = 8, p = 1)
+    Scrypt,
+    /// PBKDF2 with HMAC-SHA256 (c = 262144)
+    Pbkdf2,
+}
+
+/// what secret the keystore holds
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KeystoreContent {
+    /// a single 32-byte secp256k1 private key (the standard Ethereum keystore)
+    SecretKey,
+    /// a 64-byte HD wallet seed, optionally followed by the UTF-8 backup mnemonic phrase
+    HdWallet,
+}
+
+/// Web3 Secret Storage (V3) keystore
+/// https://ethereum.org/en/developers/docs/data-structures-and-encoding/web3-secret-storage/
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Keystore {
+    /// the encrypted payload with its KDF and cipher parameters
+    pub crypto: KeystoreCrypto,
+    /// random UUID (v4) of the keystore
+    pub id: String,
+    /// keystore format version (always 3)
+    pub version: u8,
+    /// the lowercase hex address (without the 0x prefix) of the secret key, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+    /// the kind of the encrypted secret; keystores from other wallets don't have this field
+    /// and are treated as `SecretKey`
+    #[serde(default = "default_content", skip_serializing_if = "is_secret_key")]
+    pub content: KeystoreContent,
+}
+
+/// the "crypto" section of a keystore
+#[derive(Debug, Deserialize, Serialize)]
+pub struct KeystoreCrypto {
+    pub cipher: String,
+    pub cipherparams: KeystoreCipherParams,
+    #[serde(serialize_with = "buffer_to_hex", deserialize_with = "hex_to_buffer")]
+    pub ciphertext: Vec<u8>,
+    pub kdf: String,
+    pub kdfparams: KeystoreKdfParams,
+    #[serde(serialize_with = "buffer_to_hex", deserialize_with = "hex_to_buffer")]
+    pub mac: Vec<u8>,
+}
+
+/// the "cipherparams" section of a keystore
+#[derive(Debug, Deserialize, Serialize)]
+pub struct KeystoreCipherParams {
+    #[serde(serialize_with = "buffer_to_hex", deserialize_with = "hex_to_buffer")]
+    pub iv: Vec<u8>,
+}
+
+/// the "kdfparams" section of a keystore
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum KeystoreKdfParams {
+    Pbkdf2 {
+        c: u32,
+        dklen: u8,
+        prf: String,
+        #[serde(serialize_with = "buffer_to_hex", deserialize_with = "hex_to_buffer")]
+        salt: Vec<u8>,
+    },
+    Scrypt {
+        dklen: u8,
+        n: u32,
+        p: u32,
+        r: u32,
+        #[serde(serialize_with = "buffer_to_hex", deserialize_with = "hex_to_buffer")]
+        salt: Vec<u8>,
+    },
+}
+
+impl Keystore {
+    /// encrypts the raw secret with the password
+    pub fn encrypt(
+        secret: &[u8],
+        password: &str,
+        kdf: KeystoreKdf,
+        content: KeystoreContent,
+    ) -> Result<Self, KeystoreError> {
+        let mut salt = vec![0u8; DEFAULT_SALT_SIZE];
+        OsRng.fill_bytes(&mut salt);
+        let mut iv = vec![0u8; DEFAULT_IV_SIZE];
+        OsRng.fill_bytes(&mut iv);
+
+        let kdfparams = match kdf {
+            KeystoreKdf::Scrypt => KeystoreKdfParams::Scrypt {
+                dklen: DEFAULT_DKLEN,
+                n: 1 << DEFAULT_SCRYPT_LOG_N,
+                p: DEFAULT_SCRYPT_P,
+                r: DEFAULT_SCRYPT_R,
+                salt,
+            },
+            KeystoreKdf::Pbkdf2 => KeystoreKdfParams::Pbkdf2 {
+                c: DEFAULT_PBKDF2_C,
+                dklen: DEFAULT_DKLEN,
+                prf: DEFAULT_PRF.to_owned(),
+                salt,
+            },
+        };
+        let key = kdfparams.derive_key(password)?;
+
+        let mut ciphertext = secret.to_vec();
+        apply_keystream(&key[..16], &iv, &mut ciphertext)?;
+        let mac = compute_mac(&key, &ciphertext);
+
+        Ok(Self {
+            crypto: KeystoreCrypto {
+                cipher: DEFAULT_CIPHER.to_owned(),
+                cipherparams: KeystoreCipherParams { iv },
+                ciphertext,
+                kdf: kdfparams.name().to_owned(),
+                kdfparams,
+                mac: mac.to_vec(),
+            },
+            id: new_uuid_v4(),
+            version: KEYSTORE_VERSION,
+            address: None,
+            content,
+        })
+    }
+
+    /// verifies the MAC and returns the decrypted raw secret
+    pub fn decrypt(&self, password: &str) -> Result<Vec<u8>, KeystoreError> {
+        if self.version != KEYSTORE_VERSION {
+            return Err(KeystoreError::UnsupportedVersion(self.version));
+        }
+        if self.crypto.cipher != DEFAULT_CIPHER {
+            return Err(KeystoreError::UnsupportedCipher(self.crypto.cipher.clone()));
+        }
+
+        if self.crypto.kdf != self.crypto.kdfparams.name() {
+            return Err(KeystoreError::UnsupportedKdf);
+        }
+
+        let key = self.crypto.kdfparams.derive_key(password)?;
+        let mac = compute_mac(&key, &self.crypto.ciphertext);
+        if !bool::from(mac.as_slice().ct_eq(self.crypto.mac.as_slice())) {
+            return Err(KeystoreError::MacMismatch);
+        }
+
+        let mut secret = self.crypto.ciphertext.clone();
+        apply_keystream(&key[..16], &self.crypto.cipherparams.iv, &mut secret)?;
+        Ok(secret)
+    }
+
+    /// parses the keystore JSON
+    pub fn from_json(keystore_json: &str) -> Result<Self, KeystoreError> {
+        serde_json::from_str(keystore_json).map_err(KeystoreError::InvalidJson)
+    }
+
+    /// serializes the keystore into JSON
+    pub fn to_json(&self) -> Result<String, KeystoreError> {
+        serde_json::to_string(self).map_err(KeystoreError::InvalidJson)
+    }
+}
+
+impl KeystoreKdfParams {
+    fn name(&self) -> &str {
+        match self {
+            KeystoreKdfParams::Pbkdf2 { .. } => "pbkdf2",
+            KeystoreKdfParams::Scrypt { .. } => "scrypt",
+        }
+    }
+
+    /// the derived key is zeroized when dropped
+    fn derive_key(&self, password: &str) -> Result<Zeroizing<Vec<u8>>, KeystoreError> {
+        match self {
+            KeystoreKdfParams::Pbkdf2 {
+                c,
+                dklen,
+                prf,
+                salt,
+            } => {
+                if prf != DEFAULT_PRF {
+                    return Err(KeystoreError::UnsupportedKdf);
+                }
+                if *dklen < DEFAULT_DKLEN || *c > MAX_PBKDF2_C {
+                    return Err(KeystoreError::InvalidKdfParams);
+                }
+                let mut key = Zeroizing::new(vec![0u8; *dklen as usize]);
+                pbkdf2::pbkdf2::<Hmac<Sha256>>(password.as_bytes(), salt, *c, &mut key);
+                Ok(key)
+            }
+            KeystoreKdfParams::Scrypt {
+                dklen,
+                n,
+                p,
+                r,
+                salt,
+            } => {
+                if *dklen < DEFAULT_DKLEN
+                    || !n.is_power_of_two()
+                    || n.trailing_zeros() > MAX_SCRYPT_LOG_N
+                    || u64::from(*r) * u64::from(*p) > MAX_SCRYPT_R_P
+                {
+                    return Err(KeystoreError::InvalidKdfParams);
+                }
+                let log_n = n.trailing_zeros() as u8;
+                let params = scrypt::Params::new(log_n, *r, *p)
+                    .map_err(|_| KeystoreError::InvalidKdfParams)?;
+                let mut key = Zeroizing::new(vec![0u8; *dklen as usize]);
+                scrypt::scrypt(password.as_bytes(), salt, &params, &mut key)
+                    .map_err(|_| KeystoreError::InvalidKdfParams)?;
+                Ok(key)
+            }
+        }
+    }
+}
+
+/// decrypts a keystore and returns the raw secret bytes
+pub fn decrypt_keystore(keystore_json: String, password: String) -> Result<Vec<u8>, KeystoreError> {
+    Keystore::from_json(&keystore_json)?.decrypt(&password)
+}
+
+/// decrypts a keystore and encrypts its secret again with a new password (and fresh salt/IV)
+pub fn change_keystore_password(
+    keystore_json: String,
+    old_password: String,
+    new_password: String,
+    kdf: Option<KeystoreKdf>,
+) -> Result<String, KeystoreError> {
+    let keystore = Keystore::from_json(&keystore_json)?;
+    let mut secret = keystore.decrypt(&old_password)?;
+    let kdf = kdf.unwrap_or(match keystore.crypto.kdfparams {
+        KeystoreKdfParams::Pbkdf2 { .. } => KeystoreKdf::Pbkdf2,
+        KeystoreKdfParams::Scrypt { .. } => KeystoreKdf::Scrypt,
+    });
+    let result = Keystore::encrypt(&secret, &new_password, kdf, keystore.content);
+    secret.zeroize();
+    let mut new_keystore = result?;
+    new_keystore.address = keystore.address;
+    new_keystore.to_json()
+}
+
+impl SecretKey {
+    /// encrypts the private key into a Web3 Secret Storage (V3) keystore JSON
+    pub fn to_keystore(
+        &self,
+        password: String,
+        kdf: Option<KeystoreKdf>,
+    ) -> Result<String, KeystoreError> {
+        let mut secret = self.to_bytes();
+        let result = Keystore::encrypt(
+            &secret,
+            &password,
+            kdf.unwrap_or(KeystoreKdf::Scrypt),
+            KeystoreContent::SecretKey,
+        );
+        secret.zeroize();
+        let mut keystore = result?;
+        let address = ethers::utils::secret_key_to_address(
+            &self
+                .get_eth_signing_key()
+                .map_err(|e| KeystoreError::InvalidContent(e.to_string()))?,
+        );
+        keystore.address = Some(address.encode_hex());
+        keystore.to_json()
+    }
+
+    /// decrypts the private key from a Web3 Secret Storage (V3) keystore JSON
+    pub fn from_keystore(keystore_json: String, password: String) -> Result<Self, KeystoreError> {
+        let keystore = Keystore::from_json(&keystore_json)?;
+        if keystore.content != KeystoreContent::SecretKey {
+            return Err(KeystoreError::InvalidContent(
+                "the keystore does not contain a single private key".to_owned(),
+            ));
+        }
+        let mut secret = keystore.decrypt(&password)?;
+        let result = SecretKey::from_bytes(secret.clone())
+            .map_err(|e| KeystoreError::InvalidContent(e.to_string()));
+        secret.zeroize();
+        result
+    }
+}
+
+impl HDWallet {
+    /// encrypts the seed (and the backup mnemonic phrase if any)
+    /// into a Web3 Secret Storage (V3) keystore JSON
+    pub fn to_keystore(
+        &self,
+        password: String,
+        kdf: Option<KeystoreKdf>,
+    ) -> Result<String, KeystoreError> {
+        let mut secret = self.seed.expose_secret().to_vec();
+        if let Some(mnemonic) = &self.mnemonic {
+            secret.extend_from_slice(mnemonic.to_string().as_bytes());
+        }
+        let result = Keystore::encrypt(
+            &secret,
+            &password,
+            kdf.unwrap_or(KeystoreKdf::Scrypt),
+            KeystoreContent::HdWallet,
+        );
+        secret.zeroize();
+        result?.to_json()
+    }
+
+    /// decrypts the HD wallet from a keystore created by `HDWallet::to_keystore`
+    pub fn from_keystore(keystore_json: String, password: String) -> Result<Self, KeystoreError> {
+        let keystore = Keystore::from_json(&keystore_json)?;
+        if keystore.content != KeystoreContent::HdWallet {
+            return Err(KeystoreError::InvalidContent(
+                "the keystore does not contain an HD wallet".to_owned(),
+            ));
+        }
+        let mut secret = keystore.decrypt(&password)?;
+        let result = hd_wallet_from_secret(&secret);
+        secret.zeroize();
+        result
+    }
+}
+
+fn hd_wallet_from_secret(secret: &[u8]) -> Result<HDWallet, KeystoreError> {
+    if secret.len() < BIP39_SEED_SIZE {
+        return Err(KeystoreError::InvalidContent(
+            "the HD wallet seed should be 64-bytes".to_owned(),
+        ));
+    }
+    let (seed_bytes, phrase_bytes) = secret.split_at(BIP39_SEED_SIZE);
+
+    let mnemonic = if phrase_bytes.is_empty() {
+        None
+    } else {
+        let phrase = std::str::from_utf8(phrase_bytes)
+            .map_err(|e| KeystoreError::InvalidContent(e.to_string()))?;
+        Some(
+            parse_mnemonic(phrase, None)
+                .map_err(|e| KeystoreError::InvalidContent(e.to_string()))?,
+        )
+    };
+
+    Ok(HDWallet {
+        seed: SecretVec::new(seed_bytes.to_vec()),
+        mnemonic,
+    })
+}
+
+fn apply_keystream(key: &[u8], iv: &[u8], buf: &mut [u8]) -> Result<(), KeystoreError> {
+    let mut cipher =
+        Aes128Ctr::new_from_slices(key, iv).map_err(|_| KeystoreError::InvalidKdfParams)?;
+    cipher.apply_keystream(buf);
+    Ok(())
+}
+
+fn compute_mac(key: &[u8], ciphertext: &[u8]) -> [u8; 32] {
+    let mut data = key[16..32].to_vec();
+    data.extend_from_slice(ciphertext);
+    let mac = keccak256(&data);
+    data.zeroize();
+    mac
+}
+
+fn new_uuid_v4() -> String {
+    let mut bytes = [0u8; 16];
+    OsRng.fill_bytes(&mut bytes);
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    let hex = hex::encode(bytes);
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    )
+}
+
+fn default_content() -> KeystoreContent {
+    KeystoreContent::SecretKey
+}
+
+fn is_secret_key(content: &KeystoreContent) -> bool {
+    *content == KeystoreContent::SecretKey
+}
+
+fn buffer_to_hex<S>(buffer: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&hex::encode(buffer))
+}
+
+fn hex_to_buffer<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let string = String::deserialize(deserializer)?;
+    Vec::from_hex(string).map_err(serde::de::Error::custom)
+}
+
+#[cfg(test)]
+mod keystore_tests {
+    use super::*;
+    use crate::{EthNetwork, Network, WalletCoin};
+
+    const PBKDF2_KEYSTORE: &str = r#"{
+        "crypto" : {
+            "cipher" : "aes-128-ctr",
+            "cipherparams" : { "iv" : "6087dab2f9fdbbfaddc31a909735c1e6" },
+            "ciphertext" : "5318b4d5bcd28de64ee5559e671353e16f075ecae9f99c7a79a38af5f869aa46",
+            "kdf" : "pbkdf2",
+            "kdfparams" : {
+                "c" : 262144,
+                "dklen" : 32,
+                "prf" : "hmac-sha256",
+                "salt" : "ae3cd4e7013836a3df6bd7241b12db061dbe2c6785853cce422d148a624ce0bd"
+            },
+            "mac" : "517ead924a9d0dc3124507e3393d175ce3ff7c1e96529c6c555ce9e51205e9b2"
+        },
+        "id" : "3198bc9c-6672-5ab3-d995-4942343ae5b6",
+        "version" : 3
+    }"#;
+
+    #[test]
+    fn test_decrypt_pbkdf2_test_vector() {
+        let secret_key =
+            SecretKey::from_keystore(PBKDF2_KEYSTORE.to_owned(), "testpassword".to_owned())
+                .expect("Failed to decrypt keystore");
+        assert_eq!(
+            secret_key.to_hex(),
+            "7a28b5ba57c53603b0b07b56bba752f7784bf506fa95edc395f5cf6c7514fe9d"
+        );
+
+        assert!(matches!(
+            SecretKey::from_keystore(PBKDF2_KEYSTORE.to_owned(), "wrongpassword".to_owned()),
+            Err(KeystoreError::MacMismatch)
+        ));
+    }
+
+    #[test]
+    fn test_reject_mismatched_kdf_and_prf() {
+        let mismatched_kdf = PBKDF2_KEYSTORE.replace(r#""kdf" : "pbkdf2""#, r#""kdf" : "scrypt""#);
+        assert!(matches!(
+            decrypt_keystore(mismatched_kdf, "testpassword".to_owned()),
+            Err(KeystoreError::UnsupportedKdf)
+        ));
+
+        let unsupported_prf = PBKDF2_KEYSTORE.replace("hmac-sha256", "hmac-sha512");
+        assert!(matches!(
+            decrypt_keystore(unsupported_prf, "testpassword".to_owned()),
+            Err(KeystoreError::UnsupportedKdf)
+        ));
+    }
+
+    #[test]
+    fn test_reject_expensive_kdf_params() {
+        let expensive_pbkdf2 = PBKDF2_KEYSTORE.replace(r#""c" : 262144"#, r#""c" : 4294967295"#);
+        assert!(matches!(
+            decrypt_keystore(expensive_pbkdf2, "testpassword".to_owned()),
+            Err(KeystoreError::InvalidKdfParams)
+        ));
+
+        let keystore = SecretKey::new()
+            .to_keystore("password".to_owned(), None)
+            .unwrap();
+        for (key, value) in [("n", 1u32 << 31), ("r", 1024), ("p", 1024)] {
+            let mut json: serde_json::Value = serde_json::from_str(&keystore).unwrap();
+            json["crypto"]["kdfparams"][key] = value.into();
+            assert!(matches!(
+                decrypt_keystore(json.to_string(), "password".to_owned()),
+                Err(KeystoreError::InvalidKdfParams)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_secret_key_keystore_roundtrip() {
+        let secret_key = SecretKey::new();
+        let keystore = secret_key
+            .to_keystore("password".to_owned(), None)
+            .expect("Failed to encrypt keystore");
+
+        let parsed = Keystore::from_json(&keystore).unwrap();
+        assert_eq!(parsed.crypto.kdf, "scrypt");
+        let address = secret_key
+            .to_address(WalletCoin::Ethereum {
+                network: EthNetwork::Mainnet,
+            })
+            .unwrap();
+        assert_eq!(Some(address[2..].to_owned()), parsed.address);
+
+        let decrypted = SecretKey::from_keystore(keystore, "password".to_owned())
+            .expect("Failed to decrypt keystore");
+        assert_eq!(decrypted.to_hex(), secret_key.to_hex());
+    }
+
+    #[test]
+    fn test_hd_wallet_keystore_roundtrip() {
+        let words = "guard input oyster oyster slot doctor repair shed soon assist blame power";
+        let wallet =
+            HDWallet::recover_wallet(words.to_owned(), Some("passphrase".to_owned()), None)
+                .expect("Failed to recover wallet");
+        let keystore = wallet
+            .to_keystore("password".to_owned(), None)
+            .expect("Failed to encrypt keystore");
+
+        assert!(SecretKey::from_keystore(keystore.clone(), "password".to_owned()).is_err());
+
+        let restored = HDWallet::from_keystore(keystore, "password".to_owned())
+            .expect("Failed to decrypt keystore");
+        assert_eq!(
+            restored.get_backup_mnemonic_phrase(),
+            Some(words.to_owned())
+        );
+        let coin = WalletCoin::CosmosSDK {
+            network: Network::CryptoOrgMainnet,
+        };
+        assert_eq!(
+            restored.get_default_address(coin.clone()).unwrap(),
+            wallet.get_default_address(coin).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_change_keystore_password() {
+        let secret_key = SecretKey::new();
+        let keystore = secret_key
+            .to_keystore("old password".to_owned(), None)
+            .unwrap();
+        let keystore = change_keystore_password(
+            keystore,
+            "old password".to_owned(),
+            "new password".to_owned(),
+            None,
+        )
+        .expect("Failed to change password");
+
+        assert!(decrypt_keystore(keystore.clone(), "old password".to_owned()).is_err());
+        assert_eq!(
+            decrypt_keystore(keystore, "new password".to_owned()).unwrap(),
+            secret_key.to_bytes()
+        );
+    }
+}
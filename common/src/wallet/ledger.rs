@@ -0,0 +1,678 @@
+use super::signer::{SignerError, WalletSigner};
+use cosmrs::bip32::DerivationPath;
+use ethers::core::k256::ecdsa::{Signature as EcdsaSignature, VerifyingKey as EthVerifyingKey};
+use ethers::core::k256::elliptic_curve::sec1::ToEncodedPoint;
+use ethers::prelude::{Signature, U256};
+use ethers::signers::to_eip155_v;
+use ethers::types::transaction::eip2718::TypedTransaction;
+use std::sync::Arc;
+
+/// the instruction class of the Ledger Cosmos app
+const COSMOS_CLA: u8 = 0x55;
+/// signs a secp256k1 payload (in chunks) with the Ledger Cosmos app
+const COSMOS_INS_SIGN_SECP256K1: u8 = 0x02;
+/// returns the public key and the bech32 address with the Ledger Cosmos app
+const COSMOS_INS_GET_ADDR_SECP256K1: u8 = 0x04;
+/// the chunk types of the Ledger Cosmos app signing
+const COSMOS_CHUNK_INIT: u8 = 0x00;
+const COSMOS_CHUNK_ADD: u8 = 0x01;
+const COSMOS_CHUNK_LAST: u8 = 0x02;
+/// the Ledger Cosmos app requires BIP44 paths (m/44'/coin_type'/account'/change/index)
+const COSMOS_PATH_LENGTH: usize = 5;
+
+/// the instruction class of the Ledger Ethereum app
+const ETH_CLA: u8 = 0xe0;
+const ETH_INS_GET_PUBLIC_KEY: u8 = 0x02;
+const ETH_INS_SIGN_TX: u8 = 0x04;
+const ETH_INS_SIGN_PERSONAL_MESSAGE: u8 = 0x08;
+const ETH_INS_SIGN_EIP712_HASHED: u8 = 0x0c;
+/// the chunk types of the Ledger Ethereum app signing
+const ETH_CHUNK_FIRST: u8 = 0x00;
+const ETH_CHUNK_MORE: u8 = 0x80;
+
+/// the maximum size of the APDU data
+const MAX_APDU_DATA_SIZE: usize = 255;
+/// the Ledger Cosmos app expects chunks of 250 bytes
+const COSMOS_CHUNK_SIZE: usize = 250;
+/// the status word of a successful APDU command
+const SW_OK: u16 = 0x9000;
+
+/// sends APDU commands to a Ledger device (e.g. over USB HID, BLE or WebHID)
+/// the transport is provided by the application
+pub trait LedgerTransport: Send + Sync {
+    /// sends an APDU command and returns the response data followed by the 2-byte status word
+    fn exchange(&self, apdu: &[u8]) -> Result<Vec<u8>, SignerError>;
+}
+
+/// signer that uses the Ledger Cosmos app
+/// NOTE: the app only signs Amino JSON sign docs (`SIGN_MODE_LEGACY_AMINO_JSON`),
+/// so it has to be used with `build_signed_amino_msg_tx_with_signer`
+pub struct LedgerCosmosSigner {
+    transport: Arc<dyn LedgerTransport>,
+    path: Vec<u32>,
+    bech32_hrp: String,
+}
+
+impl LedgerCosmosSigner {
+    /// constructs a signer for the key at the BIP44 derivation path (e.g. m/44'/118'/0'/0/0)
+    pub fn new(
+        transport: Arc<dyn LedgerTransport>,
+        derivation_path: &str,
+        bech32_hrp: &str,
+    ) -> Result<Self, SignerError> {
+        let path = parse_derivation_path(derivation_path)?;
+        if path.len() != COSMOS_PATH_LENGTH {
+            return Err(SignerError::InvalidDerivationPath(
+                derivation_path.to_owned(),
+            ));
+        }
+        Ok(Self {
+            transport,
+            path,
+            bech32_hrp: bech32_hrp.to_owned(),
+        })
+    }
+
+    /// returns the bech32 address (and shows it on the device for a confirmation if `display` is set)
+    pub fn get_address(&self, display: bool) -> Result<String, SignerError> {
+        let (_, address) = self.get_public_key_and_address(display)?;
+        Ok(address)
+    }
+
+    fn get_public_key_and_address(&self, display: bool) -> Result<(Vec<u8>, String), SignerError> {
+        let mut data = vec![self.bech32_hrp.len() as u8];
+        data.extend_from_slice(self.bech32_hrp.as_bytes());
+        data.extend(self.path.iter().flat_map(|x| x.to_le_bytes()));
+        let response = exchange(
+            self.transport.as_ref(),
+            COSMOS_CLA,
+            COSMOS_INS_GET_ADDR_SECP256K1,
+            display as u8,
+            0,
+            &data,
+        )?;
+        if response.len() <= 33 {
+            return Err(SignerError::InvalidLedgerResponse);
+        }
+        let address = String::from_utf8(response[33..].to_vec())
+            .map_err(|_| SignerError::InvalidLedgerResponse)?;
+        Ok((response[..33].to_vec(), address))
+    }
+}
+
+impl WalletSigner for LedgerCosmosSigner {
+    fn get_public_key_bytes(&self) -> Result<Vec<u8>, SignerError> {
+        let (public_key, _) = self.get_public_key_and_address(false)?;
+        Ok(public_key)
+    }
+
+    fn sign_cosmos(&self, sign_bytes: &[u8]) -> Result<Vec<u8>, SignerError> {
+        // e.g. the protobuf `SignDoc` of `SIGN_MODE_DIRECT`
+        if !matches!(
+            serde_json::from_slice(sign_bytes),
+            Ok(serde_json::Value::Object(_))
+        ) {
+            return Err(SignerError::SigningFailed(
+                "the Ledger Cosmos app only signs Amino JSON sign docs".to_owned(),
+            ));
+        }
+        let path: Vec<u8> = self.path.iter().flat_map(|x| x.to_le_bytes()).collect();
+        exchange(
+            self.transport.as_ref(),
+            COSMOS_CLA,
+            COSMOS_INS_SIGN_SECP256K1,
+            COSMOS_CHUNK_INIT,
+            0,
+            &path,
+        )?;
+        let chunks: Vec<&[u8]> = sign_bytes.chunks(COSMOS_CHUNK_SIZE).collect();
+        let mut response = vec![];
+        for (i, chunk) in chunks.iter().enumerate() {
+            let chunk_type = if i + 1 == chunks.len() {
+                COSMOS_CHUNK_LAST
+            } else {
+                COSMOS_CHUNK_ADD
+            };
+            response = exchange(
+                self.transport.as_ref(),
+                COSMOS_CLA,
+                COSMOS_INS_SIGN_SECP256K1,
+                chunk_type,
+                0,
+                chunk,
+            )?;
+        }
+        der_signature_to_compact(&response)
+    }
+}
+
+/// signer that uses the Ledger Ethereum app
+pub struct LedgerEthSigner {
+    transport: Arc<dyn LedgerTransport>,
+    path: Vec<u32>,
+}
+
+impl LedgerEthSigner {
+    /// constructs a signer for the key at the derivation path (e.g. m/44'/60'/0'/0/0)
+    pub fn new(
+        transport: Arc<dyn LedgerTransport>,
+        derivation_path: &str,
+    ) -> Result<Self, SignerError> {
+        let path = parse_derivation_path(derivation_path)?;
+        Ok(Self { transport, path })
+    }
+
+    /// the number of path components followed by the big-endian path components
+    fn encoded_path(&self) -> Vec<u8> {
+        let mut data = vec![self.path.len() as u8];
+        data.extend(self.path.iter().flat_map(|x| x.to_be_bytes()));
+        data
+    }
+
+    /// sends the payload in chunks (the first one is prefixed with the path)
+    /// and returns the response to the last one
+    fn send_chunks(&self, ins: u8, payload: &[u8]) -> Result<Vec<u8>, SignerError> {
+        let mut data = self.encoded_path();
+        data.extend_from_slice(payload);
+        let mut response = vec![];
+        for (i, chunk) in data.chunks(MAX_APDU_DATA_SIZE).enumerate() {
+            let chunk_type = if i == 0 {
+                ETH_CHUNK_FIRST
+            } else {
+                ETH_CHUNK_MORE
+            };
+            response = exchange(self.transport.as_ref(), ETH_CLA, ins, chunk_type, 0, chunk)?;
+        }
+        Ok(response)
+    }
+}
+
+impl WalletSigner for LedgerEthSigner {
+    fn get_public_key_bytes(&self) -> Result<Vec<u8>, SignerError> {
+        let response = exchange(
+            self.transport.as_ref(),
+            ETH_CLA,
+            ETH_INS_GET_PUBLIC_KEY,
+            0,
+            0,
+            &self.encoded_path(),
+        )?;
+        let public_key_length = *response.first().ok_or(SignerError::InvalidLedgerResponse)?;
+        let public_key = response
+            .get(1..1 + public_key_length as usize)
+            .ok_or(SignerError::InvalidLedgerResponse)?;
+        let public_key = EthVerifyingKey::from_sec1_bytes(public_key)
+            .map_err(|_| SignerError::InvalidPublicKey)?;
+        Ok(public_key.to_encoded_point(true).as_bytes().to_vec())
+    }
+
+    fn sign_eth_transaction(&self, tx: &TypedTransaction) -> Result<Signature, SignerError> {
+        let chain_id = tx
+            .chain_id()
+            .ok_or_else(|| SignerError::SigningFailed("missing chain id".to_owned()))?
+            .as_u64();
+        let response = self.send_chunks(ETH_INS_SIGN_TX, &tx.rlp())?;
+        let signature = parse_eth_signature(&response)?;
+        // the app returns the parity for typed transactions
+        // and the lowest byte of the EIP-155 `v` for legacy ones
+        let recovery_id = match tx {
+            TypedTransaction::Legacy(_) => signature.v.wrapping_sub(35 + chain_id * 2) & 1,
+            _ => signature.v & 1,
+        };
+        Ok(Signature {
+            v: to_eip155_v(recovery_id as u8, chain_id),
+            ..signature
+        })
+    }
+
+    fn sign_eth_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        let mut payload = (message.len() as u32).to_be_bytes().to_vec();
+        payload.extend_from_slice(message);
+        let response = self.send_chunks(ETH_INS_SIGN_PERSONAL_MESSAGE, &payload)?;
+        parse_eth_signature(&response)
+    }
+
+    fn sign_eth_typed_data(
+        &self,
+        domain_separator: [u8; 32],
+        struct_hash: [u8; 32],
+    ) -> Result<Signature, SignerError> {
+        let mut data = self.encoded_path();
+        data.extend_from_slice(&domain_separator);
+        data.extend_from_slice(&struct_hash);
+        let response = exchange(
+            self.transport.as_ref(),
+            ETH_CLA,
+            ETH_INS_SIGN_EIP712_HASHED,
+            0,
+            0,
+            &data,
+        )?;
+        parse_eth_signature(&response)
+    }
+}
+
+/// sends an APDU command and checks its status word
+fn exchange(
+    transport: &dyn LedgerTransport,
+    cla: u8,
+    ins: u8,
+    p1: u8,
+    p2: u8,
+    data: &[u8],
+) -> Result<Vec<u8>, SignerError> {
+    if data.len() > MAX_APDU_DATA_SIZE {
+        return Err(SignerError::TransportError(
+            "APDU data is too long".to_owned(),
+        ));
+    }
+    let mut apdu = vec![cla, ins, p1, p2, data.len() as u8];
+    apdu.extend_from_slice(data);
+    let mut response = transport.exchange(&apdu)?;
+    if response.len() < 2 {
+        return Err(SignerError::InvalidLedgerResponse);
+    }
+    let status_bytes = response.split_off(response.len() - 2);
+    let status = u16::from_be_bytes([status_bytes[0], status_bytes[1]]);
+    if status != SW_OK {
+        return Err(SignerError::LedgerStatus(status));
+    }
+    Ok(response)
+}
+
+fn parse_derivation_path(derivation_path: &str) -> Result<Vec<u32>, SignerError> {
+    let path: DerivationPath = derivation_path
+        .parse()
+        .map_err(|_| SignerError::InvalidDerivationPath(derivation_path.to_owned()))?;
+    Ok(path.iter().map(u32::from).collect())
+}
+
+/// parses the `v` (1 byte), `r` (32 bytes) and `s` (32 bytes) returned by the Ledger Ethereum app
+fn parse_eth_signature(response: &[u8]) -> Result<Signature, SignerError> {
+    if response.len() < 65 {
+        return Err(SignerError::InvalidLedgerResponse);
+    }
+    Ok(Signature {
+        r: U256::from_big_endian(&response[1..33]),
+        s: U256::from_big_endian(&response[33..65]),
+        v: response[0] as u64,
+    })
+}
+
+/// converts a DER-encoded ECDSA signature to the 64-byte compact (r, s) one with a low `s`
+fn der_signature_to_compact(der: &[u8]) -> Result<Vec<u8>, SignerError> {
+    // SEQUENCE { INTEGER r, INTEGER s }
+    let body = match der {
+        [0x30, length, body @ ..] if *length as usize == body.len() => body,
+        _ => return Err(SignerError::InvalidLedgerResponse),
+    };
+    let (r, rest) = parse_der_integer(body)?;
+    let (s, rest) = parse_der_integer(rest)?;
+    if !rest.is_empty() {
+        return Err(SignerError::InvalidLedgerResponse);
+    }
+    let mut compact = [0u8; 64];
+    compact[32 - r.len()..32].copy_from_slice(r);
+    compact[64 - s.len()..].copy_from_slice(s);
+    let signature =
+        EcdsaSignature::from_slice(&compact).map_err(|_| SignerError::InvalidLedgerResponse)?;
+    let signature = signature.normalize_s().unwrap_or(signature);
+    Ok(signature.to_bytes().to_vec())
+}
+
+/// returns the (at most 32-byte) big-endian value of the DER integer and the remaining bytes
+fn parse_der_integer(der: &[u8]) -> Result<(&[u8], &[u8]), SignerError> {
+    match der {
+        [0x02, length, rest @ ..] if *length as usize <= rest.len() => {
+            let (value, rest) = rest.split_at(*length as usize);
+            // strip the sign padding
+            let value = match value {
+                [0, value @ ..] => value,
+                value => value,
+            };
+            if value.is_empty() || value.len() > 32 {
+                return Err(SignerError::InvalidLedgerResponse);
+            }
+            Ok((value, rest))
+        }
+        _ => Err(SignerError::InvalidLedgerResponse),
+    }
+}
+
+#[cfg(test)]
+mod ledger_tests {
+    use super::*;
+    use crate::wallet::{HDWallet, SecretKey};
+    use crate::{
+        build_signed_amino_msg_tx, build_signed_amino_msg_tx_with_signer, build_signed_eth_tx,
+        build_signed_eth_tx_with_signer, build_signed_msg_tx_with_signer,
+        get_amino_msg_sign_payload, CosmosError, CosmosSDKMsg, CosmosSDKTxInfo, EthAmount,
+        EthNetwork, EthTxInfo, Network, PublicKeyBytesWrapper, SingleCoin,
+    };
+    use ethers::core::k256::ecdsa::signature::hazmat::PrehashVerifier;
+    use ethers::utils::hash_message;
+    use sha2::{Digest, Sha256};
+    use std::sync::Mutex;
+
+    const WORDS: &str = "apple elegant knife hawk there screen vehicle lounge tube sun engage bus custom market pioneer casual wink present cat metal ride shallow fork brief";
+
+    /// emulates the Cosmos and Ethereum apps with an in-memory key
+    struct MockLedger {
+        key: Arc<SecretKey>,
+        chain_id: u64,
+        payload: Mutex<Vec<u8>>,
+        status: Option<u16>,
+    }
+
+    impl MockLedger {
+        fn new(key: Arc<SecretKey>, chain_id: u64) -> Self {
+            Self {
+                key,
+                chain_id,
+                payload: Mutex::new(vec![]),
+                status: None,
+            }
+        }
+
+        fn reply(&self, data: Vec<u8>) -> Vec<u8> {
+            let mut response = data;
+            response.extend_from_slice(&self.status.unwrap_or(SW_OK).to_be_bytes());
+            response
+        }
+
+        fn eth_signature(signature: Signature, v: u8) -> Vec<u8> {
+            let mut response = vec![v];
+            let mut bytes = [0u8; 32];
+            signature.r.to_big_endian(&mut bytes);
+            response.extend_from_slice(&bytes);
+            signature.s.to_big_endian(&mut bytes);
+            response.extend_from_slice(&bytes);
+            response
+        }
+
+        /// strips the encoded path from the first chunk of the Ethereum app
+        fn accumulate_eth_payload(&self, p1: u8, data: &[u8]) -> Vec<u8> {
+            let mut payload = self.payload.lock().unwrap();
+            if p1 == ETH_CHUNK_FIRST {
+                payload.clear();
+                payload.extend_from_slice(&data[1 + 4 * data[0] as usize..]);
+            } else {
+                payload.extend_from_slice(data);
+            }
+            payload.clone()
+        }
+    }
+
+    impl LedgerTransport for MockLedger {
+        fn exchange(&self, apdu: &[u8]) -> Result<Vec<u8>, SignerError> {
+            let (cla, ins, p1, data) = (apdu[0], apdu[1], apdu[2], &apdu[5..]);
+            assert_eq!(apdu[4] as usize, data.len());
+            if self.status.is_some() {
+                return Ok(self.reply(vec![]));
+            }
+            match (cla, ins) {
+                (COSMOS_CLA, COSMOS_INS_GET_ADDR_SECP256K1) => {
+                    let mut response = self.key.get_public_key_bytes();
+                    response.extend_from_slice(b"cosmos1address");
+                    Ok(self.reply(response))
+                }
+                (COSMOS_CLA, COSMOS_INS_SIGN_SECP256K1) => {
+                    let mut payload = self.payload.lock().unwrap();
+                    match p1 {
+                        COSMOS_CHUNK_INIT => {
+                            assert_eq!(data.len(), 4 * COSMOS_PATH_LENGTH);
+                            payload.clear();
+                            Ok(self.reply(vec![]))
+                        }
+                        COSMOS_CHUNK_ADD => {
+                            payload.extend_from_slice(data);
+                            Ok(self.reply(vec![]))
+                        }
+                        _ => {
+                            payload.extend_from_slice(data);
+                            let signature = WalletSigner::sign_cosmos(self.key.as_ref(), &payload)?;
+                            Ok(self.reply(compact_signature_to_der(&signature)))
+                        }
+                    }
+                }
+                (ETH_CLA, ETH_INS_GET_PUBLIC_KEY) => {
+                    let public_key =
+                        EthVerifyingKey::from_sec1_bytes(&self.key.get_public_key_bytes())
+                            .unwrap()
+                            .to_encoded_point(false);
+                    let mut response = vec![65];
+                    response.extend_from_slice(public_key.as_bytes());
+                    Ok(self.reply(response))
+                }
+                (ETH_CLA, ETH_INS_SIGN_TX) => {
+                    let payload = self.accumulate_eth_payload(p1, data);
+                    // the typed transactions are prefixed with their type
+                    let (legacy, rlp) = match payload[0] {
+                        0..=0x7f => (false, &payload[1..]),
+                        _ => (true, &payload[..]),
+                    };
+                    if !is_complete_rlp_list(rlp) {
+                        return Ok(self.reply(vec![]));
+                    }
+                    let hash = ethers::utils::keccak256(&payload);
+                    let signature = self.key.sign_eth_hash(hash)?;
+                    let recovery_id = signature.v - 27;
+                    let v = if legacy {
+                        to_eip155_v(recovery_id as u8, self.chain_id) as u8
+                    } else {
+                        recovery_id as u8
+                    };
+                    Ok(self.reply(Self::eth_signature(signature, v)))
+                }
+                (ETH_CLA, ETH_INS_SIGN_PERSONAL_MESSAGE) => {
+                    let payload = self.accumulate_eth_payload(p1, data);
+                    let length = u32::from_be_bytes(payload[..4].try_into().unwrap()) as usize;
+                    if payload.len() < 4 + length {
+                        return Ok(self.reply(vec![]));
+                    }
+                    let signature = self.key.sign_eth_message(&payload[4..])?;
+                    Ok(self.reply(Self::eth_signature(signature, signature.v as u8)))
+                }
+                (ETH_CLA, ETH_INS_SIGN_EIP712_HASHED) => {
+                    let hashes = &data[1 + 4 * data[0] as usize..];
+                    let signature = self.key.sign_eth_typed_data(
+                        hashes[..32].try_into().unwrap(),
+                        hashes[32..].try_into().unwrap(),
+                    )?;
+                    Ok(self.reply(Self::eth_signature(signature, signature.v as u8)))
+                }
+                _ => Ok(self.reply(vec![])),
+            }
+        }
+    }
+
+    fn is_complete_rlp_list(rlp: &[u8]) -> bool {
+        match rlp.first() {
+            Some(&prefix) if prefix >= 0xf8 => {
+                let length_size = (prefix - 0xf7) as usize;
+                rlp.len() > length_size
+                    && rlp.len()
+                        >= 1 + length_size
+                            + rlp[1..=length_size]
+                                .iter()
+                                .fold(0usize, |length, byte| (length << 8) | *byte as usize)
+            }
+            Some(&prefix) if prefix >= 0xc0 => rlp.len() > (prefix - 0xc0) as usize,
+            _ => false,
+        }
+    }
+
+    fn compact_signature_to_der(signature: &[u8]) -> Vec<u8> {
+        let encode_integer = |value: &[u8]| {
+            let value: Vec<u8> = value.iter().copied().skip_while(|x| *x == 0).collect();
+            let mut integer = vec![0x02];
+            if value[0] & 0x80 != 0 {
+                integer.push(value.len() as u8 + 1);
+                integer.push(0);
+            } else {
+                integer.push(value.len() as u8);
+            }
+            integer.extend(value);
+            integer
+        };
+        let body = [
+            encode_integer(&signature[..32]),
+            encode_integer(&signature[32..]),
+        ]
+        .concat();
+        [vec![0x30, body.len() as u8], body].concat()
+    }
+
+    fn get_key(path: &str) -> Arc<SecretKey> {
+        let wallet = HDWallet::recover_wallet(WORDS.to_owned(), None, None).unwrap();
+        wallet.get_key(path.to_owned()).unwrap()
+    }
+
+    #[test]
+    fn test_ledger_cosmos_signer() {
+        let path = "m/44'/118'/0'/0/0";
+        let key = get_key(path);
+        let transport = Arc::new(MockLedger::new(key.clone(), 0));
+        let signer = LedgerCosmosSigner::new(transport, path, "cosmos").unwrap();
+        assert_eq!(
+            signer.get_public_key_bytes().unwrap(),
+            key.get_public_key_bytes()
+        );
+        assert_eq!(signer.get_address(false).unwrap(), "cosmos1address");
+
+        let tx_info = || CosmosSDKTxInfo {
+            account_number: 1,
+            sequence_number: 0,
+            gas_limit: 100_000,
+            timeout_height: 9001,
+            fee_amount: vec![SingleCoin::ATOM { amount: 1 }],
+            memo_note: Some("a memo spanning several chunks ".repeat(10)),
+            network: Network::CosmosHub,
+            payer: None,
+            granter: None,
+        };
+        let msgs = || {
+            vec![CosmosSDKMsg::BankSend {
+                recipient_address: "cosmos1l5s7tnj28a7zxeeckhgwlhjys8dlrrefgqr4pj".to_owned(),
+                amount: SingleCoin::ATOM { amount: 1 },
+            }]
+        };
+        let sign_bytes = get_amino_msg_sign_payload(
+            tx_info(),
+            msgs(),
+            PublicKeyBytesWrapper(key.get_public_key_bytes()),
+        )
+        .unwrap();
+        assert!(sign_bytes.len() > COSMOS_CHUNK_SIZE);
+        let signature = signer.sign_cosmos(&sign_bytes).unwrap();
+        assert_eq!(
+            signature,
+            WalletSigner::sign_cosmos(key.as_ref(), &sign_bytes).unwrap()
+        );
+        let verifying_key = EthVerifyingKey::from_sec1_bytes(&key.get_public_key_bytes()).unwrap();
+        let signature = EcdsaSignature::from_slice(&signature).unwrap();
+        verifying_key
+            .verify_prehash(&Sha256::digest(&sign_bytes), &signature)
+            .unwrap();
+
+        assert_eq!(
+            build_signed_amino_msg_tx_with_signer(tx_info(), msgs(), &signer).unwrap(),
+            build_signed_amino_msg_tx(tx_info(), msgs(), key).unwrap()
+        );
+        // the direct sign mode is rejected
+        assert!(matches!(
+            build_signed_msg_tx_with_signer(tx_info(), msgs(), &signer),
+            Err(CosmosError::SignerError(SignerError::SigningFailed(_)))
+        ));
+
+        assert!(matches!(
+            LedgerCosmosSigner::new(
+                Arc::new(MockLedger::new(get_key(path), 0)),
+                "m/44'/118'/0'",
+                "cosmos"
+            ),
+            Err(SignerError::InvalidDerivationPath(_))
+        ));
+    }
+
+    #[test]
+    fn test_ledger_eth_signer() {
+        let path = "m/44'/60'/0'/0/0";
+        let key = get_key(path);
+        let networks = [(EthNetwork::Mainnet, 1), (EthNetwork::Cronos, 25)];
+        for (network, chain_id) in networks {
+            let transport = Arc::new(MockLedger::new(key.clone(), chain_id));
+            let signer = LedgerEthSigner::new(transport, path).unwrap();
+            assert_eq!(
+                signer.get_public_key_bytes().unwrap(),
+                key.get_public_key_bytes()
+            );
+            assert_eq!(
+                signer.get_eth_address().unwrap(),
+                key.get_eth_address().unwrap()
+            );
+            for legacy_tx in [true, false] {
+                let tx_info = || EthTxInfo {
+                    to_address: "0x2c600e0a72b3ae39e9b27d2e310b180abe779368".to_owned(),
+                    amount: EthAmount::EthDecimal {
+                        amount: "1".to_owned(),
+                    },
+                    nonce: "0".to_owned(),
+                    gas_limit: "21000".to_owned(),
+                    gas_price: EthAmount::WeiDecimal {
+                        amount: "1000".to_owned(),
+                    },
+                    // spans several chunks
+                    data: Some(vec![1u8; 300]),
+                    legacy_tx,
+                };
+                assert_eq!(
+                    build_signed_eth_tx_with_signer(tx_info(), network.clone(), &signer).unwrap(),
+                    build_signed_eth_tx(tx_info(), network.clone(), key.clone()).unwrap()
+                );
+            }
+
+            let message = vec![3u8; 400];
+            let signature = signer.sign_eth_message(&message).unwrap();
+            assert_eq!(signature, key.sign_eth_message(&message).unwrap());
+            assert_eq!(
+                signature.recover(hash_message(&message)).unwrap(),
+                key.get_eth_address().unwrap()
+            );
+            assert_eq!(
+                signer.sign_eth_typed_data([1; 32], [2; 32]).unwrap(),
+                key.sign_eth_typed_data([1; 32], [2; 32]).unwrap()
+            );
+            assert!(matches!(
+                signer.sign_eth_hash([0; 32]),
+                Err(SignerError::Unsupported)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_ledger_status_error() {
+        let path = "m/44'/60'/0'/0/0";
+        let mut transport = MockLedger::new(get_key(path), 1);
+        // conditions of use not satisfied (i.e. rejected by the user)
+        transport.status = Some(0x6985);
+        let signer = LedgerEthSigner::new(Arc::new(transport), path).unwrap();
+        assert!(matches!(
+            signer.sign_eth_typed_data([1; 32], [2; 32]),
+            Err(SignerError::LedgerStatus(0x6985))
+        ));
+    }
+
+    #[test]
+    fn test_der_signature_to_compact() {
+        let mut compact = [0u8; 64];
+        // `r` needs the sign padding and `s` has leading zeros
+        compact[0] = 0x80;
+        compact[31] = 1;
+        compact[63] = 2;
+        let der = compact_signature_to_der(&compact);
+        assert_eq!(der_signature_to_compact(&der).unwrap(), compact.to_vec());
+        assert!(der_signature_to_compact(&der[..der.len() - 1]).is_err());
+        assert!(der_signature_to_compact(&[0x30, 0]).is_err());
+    }
+}
@@ -0,0 +1,182 @@
+use super::MnemonicLanguage;
+use bip39::{Language, Mnemonic};
+use serde::Serialize;
+use std::borrow::Cow;
+
+/// the result of validating a (possibly partially typed) BIP39 phrase
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct MnemonicValidation {
+    /// the number of words in the phrase
+    pub word_count: u32,
+    /// the (zero-based) positions of the words that are not in the wordlist
+    pub invalid_word_positions: Vec<u32>,
+    /// whether the number of words is 12, 15, 18, 21 or 24
+    pub valid_word_count: bool,
+    /// whether the checksum is correct
+    /// (false if it cannot be verified due to unknown words or an invalid word count)
+    pub checksum_valid: bool,
+    /// the language of the wordlist (given or detected), if any
+    pub language: Option<MnemonicLanguage>,
+    /// whether the phrase can be used to recover a wallet
+    pub is_valid: bool,
+}
+
+/// validates a BIP39 phrase word by word
+/// the language is detected from the known words if it is not provided
+pub fn validate_mnemonic(
+    mnemonic_phrase: String,
+    language: Option<MnemonicLanguage>,
+) -> MnemonicValidation {
+    let phrase = normalize(&mnemonic_phrase);
+    let words: Vec<&str> = phrase.split_whitespace().collect();
+    let word_count = words.len();
+    let valid_word_count = (12..=24).contains(&word_count) && word_count % 3 == 0;
+
+    let language = language
+        .map(Language::from)
+        .or_else(|| detect_language(&words));
+    let invalid_word_positions: Vec<u32> = match language {
+        Some(language) => words
+            .iter()
+            .enumerate()
+            .filter(|(_, word)| language.find_word(word).is_none())
+            .map(|(i, _)| i as u32)
+            .collect(),
+        None => (0..word_count as u32).collect(),
+    };
+
+    let checksum_valid = match language {
+        Some(language) if valid_word_count && invalid_word_positions.is_empty() => {
+            Mnemonic::parse_in_normalized(language, &phrase).is_ok()
+        }
+        _ => false,
+    };
+
+    MnemonicValidation {
+        word_count: word_count as u32,
+        invalid_word_positions,
+        valid_word_count,
+        checksum_valid,
+        language: language.map(MnemonicLanguage::from),
+        is_valid: checksum_valid,
+    }
+}
+
+/// returns up to `limit` words of the wordlist (English by default) that start with the prefix
+pub fn suggest_mnemonic_words(
+    prefix: String,
+    language: Option<MnemonicLanguage>,
+    limit: u32,
+) -> Vec<String> {
+    let prefix = normalize(prefix.trim()).to_lowercase();
+    if prefix.is_empty() {
+        return vec![];
+    }
+    let language: Language = language.unwrap_or_default().into();
+    language
+        .word_list()
+        .iter()
+        .filter(|word| word.starts_with(&prefix))
+        .take(limit as usize)
+        .map(|word| word.to_string())
+        .collect()
+}
+
+/// the language with the most matching words wins;
+/// ties are resolved in the order of `Language::all()` (English first)
+fn detect_language(words: &[&str]) -> Option<Language> {
+    let mut best: Option<(Language, usize)> = None;
+    for language in Language::all() {
+        let matches = words
+            .iter()
+            .filter(|word| language.find_word(word).is_some())
+            .count();
+        if matches > 0 && best.map_or(true, |(_, best_matches)| matches > best_matches) {
+            best = Some((*language, matches));
+        }
+    }
+    best.map(|(language, _)| language)
+}
+
+fn normalize(phrase: &str) -> String {
+    let mut cow = Cow::Borrowed(phrase);
+    Mnemonic::normalize_utf8_cow(&mut cow);
+    cow.into_owned()
+}
+
+#[cfg(test)]
+mod mnemonic_tests {
+    use super::*;
+
+    const WORDS: &str =
+        "lumber flower voice hood obvious behave relax chief warm they they mountain";
+
+    #[test]
+    fn test_validate_valid_mnemonic() {
+        let result = validate_mnemonic(WORDS.to_owned(), None);
+        assert_eq!(
+            result,
+            MnemonicValidation {
+                word_count: 12,
+                invalid_word_positions: vec![],
+                valid_word_count: true,
+                checksum_valid: true,
+                language: Some(MnemonicLanguage::English),
+                is_valid: true,
+            }
+        );
+    }
+
+    #[test]
+    fn test_validate_mnemonic_with_unknown_words() {
+        let words = "lumber flowr voice hood obvious behave relax chief warm theyy they mountain";
+        let result = validate_mnemonic(words.to_owned(), None);
+        assert_eq!(result.word_count, 12);
+        assert_eq!(result.invalid_word_positions, vec![1, 9]);
+        assert_eq!(result.language, Some(MnemonicLanguage::English));
+        assert!(!result.checksum_valid);
+        assert!(!result.is_valid);
+    }
+
+    #[test]
+    fn test_validate_mnemonic_with_invalid_checksum() {
+        let words = "lumber flower voice hood obvious behave relax chief warm they they they";
+        let result = validate_mnemonic(words.to_owned(), Some(MnemonicLanguage::English));
+        assert!(result.invalid_word_positions.is_empty());
+        assert!(result.valid_word_count);
+        assert!(!result.checksum_valid);
+        assert!(!result.is_valid);
+    }
+
+    #[test]
+    fn test_validate_mnemonic_with_invalid_word_count() {
+        let result = validate_mnemonic("lumber flower voice".to_owned(), None);
+        assert_eq!(result.word_count, 3);
+        assert!(!result.valid_word_count);
+        assert!(result.invalid_word_positions.is_empty());
+        assert!(!result.is_valid);
+
+        let result = validate_mnemonic("".to_owned(), None);
+        assert_eq!(result.word_count, 0);
+        assert_eq!(result.language, None);
+        assert!(!result.is_valid);
+    }
+
+    #[test]
+    fn test_suggest_mnemonic_words() {
+        assert_eq!(
+            suggest_mnemonic_words("lum".to_owned(), None, 10),
+            vec!["lumber".to_owned()]
+        );
+        assert_eq!(
+            suggest_mnemonic_words("ab".to_owned(), None, 3),
+            vec![
+                "abandon".to_owned(),
+                "ability".to_owned(),
+                "able".to_owned()
+            ]
+        );
+        assert!(suggest_mnemonic_words("".to_owned(), None, 10).is_empty());
+        assert!(suggest_mnemonic_words("xyz".to_owned(), None, 10).is_empty());
+    }
+}
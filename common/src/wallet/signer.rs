@@ -0,0 +1,130 @@
+use super::{HdWrapError, SecretKey};
+use cosmrs::crypto::secp256k1::SigningKey as CosmosSigningKey;
+use ethers::core::k256::ecdsa::VerifyingKey as EthVerifyingKey;
+use ethers::core::k256::elliptic_curve::sec1::ToEncodedPoint;
+use ethers::prelude::{Address, LocalWallet, Signature, Signer, H256};
+use ethers::types::transaction::eip2718::TypedTransaction;
+use ethers::utils::{hash_message, keccak256};
+
+/// wrapper around signer errors
+#[derive(Debug, thiserror::Error)]
+pub enum SignerError {
+    #[error("The operation is not supported by the signer")]
+    Unsupported,
+    #[error("Invalid public key")]
+    InvalidPublicKey,
+    #[error("Signing failed: {0}")]
+    SigningFailed(String),
+    #[error("Invalid derivation path: {0}")]
+    InvalidDerivationPath(String),
+    #[error("Ledger transport error: {0}")]
+    TransportError(String),
+    #[error("Ledger device error: status word {0:#06x}")]
+    LedgerStatus(u16),
+    #[error("Invalid Ledger response")]
+    InvalidLedgerResponse,
+}
+
+/// signs Cosmos SDK and Ethereum payloads with a secp256k1 key
+/// that may live in memory (`SecretKey`) or on a hardware wallet (e.g. `LedgerEthSigner`)
+/// the operations a signer doesn't support return `SignerError::Unsupported`
+pub trait WalletSigner: Send + Sync {
+    /// returns the compressed SEC1 public key
+    fn get_public_key_bytes(&self) -> Result<Vec<u8>, SignerError>;
+
+    /// signs the sign bytes of a Cosmos SDK transaction (e.g. the serialized `SignDoc`)
+    /// and returns the 64-byte compact (r, s) signature
+    fn sign_cosmos(&self, _sign_bytes: &[u8]) -> Result<Vec<u8>, SignerError> {
+        Err(SignerError::Unsupported)
+    }
+
+    /// signs an Ethereum transaction (with its chain id set) and returns the EIP-155 signature
+    fn sign_eth_transaction(&self, _tx: &TypedTransaction) -> Result<Signature, SignerError> {
+        Err(SignerError::Unsupported)
+    }
+
+    /// signs an arbitrary message as per EIP-191
+    fn sign_eth_message(&self, _message: &[u8]) -> Result<Signature, SignerError> {
+        Err(SignerError::Unsupported)
+    }
+
+    /// signs EIP-712 typed data given its domain separator and the hash of its message
+    fn sign_eth_typed_data(
+        &self,
+        _domain_separator: [u8; 32],
+        _struct_hash: [u8; 32],
+    ) -> Result<Signature, SignerError> {
+        Err(SignerError::Unsupported)
+    }
+
+    /// signs a 32-byte hash directly (i.e. `eth_sign`; hardware wallets don't support it)
+    fn sign_eth_hash(&self, _hash: [u8; 32]) -> Result<Signature, SignerError> {
+        Err(SignerError::Unsupported)
+    }
+
+    /// returns the Ethereum address of the signer
+    fn get_eth_address(&self) -> Result<Address, SignerError> {
+        public_key_to_eth_address(&self.get_public_key_bytes()?)
+            .map_err(|_| SignerError::InvalidPublicKey)
+    }
+}
+
+impl WalletSigner for SecretKey {
+    fn get_public_key_bytes(&self) -> Result<Vec<u8>, SignerError> {
+        Ok(SecretKey::get_public_key_bytes(self))
+    }
+
+    fn sign_cosmos(&self, sign_bytes: &[u8]) -> Result<Vec<u8>, SignerError> {
+        let signing_key = CosmosSigningKey::new(Box::new(self.get_signing_key()));
+        let signature = signing_key
+            .sign(sign_bytes)
+            .map_err(|e| SignerError::SigningFailed(e.to_string()))?;
+        Ok(signature.to_vec())
+    }
+
+    fn sign_eth_transaction(&self, tx: &TypedTransaction) -> Result<Signature, SignerError> {
+        self.local_wallet()?
+            .sign_transaction_sync(tx)
+            .map_err(|e| SignerError::SigningFailed(e.to_string()))
+    }
+
+    fn sign_eth_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        self.sign_eth_hash(hash_message(message).0)
+    }
+
+    fn sign_eth_typed_data(
+        &self,
+        domain_separator: [u8; 32],
+        struct_hash: [u8; 32],
+    ) -> Result<Signature, SignerError> {
+        self.sign_eth_hash(eip712_hash(domain_separator, struct_hash))
+    }
+
+    fn sign_eth_hash(&self, hash: [u8; 32]) -> Result<Signature, SignerError> {
+        self.local_wallet()?
+            .sign_hash(H256(hash))
+            .map_err(|e| SignerError::SigningFailed(e.to_string()))
+    }
+}
+
+impl SecretKey {
+    fn local_wallet(&self) -> Result<LocalWallet, SignerError> {
+        self.get_eth_signing_key()
+            .map(LocalWallet::from)
+            .map_err(|e| SignerError::SigningFailed(e.to_string()))
+    }
+}
+
+/// the EIP-712 signing hash: keccak256(0x1901 ‖ domain separator ‖ struct hash)
+pub(crate) fn eip712_hash(domain_separator: [u8; 32], struct_hash: [u8; 32]) -> [u8; 32] {
+    keccak256([&[0x19, 0x01], &domain_separator[..], &struct_hash[..]].concat())
+}
+
+/// converts a SEC1-encoded secp256k1 public key to an Ethereum address
+pub(crate) fn public_key_to_eth_address(public_key: &[u8]) -> Result<Address, HdWrapError> {
+    let public_key =
+        EthVerifyingKey::from_sec1_bytes(public_key).map_err(|_| HdWrapError::InvalidPublicKey)?;
+    let public_key = public_key.to_encoded_point(false);
+    let hash = keccak256(&public_key.as_bytes()[1..]);
+    Ok(Address::from_slice(&hash[12..]))
+}
@@ -0,0 +1,861 @@
+use super::HDWallet;
+use bip39::{Language, Mnemonic};
+use hmac::{Hmac, Mac};
+use rand_core::{OsRng, RngCore};
+use secrecy::zeroize::Zeroizing;
+use secrecy::{ExposeSecret, SecretString, SecretVec, Zeroize};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use std::collections::BTreeMap;
+
+/// the SLIP-0039 wordlist
+mod wordlist;
+
+use wordlist::WORDLIST;
+
+const RADIX_BITS: usize = 10;
+const RADIX: u32 = 1 << RADIX_BITS;
+const ID_LENGTH_BITS: usize = 15;
+const ITERATION_EXP_LENGTH_BITS: usize = 4;
+const EXTENDABLE_FLAG_LENGTH_BITS: usize = 1;
+const CHECKSUM_LENGTH_WORDS: usize = 3;
+const METADATA_LENGTH_WORDS: usize = 4 + CHECKSUM_LENGTH_WORDS;
+const MIN_STRENGTH_BITS: usize = 128;
+/// 7 metadata words and 13 words of a 128-bit secret
+const MIN_MNEMONIC_LENGTH_WORDS: usize = 20;
+const MAX_SHARE_COUNT: u8 = 16;
+const BASE_ITERATION_COUNT: u32 = 10000;
+const ROUND_COUNT: u8 = 4;
+const SECRET_INDEX: u8 = 255;
+const DIGEST_INDEX: u8 = 254;
+const DIGEST_LENGTH_BYTES: usize = 4;
+const CUSTOMIZATION_STRING_ORIG: &[u8] = b"shamir";
+const CUSTOMIZATION_STRING_EXTENDABLE: &[u8] = b"shamir_extendable";
+const DEFAULT_ITERATION_EXPONENT: u8 = 1;
+const BIP39_SEED_LENGTH_BYTES: usize = 64;
+
+/// wrapper around SLIP-0039 errors
+#[derive(Debug, thiserror::Error)]
+pub enum Slip39Error {
+    #[error("Invalid master secret length: {0} bytes")]
+    InvalidSecretLength(usize),
+    #[error("Invalid sharing parameters: {0}")]
+    InvalidParameters(String),
+    #[error("Invalid share mnemonic: {0}")]
+    InvalidMnemonic(String),
+    #[error("Invalid share mnemonic checksum")]
+    InvalidChecksum,
+    #[error("Insufficient number of shares: {0}")]
+    InsufficientShares(String),
+    #[error("Invalid digest of the shared secret")]
+    InvalidDigest,
+}
+
+/// the member threshold and the member count of a SLIP-0039 group
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Slip39Group {
+    /// the number of member shares required to reconstruct the group share
+    pub member_threshold: u8,
+    /// the number of member shares in the group
+    pub member_count: u8,
+}
+
+/// a decoded SLIP-0039 share
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Share {
+    identifier: u16,
+    extendable: bool,
+    iteration_exponent: u8,
+    group_index: u8,
+    group_threshold: u8,
+    group_count: u8,
+    member_index: u8,
+    member_threshold: u8,
+    value: Vec<u8>,
+}
+
+impl Share {
+    fn to_mnemonic(&self) -> String {
+        let id_exp = (u32::from(self.identifier)
+            << (EXTENDABLE_FLAG_LENGTH_BITS + ITERATION_EXP_LENGTH_BITS))
+            | (u32::from(self.extendable) << ITERATION_EXP_LENGTH_BITS)
+            | u32::from(self.iteration_exponent);
+        let params = (u32::from(self.group_index) << 16)
+            | (u32::from(self.group_threshold - 1) << 12)
+            | (u32::from(self.group_count - 1) << 8)
+            | (u32::from(self.member_index) << 4)
+            | u32::from(self.member_threshold - 1);
+
+        let mut words = vec![
+            id_exp >> RADIX_BITS,
+            id_exp % RADIX,
+            params >> RADIX_BITS,
+            params % RADIX,
+        ];
+        words.extend(bytes_to_words(&self.value));
+        let checksum = rs1024_create_checksum(customization_string(self.extendable), &words);
+        words.extend(checksum);
+
+        words
+            .iter()
+            .map(|index| WORDLIST[*index as usize])
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    fn from_mnemonic(mnemonic: &str) -> Result<Self, Slip39Error> {
+        let words = mnemonic
+            .split_whitespace()
+            .map(|word| {
+                let word = word.to_lowercase();
+                WORDLIST
+                    .binary_search(&word.as_str())
+                    .map(|index| index as u32)
+                    .map_err(|_| Slip39Error::InvalidMnemonic(format!("unknown word: {word}")))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if words.len() < MIN_MNEMONIC_LENGTH_WORDS {
+            return Err(Slip39Error::InvalidMnemonic(format!(
+                "the share should have at least {MIN_MNEMONIC_LENGTH_WORDS} words"
+            )));
+        }
+        let padding_len = (RADIX_BITS * (words.len() - METADATA_LENGTH_WORDS)) % 16;
+        if padding_len > 8 {
+            return Err(Slip39Error::InvalidMnemonic(
+                "invalid number of words".to_owned(),
+            ));
+        }
+
+        let id_exp = (words[0] << RADIX_BITS) | words[1];
+        let identifier =
+            (id_exp >> (EXTENDABLE_FLAG_LENGTH_BITS + ITERATION_EXP_LENGTH_BITS)) as u16;
+        let extendable = (id_exp >> ITERATION_EXP_LENGTH_BITS) & 1 == 1;
+        let iteration_exponent = (id_exp & ((1 << ITERATION_EXP_LENGTH_BITS) - 1)) as u8;
+
+        if !rs1024_verify_checksum(customization_string(extendable), &words) {
+            return Err(Slip39Error::InvalidChecksum);
+        }
+
+        let params = (words[2] << RADIX_BITS) | words[3];
+        let group_index = (params >> 16) as u8;
+        let group_threshold = ((params >> 12) & 0xf) as u8 + 1;
+        let group_count = ((params >> 8) & 0xf) as u8 + 1;
+        let member_index = ((params >> 4) & 0xf) as u8;
+        let member_threshold = (params & 0xf) as u8 + 1;
+        if group_count < group_threshold {
+            return Err(Slip39Error::InvalidMnemonic(
+                "the group threshold exceeds the number of groups".to_owned(),
+            ));
+        }
+
+        let value_words = &words[4..words.len() - CHECKSUM_LENGTH_WORDS];
+        let value = words_to_bytes(value_words, padding_len)?;
+
+        Ok(Self {
+            identifier,
+            extendable,
+            iteration_exponent,
+            group_index,
+            group_threshold,
+            group_count,
+            member_index,
+            member_threshold,
+            value,
+        })
+    }
+}
+
+/// splits the master secret into SLIP-0039 share mnemonics (one list per group)
+/// the passphrase should only contain printable ASCII characters
+pub fn generate_slip39_shares(
+    master_secret: &[u8],
+    group_threshold: u8,
+    groups: &[Slip39Group],
+    passphrase: &str,
+    iteration_exponent: u8,
+    extendable: bool,
+) -> Result<Vec<Vec<String>>, Slip39Error> {
+    if master_secret.len() * 8 < MIN_STRENGTH_BITS || master_secret.len() & 1 == 1 {
+        return Err(Slip39Error::InvalidSecretLength(master_secret.len()));
+    }
+    if !passphrase.bytes().all(|c| (32..=126).contains(&c)) {
+        return Err(Slip39Error::InvalidParameters(
+            "the passphrase should only contain printable ASCII characters".to_owned(),
+        ));
+    }
+    if iteration_exponent >= 1 << ITERATION_EXP_LENGTH_BITS {
+        return Err(Slip39Error::InvalidParameters(format!(
+            "the iteration exponent should be less than {}",
+            1 << ITERATION_EXP_LENGTH_BITS
+        )));
+    }
+    if group_threshold == 0 || usize::from(group_threshold) > groups.len() {
+        return Err(Slip39Error::InvalidParameters(
+            "the group threshold should be between 1 and the number of groups".to_owned(),
+        ));
+    }
+    if groups.len() > usize::from(MAX_SHARE_COUNT) {
+        return Err(Slip39Error::InvalidParameters(format!(
+            "the number of groups should not exceed {MAX_SHARE_COUNT}"
+        )));
+    }
+    for group in groups {
+        if group.member_threshold == 1 && group.member_count > 1 {
+            return Err(Slip39Error::InvalidParameters(
+                "creating multiple member shares with member threshold 1 is not allowed".to_owned(),
+            ));
+        }
+    }
+
+    let mut random = [0u8; 2];
+    OsRng.fill_bytes(&mut random);
+    let identifier = u16::from_be_bytes(random) & ((1 << ID_LENGTH_BITS) - 1);
+
+    let mut encrypted_master_secret = encrypt(
+        master_secret,
+        passphrase.as_bytes(),
+        iteration_exponent,
+        identifier,
+        extendable,
+    );
+    let group_shares = split_secret(
+        group_threshold,
+        groups.len() as u8,
+        &encrypted_master_secret,
+    );
+    encrypted_master_secret.zeroize();
+
+    let mut result = Vec::with_capacity(groups.len());
+    for (group, (group_index, mut group_secret)) in groups.iter().zip(group_shares?) {
+        let member_shares = split_secret(group.member_threshold, group.member_count, &group_secret);
+        group_secret.zeroize();
+        let mnemonics = member_shares?
+            .into_iter()
+            .map(|(member_index, value)| {
+                Share {
+                    identifier,
+                    extendable,
+                    iteration_exponent,
+                    group_index,
+                    group_threshold,
+                    group_count: groups.len() as u8,
+                    member_index,
+                    member_threshold: group.member_threshold,
+                    value,
+                }
+                .to_mnemonic()
+            })
+            .collect();
+        result.push(mnemonics);
+    }
+    Ok(result)
+}
+
+/// recovers the master secret from a quorum of SLIP-0039 share mnemonics
+pub fn combine_slip39_shares(
+    mnemonics: &[String],
+    passphrase: &str,
+) -> Result<Vec<u8>, Slip39Error> {
+    let shares = mnemonics
+        .iter()
+        .map(|mnemonic| Share::from_mnemonic(mnemonic))
+        .collect::<Result<Vec<_>, _>>()?;
+    let first = shares
+        .first()
+        .ok_or_else(|| Slip39Error::InsufficientShares("no share provided".to_owned()))?;
+
+    let mut groups: BTreeMap<u8, Vec<&Share>> = BTreeMap::new();
+    for share in shares.iter() {
+        if share.identifier != first.identifier
+            || share.extendable != first.extendable
+            || share.iteration_exponent != first.iteration_exponent
+            || share.group_threshold != first.group_threshold
+            || share.group_count != first.group_count
+            || share.value.len() != first.value.len()
+        {
+            return Err(Slip39Error::InvalidMnemonic(
+                "all shares should belong to the same secret".to_owned(),
+            ));
+        }
+        let group = groups.entry(share.group_index).or_default();
+        if group
+            .iter()
+            .any(|other| other.member_threshold != share.member_threshold)
+        {
+            return Err(Slip39Error::InvalidMnemonic(
+                "all shares of a group should have the same member threshold".to_owned(),
+            ));
+        }
+        if group
+            .iter()
+            .any(|other| other.member_index == share.member_index)
+        {
+            return Err(Slip39Error::InvalidMnemonic(format!(
+                "duplicate member index {} in group {}",
+                share.member_index, share.group_index
+            )));
+        }
+        group.push(share);
+    }
+
+    let mut group_shares = vec![];
+    for (group_index, members) in groups.iter() {
+        let member_threshold = members[0].member_threshold;
+        if members.len() < usize::from(member_threshold) {
+            continue;
+        }
+        let member_shares: Vec<(u8, Vec<u8>)> = members
+            .iter()
+            .take(usize::from(member_threshold))
+            .map(|share| (share.member_index, share.value.clone()))
+            .collect();
+        group_shares.push((
+            *group_index,
+            recover_secret(member_threshold, &member_shares)?,
+        ));
+    }
+    if group_shares.len() < usize::from(first.group_threshold) {
+        return Err(Slip39Error::InsufficientShares(format!(
+            "{} of {} groups are complete",
+            group_shares.len(),
+            first.group_threshold
+        )));
+    }
+    group_shares.truncate(usize::from(first.group_threshold));
+
+    let mut encrypted_master_secret = recover_secret(first.group_threshold, &group_shares)?;
+    for (_, secret) in group_shares.iter_mut() {
+        secret.zeroize();
+    }
+    let master_secret = decrypt(
+        &encrypted_master_secret,
+        passphrase.as_bytes(),
+        first.iteration_exponent,
+        first.identifier,
+        first.extendable,
+    );
+    encrypted_master_secret.zeroize();
+    Ok(master_secret)
+}
+
+impl HDWallet {
+    /// splits the wallet into SLIP-0039 share mnemonics (one list per group)
+    /// the entropy of an English backup mnemonic phrase without a BIP39 password is split,
+    /// so that other SLIP-0039 implementations recover the same secret;
+    /// otherwise (other languages, a BIP39 password or no mnemonic) the 64-byte seed is split
+    /// `passphrase` defaults to an empty string and `iteration_exponent` to 1
+    pub fn to_slip39_shares(
+        &self,
+        group_threshold: u8,
+        groups: Vec<Slip39Group>,
+        passphrase: Option<String>,
+        iteration_exponent: Option<u8>,
+    ) -> Result<Vec<Vec<String>>, Slip39Error> {
+        let passphrase = SecretString::new(passphrase.unwrap_or_default());
+        let master_secret = match &self.mnemonic {
+            Some(mnemonic)
+                if mnemonic.language() == Language::English
+                    && Zeroizing::new(mnemonic.to_seed("")).as_slice()
+                        == self.seed.expose_secret().as_slice() =>
+            {
+                SecretVec::new(mnemonic.to_entropy())
+            }
+            _ => SecretVec::new(self.seed.expose_secret().clone()),
+        };
+        generate_slip39_shares(
+            master_secret.expose_secret(),
+            group_threshold,
+            &groups,
+            passphrase.expose_secret(),
+            iteration_exponent.unwrap_or(DEFAULT_ITERATION_EXPONENT),
+            true,
+        )
+    }
+
+    /// recovers/imports HD wallet from a quorum of SLIP-0039 share mnemonics and a passphrase
+    /// (a 64-byte secret is the seed of a wallet without the backup mnemonic phrase,
+    /// other secrets are the entropy of an English backup mnemonic phrase,
+    /// which is used without a BIP39 password)
+    pub fn recover_from_slip39_shares(
+        shares: Vec<String>,
+        passphrase: Option<String>,
+    ) -> Result<Self, Slip39Error> {
+        let passphrase = SecretString::new(passphrase.unwrap_or_default());
+        let master_secret =
+            SecretVec::new(combine_slip39_shares(&shares, passphrase.expose_secret())?);
+        if master_secret.expose_secret().len() == BIP39_SEED_LENGTH_BYTES {
+            return Ok(HDWallet {
+                seed: master_secret,
+                mnemonic: None,
+            });
+        }
+        let mnemonic = Mnemonic::from_entropy(master_secret.expose_secret())
+            .map_err(|_| Slip39Error::InvalidSecretLength(master_secret.expose_secret().len()))?;
+        Ok(HDWallet {
+            seed: SecretVec::new(mnemonic.to_seed("").to_vec()),
+            mnemonic: Some(mnemonic),
+        })
+    }
+}
+
+fn customization_string(extendable: bool) -> &'static [u8] {
+    if extendable {
+        CUSTOMIZATION_STRING_EXTENDABLE
+    } else {
+        CUSTOMIZATION_STRING_ORIG
+    }
+}
+
+fn rs1024_polymod(values: impl Iterator<Item = u32>) -> u32 {
+    const GEN: [u32; 10] = [
+        0xE0E040, 0x1C1C080, 0x3838100, 0x7070200, 0xE0E0009, 0x1C0C2412, 0x38086C24, 0x3090FC48,
+        0x21B1F890, 0x3F3F120,
+    ];
+    let mut chk = 1u32;
+    for value in values {
+        let b = chk >> 20;
+        chk = ((chk & 0xFFFFF) << 10) ^ value;
+        for (i, generator) in GEN.iter().enumerate() {
+            if (b >> i) & 1 == 1 {
+                chk ^= generator;
+            }
+        }
+    }
+    chk
+}
+
+fn rs1024_create_checksum(
+    customization_string: &[u8],
+    data: &[u32],
+) -> [u32; CHECKSUM_LENGTH_WORDS] {
+    let values = customization_string
+        .iter()
+        .map(|c| u32::from(*c))
+        .chain(data.iter().copied())
+        .chain([0; CHECKSUM_LENGTH_WORDS]);
+    let polymod = rs1024_polymod(values) ^ 1;
+    [
+        (polymod >> 20) % RADIX,
+        (polymod >> 10) % RADIX,
+        polymod % RADIX,
+    ]
+}
+
+fn rs1024_verify_checksum(customization_string: &[u8], data: &[u32]) -> bool {
+    let values = customization_string
+        .iter()
+        .map(|c| u32::from(*c))
+        .chain(data.iter().copied());
+    rs1024_polymod(values) == 1
+}
+
+/// converts bytes into 10-bit words (left-padded with zero bits)
+fn bytes_to_words(bytes: &[u8]) -> Vec<u32> {
+    let mut words = vec![];
+    let mut acc = 0u32;
+    let mut acc_bits = (RADIX_BITS - bytes.len() * 8 % RADIX_BITS) % RADIX_BITS;
+    for byte in bytes {
+        acc = (acc << 8) | u32::from(*byte);
+        acc_bits += 8;
+        while acc_bits >= RADIX_BITS {
+            acc_bits -= RADIX_BITS;
+            words.push((acc >> acc_bits) % RADIX);
+        }
+        acc &= (1 << acc_bits) - 1;
+    }
+    words
+}
+
+/// converts 10-bit words into bytes (the leading padding bits should be zero)
+fn words_to_bytes(words: &[u32], padding_len: usize) -> Result<Vec<u8>, Slip39Error> {
+    let mut bytes = Vec::with_capacity((words.len() * RADIX_BITS - padding_len) / 8);
+    let mut acc = 0u32;
+    let mut acc_bits = 0usize;
+    let mut padding = padding_len;
+    for word in words {
+        acc = (acc << RADIX_BITS) | word;
+        acc_bits += RADIX_BITS;
+        if padding > 0 {
+            acc_bits -= padding;
+            if acc >> acc_bits != 0 {
+                return Err(Slip39Error::InvalidMnemonic(
+                    "invalid padding of the share value".to_owned(),
+                ));
+            }
+            padding = 0;
+        }
+        while acc_bits >= 8 {
+            acc_bits -= 8;
+            bytes.push((acc >> acc_bits) as u8);
+        }
+        acc &= (1 << acc_bits) - 1;
+    }
+    Ok(bytes)
+}
+
+/// the exponent and logarithm tables of GF(256) with the Rijndael polynomial
+fn gf256_tables() -> ([u8; 255], [u8; 256]) {
+    let mut exp = [0u8; 255];
+    let mut log = [0u8; 256];
+    let mut poly = 1u16;
+    for (i, exp_value) in exp.iter_mut().enumerate() {
+        *exp_value = poly as u8;
+        log[poly as usize] = i as u8;
+        // multiply poly by the polynomial x + 1
+        poly = (poly << 1) ^ poly;
+        // reduce poly by x^8 + x^4 + x^3 + x + 1
+        if poly & 0x100 != 0 {
+            poly ^= 0x11B;
+        }
+    }
+    (exp, log)
+}
+
+/// evaluates the Lagrange interpolation polynomial of the shares at `x`
+fn interpolate(shares: &[(u8, Vec<u8>)], x: u8) -> Result<Vec<u8>, Slip39Error> {
+    if let Some((_, value)) = shares.iter().find(|(share_x, _)| *share_x == x) {
+        return Ok(value.clone());
+    }
+    let (exp, log) = gf256_tables();
+    let value_len = shares.first().map(|(_, value)| value.len()).unwrap_or(0);
+    if shares.iter().any(|(_, value)| value.len() != value_len) {
+        return Err(Slip39Error::InvalidMnemonic(
+            "all share values should have the same length".to_owned(),
+        ));
+    }
+
+    let log_prod: u32 = shares
+        .iter()
+        .map(|(share_x, _)| u32::from(log[usize::from(share_x ^ x)]))
+        .sum();
+    let mut result = vec![0u8; value_len];
+    for (share_x, value) in shares {
+        let log_others: u32 = shares
+            .iter()
+            .map(|(other_x, _)| u32::from(log[usize::from(share_x ^ other_x)]))
+            .sum();
+        let log_basis_eval = (log_prod + 255 * shares.len() as u32 * 2
+            - u32::from(log[usize::from(share_x ^ x)])
+            - log_others)
+            % 255;
+        for (r, v) in result.iter_mut().zip(value) {
+            if *v != 0 {
+                *r ^= exp[((u32::from(log[usize::from(*v)]) + log_basis_eval) % 255) as usize];
+            }
+        }
+    }
+    Ok(result)
+}
+
+fn split_secret(
+    threshold: u8,
+    share_count: u8,
+    secret: &[u8],
+) -> Result<Vec<(u8, Vec<u8>)>, Slip39Error> {
+    if threshold == 0 || threshold > share_count || share_count > MAX_SHARE_COUNT {
+        return Err(Slip39Error::InvalidParameters(format!(
+            "the threshold {threshold} and the number of shares {share_count} are invalid"
+        )));
+    }
+    if threshold == 1 {
+        return Ok((0..share_count).map(|i| (i, secret.to_vec())).collect());
+    }
+
+    let random_share_count = threshold - 2;
+    let mut shares: Vec<(u8, Vec<u8>)> = (0..random_share_count)
+        .map(|i| {
+            let mut value = vec![0u8; secret.len()];
+            OsRng.fill_bytes(&mut value);
+            (i, value)
+        })
+        .collect();
+
+    let mut random_part = vec![0u8; secret.len() - DIGEST_LENGTH_BYTES];
+    OsRng.fill_bytes(&mut random_part);
+    let mut digest_share = create_digest(&random_part, secret);
+    digest_share.extend_from_slice(&random_part);
+    random_part.zeroize();
+
+    let mut base_shares = shares.clone();
+    base_shares.push((DIGEST_INDEX, digest_share));
+    base_shares.push((SECRET_INDEX, secret.to_vec()));
+
+    for i in random_share_count..share_count {
+        shares.push((i, interpolate(&base_shares, i)?));
+    }
+    for (_, value) in base_shares.iter_mut() {
+        value.zeroize();
+    }
+    Ok(shares)
+}
+
+fn recover_secret(threshold: u8, shares: &[(u8, Vec<u8>)]) -> Result<Vec<u8>, Slip39Error> {
+    if threshold == 1 {
+        return shares
+            .first()
+            .map(|(_, value)| value.clone())
+            .ok_or_else(|| Slip39Error::InsufficientShares("no share provided".to_owned()));
+    }
+    let secret = interpolate(shares, SECRET_INDEX)?;
+    let digest_share = interpolate(shares, DIGEST_INDEX)?;
+    let (digest, random_part) = digest_share.split_at(DIGEST_LENGTH_BYTES);
+    if digest != create_digest(random_part, &secret).as_slice() {
+        return Err(Slip39Error::InvalidDigest);
+    }
+    Ok(secret)
+}
+
+fn create_digest(random_data: &[u8], shared_secret: &[u8]) -> Vec<u8> {
+    let mut mac =
+        <Hmac<Sha256> as Mac>::new_from_slice(random_data).expect("HMAC can take key of any size");
+    mac.update(shared_secret);
+    mac.finalize().into_bytes()[..DIGEST_LENGTH_BYTES].to_vec()
+}
+
+fn get_salt(identifier: u16, extendable: bool) -> Vec<u8> {
+    if extendable {
+        vec![]
+    } else {
+        let mut salt = CUSTOMIZATION_STRING_ORIG.to_vec();
+        salt.extend_from_slice(&identifier.to_be_bytes());
+        salt
+    }
+}
+
+fn round_function(
+    round: u8,
+    passphrase: &[u8],
+    iteration_exponent: u8,
+    salt: &[u8],
+    r: &[u8],
+) -> Vec<u8> {
+    let mut password = vec![round];
+    password.extend_from_slice(passphrase);
+    let mut salt = salt.to_vec();
+    salt.extend_from_slice(r);
+    let iterations = (BASE_ITERATION_COUNT << iteration_exponent) / u32::from(ROUND_COUNT);
+    let mut output = vec![0u8; r.len()];
+    pbkdf2::pbkdf2::<Hmac<Sha256>>(&password, &salt, iterations, &mut output);
+    password.zeroize();
+    output
+}
+
+/// the 4-round Feistel network encryption of the master secret
+fn encrypt(
+    master_secret: &[u8],
+    passphrase: &[u8],
+    iteration_exponent: u8,
+    identifier: u16,
+    extendable: bool,
+) -> Vec<u8> {
+    let (l, r) = master_secret.split_at(master_secret.len() / 2);
+    let (mut l, mut r) = (l.to_vec(), r.to_vec());
+    let salt = get_salt(identifier, extendable);
+    for round in 0..ROUND_COUNT {
+        let f = round_function(round, passphrase, iteration_exponent, &salt, &r);
+        let new_r = xor(&l, &f);
+        l.zeroize();
+        l = r;
+        r = new_r;
+    }
+    r.extend_from_slice(&l);
+    l.zeroize();
+    r
+}
+
+/// the 4-round Feistel network decryption of the encrypted master secret
+fn decrypt(
+    encrypted_master_secret: &[u8],
+    passphrase: &[u8],
+    iteration_exponent: u8,
+    identifier: u16,
+    extendable: bool,
+) -> Vec<u8> {
+    let (l, r) = encrypted_master_secret.split_at(encrypted_master_secret.len() / 2);
+    let (mut l, mut r) = (l.to_vec(), r.to_vec());
+    let salt = get_salt(identifier, extendable);
+    for round in (0..ROUND_COUNT).rev() {
+        let f = round_function(round, passphrase, iteration_exponent, &salt, &r);
+        let new_r = xor(&l, &f);
+        l.zeroize();
+        l = r;
+        r = new_r;
+    }
+    r.extend_from_slice(&l);
+    l.zeroize();
+    r
+}
+
+fn xor(a: &[u8], b: &[u8]) -> Vec<u8> {
+    a.iter().zip(b).map(|(x, y)| x ^ y).collect()
+}
+
+#[cfg(test)]
+mod slip39_tests {
+    use super::*;
+    use crate::{MnemonicLanguage, Network, WalletCoin};
+
+    // test vectors from https://github.com/trezor/python-shamir-mnemonic/blob/master/vectors.json
+    #[test]
+    fn test_combine_single_share() {
+        let shares = vec!["duckling enlarge academic academic agency result length solution fridge kidney coal piece deal husband erode duke ajar critical decision keyboard".to_owned()];
+        let secret = combine_slip39_shares(&shares, "TREZOR").unwrap();
+        assert_eq!(hex::encode(secret), "bb54aac4b89dc868ba37d9cc21b2cece");
+    }
+
+    #[test]
+    fn test_combine_two_of_three_shares() {
+        let shares = vec![
+            "shadow pistol academic always adequate wildlife fancy gross oasis cylinder mustang wrist rescue view short owner flip making coding armed".to_owned(),
+            "shadow pistol academic acid actress prayer class unknown daughter sweater depict flip twice unkind craft early superior advocate guest smoking".to_owned(),
+        ];
+        let secret = combine_slip39_shares(&shares, "TREZOR").unwrap();
+        assert_eq!(hex::encode(secret), "b43ceb7e57a0ea8766221624d01b0864");
+
+        assert!(matches!(
+            combine_slip39_shares(&shares[..1], "TREZOR"),
+            Err(Slip39Error::InsufficientShares(_))
+        ));
+    }
+
+    #[test]
+    fn test_combine_256_bit_share() {
+        let shares = vec!["theory painting academic academic armed sweater year military elder discuss acne wildlife boring employer fused large satoshi bundle carbon diagnose anatomy hamster leaves tracks paces beyond phantom capital marvel lips brave detect luck".to_owned()];
+        let secret = combine_slip39_shares(&shares, "TREZOR").unwrap();
+        assert_eq!(
+            hex::encode(secret),
+            "989baf9dcaad5b10ca33dfd8cc75e42477025dce88ae83e75a230086a0e00e92"
+        );
+    }
+
+    #[test]
+    fn test_invalid_checksum() {
+        let shares = vec!["duckling enlarge academic academic agency result length solution fridge kidney coal piece deal husband erode duke ajar critical decision kidney".to_owned()];
+        assert!(matches!(
+            combine_slip39_shares(&shares, "TREZOR"),
+            Err(Slip39Error::InvalidChecksum)
+        ));
+    }
+
+    #[test]
+    fn test_hd_wallet_slip39_roundtrip() {
+        let words = "guard input oyster oyster slot doctor repair shed soon assist blame power";
+        let wallet = HDWallet::recover_wallet(words.to_owned(), None, None).unwrap();
+        let groups = vec![
+            Slip39Group {
+                member_threshold: 1,
+                member_count: 1,
+            },
+            Slip39Group {
+                member_threshold: 2,
+                member_count: 3,
+            },
+            Slip39Group {
+                member_threshold: 3,
+                member_count: 5,
+            },
+        ];
+        let shares = wallet
+            .to_slip39_shares(2, groups, Some("passphrase".to_owned()), Some(0))
+            .expect("Failed to generate shares");
+        assert_eq!(shares.len(), 3);
+        assert_eq!(shares[1].len(), 3);
+        assert_eq!(shares[2].len(), 5);
+        // a 16-byte entropy takes 4 + 13 + 3 words
+        assert_eq!(shares[0][0].split_whitespace().count(), 20);
+
+        let quorum = vec![
+            shares[0][0].clone(),
+            shares[2][4].clone(),
+            shares[2][0].clone(),
+            shares[2][2].clone(),
+        ];
+        let recovered =
+            HDWallet::recover_from_slip39_shares(quorum.clone(), Some("passphrase".to_owned()))
+                .expect("Failed to recover wallet");
+        let coin = WalletCoin::CosmosSDK {
+            network: Network::CosmosHub,
+        };
+        assert_eq!(
+            recovered.get_default_address(coin.clone()).unwrap(),
+            wallet.get_default_address(coin.clone()).unwrap()
+        );
+        assert_eq!(
+            recovered.get_backup_mnemonic_phrase().as_deref(),
+            Some(words)
+        );
+
+        // a wrong passphrase recovers a different wallet
+        let other = HDWallet::recover_from_slip39_shares(quorum, None).unwrap();
+        assert_ne!(
+            other.get_default_address(coin.clone()).unwrap(),
+            wallet.get_default_address(coin).unwrap()
+        );
+
+        assert!(matches!(
+            HDWallet::recover_from_slip39_shares(
+                vec![shares[1][0].clone(), shares[2][1].clone()],
+                None
+            ),
+            Err(Slip39Error::InsufficientShares(_))
+        ));
+    }
+
+    #[test]
+    fn test_hd_wallet_slip39_seed_roundtrip() {
+        let words = "guard input oyster oyster slot doctor repair shed soon assist blame power";
+        let wallets = [
+            // a non-English mnemonic
+            HDWallet::generate_wallet(None, None, Some(MnemonicLanguage::Japanese)).unwrap(),
+            // a BIP39 password
+            HDWallet::recover_wallet(words.to_owned(), Some("password".to_owned()), None).unwrap(),
+        ];
+        let group = Slip39Group {
+            member_threshold: 2,
+            member_count: 3,
+        };
+        let coin = WalletCoin::CosmosSDK {
+            network: Network::CosmosHub,
+        };
+        for wallet in wallets {
+            let shares = wallet
+                .to_slip39_shares(1, vec![group], Some("passphrase".to_owned()), Some(0))
+                .expect("Failed to generate shares");
+            // a 64-byte seed takes 4 + 52 + 3 words
+            assert_eq!(shares[0][0].split_whitespace().count(), 59);
+
+            let recovered = HDWallet::recover_from_slip39_shares(
+                shares[0][1..].to_vec(),
+                Some("passphrase".to_owned()),
+            )
+            .expect("Failed to recover wallet");
+            assert_eq!(
+                recovered.get_default_address(coin.clone()).unwrap(),
+                wallet.get_default_address(coin.clone()).unwrap()
+            );
+            assert_eq!(recovered.get_backup_mnemonic_phrase(), None);
+        }
+    }
+
+    #[test]
+    fn test_invalid_sharing_parameters() {
+        let wallet = HDWallet::generate_wallet(None, None, None).unwrap();
+        let group = Slip39Group {
+            member_threshold: 1,
+            member_count: 2,
+        };
+        assert!(matches!(
+            wallet.to_slip39_shares(1, vec![group], None, None),
+            Err(Slip39Error::InvalidParameters(_))
+        ));
+        let group = Slip39Group {
+            member_threshold: 2,
+            member_count: 3,
+        };
+        assert!(matches!(
+            wallet.to_slip39_shares(2, vec![group], None, None),
+            Err(Slip39Error::InvalidParameters(_))
+        ));
+    }
+}
@@ -1,6 +1,6 @@
 #![cfg(target_arch = "wasm32")]
 
-use crate::{HdWrapError, SecretKeyWrapError};
+use crate::{HdWrapError, KeystoreError, SecretKeyWrapError, Slip39Error};
 use wasm_bindgen::JsValue;
 
 impl From<HdWrapError> for JsValue {
@@ -14,3 +14,15 @@ impl From<SecretKeyWrapError> for JsValue {
         JsValue::from_str(&format!("error: {error}"))
     }
 }
+
+impl From<KeystoreError> for JsValue {
+    fn from(error: KeystoreError) -> Self {
+        JsValue::from_str(&format!("error: {error}"))
+    }
+}
+
+impl From<Slip39Error> for JsValue {
+    fn from(error: Slip39Error) -> Self {
+        JsValue::from_str(&format!("error: {error}"))
+    }
+}
@@ -0,0 +1,248 @@
+use super::signer::public_key_to_eth_address;
+use super::{HdWrapError, KeyScheme, WalletCoin, WalletCoinFunc};
+use crate::get_bitcoin_address;
+use cosmrs::bip32::secp256k1::ecdsa::VerifyingKey;
+use cosmrs::bip32::{self, ChildNumber, ExtendedKey, Prefix, XPub};
+use cosmrs::crypto::PublicKey;
+use ethers::utils::hex::ToHex;
+use std::str::FromStr;
+
+/// the external (receiving) chain of a BIP44 account
+const EXTERNAL_CHAIN: u32 = 0;
+
+/// wallet that derives addresses from an account-level extended public key
+/// (e.g. exported by `HDWallet::get_account_extended_public_key`) without holding the seed
+/// only non-hardened children of the external chain (.../0/index) can be derived
+pub struct WatchOnlyWallet {
+    xpub: XPub,
+    prefix: Prefix,
+    coin: WalletCoin,
+}
+
+impl WatchOnlyWallet {
+    /// constructs a watch-only wallet from an extended public key (xpub, tpub, ...)
+    pub fn new(extended_public_key: String, coin: WalletCoin) -> Result<Self, HdWrapError> {
+        if WalletCoinFunc::new(coin.clone()).get_key_scheme() != KeyScheme::Secp256k1 {
+            return Err(HdWrapError::UnsupportedKeyScheme);
+        }
+        let extended_key =
+            ExtendedKey::from_str(&extended_public_key).map_err(HdWrapError::HDErrorBip32)?;
+        let prefix = extended_key.prefix;
+        if !prefix.is_public() {
+            return Err(HdWrapError::InvalidPublicKey);
+        }
+        let xpub = XPub::try_from(extended_key).map_err(HdWrapError::HDErrorBip32)?;
+        Ok(Self { xpub, prefix, coin })
+    }
+
+    /// returns the extended public key of the wallet
+    pub fn get_extended_public_key(&self) -> String {
+        self.xpub.to_string(self.prefix)
+    }
+
+    /// returns the compressed public key bytes for a given index
+    pub fn get_public_key_bytes(&self, index: u32) -> Result<Vec<u8>, HdWrapError> {
+        let public_key = self.derive_public_key(index)?;
+        Ok(bip32::PublicKey::to_bytes(&public_key).to_vec())
+    }
+
+    /// returns the address for a given index
+    pub fn get_address(&self, index: u32) -> Result<String, HdWrapError> {
+        let public_key = self.derive_public_key(index)?;
+        match &self.coin {
+            WalletCoin::CosmosSDK { network } => PublicKey::from(public_key)
+                .account_id(network.get_bech32_hrp())
+                .map(|x| x.to_string())
+                .map_err(HdWrapError::AccountId),
+            WalletCoin::Ethereum { .. } => {
+                let address = public_key_to_eth_address(&bip32::PublicKey::to_bytes(&public_key))?;
+                let address_hex: String = address.encode_hex();
+                Ok(format!("0x{}", address_hex))
+            }
+            WalletCoin::Bitcoin {
+                network,
+                address_type,
+            } => get_bitcoin_address(
+                &bip32::PublicKey::to_bytes(&public_key),
+                *network,
+                *address_type,
+            )
+            .map_err(|_| HdWrapError::InvalidPublicKey),
+            WalletCoin::Solana | WalletCoin::Aptos => Err(HdWrapError::UnsupportedKeyScheme),
+        }
+    }
+
+    /// returns the default address of the wallet
+    pub fn get_default_address(&self) -> Result<String, HdWrapError> {
+        self.get_address(0)
+    }
+
+    fn derive_public_key(&self, index: u32) -> Result<VerifyingKey, HdWrapError> {
+        let external =
+            ChildNumber::new(EXTERNAL_CHAIN, false).map_err(HdWrapError::HDErrorBip32)?;
+        let child = ChildNumber::new(index, false).map_err(HdWrapError::HDErrorBip32)?;
+        let xpub = self
+            .xpub
+            .derive_child(external)
+            .and_then(|xpub| xpub.derive_child(child))
+            .map_err(HdWrapError::HDErrorBip32)?;
+        Ok(*xpub.public_key())
+    }
+}
+
+#[cfg(test)]
+mod watch_only_tests {
+    use super::*;
+    use crate::{BitcoinAddressType, BitcoinNetwork, EthNetwork, HDWallet, Network};
+    use secrecy::SecretVec;
+
+    const WORDS: &str = "guard input oyster oyster slot doctor repair shed soon assist blame power";
+
+    #[test]
+    fn test_extended_keys_from_bip32_test_vector() {
+        let seed = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+        let wallet = HDWallet {
+            seed: SecretVec::new(seed),
+            mnemonic: None,
+        };
+        assert_eq!(
+            wallet.get_extended_private_key("m/0'".to_owned()).unwrap(),
+            "xprv9uHRZZhk6KAJC1avXpDAp4MDc3sQKNxDiPvvkX8Br5ngLNv1TxvUxt4cV1rGL5hj6KCesnDYUhd7oWgT11eZG7XnxHrnYeSvkzY7d2bhkJ7"
+        );
+        assert_eq!(
+            wallet.get_extended_public_key("m/0'".to_owned()).unwrap(),
+            "xpub68Gmy5EdvgibQVfPdqkBBCHxA5htiqg55crXYuXoQRKfDBFA1WEjWgP6LHhwBZeNK1VTsfTFUHCdrfp1bgwQ9xv5ski8PX9rL2dZXvgGDnw"
+        );
+        assert_eq!(
+            wallet.get_extended_public_key("m/0'/1".to_owned()).unwrap(),
+            "xpub6ASuArnXKPbfEwhqN6e3mwBcDTgzisQN1wXN9BJcM47sSikHjJf3UFHKkNAWbWMiGj7Wf5uMash7SyYq527Hqck2AxYysAA7xmALppuCkwQ"
+        );
+    }
+
+    #[test]
+    fn test_extended_key_prefixes() {
+        // BIP84 test vector
+        let wallet = HDWallet::recover_wallet(
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about".to_owned(),
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            wallet.get_extended_private_key("m/84'/0'/0'".to_owned()).unwrap(),
+            "zprvAdG4iTXWBoARxkkzNpNh8r6Qag3irQB8PzEMkAFeTRXxHpbF9z4QgEvBRmfvqWvGp42t42nvgGpNgYSJA9iefm1yYNZKEm7z6qUWCroSQnE"
+        );
+        assert_eq!(
+            wallet.get_extended_public_key("m/84'/0'/0'".to_owned()).unwrap(),
+            "zpub6rFR7y4Q2AijBEqTUquhVz398htDFrtymD9xYYfG1m4wAcvPhXNfE3EfH1r1ADqtfSdVCToUG868RvUUkgDKf31mGDtKsAYz2oz2AGutZYs"
+        );
+
+        let prefixes = [
+            ("m/44'/0'/0'", "xpub"),
+            ("m/49'/0'/0'", "ypub"),
+            ("m/86'/0'/0'", "xpub"),
+            ("m/44'/1'/0'", "tpub"),
+            ("m/49'/1'/0'", "upub"),
+            ("m/84'/1'/0'", "vpub"),
+        ];
+        for (path, prefix) in prefixes {
+            let xpub = wallet.get_extended_public_key(path.to_owned()).unwrap();
+            assert!(xpub.starts_with(prefix));
+        }
+
+        for (network, address_type) in [
+            (BitcoinNetwork::Mainnet, BitcoinAddressType::P2shP2wpkh),
+            (BitcoinNetwork::Testnet, BitcoinAddressType::P2wpkh),
+        ] {
+            let coin = WalletCoin::Bitcoin {
+                network,
+                address_type,
+            };
+            let xpub = wallet
+                .get_account_extended_public_key(coin.clone(), 0)
+                .unwrap();
+            let watch_only = WatchOnlyWallet::new(xpub.clone(), coin.clone()).unwrap();
+            assert_eq!(watch_only.get_extended_public_key(), xpub);
+            assert_eq!(
+                watch_only.get_default_address().unwrap(),
+                wallet.get_default_address(coin).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_watch_only_wallet_matches_hd_wallet() {
+        let wallet = HDWallet::recover_wallet(WORDS.to_owned(), None, None).unwrap();
+        let coins = [
+            WalletCoin::CosmosSDK {
+                network: Network::CryptoOrgMainnet,
+            },
+            WalletCoin::Ethereum {
+                network: EthNetwork::Mainnet,
+            },
+        ];
+        for coin in coins {
+            let xpub = wallet
+                .get_account_extended_public_key(coin.clone(), 0)
+                .expect("Failed to get xpub");
+            assert!(xpub.starts_with("xpub"));
+            let watch_only = WatchOnlyWallet::new(xpub.clone(), coin.clone()).unwrap();
+            assert_eq!(watch_only.get_extended_public_key(), xpub);
+            for index in 0..3 {
+                assert_eq!(
+                    watch_only.get_address(index).unwrap(),
+                    wallet.get_address(coin.clone(), index).unwrap()
+                );
+                assert_eq!(
+                    watch_only.get_public_key_bytes(index).unwrap(),
+                    wallet
+                        .get_key_from_index(coin.clone(), index)
+                        .unwrap()
+                        .get_public_key_bytes()
+                );
+            }
+        }
+
+        let watch_only = WatchOnlyWallet::new(
+            wallet
+                .get_account_extended_public_key(
+                    WalletCoin::Ethereum {
+                        network: EthNetwork::Mainnet,
+                    },
+                    0,
+                )
+                .unwrap(),
+            WalletCoin::Ethereum {
+                network: EthNetwork::Mainnet,
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            watch_only.get_default_address().unwrap(),
+            "0xda25e7a4b1bda34e303e6d7f22abef78ce9a55db"
+        );
+    }
+
+    #[test]
+    fn test_watch_only_wallet_rejects_invalid_keys() {
+        let wallet = HDWallet::recover_wallet(WORDS.to_owned(), None, None).unwrap();
+        let coin = WalletCoin::CosmosSDK {
+            network: Network::CosmosHub,
+        };
+        let xprv = wallet
+            .get_account_extended_private_key(coin.clone(), 0)
+            .unwrap();
+        assert!(matches!(
+            WatchOnlyWallet::new(xprv, coin.clone()),
+            Err(HdWrapError::InvalidPublicKey)
+        ));
+        assert!(WatchOnlyWallet::new("xpub".to_owned(), coin.clone()).is_err());
+
+        let xpub = wallet
+            .get_account_extended_public_key(coin.clone(), 0)
+            .unwrap();
+        let watch_only = WatchOnlyWallet::new(xpub, coin).unwrap();
+        // hardened indices cannot be derived from a public key
+        assert!(watch_only.get_address(1 << 31).is_err());
+    }
+}
@@ -22,7 +22,7 @@ class MainActivity : AppCompatActivity() {
             1UL,
             1UL,
             100UL,
-            SingleCoin.Atom(100UL),
+            listOf(SingleCoin.Atom(100UL)),
             9000U,
             "memo",
             Network.CosmosHub
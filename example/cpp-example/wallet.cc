@@ -20,15 +20,16 @@ void test_wallet_restore(String password) {
 }
 
 void test_wallet_generatemnemonics(String passowrd) {
-  rust::String mymnemonics =
-      generate_mnemonics("", MnemonicWordCount::TwentyFour);
+  rust::String mymnemonics = generate_mnemonics(
+      "", MnemonicWordCount::TwentyFour, MnemonicLanguage::English);
   int index = 0;
   Box<Wallet> mywallet = restore_wallet(mymnemonics, "");
   rust::String backupmnemonics = mywallet->get_backup_mnemonic_phrase();
   assert(mymnemonics == backupmnemonics);
 }
 void test_wallet_new(String password) {
-  Box<Wallet> mywallet = new_wallet("", MnemonicWordCount::Twelve);
+  Box<Wallet> mywallet =
+      new_wallet("", MnemonicWordCount::Twelve, MnemonicLanguage::English);
   int index = 0;
   rust::String mymnemonics = mywallet->get_backup_mnemonic_phrase();
   Box<Wallet> mywallet2 = restore_wallet(mymnemonics, "");
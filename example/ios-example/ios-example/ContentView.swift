@@ -11,7 +11,7 @@ struct ContentView: View {
         Text("address: " + address!)
         var priv = try? wallet?.getKey(derivationPath: "m/44'/118'/0'/0/0")
 
-        var txinfo = CosmosSdkTxInfo.init(accountNumber: 1, sequenceNumber: 1, gasLimit: 100, feeAmount: SingleCoin.atom(amount: 100), timeoutHeight: 9000, memoNote: "memo", network: Network.cosmosHub)
+        var txinfo = CosmosSdkTxInfo.init(accountNumber: 1, sequenceNumber: 1, gasLimit: 100, feeAmount: [SingleCoin.atom(amount: 100)], timeoutHeight: 9000, memoNote: "memo", network: Network.cosmosHub)
         
         var siged_tx = try? buildSignedSingleMsgTx(txInfo: txinfo, msg: CosmosSdkMsg.bankSend(recipientAddress: "cosmos1rw2cc4hj6ahfk87pqh9znjzgmqwq8ec8nzt0e9", amount: SingleCoin.atom(amount: 20)), secretKey: priv!)
         
@@ -27,7 +27,7 @@ class ExampleInstrumentedTest {
             1UL,
             0UL,
             100000UL,
-            SingleCoin.Atom(1UL),
+            listOf(SingleCoin.Atom(1UL)),
             9001U,
             "",
             Network.CosmosHub
@@ -427,6 +427,7 @@ class ExampleInstrumentedTest {
                 SingleCoin.Other("100000000", "basetcro"),
                 Height(0UL, 0UL),
                 1645800000000000000UL,
+                null,
             )
         )
         sigedTx = buildSignedMsgTx(
@@ -6,7 +6,7 @@ class dwclibTests: XCTestCase {
     
     func txinfo1() -> CosmosSdkTxInfo {
         var txinfo = CosmosSdkTxInfo(
-            accountNumber: 1, sequenceNumber: 0, gasLimit: 100000, feeAmount: SingleCoin.atom(amount: 1), timeoutHeight: 9001, memoNote: "", network: Network.cosmosHub
+            accountNumber: 1, sequenceNumber: 0, gasLimit: 100000, feeAmount: [SingleCoin.atom(amount: 1)], timeoutHeight: 9001, memoNote: "", network: Network.cosmosHub
         )
         return txinfo
     }
@@ -238,7 +238,7 @@ class dwclibTests: XCTestCase {
         
         // ibc transfer transaction
         msgList = [
-            CosmosSdkMsg.ibcTransfer(receiver: "cosmos19dyl0uyzes4k23lscla02n06fc22h4uqsdwq6z", sourcePort: "transfer", sourceChannel: "channel-3", token: SingleCoin.other(amount: "100000000", denom: "basetcro"), timeoutHeight: Height.init(revisionNumber: 0, revisionHeight: 0), timeoutTimestamp: 1645800000000000000)
+            CosmosSdkMsg.ibcTransfer(receiver: "cosmos19dyl0uyzes4k23lscla02n06fc22h4uqsdwq6z", sourcePort: "transfer", sourceChannel: "channel-3", token: SingleCoin.other(amount: "100000000", denom: "basetcro"), timeoutHeight: Height.init(revisionNumber: 0, revisionHeight: 0), timeoutTimestamp: 1645800000000000000, memo: nil)
         ]
         sigedTx = try? buildSignedMsgTx(txInfo: txinfo1(), msgs: msgList, secretKey: priv!)
         sigedTxHex = bytesToHex(data: sigedTx!)
@@ -354,7 +354,7 @@ class dwclibTests: XCTestCase {
         )
         
         // personal sign
-        signature = ethSigner.personalSign(message: "Example `personal_sign` message")
+        signature = try? ethSigner.personalSign(message: "Example `personal_sign` message")
         assert(
             signature ==
             "0x1490cd65cdfd5145a2b4e4e562b8c78008cb374ac36b2bbcd6b65dbcc14d31c453c705c4399e745fbf22ccd3939754ff2e4bbbe13a7dacae8a44aeb95f6e68c81b"
@@ -64,6 +64,13 @@ pub const LUNA_CLASSIC_VERSION: &str = include_str!("prost/LUNA_CLASSIC_COMMIT")
 
 /// luna_classic protobuf definitions.
 pub mod luna_classic {
+    /// vesting
+    pub mod vesting {
+        pub mod v1beta1 {
+            include!("prost/terra.vesting.v1beta1.rs");
+        }
+    }
+
     /// wasm
     pub mod wasm {
         pub mod v1beta1 {
@@ -75,3 +82,7 @@ pub mod luna_classic {
 impl TypeUrl for luna_classic::wasm::v1beta1::MsgExecuteContract {
     const TYPE_URL: &'static str = "terra.wasm.v1beta1.MsgExecuteContract";
 }
+
+impl TypeUrl for luna_classic::vesting::v1beta1::LazyGradedVestingAccount {
+    const TYPE_URL: &'static str = "/terra.vesting.v1beta1.LazyGradedVestingAccount";
+}